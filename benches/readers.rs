@@ -0,0 +1,93 @@
+//! Accessor latency for the different `CpuIdReader` implementations.
+//!
+//! Run with `cargo bench --features alloc`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use raw_cpuid::{CachedCpuIdReader, CpuId, CpuIdReader, CpuIdReaderNative, CpuIdSnapshot};
+
+fn accessors<R: CpuIdReader>(c: &mut Criterion, name: &str, cpuid: &CpuId<R>) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("has_avx2", |b| {
+        b.iter(|| {
+            black_box(cpuid)
+                .get_extended_feature_info()
+                .is_some_and(|f| f.has_avx2())
+        })
+    });
+    group.bench_function("cpu_capabilities", |b| {
+        b.iter(|| black_box(cpuid).get_cpu_capabilities())
+    });
+    group.bench_function("cache_parameters", |b| {
+        b.iter(|| {
+            black_box(cpuid)
+                .get_cache_parameters()
+                .map_or(0, |iter| iter.count())
+        })
+    });
+    group.bench_function("brand_string", |b| {
+        b.iter(|| black_box(cpuid).get_processor_brand_string())
+    });
+    group.bench_function("frequency", |b| b.iter(|| black_box(cpuid).frequency()));
+    group.finish();
+}
+
+fn native(c: &mut Criterion) {
+    c.bench_function("native/construct", |b| b.iter(CpuId::new));
+    accessors(c, "native", &CpuId::new());
+}
+
+fn cached(c: &mut Criterion) {
+    let reader = CachedCpuIdReader::new(CpuIdReaderNative);
+    c.bench_function("cached/construct", |b| {
+        b.iter(|| CpuId::with_cpuid_reader(&reader))
+    });
+    accessors(c, "cached", &CpuId::with_cpuid_reader(&reader));
+}
+
+fn snapshot(c: &mut Criterion) {
+    let cpuid = CpuId::new();
+    c.bench_function("snapshot/construct", |b| b.iter(|| cpuid.snapshot()));
+    let snapshot = cpuid.snapshot();
+    c.bench_function("snapshot/from_entries", |b| {
+        b.iter(|| {
+            black_box(snapshot.entries())
+                .iter()
+                .copied()
+                .collect::<CpuIdSnapshot>()
+        })
+    });
+    accessors(c, "snapshot", &snapshot.cpuid());
+}
+
+fn snapshot_clone(c: &mut Criterion) {
+    let snapshot = CpuId::new().snapshot();
+    // The entries in a `Vec`, like `CpuIdSnapshot` stored them before they
+    // were shared through an `Arc`
+    let entries = snapshot.entries().to_vec();
+    let mut group = c.benchmark_group("snapshot/clone");
+    group.bench_function("arc", |b| b.iter(|| black_box(&snapshot).clone()));
+    group.bench_function("vec", |b| b.iter(|| black_box(&entries).clone()));
+    group.finish();
+}
+
+fn snapshot_diff(c: &mut Criterion) {
+    let snapshot = CpuId::new().snapshot();
+    let sanitized = snapshot.sanitize();
+    let mut group = c.benchmark_group("snapshot/diff");
+    group.bench_function("same", |b| {
+        b.iter(|| black_box(&snapshot).diff(black_box(&snapshot)))
+    });
+    group.bench_function("sanitized", |b| {
+        b.iter(|| black_box(&snapshot).diff(black_box(&sanitized)))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    native,
+    cached,
+    snapshot,
+    snapshot_clone,
+    snapshot_diff
+);
+criterion_main!(benches);
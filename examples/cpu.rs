@@ -65,7 +65,7 @@ fn main() {
     );
 
     let mut features = Vec::with_capacity(80);
-    cpuid.get_feature_info().map(|finfo| {
+    if let Some(finfo) = cpuid.get_feature_info() {
         if finfo.has_sse3() {
             features.push("sse3")
         }
@@ -237,9 +237,9 @@ fn main() {
         if finfo.has_pbe() {
             features.push("pbe")
         }
-    });
+    }
 
-    cpuid.get_extended_feature_info().map(|finfo| {
+    if let Some(finfo) = cpuid.get_extended_feature_info() {
         if finfo.has_bmi1() {
             features.push("bmi1")
         }
@@ -345,7 +345,7 @@ fn main() {
         if finfo.has_sgx_lc() {
             features.push("sgx_lc")
         }
-    });
+    }
 
     println!("CPU Features: {}", features.join(" "));
 }
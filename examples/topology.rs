@@ -93,9 +93,9 @@ fn enumerate_with_x2apic_ids() {
     let mut all_x2apic_ids: Vec<u32> = gather_all_x2apic_ids();
     all_x2apic_ids.sort_unstable();
     for x2apic_id in all_x2apic_ids {
-        let smt_select_mask = !(u32::max_value() << smt_x2apic_shift);
-        let core_select_mask = (!((u32::max_value()) << core_x2apic_shift)) ^ smt_select_mask;
-        let pkg_select_mask = u32::max_value() << core_x2apic_shift;
+        let smt_select_mask = !(u32::MAX << smt_x2apic_shift);
+        let core_select_mask = (!((u32::MAX) << core_x2apic_shift)) ^ smt_select_mask;
+        let pkg_select_mask = u32::MAX << core_x2apic_shift;
 
         let smt_id = x2apic_id & smt_select_mask;
         let core_id = (x2apic_id & core_select_mask) >> smt_x2apic_shift;
@@ -130,7 +130,7 @@ fn get_processor_limits() -> (u8, u8) {
 
         return (
             max_logical_processor_ids.try_into().unwrap(),
-            smt_max_cores_for_package.try_into().unwrap(),
+            smt_max_cores_for_package,
         );
     }
     // This is for Intel processors:
@@ -146,10 +146,7 @@ fn get_processor_limits() -> (u8, u8) {
             }
         }
 
-        return (
-            max_logical_processor_ids as u8,
-            smt_max_cores_for_package as u8,
-        );
+        return (max_logical_processor_ids, smt_max_cores_for_package);
     }
 
     unreachable!("Example doesn't support this CPU")
@@ -161,11 +158,11 @@ fn enumerate_with_xapic_ids() {
     let smt_mask_width: u8 = cpuid_bits_needed(
         (max_logical_processor_ids.next_power_of_two() / smt_max_cores_for_package) - 1,
     );
-    let smt_select_mask: u8 = !(u8::max_value() << smt_mask_width);
+    let smt_select_mask: u8 = !(u8::MAX << smt_mask_width);
     let core_mask_width: u8 = cpuid_bits_needed(smt_max_cores_for_package - 1);
     let core_only_select_mask =
-        (!(u8::max_value() << (core_mask_width + smt_mask_width))) ^ smt_select_mask;
-    let pkg_select_mask = u8::max_value() << (core_mask_width + smt_mask_width);
+        (!(u8::MAX << (core_mask_width + smt_mask_width))) ^ smt_select_mask;
+    let pkg_select_mask = u8::MAX << (core_mask_width + smt_mask_width);
 
     println!("Enumeration of all cores in the system (with APIC IDs):");
     let mut all_xapic_ids: Vec<u8> = gather_all_xapic_ids();
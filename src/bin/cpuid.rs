@@ -1,10 +1,16 @@
 //! The cpuid binary accompanying the library.
 //!
 //! The cpuid binary only compiles/runs on x86 platforms.
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use clap::{Parser, ValueEnum};
-use raw_cpuid::{CpuId, CpuIdReaderNative};
+use clap::{Parser, Subcommand, ValueEnum};
+use raw_cpuid::{CpuId, CpuIdReaderNative, CpuIdResult, CpuIdSnapshot};
+
+#[cfg(feature = "tui")]
+#[path = "cpuid/tui.rs"]
+mod tui;
 
 #[derive(ValueEnum, Clone)]
 enum OutputFormat {
@@ -12,6 +18,10 @@ enum OutputFormat {
     Raw,
     #[value(alias("cli"))]
     Cli,
+    #[value(alias("env"))]
+    Env,
+    #[value(alias("jsonl"))]
+    Jsonl,
 }
 
 impl FromStr for OutputFormat {
@@ -21,6 +31,8 @@ impl FromStr for OutputFormat {
         match s {
             "raw" => Ok(OutputFormat::Raw),
             "cli" => Ok(OutputFormat::Cli),
+            "env" => Ok(OutputFormat::Env),
+            "jsonl" => Ok(OutputFormat::Jsonl),
             _ => Err("no match"),
         }
     }
@@ -31,18 +43,191 @@ impl FromStr for OutputFormat {
 #[clap(version = "10.2", author = "Gerd Zellweger <mail@gerdzellweger.com>")]
 #[clap(disable_colored_help(true))]
 struct Opts {
-    /// Configures the output format.
+    /// Configures the output format (`env` prints `CPUID_<KEY>=<value>` lines for `eval`,
+    /// `jsonl` streams one JSON object per leaf).
     #[clap(short, long, default_value = "cli")]
     format: OutputFormat,
+
+    /// Decode a dump (`raw` output format or JSON) instead of the current CPU, `-` reads
+    /// it from stdin.
+    #[clap(long)]
+    file: Option<PathBuf>,
+
+    /// Decode a dump read from stdin, same as `--file -`.
+    #[clap(long, conflicts_with = "file")]
+    stdin: bool,
+
+    #[clap(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Report differences between the CPUID values of all logical CPUs (e.g., a
+    /// feature disabled on some cores, or hybrid core types).
+    ///
+    /// Exits with status 2 if the CPUs differ.
+    CompareCpus {
+        /// Compare these dumps (`raw` output format or JSON) instead of the CPUs
+        /// of this system.
+        dumps: Vec<PathBuf>,
+    },
+    /// Print Rust code that rebuilds the CPUID values (of `--file`, or this CPU) as a
+    /// `CpuIdSnapshot`, with one line per known feature flag.
+    Codegen {
+        /// Name of the generated function.
+        #[clap(long, default_value = "cpu_profile")]
+        name: String,
+    },
+    /// Browse the leafs interactively: expand sub-leafs and toggle between the raw
+    /// and decoded view.
+    #[cfg(feature = "tui")]
+    Tui,
+}
+
+/// Parse a dump in the `raw` output format or as JSON.
+///
+/// JSON dumps are either an object of `CpuIdResult`s keyed by `leaf << 32 | subleaf`
+/// (the format used by the `serialize_deserialize` example) or a list of
+/// `[leaf, subleaf, CpuIdResult]` entries.
+fn parse_dump(dump: &str) -> Result<CpuIdSnapshot, String> {
+    match dump.trim_start().chars().next() {
+        Some('{') => serde_json::from_str::<HashMap<u64, CpuIdResult>>(dump)
+            .map(|entries| {
+                entries
+                    .into_iter()
+                    .map(|(key, res)| ((key >> 32) as u32, key as u32, res))
+                    .collect()
+            })
+            .map_err(|e| e.to_string()),
+        Some('[') => serde_json::from_str::<Vec<(u32, u32, CpuIdResult)>>(dump)
+            .map(|entries| entries.into_iter().collect())
+            .map_err(|e| e.to_string()),
+        _ => parse_raw(dump),
+    }
+}
+
+/// Parse lines like `(0x1, 0x0) => CpuIdResult { eax: 0x906a3, ebx: .., ecx: .., edx: .. }`.
+fn parse_raw(dump: &str) -> Result<CpuIdSnapshot, String> {
+    dump.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim().starts_with('#'))
+        .map(|(idx, line)| {
+            let numbers: Vec<u32> = line
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .filter_map(|word| word.strip_prefix("0x"))
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .collect::<Result<_, _>>()
+                .map_err(|e| format!("line {}: {}", idx + 1, e))?;
+            match numbers[..] {
+                [leaf, subleaf, eax, ebx, ecx, edx] => {
+                    Ok((leaf, subleaf, CpuIdResult { eax, ebx, ecx, edx }))
+                }
+                _ => Err(format!("line {}: expected 6 hex values", idx + 1)),
+            }
+        })
+        .collect()
+}
+
+/// Read and parse the dump at `path`, `-` reads it from stdin.
+fn read_dump(path: &Path) -> CpuIdSnapshot {
+    let (name, dump) = if path.as_os_str() != "-" {
+        (path.display().to_string(), std::fs::read_to_string(path))
+    } else {
+        (
+            String::from("stdin"),
+            std::io::read_to_string(std::io::stdin()),
+        )
+    };
+    dump.map_err(|e| e.to_string())
+        .and_then(|dump| parse_dump(&dump))
+        .unwrap_or_else(|e| {
+            eprintln!("Can't read {}: {}", name, e);
+            std::process::exit(1);
+        })
+}
+
+/// Snapshot every logical CPU this process may run on.
+fn snapshot_cpus() -> Vec<(String, CpuIdSnapshot)> {
+    let core_ids = core_affinity::get_core_ids().unwrap_or_else(|| {
+        eprintln!("Can't determine the CPUs of this system");
+        std::process::exit(1);
+    });
+    core_ids
+        .into_iter()
+        .map(|id| {
+            let snapshot = std::thread::spawn(move || {
+                if !core_affinity::set_for_current(id) {
+                    eprintln!("Can't run on CPU {}", id.id);
+                    std::process::exit(1);
+                }
+                CpuId::new().snapshot()
+            })
+            .join()
+            .expect("Snapshot thread panicked");
+            (format!("CPU {}", id.id), snapshot)
+        })
+        .collect()
 }
 
 fn main() {
     let opts: Opts = Opts::parse();
+
+    if let Some(Command::CompareCpus { dumps }) = opts.command {
+        let cpus = if dumps.is_empty() {
+            snapshot_cpus()
+        } else {
+            dumps
+                .iter()
+                .map(|path| (path.display().to_string(), read_dump(path)))
+                .collect()
+        };
+        if raw_cpuid::display::compare(&cpus) {
+            std::process::exit(2);
+        }
+        return;
+    }
+
+    let path = match (opts.file, opts.stdin) {
+        (Some(path), _) => Some(path),
+        (None, true) => Some(PathBuf::from("-")),
+        (None, false) => None,
+    };
+
+    if let Some(Command::Codegen { name }) = &opts.command {
+        let snapshot = path.map_or_else(|| CpuId::new().snapshot(), |path| read_dump(&path));
+        print!("{}", raw_cpuid::codegen::rust_code(&snapshot, name));
+        return;
+    }
+
+    #[cfg(feature = "tui")]
+    if let Some(Command::Tui) = opts.command {
+        let snapshot = path.map_or_else(|| CpuId::new().snapshot(), |path| read_dump(&path));
+        if let Err(e) = tui::run(&snapshot) {
+            eprintln!("Can't run the browser: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(path) = path {
+        let snapshot = read_dump(&path);
+        match opts.format {
+            OutputFormat::Raw => raw_cpuid::display::raw(&snapshot),
+            OutputFormat::Cli => raw_cpuid::display::markdown(snapshot.cpuid()),
+            OutputFormat::Env => raw_cpuid::display::env(snapshot.cpuid()),
+            OutputFormat::Jsonl => raw_cpuid::display::jsonl(&snapshot),
+        };
+        return;
+    }
+
     match opts.format {
         OutputFormat::Raw => raw_cpuid::display::raw(CpuIdReaderNative),
         OutputFormat::Cli => {
             let cpuid = CpuId::new();
             raw_cpuid::display::markdown(cpuid);
         }
+        OutputFormat::Env => raw_cpuid::display::env(CpuId::new()),
+        OutputFormat::Jsonl => raw_cpuid::display::jsonl(CpuIdReaderNative),
     };
 }
@@ -0,0 +1,336 @@
+//! Interactive browser for the leafs of a dump (`cpuid tui`).
+//!
+//! Every leaf is a line that can be expanded to show its sub-leafs (raw view) or
+//! what the library decodes from it (decoded view).
+use std::io::{self, Write};
+
+use raw_cpuid::{CpuId, CpuIdResult, CpuIdSnapshot};
+use termimad::crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+const HELP: &str = "↑↓ PgUp PgDn: move  ⏎/←/→: expand  a: expand all  d: raw/decoded  q: quit";
+
+/// All sub-leafs of one leaf.
+struct Leaf {
+    leaf: u32,
+    subleafs: Vec<(u32, CpuIdResult)>,
+    /// Name and `{:#?}` output of the library type that decodes this leaf.
+    decoded: Option<(&'static str, String)>,
+}
+
+/// A displayed line, belongs to the leaf at index `leaf`.
+struct Line {
+    leaf: usize,
+    text: String,
+}
+
+struct Browser {
+    leafs: Vec<Leaf>,
+    expanded: Vec<bool>,
+    decoded: bool,
+    /// Index of the selected line.
+    selected: usize,
+    /// Index of the first line on screen.
+    top: usize,
+}
+
+/// Name and debug output of the library type that decodes `leaf`.
+fn decode(cpuid: &CpuId<&CpuIdSnapshot>, leaf: u32) -> Option<(&'static str, String)> {
+    fn debug<T: std::fmt::Debug>(name: &'static str, value: T) -> Option<(&'static str, String)> {
+        Some((name, format!("{:#?}", value)))
+    }
+
+    match leaf {
+        0x00 => debug("vendor_info", cpuid.get_vendor_info()),
+        0x01 => debug("feature_info", cpuid.get_feature_info()),
+        0x02 => debug("cache_info", cpuid.get_cache_info()),
+        0x03 => debug("processor_serial", cpuid.get_processor_serial()),
+        0x04 | 0x8000_001d => debug("cache_parameters", cpuid.get_cache_parameters()),
+        0x05 => debug("monitor_mwait_info", cpuid.get_monitor_mwait_info()),
+        0x06 => debug("thermal_power_info", cpuid.get_thermal_power_info()),
+        0x07 => debug("extended_feature_info", cpuid.get_extended_feature_info()),
+        0x09 => debug(
+            "direct_cache_access_info",
+            cpuid.get_direct_cache_access_info(),
+        ),
+        0x0a => debug(
+            "performance_monitoring_info",
+            cpuid.get_performance_monitoring_info(),
+        ),
+        0x0b => debug("extended_topology_info", cpuid.get_extended_topology_info()),
+        0x0d => debug("extended_state_info", cpuid.get_extended_state_info()),
+        0x0f => debug("rdt_monitoring_info", cpuid.get_rdt_monitoring_info()),
+        0x10 => debug("rdt_allocation_info", cpuid.get_rdt_allocation_info()),
+        0x12 => debug("sgx_info", cpuid.get_sgx_info()),
+        0x14 => debug("processor_trace_info", cpuid.get_processor_trace_info()),
+        0x15 => debug("tsc_info", cpuid.get_tsc_info()),
+        0x16 => debug(
+            "processor_frequency_info",
+            cpuid.get_processor_frequency_info(),
+        ),
+        0x17 => debug("soc_vendor_info", cpuid.get_soc_vendor_info()),
+        0x18 => debug(
+            "deterministic_address_translation_info",
+            cpuid.get_deterministic_address_translation_info(),
+        ),
+        0x1a => debug("hybrid_info", cpuid.get_hybrid_info()),
+        0x1b => debug("pconfig_info", cpuid.get_pconfig_info()),
+        0x1d => debug("tile_info", cpuid.get_tile_info()),
+        0x1e => debug("tmul_info", cpuid.get_tmul_info()),
+        0x1f => debug(
+            "extended_topology_info_v2",
+            cpuid.get_extended_topology_info_v2(),
+        ),
+        0x20 => debug("hreset_info", cpuid.get_hreset_info()),
+        0x23 => debug(
+            "performance_monitoring_ext_info",
+            cpuid.get_performance_monitoring_ext_info(),
+        ),
+        0x4000_0000..=0x4000_00ff => debug("hypervisor_info", cpuid.get_hypervisor_info()),
+        0x8000_0001 => debug(
+            "extended_processor_and_feature_identifiers",
+            cpuid.get_extended_processor_and_feature_identifiers(),
+        ),
+        0x8000_0002..=0x8000_0004 => {
+            debug("processor_brand_string", cpuid.get_processor_brand_string())
+        }
+        0x8000_0005 => debug("l1_cache_and_tlb_info", cpuid.get_l1_cache_and_tlb_info()),
+        0x8000_0006 => debug(
+            "l2_l3_cache_and_tlb_info",
+            cpuid.get_l2_l3_cache_and_tlb_info(),
+        ),
+        0x8000_0007 => debug(
+            "advanced_power_mgmt_info",
+            cpuid.get_advanced_power_mgmt_info(),
+        ),
+        0x8000_0008 => debug(
+            "processor_capacity_feature_info",
+            cpuid.get_processor_capacity_feature_info(),
+        ),
+        0x8000_000a => debug("svm_info", cpuid.get_svm_info()),
+        0x8000_0019 => debug("tlb_1gb_page_info", cpuid.get_tlb_1gb_page_info()),
+        0x8000_001a => debug(
+            "performance_optimization_info",
+            cpuid.get_performance_optimization_info(),
+        ),
+        0x8000_001e => debug(
+            "processor_topology_info",
+            cpuid.get_processor_topology_info(),
+        ),
+        0x8000_001f => debug("memory_encryption_info", cpuid.get_memory_encryption_info()),
+        0x8000_0021 => debug(
+            "extended_feature_identification_2",
+            cpuid.get_extended_feature_identification_2(),
+        ),
+        0x8000_0022 => debug("amd_perfmon_debug_info", cpuid.get_amd_perfmon_debug_info()),
+        _ => None,
+    }
+}
+
+fn registers(res: &CpuIdResult) -> String {
+    format!(
+        "eax={:#010x} ebx={:#010x} ecx={:#010x} edx={:#010x}",
+        res.eax, res.ebx, res.ecx, res.edx
+    )
+}
+
+impl Browser {
+    fn new(snapshot: &CpuIdSnapshot) -> Browser {
+        let cpuid = snapshot.cpuid();
+        let mut leafs: Vec<Leaf> = Vec::new();
+        for &(leaf, subleaf, res) in snapshot.entries() {
+            match leafs.last_mut() {
+                Some(last) if last.leaf == leaf => last.subleafs.push((subleaf, res)),
+                _ => leafs.push(Leaf {
+                    leaf,
+                    subleafs: vec![(subleaf, res)],
+                    decoded: decode(&cpuid, leaf),
+                }),
+            }
+        }
+        Browser {
+            expanded: vec![false; leafs.len()],
+            leafs,
+            decoded: false,
+            selected: 0,
+            top: 0,
+        }
+    }
+
+    /// The lines of the current view.
+    fn lines(&self) -> Vec<Line> {
+        let mut lines = Vec::new();
+        for (idx, leaf) in self.leafs.iter().enumerate() {
+            let name = leaf.decoded.as_ref().map_or("", |(name, _)| name);
+            let summary = match &leaf.subleafs[..] {
+                [(_, res)] if !self.expanded[idx] => registers(res),
+                subleafs => format!("{} sub-leafs", subleafs.len()),
+            };
+            let marker = if self.expanded[idx] { '-' } else { '+' };
+            lines.push(Line {
+                leaf: idx,
+                text: format!("{} {:#010x}  {:<28} {}", marker, leaf.leaf, name, summary),
+            });
+            if !self.expanded[idx] {
+                continue;
+            }
+
+            let details: Vec<String> = match (&leaf.decoded, self.decoded) {
+                (Some((_, decoded)), true) => decoded.lines().map(String::from).collect(),
+                (None, true) => vec![String::from("(not decoded by this library)")],
+                (_, false) => leaf
+                    .subleafs
+                    .iter()
+                    .map(|(subleaf, res)| format!("{:#010x}: {}", subleaf, registers(res)))
+                    .collect(),
+            };
+            lines.extend(details.into_iter().map(|text| Line {
+                leaf: idx,
+                text: format!("      {}", text),
+            }));
+        }
+        lines
+    }
+
+    /// Expand or collapse the leaf of the selected line.
+    fn toggle(&mut self, lines: &[Line], expand: Option<bool>) {
+        if let Some(line) = lines.get(self.selected) {
+            let leaf = line.leaf;
+            self.expanded[leaf] = expand.unwrap_or(!self.expanded[leaf]);
+            self.select_leaf(leaf);
+        }
+    }
+
+    /// Select the header line of the leaf at index `leaf` (its position changes
+    /// when leafs are expanded or collapsed).
+    fn select_leaf(&mut self, leaf: usize) {
+        self.selected = self
+            .lines()
+            .iter()
+            .position(|line| line.leaf == leaf)
+            .unwrap_or(0);
+    }
+
+    fn draw(&mut self, out: &mut impl Write, lines: &[Line]) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, rows) = (width as usize, height.saturating_sub(1) as usize);
+
+        // Scroll so the selection is on screen
+        if self.selected < self.top {
+            self.top = self.selected;
+        } else if rows > 0 && self.selected >= self.top + rows {
+            self.top = self.selected + 1 - rows;
+        }
+
+        queue!(out, terminal::Clear(ClearType::All))?;
+        for (row, (idx, line)) in lines
+            .iter()
+            .enumerate()
+            .skip(self.top)
+            .take(rows)
+            .enumerate()
+        {
+            let text: String = line.text.chars().take(width).collect();
+            queue!(out, cursor::MoveTo(0, row as u16))?;
+            if idx == self.selected {
+                queue!(
+                    out,
+                    SetAttribute(Attribute::Reverse),
+                    Print(format!("{:<1$}", text, width)),
+                    SetAttribute(Attribute::Reset)
+                )?;
+            } else {
+                queue!(out, Print(text))?;
+            }
+        }
+
+        let view = if self.decoded { "decoded" } else { "raw" };
+        let status = format!("[{}] {}", view, HELP);
+        queue!(
+            out,
+            cursor::MoveTo(0, rows as u16),
+            SetAttribute(Attribute::Dim),
+            Print(status.chars().take(width).collect::<String>()),
+            SetAttribute(Attribute::Reset)
+        )?;
+        out.flush()
+    }
+
+    /// Handle a key, returns `false` to quit.
+    fn key(&mut self, key: KeyEvent, lines: &[Line]) -> io::Result<bool> {
+        let page = terminal::size()?.1.saturating_sub(1).max(1) as usize;
+        let last = lines.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Ok(false)
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1).min(last),
+            KeyCode::PageUp => self.selected = self.selected.saturating_sub(page),
+            KeyCode::PageDown => self.selected = (self.selected + page).min(last),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = last,
+            KeyCode::Enter | KeyCode::Char(' ') => self.toggle(lines, None),
+            KeyCode::Right | KeyCode::Char('l') => self.toggle(lines, Some(true)),
+            KeyCode::Left | KeyCode::Char('h') => self.toggle(lines, Some(false)),
+            KeyCode::Char('a') => {
+                let expand = !self.expanded.iter().all(|&expanded| expanded);
+                self.expanded
+                    .iter_mut()
+                    .for_each(|expanded| *expanded = expand);
+                if let Some(line) = lines.get(self.selected) {
+                    self.select_leaf(line.leaf);
+                }
+            }
+            KeyCode::Char('d') => self.decoded = !self.decoded,
+            _ => {}
+        }
+        Ok(true)
+    }
+}
+
+/// Restores the terminal when the browser exits (or panics).
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter(out: &mut impl Write) -> io::Result<RawTerminal> {
+        terminal::enable_raw_mode()?;
+        queue!(out, EnterAlternateScreen, cursor::Hide)?;
+        out.flush()?;
+        Ok(RawTerminal)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let mut out = io::stdout();
+        let _ = queue!(out, cursor::Show, LeaveAlternateScreen);
+        let _ = out.flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Browse `snapshot` until the user quits.
+pub fn run(snapshot: &CpuIdSnapshot) -> io::Result<()> {
+    let mut browser = Browser::new(snapshot);
+    let mut out = io::stdout();
+    let _terminal = RawTerminal::enter(&mut out)?;
+
+    loop {
+        let lines = browser.lines();
+        browser.selected = browser.selected.min(lines.len().saturating_sub(1));
+        browser.draw(&mut out, &lines)?;
+        // Resizes and other events just redraw
+        if let Event::Key(key) = event::read()? {
+            if !browser.key(key, &lines)? {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Emit `cfg` flags for the features of the build machine from a build script.
+//!
+//! Crates that specialize code for the machine they are built on (e.g., with
+//! `-C target-cpu=native` in mind) can detect the host features in `build.rs`
+//! instead of running external tools. Add `raw-cpuid` with the `build-helper` feature
+//! to `[build-dependencies]` and call [`emit_cfgs`] with the features the crate
+//! cares about:
+//!
+//! ```no_run
+//! // In `fn main` of build.rs
+//! raw_cpuid::build_helper::emit_cfgs(&["avx2", "bmi2", "avx512f"]);
+//! ```
+//!
+//! The crate can then use `#[cfg(cpu_feature_avx2)]`. Feature names are the ones of
+//! [`CpuId::features`] (case doesn't matter). Only the CPUID bits are checked, not
+//! whether the OS enables the AVX or AVX-512 register state.
+//!
+//! The flags describe the build machine, so they are only emitted when it is also
+//! the target (no cross compilation). Binaries built with them may not run on other
+//! machines.
+
+use std::string::String;
+use std::vec::Vec;
+
+use crate::{CpuId, CpuIdReader};
+
+/// Name of the `cfg` flag for `feature`, e.g., `cpu_feature_avx2` for "AVX2".
+///
+/// ASCII letters are lowercased, digits and `_` are kept and every other
+/// character becomes `_` (e.g., `cpu_feature_sse4_1` for "sse4.1"), so the
+/// result is always a valid `cfg` identifier.
+pub fn cfg_name(feature: &str) -> String {
+    let name: String = feature
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | '0'..='9' | '_' => c,
+            'A'..='Z' => c.to_ascii_lowercase(),
+            _ => '_',
+        })
+        .collect();
+    format!("cpu_feature_{}", name)
+}
+
+/// The `cfg` flags of the features in `allow` that `cpuid` reports.
+pub fn cfgs<R: CpuIdReader>(cpuid: &CpuId<R>, allow: &[&str]) -> Vec<String> {
+    let present = cpuid.features();
+    allow
+        .iter()
+        .filter(|name| present.iter().any(|p| p.eq_ignore_ascii_case(name)))
+        .map(|name| cfg_name(name))
+        .collect()
+}
+
+/// Print `cargo:rustc-cfg=cpu_feature_<name>` for every feature in `allow` the build
+/// machine supports.
+///
+/// All flags in `allow` are declared with `cargo:rustc-check-cfg`, so `cfg`s of
+/// missing features don't trigger `unexpected_cfgs` warnings.
+pub fn emit_cfgs(allow: &[&str]) {
+    for name in allow {
+        println!("cargo:rustc-check-cfg=cfg({})", cfg_name(name));
+    }
+
+    #[cfg(any(
+        all(target_arch = "x86", not(target_env = "sgx"), target_feature = "sse"),
+        all(target_arch = "x86_64", not(target_env = "sgx"))
+    ))]
+    {
+        // Cargo sets both for build scripts
+        let target = std::env::var("TARGET");
+        if target.is_ok() && target == std::env::var("HOST") {
+            for cfg in cfgs(&CpuId::new(), allow) {
+                println!("cargo:rustc-cfg={}", cfg);
+            }
+        }
+    }
+}
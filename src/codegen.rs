@@ -0,0 +1,127 @@
+//! Turn a captured [`CpuIdSnapshot`] into Rust source code that rebuilds it.
+//!
+//! VMM developers often start a guest CPU profile from a real machine. Instead of
+//! shipping an opaque dump, [`rust_code`] writes a function that returns the same
+//! snapshot, with every known feature flag on a line of its own (named after
+//! `feature_table`, with the `feature-db` feature), so hiding a feature from the
+//! guest is a one-line, reviewable change:
+//!
+//! ```text
+//! (
+//!     0x1,
+//!     0x0,
+//!     CpuIdResult {
+//!         eax: 0x000c_06f2,
+//!         ebx: 0x0001_0800,
+//!         ecx: 0x0000_0000
+//!             | 1 << 0 // SSE3
+//!             | 1 << 1, // PCLMULQDQ
+//!         ...
+//! ```
+//!
+//! Bits without an entry in the table (and all bits if the `feature-db` feature is
+//! disabled) stay in the hex constant of each register.
+
+use alloc::{format, string::String, vec, vec::Vec};
+use core::fmt::Write;
+
+use crate::{CpuIdResult, CpuIdSnapshot};
+
+/// Format `value` as hex with a `_` between the 16-bit halves.
+fn hex(value: u32) -> String {
+    format!("{:#06x}_{:04x}", value >> 16, value & 0xffff)
+}
+
+/// Lines of a register value: the bits without a name, then one line per named flag.
+#[cfg(feature = "feature-db")]
+fn register_lines(
+    leaf: u32,
+    subleaf: u32,
+    register: crate::feature_table::Register,
+    value: u32,
+) -> Vec<String> {
+    let flags: Vec<_> = (0..32)
+        .filter(|bit| value & (1 << bit) != 0)
+        .filter_map(|bit| crate::feature_table::at(leaf, subleaf, register, bit))
+        .collect();
+    let named = flags.iter().fold(0, |acc, flag| acc | 1 << flag.bit());
+
+    let mut lines = vec![hex(value & !named)];
+    lines.extend(
+        flags
+            .iter()
+            .map(|flag| format!("| 1 << {} // {}", flag.bit(), flag.name())),
+    );
+    lines
+}
+
+/// Write `name: value,` for a register, keeping the comma ahead of a trailing comment.
+fn write_register(out: &mut String, name: &str, lines: &[String]) {
+    for (idx, line) in lines.iter().enumerate() {
+        let (code, comment) = match line.find(" //") {
+            Some(pos) => line.split_at(pos),
+            None => (line.as_str(), ""),
+        };
+        let comma = if idx + 1 == lines.len() { "," } else { "" };
+        if idx == 0 {
+            let _ = writeln!(
+                out,
+                "                {}: {}{}{}",
+                name, code, comma, comment
+            );
+        } else {
+            let _ = writeln!(out, "                    {}{}{}", code, comma, comment);
+        }
+    }
+}
+
+/// Rust source of a function `fn_name` that returns `snapshot`.
+///
+/// The code only depends on `raw_cpuid` (with the `alloc` feature), paste it into a
+/// module and edit the values as needed.
+pub fn rust_code(snapshot: &CpuIdSnapshot, fn_name: &str) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "use raw_cpuid::{{CpuIdResult, CpuIdSnapshot}};");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "pub fn {}() -> CpuIdSnapshot {{", fn_name);
+    let _ = writeln!(out, "    [");
+    for &(leaf, subleaf, res) in snapshot.entries() {
+        let _ = writeln!(out, "        (");
+        let _ = writeln!(out, "            {:#x},", leaf);
+        let _ = writeln!(out, "            {:#x},", subleaf);
+        let _ = writeln!(out, "            CpuIdResult {{");
+        for (name, lines) in registers(leaf, subleaf, res).iter() {
+            write_register(&mut out, name, lines);
+        }
+        let _ = writeln!(out, "            }},");
+        let _ = writeln!(out, "        ),");
+    }
+    let _ = writeln!(out, "    ]");
+    let _ = writeln!(out, "    .iter()");
+    let _ = writeln!(out, "    .copied()");
+    let _ = writeln!(out, "    .collect()");
+    let _ = writeln!(out, "}}");
+    out
+}
+
+#[cfg(feature = "feature-db")]
+fn registers(leaf: u32, subleaf: u32, res: CpuIdResult) -> [(&'static str, Vec<String>); 4] {
+    use crate::feature_table::Register;
+
+    [
+        ("eax", register_lines(leaf, subleaf, Register::Eax, res.eax)),
+        ("ebx", register_lines(leaf, subleaf, Register::Ebx, res.ebx)),
+        ("ecx", register_lines(leaf, subleaf, Register::Ecx, res.ecx)),
+        ("edx", register_lines(leaf, subleaf, Register::Edx, res.edx)),
+    ]
+}
+
+#[cfg(not(feature = "feature-db"))]
+fn registers(_leaf: u32, _subleaf: u32, res: CpuIdResult) -> [(&'static str, Vec<String>); 4] {
+    [
+        ("eax", vec![hex(res.eax)]),
+        ("ebx", vec![hex(res.ebx)]),
+        ("ecx", vec![hex(res.ecx)]),
+        ("edx", vec![hex(res.edx)]),
+    ]
+}
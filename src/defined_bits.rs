@@ -0,0 +1,187 @@
+//! Masks of the bits that have a defined meaning in the feature leafs.
+//!
+//! [`MASKS`] lists, for the leafs that enumerate features (LEAF=0x01, LEAF=0x06,
+//! LEAF=0x07 and the AMD extended feature leafs), the bits that are documented by
+//! Intel or AMD. All other bits of these leafs are reserved. A hypervisor that
+//! passes host values through to a guest should clear them, a future processor
+//! may give them a meaning the guest then wrongly assumes is available.
+//! `CpuIdSnapshot::sanitize` (with the `alloc` feature) does this for a whole
+//! snapshot:
+//!
+//! ```
+//! use raw_cpuid::{defined_bits, CpuIdResult};
+//!
+//! let leaf1 = defined_bits::find(0x1, 0x0).unwrap();
+//! let res = leaf1.apply(CpuIdResult { eax: 0xffff_ffff, ebx: 0, ecx: 0, edx: 0 });
+//! // Bits 14, 15 and 28-31 of the version information are reserved
+//! assert_eq!(res.eax, 0x0fff_3fff);
+//! ```
+//!
+//! Leafs that are not in the table are either fully defined (e.g., the vendor and
+//! brand strings) or not covered yet.
+
+use crate::CpuIdResult;
+
+/// Defined bits of a leaf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeafMask {
+    leaf: u32,
+    subleaf: Option<u32>,
+    mask: CpuIdResult,
+}
+
+impl LeafMask {
+    /// The leaf (EAX input).
+    pub const fn leaf(&self) -> u32 {
+        self.leaf
+    }
+
+    /// The sub-leaf (ECX input), `None` if the mask applies to all sub-leafs.
+    pub const fn subleaf(&self) -> Option<u32> {
+        self.subleaf
+    }
+
+    /// The defined bits of every register.
+    pub const fn mask(&self) -> CpuIdResult {
+        self.mask
+    }
+
+    /// Is this the mask of `leaf` and `subleaf`?
+    pub const fn matches(&self, leaf: u32, subleaf: u32) -> bool {
+        self.leaf == leaf
+            && match self.subleaf {
+                Some(s) => s == subleaf,
+                None => true,
+            }
+    }
+
+    /// Clear the reserved bits of `res`.
+    pub const fn apply(&self, res: CpuIdResult) -> CpuIdResult {
+        CpuIdResult {
+            eax: res.eax & self.mask.eax,
+            ebx: res.ebx & self.mask.ebx,
+            ecx: res.ecx & self.mask.ecx,
+            edx: res.edx & self.mask.edx,
+        }
+    }
+}
+
+const fn mask(leaf: u32, subleaf: Option<u32>, eax: u32, ebx: u32, ecx: u32, edx: u32) -> LeafMask {
+    LeafMask {
+        leaf,
+        subleaf,
+        mask: CpuIdResult { eax, ebx, ecx, edx },
+    }
+}
+
+/// Defined bits of the feature leafs, sorted by leaf and sub-leaf.
+pub static MASKS: &[LeafMask] = &[
+    // Reserved: EAX[15:14, 31:28], ECX[16] and EDX[10, 20]
+    mask(
+        0x1,
+        None,
+        0x0fff_3fff,
+        0xffff_ffff,
+        0xfffe_ffff,
+        0xffef_fbff,
+    ),
+    // Reserved: EAX[3, 12, 22:21, 31:25], EBX[31:4], ECX[2:1, 7:4, 31:16] and
+    // EDX[7:2, 15:12]
+    mask(
+        0x6,
+        None,
+        0x019f_eff7,
+        0x0000_000f,
+        0x0000_ff09,
+        0xffff_0f03,
+    ),
+    // Reserved: EBX[22], ECX[15, 26] and EDX[0, 7:6, 12, 17, 21]
+    mask(
+        0x7,
+        Some(0),
+        0xffff_ffff,
+        0xffbf_ffff,
+        0xfbff_7fff,
+        0xffdd_ef3e,
+    ),
+    // Defined: EAX[8:0, 12:10, 23:17, 27:26, 30, 31], EBX[1:0], ECX[2, 4] and
+    // EDX[5:4, 8, 10, 15:14, 19:17, 21, 23]
+    mask(
+        0x7,
+        Some(1),
+        0xccfe_1dff,
+        0x0000_0003,
+        0x0000_0014,
+        0x00ae_c530,
+    ),
+    // Defined: EDX[7:0] (PSFD, IPRED_CTRL, RRSBA_CTRL, DDPD_U, BHI_CTRL, MCDT_NO,
+    // UC_LOCK_DISABLE and MONITOR_MITG_NO)
+    mask(
+        0x7,
+        Some(2),
+        0x0000_0000,
+        0x0000_0000,
+        0x0000_0000,
+        0x0000_00ff,
+    ),
+    // Reserved: EAX[15:12, 31:28], EBX[27:16], ECX[14, 18, 20, 25, 31] and
+    // EDX[10, 19:18, 21, 28]
+    mask(
+        0x8000_0001,
+        None,
+        0x0fff_0fff,
+        0xf000_ffff,
+        0x7deb_bfff,
+        0xefd3_fbff,
+    ),
+    // Reserved: EAX, EBX[31:5] and EDX[5, 31:16]
+    mask(
+        0x8000_0007,
+        None,
+        0x0000_0000,
+        0x0000_001f,
+        0xffff_ffff,
+        0x0000_ffdf,
+    ),
+    // Reserved: EAX[31:24], EBX[5, 7, 11, 22, 31], ECX[11:8, 31:18] and EDX[31:24]
+    mask(
+        0x8000_0008,
+        None,
+        0x00ff_ffff,
+        0x7fbf_f75f,
+        0x0003_f0ff,
+        0x00ff_ffff,
+    ),
+    // Reserved: EBX[31:16]
+    mask(
+        0x8000_001f,
+        None,
+        0xffff_ffff,
+        0x0000_ffff,
+        0xffff_ffff,
+        0xffff_ffff,
+    ),
+    // Defined: EAX[3:0, 11:5, 13, 18:17, 20, 24, 31:27] and EBX[11:0, 23:16]
+    mask(
+        0x8000_0021,
+        None,
+        0xf916_2fef,
+        0x00ff_0fff,
+        0x0000_0000,
+        0x0000_0000,
+    ),
+    // Reserved: EAX[31:3], EBX[31:22] and EDX
+    mask(
+        0x8000_0022,
+        None,
+        0x0000_0007,
+        0x003f_ffff,
+        0xffff_ffff,
+        0x0000_0000,
+    ),
+];
+
+/// Find the mask for `leaf` and `subleaf`.
+pub fn find(leaf: u32, subleaf: u32) -> Option<&'static LeafMask> {
+    MASKS.iter().find(|mask| mask.matches(leaf, subleaf))
+}
@@ -1,30 +1,186 @@
 use std::fmt::Display;
+use std::io::Write;
 
 use crate::{
-    Associativity, CacheType, CpuIdResult, DatType, ExtendedRegisterStateLocation, SgxSectionInfo,
-    SoCVendorBrand, TopologyType,
+    Associativity, CacheType, CoreType, CpuIdResult, DatType, ExtendedRegisterStateLocation,
+    Frequency, ProcessorType, SgxSectionInfo, SoCVendorBrand, TopologyType,
 };
 
 use termimad::{minimad::TextTemplate, minimad::TextTemplateExpander, MadSkin};
 
+/// Read the basic, hypervisor and extended leafs (sub-leaf 0) one after the other.
+fn leafs<R: crate::CpuIdReader>(cpuid: &R) -> impl Iterator<Item = (u32, u32, CpuIdResult)> + '_ {
+    let max_leafs = cpuid.cpuid1(0x0).eax;
+    let max_hypervisor_leafs = cpuid.cpuid1(0x4000_0000).eax;
+    let max_extended_leafs = cpuid.cpuid1(0x8000_0000).eax;
+
+    (0..max_leafs)
+        .chain(0x4000_0000..max_hypervisor_leafs)
+        .chain(0x8000_0000..max_extended_leafs)
+        .map(move |idx| (idx, 0x0, cpuid.cpuid1(idx)))
+}
+
 pub fn raw<R: crate::CpuIdReader>(cpuid: R) {
-    let _leafs_with_subleafs = &[0x04, 0x0d, 0x0f, 0x10, 0x12];
+    for (leaf, subleaf, res) in leafs(&cpuid) {
+        println!("({:#x}, {:#x}) => {:?}", leaf, subleaf, res);
+    }
+}
 
-    let max_leafs = cpuid.cpuid1(0x0).eax;
-    for idx in 0..max_leafs {
-        let res = cpuid.cpuid1(idx);
-        println!("({:#x}, {:#x}) => {:?}", idx, 0x0, res);
+/// One line of the `jsonl` output.
+#[derive(serde_derive::Serialize)]
+struct JsonlEntry {
+    leaf: u32,
+    subleaf: u32,
+    #[serde(flatten)]
+    res: CpuIdResult,
+}
+
+/// Prints one JSON object (`{"leaf":1,"subleaf":0,"eax":..,"ebx":..,"ecx":..,"edx":..}`)
+/// per line.
+///
+/// Every leaf is written as soon as it is read, nothing is collected. Stops quietly if
+/// standard output is closed (e.g., the reader of a pipe exits).
+pub fn jsonl<R: crate::CpuIdReader>(cpuid: R) {
+    let mut out = std::io::stdout().lock();
+    for (leaf, subleaf, res) in leafs(&cpuid) {
+        let entry = JsonlEntry { leaf, subleaf, res };
+        if serde_json::to_writer(&mut out, &entry).is_err() || writeln!(out).is_err() {
+            return;
+        }
     }
+}
 
-    let max_hypervisor_leafs = cpuid.cpuid1(0x4000_0000).eax;
-    for idx in 0x4000_0000..max_hypervisor_leafs {
-        println!("({:#x}, {:#x}) => {:?}", idx, 0x0, cpuid.cpuid1(idx));
+/// Quote `value` for a POSIX shell, unless it only consists of safe characters.
+fn shell_quote(value: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "._-+:,/@%".contains(c);
+    if !value.is_empty() && value.chars().all(safe) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
     }
+}
 
-    let max_extended_leafs = cpuid.cpuid1(0x8000_0000).eax;
-    for idx in 0x8000_0000..max_extended_leafs {
-        println!("({:#x}, {:#x}) => {:?}", idx, 0x0, cpuid.cpuid1(idx));
+/// Prints `CPUID_<KEY>=<value>` lines that can be `eval`ed by a shell script.
+///
+/// Keys for information that isn't available are omitted.
+pub fn env<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
+    let var = |key: &str, value: &dyn Display| {
+        println!("CPUID_{}={}", key, shell_quote(&value.to_string()));
+    };
+
+    if let Some(info) = cpuid.get_vendor_info() {
+        var("VENDOR", &info.as_str());
+    }
+    if let Some(brand) = cpuid.get_processor_brand_string() {
+        var("BRAND", &brand.as_str().trim());
+    }
+    if let Some(info) = cpuid.get_feature_info() {
+        var("FAMILY", &info.family_id());
+        var("MODEL", &info.model_id());
+        var("STEPPING", &info.stepping_id());
+        var("HYPERVISOR", &u8::from(info.has_hypervisor()));
+    }
+    if let Some(uarch) = cpuid.get_microarchitecture() {
+        var("MICROARCH", &uarch);
+    }
+    if let Some(level) = cpuid.x86_64_abi_level() {
+        var("X86_64_LEVEL", &level);
+    }
+    if let Some(caches) = cpuid.get_cache_parameters() {
+        for cache in caches {
+            let suffix = match cache.cache_type() {
+                CacheType::Data => "D",
+                CacheType::Instruction => "I",
+                CacheType::Unified => "",
+                _ => continue,
+            };
+            let size = cache.associativity()
+                * cache.physical_line_partitions()
+                * cache.coherency_line_size()
+                * cache.sets();
+            var(&format!("L{}{}_SIZE", cache.level(), suffix), &size);
+        }
+    }
+    if let Some(line) = cpuid.cache_line_size() {
+        var("CACHE_LINE_SIZE", &line.bytes());
     }
+    if let Some(tsc) = cpuid.frequency().tsc_frequency() {
+        var("TSC_HZ", &tsc.hz());
+    }
+    var("FEATURES", &cpuid.features().join(" "));
+}
+
+/// Bits of eax, ebx, ecx and edx that legitimately differ between the logical
+/// processors of a system (APIC and node IDs).
+fn per_cpu_bits(leaf: u32) -> [u32; 4] {
+    match leaf {
+        0x1 => [0, 0xff00_0000, 0, 0],
+        0xb | 0x1f | 0x8000_0026 => [0, 0, 0, u32::MAX],
+        0x8000_001e => [u32::MAX, 0xff, 0xff, 0],
+        _ => [0, 0, 0, 0],
+    }
+}
+
+/// Prints the registers that differ between `cpus`, e.g., snapshots of all
+/// logical processors of a system, each with a label.
+///
+/// APIC and node IDs are ignored. Returns whether any differences were found.
+pub fn compare(cpus: &[(String, crate::CpuIdSnapshot)]) -> bool {
+    let mut keys: Vec<(u32, u32)> = cpus
+        .iter()
+        .flat_map(|(_, snapshot)| snapshot.entries().iter().map(|&(l, s, _)| (l, s)))
+        .collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    let mut differences = 0;
+    for (leaf, subleaf) in keys {
+        let ignored = per_cpu_bits(leaf);
+        for (idx, name) in ["eax", "ebx", "ecx", "edx"].iter().enumerate() {
+            // Distinct values, with the CPUs that report them
+            let mut values: Vec<(u32, Vec<&str>)> = Vec::new();
+            for (label, snapshot) in cpus {
+                let res = snapshot.get(leaf, subleaf);
+                let value = res.map_or(0, |r| [r.eax, r.ebx, r.ecx, r.edx][idx]) & !ignored[idx];
+                match values.iter_mut().find(|(v, _)| *v == value) {
+                    Some((_, labels)) => labels.push(label),
+                    None => values.push((value, vec![label])),
+                }
+            }
+            if values.len() < 2 {
+                continue;
+            }
+
+            differences += 1;
+            println!("({:#x}, {:#x}) {}:", leaf, subleaf, name);
+            for (value, labels) in values.iter() {
+                println!("    {:#010x}: {}", value, labels.join(", "));
+            }
+            let bits = values.iter().fold(0, |acc, (v, _)| acc | v)
+                & !values.iter().fold(u32::MAX, |acc, (v, _)| acc & v);
+            let bits: Vec<String> = (0..32u8)
+                .filter(|bit| bits & (1 << bit) != 0)
+                .map(|bit| {
+                    use crate::feature_table::{self, Register};
+                    let register =
+                        [Register::Eax, Register::Ebx, Register::Ecx, Register::Edx][idx];
+                    match feature_table::at(leaf, subleaf, register, bit) {
+                        Some(feature) => format!("{} ({})", bit, feature.name()),
+                        None => format!("{}", bit),
+                    }
+                })
+                .collect();
+            println!("    differing bits: {}", bits.join(", "));
+        }
+    }
+
+    if differences == 0 {
+        println!(
+            "All {} CPUs report the same values (ignoring APIC IDs).",
+            cpus.len()
+        );
+    }
+    differences > 0
 }
 
 fn table2(skin: &MadSkin, attrs: &[(&str, String)]) {
@@ -210,6 +366,12 @@ impl RowGen for TopologyType {
     }
 }
 
+impl RowGen for CoreType {
+    fn fmt(attr: &Self) -> String {
+        format!("{}", attr)
+    }
+}
+
 impl RowGen for ExtendedRegisterStateLocation {
     fn fmt(attr: &Self) -> String {
         format!("{}", attr)
@@ -222,6 +384,12 @@ impl RowGen for DatType {
     }
 }
 
+impl RowGen for ProcessorType {
+    fn fmt(attr: &Self) -> String {
+        format!("{}", attr)
+    }
+}
+
 impl RowGen for Option<SoCVendorBrand> {
     fn fmt(attr: &Self) -> String {
         format!(
@@ -233,6 +401,12 @@ impl RowGen for Option<SoCVendorBrand> {
     }
 }
 
+impl RowGen for Option<Frequency> {
+    fn fmt(attr: &Self) -> String {
+        attr.map(|f| f.to_string()).unwrap_or_default()
+    }
+}
+
 pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
     let skin = MadSkin::default();
     skin.print_text("# CpuId\n");
@@ -249,6 +423,7 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                 RowGen::tuple("base family", info.base_family_id()),
                 RowGen::tuple("base model", info.base_model_id()),
                 RowGen::tuple("stepping", info.stepping_id()),
+                RowGen::tuple("processor type", info.processor_type()),
                 RowGen::tuple("extended family", info.extended_family_id()),
                 RowGen::tuple("extended model", info.extended_model_id()),
                 RowGen::tuple("family", info.family_id()),
@@ -357,13 +532,7 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
         print_title_attr(
             &skin,
             "processor serial number (0x03)",
-            format!(
-                "{:0>8x}-{:0>8x}-{:0>8x}",
-                info.serial_upper(),
-                info.serial_middle(),
-                info.serial_lower()
-            )
-            .as_str(),
+            format!("{}", info).as_str(),
         );
     }
 
@@ -489,6 +658,8 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                     "ignoring idle logical processor HWP req",
                     info.has_ignore_idle_processor_hwp_request(),
                 ),
+                RowGen::tuple("hardware feedback interface", info.has_hw_feedback()),
+                RowGen::tuple("Intel Thread Director", info.has_thread_director()),
                 RowGen::tuple("digital thermometer threshold", info.dts_irq_threshold()),
                 RowGen::tuple(
                     "hardware coordination feedback",
@@ -498,6 +669,17 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                     "performance-energy bias capability",
                     info.has_energy_bias_pref(),
                 ),
+                RowGen::tuple("Thread Director classes", info.thread_director_classes()),
+                RowGen::tuple(
+                    "HFI performance capability reporting",
+                    info.has_hfi_performance_reporting(),
+                ),
+                RowGen::tuple(
+                    "HFI efficiency capability reporting",
+                    info.has_hfi_efficiency_reporting(),
+                ),
+                RowGen::tuple("HFI table size (4 KiB pages)", info.hfi_table_size()),
+                RowGen::tuple("HFI table row index", info.hfi_table_row_index()),
             ],
         );
     }
@@ -537,6 +719,12 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                     "AVX512-VP2INTERSECT: VP2INTERSECT instructions",
                     info.has_avx512_vp2intersect(),
                 ),
+                RowGen::tuple("SERIALIZE: SERIALIZE instruction", info.has_serialize()),
+                RowGen::tuple("HYBRID: hybrid part", info.has_hybrid()),
+                RowGen::tuple(
+                    "TSXLDTRK: TSX suspend load address tracking",
+                    info.has_tsxldtrk(),
+                ),
                 RowGen::tuple("AMX_BF16: AMX_BF16 instructions", info.has_amx_bf16()),
                 RowGen::tuple(
                     "AVX512_FP16: AVX512_FP16 instructions",
@@ -545,8 +733,27 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                 RowGen::tuple("AMX_TILE: Tile Architecture support", info.has_amx_tile()),
                 RowGen::tuple(
                     "AMX_INT8: Tile Computational Operation on 8-bit integers",
-                    info.has_amx_tile(),
+                    info.has_amx_int8(),
+                ),
+                RowGen::tuple("MD_CLEAR: VERW clears CPU buffers", info.has_md_clear()),
+                RowGen::tuple(
+                    "IBRS_IBPB: indirect branch speculation control",
+                    info.has_ibrs_ibpb(),
+                ),
+                RowGen::tuple(
+                    "STIBP: single thread indirect branch predictors",
+                    info.has_stibp(),
                 ),
+                RowGen::tuple("L1D_FLUSH: IA32_FLUSH_CMD MSR", info.has_l1d_flush()),
+                RowGen::tuple(
+                    "ARCH_CAPABILITIES: IA32_ARCH_CAPABILITIES MSR",
+                    info.has_arch_capabilities(),
+                ),
+                RowGen::tuple(
+                    "CORE_CAPABILITIES: IA32_CORE_CAPABILITIES MSR",
+                    info.has_core_capabilities(),
+                ),
+                RowGen::tuple("SSBD: speculative store bypass disable", info.has_ssbd()),
                 RowGen::tuple(
                     "AVX512DQ: double & quadword instructions",
                     info.has_avx512dq(),
@@ -580,7 +787,17 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                     info.mawau_value(),
                 ),
                 RowGen::tuple("RDPID: read processor ID", info.has_rdpid()),
+                RowGen::tuple("KL: Key Locker", info.has_kl()),
+                RowGen::tuple(
+                    "BUS_LOCK_DETECT: OS bus-lock detection",
+                    info.has_bus_lock_detect(),
+                ),
+                RowGen::tuple("CLDEMOTE: cache line demote", info.has_cldemote()),
+                RowGen::tuple("MOVDIRI: direct store", info.has_movdiri()),
+                RowGen::tuple("MOVDIR64B: 64-byte direct store", info.has_movdir64b()),
+                RowGen::tuple("ENQCMD: enqueue stores", info.has_enqcmd()),
                 RowGen::tuple("SGX_LC: SGX launch config", info.has_sgx_lc()),
+                RowGen::tuple("PKS: supervisor protection keys", info.has_pks()),
                 RowGen::tuple(
                     "AVX_VNNI: AVX vector neural network instructions",
                     info.has_avx_vnni(),
@@ -593,7 +810,92 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                 RowGen::tuple("FSRM: fast short REP STOSB", info.has_fsrs()),
                 RowGen::tuple("FSRCRS: fast short REP CMPSB, REP SCASB", info.has_fsrcrs()),
                 RowGen::tuple("HRESET: HRESET instruction", info.has_hreset()),
+                RowGen::tuple("CET_SS: CET shadow stack", info.has_cet_ss()),
+                RowGen::tuple("CET_IBT: CET indirect branch tracking", info.has_cet_ibt()),
                 RowGen::tuple("CET_SSS: CET_SSS support", info.has_cet_sss()),
+                RowGen::tuple("SHA512: SHA512 instructions", info.has_sha512()),
+                RowGen::tuple("SM3: SM3 instructions", info.has_sm3()),
+                RowGen::tuple("SM4: SM4 instructions", info.has_sm4()),
+                RowGen::tuple("RAO-INT: remote atomic instructions", info.has_rao_int()),
+                RowGen::tuple("LASS: linear address space separation", info.has_lass()),
+                RowGen::tuple("CMPCCXADD: CMPccXADD instruction", info.has_cmpccxadd()),
+                RowGen::tuple(
+                    "ARCHPERFMONEXT: perf monitoring extended leaf",
+                    info.has_arch_perfmon_ext(),
+                ),
+                RowGen::tuple("FRED: flexible return and event delivery", info.has_fred()),
+                RowGen::tuple("LKGS: LKGS instruction", info.has_lkgs()),
+                RowGen::tuple("WRMSRNS: non-serializing WRMSR", info.has_wrmsrns()),
+                RowGen::tuple("NMI_SRC: NMI-source reporting", info.has_nmi_src()),
+                RowGen::tuple("AMX-FP16: FP16 tile operations", info.has_amx_fp16()),
+                RowGen::tuple(
+                    "AVX-IFMA: AVX integer fused multiply-add",
+                    info.has_avx_ifma(),
+                ),
+                RowGen::tuple("LAM: linear address masking", info.has_lam()),
+                RowGen::tuple(
+                    "MSRLIST: RDMSRLIST and WRMSRLIST instructions",
+                    info.has_msrlist(),
+                ),
+                RowGen::tuple(
+                    "PPIN: protected processor inventory number",
+                    info.has_ppin(),
+                ),
+                RowGen::tuple("PBNDKB: PBNDKB instruction", info.has_pbndkb()),
+                RowGen::tuple(
+                    "AVX-VNNI-INT8: AVX VNNI 8-bit integer instructions",
+                    info.has_avx_vnni_int8(),
+                ),
+                RowGen::tuple(
+                    "AVX-NE-CONVERT: AVX no-exception FP conversion",
+                    info.has_avx_ne_convert(),
+                ),
+                RowGen::tuple(
+                    "AMX-COMPLEX: AMX complex tile operations",
+                    info.has_amx_complex(),
+                ),
+                RowGen::tuple(
+                    "AVX-VNNI-INT16: AVX VNNI 16-bit integer instructions",
+                    info.has_avx_vnni_int16(),
+                ),
+                RowGen::tuple(
+                    "PREFETCHI: instruction cache prefetch",
+                    info.has_prefetchi(),
+                ),
+                RowGen::tuple(
+                    "USER_MSR: URDMSR and UWRMSR instructions",
+                    info.has_user_msr(),
+                ),
+                RowGen::tuple(
+                    "UIRET_UIF: UIRET sets UIF from RFLAGS",
+                    info.has_uiret_uif(),
+                ),
+                RowGen::tuple("AVX10: AVX10 converged vector ISA", info.has_avx10()),
+                RowGen::tuple("APX_F: advanced performance extensions", info.has_apx_f()),
+                RowGen::tuple(
+                    "PSFD: fast store forwarding predictor disable",
+                    info.has_psfd(),
+                ),
+                RowGen::tuple(
+                    "IPRED_CTRL: indirect branch predictor control",
+                    info.has_ipred_ctrl(),
+                ),
+                RowGen::tuple(
+                    "RRSBA_CTRL: RSB underflow predictor control",
+                    info.has_rrsba_ctrl(),
+                ),
+                RowGen::tuple(
+                    "DDPD_U: data dependent prefetcher disable",
+                    info.has_ddpd_u(),
+                ),
+                RowGen::tuple(
+                    "BHI_CTRL: branch history injection control",
+                    info.has_bhi_ctrl(),
+                ),
+                RowGen::tuple(
+                    "MCDT_NO: no MXCSR configuration dependent timing",
+                    info.has_mcdt_no(),
+                ),
             ],
         );
     }
@@ -606,7 +908,7 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
     if let Some(info) = cpuid.get_performance_monitoring_info() {
         print_title(&skin, "Architecture Performance Monitoring Features (0x0a)");
 
-        print_subtitle(&skin, "Monitoring Hardware Info (0x0a/{eax, edx}):");
+        print_subtitle(&skin, "Monitoring Hardware Info (0x0a/{eax, ecx, edx}):");
         table2(
             &skin,
             &[
@@ -622,6 +924,10 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                     "bit width of fixed counters",
                     info.fixed_function_counters_bit_width(),
                 ),
+                RowGen::tuple(
+                    "fixed counters bitmap",
+                    format!("{:#x}", info.fixed_counter_bitmap()),
+                ),
                 RowGen::tuple("anythread deprecation", info.has_any_thread_deprecation()),
             ],
         );
@@ -658,12 +964,27 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                     "branch mispred retired event not available",
                     info.is_branch_midpred_ev_unavailable(),
                 ),
+                RowGen::tuple(
+                    "top-down slots event not available",
+                    info.is_topdown_slots_ev_unavailable(),
+                ),
             ],
         );
     }
 
-    if let Some(info) = cpuid.get_extended_topology_info() {
-        print_title(&skin, "x2APIC features / processor topology (0x0b):");
+    let topology = [
+        (
+            "x2APIC features / processor topology (0x0b):",
+            cpuid.get_extended_topology_info(),
+        ),
+        (
+            "V2 extended topology (0x1f):",
+            cpuid.get_extended_topology_info_v2(),
+        ),
+    ];
+    for (title, info) in IntoIterator::into_iter(topology) {
+        let Some(info) = info else { continue };
+        print_title(&skin, title);
 
         for level in info {
             print_subtitle(&skin, format!("level {}:", level.level_number()).as_str());
@@ -704,9 +1025,14 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                 ),
                 RowGen::triple("IA32_XSS", "PT", info.ia32_xss_supports_pt()),
                 RowGen::triple("XCR0", "PKRU", info.xcr0_supports_pkru()),
-                //("XCR0", "CET_U state", xxx),
-                //("XCR0", "CET_S state", xxx),
+                RowGen::triple("IA32_XSS", "CET_U state", info.ia32_xss_supports_cet_user()),
+                RowGen::triple(
+                    "IA32_XSS",
+                    "CET_S state",
+                    info.ia32_xss_supports_cet_supervisor(),
+                ),
                 RowGen::triple("IA32_XSS", "HDC", info.ia32_xss_supports_hdc()),
+                RowGen::triple("XCR0", "APX", info.xcr0_supports_apx()),
             ],
         );
 
@@ -733,13 +1059,17 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                 RowGen::tuple("XGETBV instruction", info.has_xgetbv()),
                 RowGen::tuple("XSAVES/XRSTORS instructions", info.has_xsaves_xrstors()),
                 RowGen::tuple("SAVE area size [Bytes]", info.xsave_size()),
+                RowGen::tuple(
+                    "IA32_XSS supported mask",
+                    format!("{:#x}", info.ia32_xss_supported_mask()),
+                ),
             ],
         );
 
         for state in info.iter() {
             print_subtitle(
                 &skin,
-                format!("{} features (0x0d/{}):", state.register(), state.subleaf).as_str(),
+                format!("{} features (0x0d/{}):", state.component(), state.subleaf()).as_str(),
             );
             table2(
                 &skin,
@@ -751,6 +1081,7 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                         "64-byte alignment in compacted XSAVE",
                         state.is_compacted_format(),
                     ),
+                    RowGen::tuple("XFD faulting supported", state.is_xfd_supported()),
                 ],
             );
         }
@@ -779,6 +1110,8 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                         "Conversion factor from IA32_QM_CTR to bytes",
                         rmid.conversion_factor(),
                     ),
+                    RowGen::tuple("IA32_QM_CTR counter width", rmid.counter_width()),
+                    RowGen::tuple("IA32_QM_CTR overflow bit", rmid.has_counter_overflow_bit()),
                     RowGen::tuple("Maximum range of RMID", rmid.maximum_rmid_range()),
                     RowGen::tuple("L3 occupancy monitoring", rmid.has_occupancy_monitoring()),
                     RowGen::tuple(
@@ -822,6 +1155,10 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                         "code and data prioritization",
                         l3_cat.has_code_data_prioritization(),
                     ),
+                    RowGen::tuple(
+                        "non-contiguous capacity bit mask",
+                        l3_cat.has_noncontiguous_capacity_bitmask(),
+                    ),
                     RowGen::tuple("highest COS number", l3_cat.highest_cos()),
                 ],
             );
@@ -836,6 +1173,14 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                         "Bit-granular map of isolation/contention",
                         l2_cat.isolation_bitmap(),
                     ),
+                    RowGen::tuple(
+                        "code and data prioritization",
+                        l2_cat.has_code_data_prioritization(),
+                    ),
+                    RowGen::tuple(
+                        "non-contiguous capacity bit mask",
+                        l2_cat.has_noncontiguous_capacity_bitmask(),
+                    ),
                     RowGen::tuple("highest COS number", l2_cat.highest_cos()),
                 ],
             );
@@ -846,6 +1191,7 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                 &skin,
                 &[
                     RowGen::tuple("maximum throttling value", mem.max_hba_throttling()),
+                    RowGen::tuple("per-thread throttling", mem.has_per_thread_throttling()),
                     RowGen::tuple("delay values are linear", mem.has_linear_response_delay()),
                     RowGen::tuple("highest COS number", mem.highest_cos()),
                 ],
@@ -870,11 +1216,24 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
                     info.has_encls_leaves_etrackc_erdinfo_eldbc_elduc(),
                 ),
                 RowGen::tuple("MISCSELECT", info.miscselect()),
+                RowGen::tuple("MISCSELECT.EXINFO", info.has_exinfo()),
+                RowGen::tuple("MISCSELECT.CPINFO", info.has_cpinfo()),
                 RowGen::tuple(
                     "MaxEnclaveSize_Not64 (log2)",
                     info.max_enclave_size_non_64bit(),
                 ),
                 RowGen::tuple("MaxEnclaveSize_64 (log2)", info.max_enclave_size_64bit()),
+                RowGen::tuple("ATTRIBUTES.DEBUG", info.secs_supports_debug()),
+                RowGen::tuple("ATTRIBUTES.MODE64BIT", info.secs_supports_mode64bit()),
+                RowGen::tuple("ATTRIBUTES.PROVISIONKEY", info.secs_supports_provisionkey()),
+                RowGen::tuple(
+                    "ATTRIBUTES.EINITTOKEN_KEY",
+                    info.secs_supports_einittokenkey(),
+                ),
+                RowGen::tuple("ATTRIBUTES.CET", info.secs_supports_cet()),
+                RowGen::tuple("ATTRIBUTES.KSS", info.secs_supports_kss()),
+                RowGen::tuple("ATTRIBUTES.AEXNOTIFY", info.secs_supports_aexnotify()),
+                RowGen::tuple("ATTRIBUTES.XFRM", format!("{:#x}", info.secs_xfrm_mask())),
             ],
         );
 
@@ -976,12 +1335,9 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
         table2(
             &skin,
             &[
-                RowGen::tuple("Core Base Frequency (MHz)", info.processor_base_frequency()),
-                RowGen::tuple(
-                    "Core Maximum Frequency (MHz)",
-                    info.processor_max_frequency(),
-                ),
-                RowGen::tuple("Bus (Reference) Frequency (MHz)", info.bus_frequency()),
+                RowGen::tuple("Core Base Frequency", info.processor_base_frequency()),
+                RowGen::tuple("Core Maximum Frequency", info.processor_max_frequency()),
+                RowGen::tuple("Bus (Reference) Frequency", info.bus_frequency()),
             ],
         );
     }
@@ -1035,12 +1391,130 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
         );
 
         if let Some(iter) = info.get_vendor_attributes() {
-            for (idx, attr) in iter.enumerate() {
-                print_cpuid_result(&skin, format!("0x17 {:#x}", idx + 4), attr);
+            for attr in iter {
+                print_cpuid_result(&skin, format!("0x17 {:#x}", attr.index()), attr.raw());
             }
         }
     }
 
+    if let Some(info) = cpuid.get_hybrid_info() {
+        print_title(&skin, "Hybrid Information (0x1a):");
+        table2(
+            &skin,
+            &[
+                RowGen::tuple("core type", info.core_type()),
+                RowGen::tuple("native model ID", info.native_model_id()),
+            ],
+        );
+    }
+
+    if let Some(iter) = cpuid.get_pconfig_info() {
+        let targets: Vec<_> = iter
+            .enumerate()
+            .map(|(idx, target)| (format!("target {}", idx + 1), target.to_string()))
+            .collect();
+        if !targets.is_empty() {
+            print_title(&skin, "PCONFIG Information (0x1b):");
+            let rows: Vec<(&str, String)> = targets
+                .iter()
+                .map(|(name, target)| (name.as_str(), target.clone()))
+                .collect();
+            table2(&skin, &rows);
+        }
+    }
+
+    if let Some(info) = cpuid.get_tile_info() {
+        // Dumps may lack the palette sub-leafs
+        for palette in info.palettes().filter(|palette| palette.max_names() > 0) {
+            print_title(
+                &skin,
+                format!("AMX Tile Information (0x1d/{}):", palette.palette_id()).as_str(),
+            );
+            table2(
+                &skin,
+                &[
+                    RowGen::tuple("total tile bytes", palette.total_tile_bytes()),
+                    RowGen::tuple("bytes per tile", palette.bytes_per_tile()),
+                    RowGen::tuple("bytes per row", palette.bytes_per_row()),
+                    RowGen::tuple("max names (tile registers)", palette.max_names()),
+                    RowGen::tuple("max rows", palette.max_rows()),
+                ],
+            );
+        }
+    }
+
+    if let Some(info) = cpuid.get_tmul_info() {
+        print_title(&skin, "TMUL Information (0x1e):");
+        table2(
+            &skin,
+            &[
+                RowGen::tuple("tmul_maxk (rows or columns)", info.tmul_maxk()),
+                RowGen::tuple("tmul_maxn (column bytes)", info.tmul_maxn()),
+            ],
+        );
+    }
+
+    if let Some(info) = cpuid.get_hreset_info() {
+        print_title(&skin, "Processor History Reset (0x20):");
+        table2(
+            &skin,
+            &[
+                RowGen::tuple("capabilities (IA32_HRESET_ENABLE)", info.capabilities()),
+                RowGen::tuple(
+                    "Intel Thread Director history reset",
+                    info.has_thread_director_reset(),
+                ),
+            ],
+        );
+    }
+
+    if let Some(info) = cpuid.get_performance_monitoring_ext_info() {
+        print_title(
+            &skin,
+            "Architectural Performance Monitoring Extended (0x23):",
+        );
+        table2(
+            &skin,
+            &[
+                RowGen::tuple("UnitMask2 supported", info.has_umask2()),
+                RowGen::tuple("EQ bit supported", info.has_eq()),
+                RowGen::tuple(
+                    "general counters",
+                    format!("{:#x}", info.general_counters()),
+                ),
+                RowGen::tuple("fixed counters", format!("{:#x}", info.fixed_counters())),
+                RowGen::tuple("core cycles event", info.has_core_cycles_ev()),
+                RowGen::tuple("instructions retired event", info.has_inst_retired_ev()),
+                RowGen::tuple("reference cycles event", info.has_ref_cycles_ev()),
+                RowGen::tuple("LLC references event", info.has_llc_references_ev()),
+                RowGen::tuple("LLC misses event", info.has_llc_misses_ev()),
+                RowGen::tuple(
+                    "branch instructions retired event",
+                    info.has_branch_inst_retired_ev(),
+                ),
+                RowGen::tuple(
+                    "branch mispredicts retired event",
+                    info.has_branch_mispredicts_retired_ev(),
+                ),
+                RowGen::tuple("topdown slots event", info.has_topdown_slots_ev()),
+                RowGen::tuple(
+                    "topdown backend bound event",
+                    info.has_topdown_backend_bound_ev(),
+                ),
+                RowGen::tuple(
+                    "topdown bad speculation event",
+                    info.has_topdown_bad_speculation_ev(),
+                ),
+                RowGen::tuple(
+                    "topdown frontend bound event",
+                    info.has_topdown_frontend_bound_ev(),
+                ),
+                RowGen::tuple("topdown retiring event", info.has_topdown_retiring_ev()),
+                RowGen::tuple("LBR inserts event", info.has_lbr_inserts_ev()),
+            ],
+        );
+    }
+
     if let Some(info) = cpuid.get_processor_brand_string() {
         print_attr(
             &skin,
@@ -1368,4 +1842,71 @@ pub fn markdown<R: crate::CpuIdReader>(cpuid: crate::CpuId<R>) {
             ],
         );
     }
+
+    if let Some(info) = cpuid.get_extended_feature_identification_2() {
+        print_title(&skin, "Extended Feature Identification 2 (0x8000_0021):");
+        table2(
+            &skin,
+            &[
+                RowGen::tuple("No nested data breakpoints", info.has_no_nested_data_bp()),
+                RowGen::tuple(
+                    "Non-serializing WRMSR to FS/GS base",
+                    info.has_fs_gs_base_non_serializing(),
+                ),
+                RowGen::tuple(
+                    "LFENCE always serializing",
+                    info.has_lfence_always_serializing(),
+                ),
+                RowGen::tuple("SMM paging config lock", info.has_smm_pg_cfg_lock()),
+                RowGen::tuple("VERW clears CPU buffers", info.has_verw_clear()),
+                RowGen::tuple(
+                    "Null selector clears base",
+                    info.has_null_select_clears_base(),
+                ),
+                RowGen::tuple("Upper Address Ignore", info.has_upper_address_ignore()),
+                RowGen::tuple("Automatic IBRS", info.has_automatic_ibrs()),
+                RowGen::tuple("No SMM_CTL MSR", info.has_no_smm_ctl_msr()),
+                RowGen::tuple("Fast short REP STOSB", info.has_fsrs()),
+                RowGen::tuple("Fast short REP CMPSB", info.has_fsrc()),
+                RowGen::tuple("Prefetch control MSR", info.has_prefetch_ctl_msr()),
+                RowGen::tuple("CPUID disable for user mode", info.has_cpuid_user_dis()),
+                RowGen::tuple("Enhanced predictive store forwarding", info.has_epsf()),
+                RowGen::tuple("PREFETCHIT0/1", info.has_prefetchi()),
+                RowGen::tuple("ERAPS", info.has_eraps()),
+                RowGen::tuple("SBPB: selective branch predictor barrier", info.has_sbpb()),
+                RowGen::tuple("IBPB flushes all branch types", info.has_ibpb_brtype()),
+                RowGen::tuple("SRSO_NO", info.has_srso_no()),
+                RowGen::tuple("SRSO_USER_KERNEL_NO", info.has_srso_user_kernel_no()),
+                RowGen::tuple("SRSO MSR fix", info.has_srso_msr_fix()),
+                RowGen::tuple(
+                    "Microcode patch size (16 byte units)",
+                    info.microcode_patch_size(),
+                ),
+                RowGen::tuple("Return address predictor size", info.rap_size()),
+            ],
+        );
+    }
+
+    if let Some(info) = cpuid.get_amd_perfmon_debug_info() {
+        print_title(
+            &skin,
+            "Extended Performance Monitoring and Debug (0x8000_0022):",
+        );
+        table2(
+            &skin,
+            &[
+                RowGen::tuple("PerfMonV2", info.has_perfmon_v2()),
+                RowGen::tuple("LbrV2", info.has_lbr_v2()),
+                RowGen::tuple("LBR and PMC freeze", info.has_lbr_and_pmc_freeze()),
+                RowGen::tuple("Core performance counters", info.num_core_counters()),
+                RowGen::tuple("LbrV2 stack size", info.lbr_v2_stack_size()),
+                RowGen::tuple(
+                    "Northbridge performance counters",
+                    info.num_northbridge_counters(),
+                ),
+                RowGen::tuple("UMC performance counters", info.num_umc_counters()),
+                RowGen::tuple("Active UMC mask", format!("{:#x}", info.active_umc_mask())),
+            ],
+        );
+    }
 }
@@ -5,7 +5,7 @@ use core::mem::size_of;
 use core::slice;
 use core::str;
 
-use crate::{get_bits, CpuIdResult, Vendor};
+use crate::{get_bits, CpuIdResult, FrequencyEstimate, FrequencySource, Vendor};
 
 /// Extended Processor and Processor Feature Identifiers (LEAF=0x8000_0001)
 ///
@@ -30,6 +30,14 @@ impl ExtendedProcessorFeatureIdentifiers {
         }
     }
 
+    /// Names of all flags set in ECX and EDX.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn flag_names(&self) -> impl Iterator<Item = &'static str> {
+        let ecx = self.ecx.iter_names().map(|(name, _)| name);
+        let edx = self.edx.iter_names().map(|(name, _)| name);
+        ecx.chain(edx)
+    }
+
     /// Extended Processor Signature.
     ///
     /// # AMD
@@ -72,7 +80,8 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn has_lahf_sahf(&self) -> bool {
+    #[inline]
+    pub const fn has_lahf_sahf(&self) -> bool {
         self.ecx.contains(ExtendedFunctionInfoEcx::LAHF_SAHF)
     }
 
@@ -80,16 +89,18 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_cmp_legacy(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::CMP_LEGACY)
+    #[inline]
+    pub const fn has_cmp_legacy(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::CMP_LEGACY)
     }
 
     /// Secure virtual machine supported.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_svm(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::SVM)
+    #[inline]
+    pub const fn has_svm(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::SVM)
     }
 
     /// Extended APIC space.
@@ -99,16 +110,19 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_ext_apic_space(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::EXT_APIC_SPACE)
+    #[inline]
+    pub const fn has_ext_apic_space(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd)
+            && self.ecx.contains(ExtendedFunctionInfoEcx::EXT_APIC_SPACE)
     }
 
     /// LOCK MOV CR0 means MOV CR8. See “MOV(CRn)” in APM3.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_alt_mov_cr8(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::ALTMOVCR8)
+    #[inline]
+    pub const fn has_alt_mov_cr8(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::ALTMOVCR8)
     }
 
     /// Is LZCNT available?
@@ -119,7 +133,8 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn has_lzcnt(&self) -> bool {
+    #[inline]
+    pub const fn has_lzcnt(&self) -> bool {
         self.ecx.contains(ExtendedFunctionInfoEcx::LZCNT)
     }
 
@@ -129,8 +144,9 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_sse4a(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::SSE4A)
+    #[inline]
+    pub const fn has_sse4a(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::SSE4A)
     }
 
     /// Misaligned SSE mode. See “Misaligned Access Support Added for SSE Instructions” in
@@ -138,8 +154,10 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_misaligned_sse_mode(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::MISALIGNSSE)
+    #[inline]
+    pub const fn has_misaligned_sse_mode(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd)
+            && self.ecx.contains(ExtendedFunctionInfoEcx::MISALIGNSSE)
     }
 
     /// Is PREFETCHW available?
@@ -149,7 +167,8 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn has_prefetchw(&self) -> bool {
+    #[inline]
+    pub const fn has_prefetchw(&self) -> bool {
         self.ecx.contains(ExtendedFunctionInfoEcx::PREFETCHW)
     }
 
@@ -157,24 +176,27 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_osvw(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::OSVW)
+    #[inline]
+    pub const fn has_osvw(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::OSVW)
     }
 
     /// Instruction based sampling.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_ibs(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::IBS)
+    #[inline]
+    pub const fn has_ibs(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::IBS)
     }
 
     /// Extended operation support.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_xop(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::XOP)
+    #[inline]
+    pub const fn has_xop(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::XOP)
     }
 
     /// SKINIT and STGI are supported.
@@ -184,8 +206,9 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_skinit(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::SKINIT)
+    #[inline]
+    pub const fn has_skinit(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::SKINIT)
     }
 
     /// Watchdog timer support.
@@ -194,32 +217,36 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_wdt(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::WDT)
+    #[inline]
+    pub const fn has_wdt(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::WDT)
     }
 
     /// Lightweight profiling support
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_lwp(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::LWP)
+    #[inline]
+    pub const fn has_lwp(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::LWP)
     }
 
     /// Four-operand FMA instruction support.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_fma4(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::FMA4)
+    #[inline]
+    pub const fn has_fma4(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::FMA4)
     }
 
     /// Trailing bit manipulation instruction support.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_tbm(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::TBM)
+    #[inline]
+    pub const fn has_tbm(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::TBM)
     }
 
     /// Topology extensions support.
@@ -228,8 +255,9 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_topology_extensions(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::TOPEXT)
+    #[inline]
+    pub const fn has_topology_extensions(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::TOPEXT)
     }
 
     /// Processor performance counter extensions support.
@@ -238,8 +266,9 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_perf_cntr_extensions(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::PERFCTREXT)
+    #[inline]
+    pub const fn has_perf_cntr_extensions(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::PERFCTREXT)
     }
 
     /// NB performance counter extensions support.
@@ -248,8 +277,10 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_nb_perf_cntr_extensions(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::PERFCTREXTNB)
+    #[inline]
+    pub const fn has_nb_perf_cntr_extensions(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd)
+            && self.ecx.contains(ExtendedFunctionInfoEcx::PERFCTREXTNB)
     }
 
     /// Data access breakpoint extension.
@@ -258,8 +289,10 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_data_access_bkpt_extension(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::DATABRKPEXT)
+    #[inline]
+    pub const fn has_data_access_bkpt_extension(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd)
+            && self.ecx.contains(ExtendedFunctionInfoEcx::DATABRKPEXT)
     }
 
     /// Performance time-stamp counter.
@@ -268,39 +301,46 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_perf_tsc(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::PERFTSC)
+    #[inline]
+    pub const fn has_perf_tsc(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::PERFTSC)
     }
 
     /// Support for L3 performance counter extension.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_perf_cntr_llc_extensions(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::PERFCTREXTLLC)
+    #[inline]
+    pub const fn has_perf_cntr_llc_extensions(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd)
+            && self.ecx.contains(ExtendedFunctionInfoEcx::PERFCTREXTLLC)
     }
 
     /// Support for MWAITX and MONITORX instructions.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_monitorx_mwaitx(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::MONITORX)
+    #[inline]
+    pub const fn has_monitorx_mwaitx(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.ecx.contains(ExtendedFunctionInfoEcx::MONITORX)
     }
 
     /// Breakpoint Addressing masking extended to bit 31.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_addr_mask_extension(&self) -> bool {
-        self.vendor == Vendor::Amd && self.ecx.contains(ExtendedFunctionInfoEcx::ADDRMASKEXT)
+    #[inline]
+    pub const fn has_addr_mask_extension(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd)
+            && self.ecx.contains(ExtendedFunctionInfoEcx::ADDRMASKEXT)
     }
 
     /// Are fast system calls available.
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn has_syscall_sysret(&self) -> bool {
+    #[inline]
+    pub const fn has_syscall_sysret(&self) -> bool {
         self.edx.contains(ExtendedFunctionInfoEdx::SYSCALL_SYSRET)
     }
 
@@ -308,7 +348,8 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn has_execute_disable(&self) -> bool {
+    #[inline]
+    pub const fn has_execute_disable(&self) -> bool {
         self.edx.contains(ExtendedFunctionInfoEdx::EXECUTE_DISABLE)
     }
 
@@ -316,23 +357,26 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_mmx_extensions(&self) -> bool {
-        self.vendor == Vendor::Amd && self.edx.contains(ExtendedFunctionInfoEdx::MMXEXT)
+    #[inline]
+    pub const fn has_mmx_extensions(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.edx.contains(ExtendedFunctionInfoEdx::MMXEXT)
     }
 
     /// FXSAVE and FXRSTOR instruction optimizations.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_fast_fxsave_fxstor(&self) -> bool {
-        self.vendor == Vendor::Amd && self.edx.contains(ExtendedFunctionInfoEdx::FFXSR)
+    #[inline]
+    pub const fn has_fast_fxsave_fxstor(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.edx.contains(ExtendedFunctionInfoEdx::FFXSR)
     }
 
     /// Is there support for 1GiB pages.
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn has_1gib_pages(&self) -> bool {
+    #[inline]
+    pub const fn has_1gib_pages(&self) -> bool {
         self.edx.contains(ExtendedFunctionInfoEdx::GIB_PAGES)
     }
 
@@ -340,7 +384,8 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn has_rdtscp(&self) -> bool {
+    #[inline]
+    pub const fn has_rdtscp(&self) -> bool {
         self.edx.contains(ExtendedFunctionInfoEdx::RDTSCP)
     }
 
@@ -348,7 +393,8 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn has_64bit_mode(&self) -> bool {
+    #[inline]
+    pub const fn has_64bit_mode(&self) -> bool {
         self.edx.contains(ExtendedFunctionInfoEdx::I64BIT_MODE)
     }
 
@@ -356,16 +402,19 @@ impl ExtendedProcessorFeatureIdentifiers {
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_amd_3dnow_extensions(&self) -> bool {
-        self.vendor == Vendor::Amd && self.edx.contains(ExtendedFunctionInfoEdx::THREEDNOWEXT)
+    #[inline]
+    pub const fn has_amd_3dnow_extensions(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd)
+            && self.edx.contains(ExtendedFunctionInfoEdx::THREEDNOWEXT)
     }
 
     /// 3DNow extensions.
     ///
     /// # Platform
     /// ✅ AMD ❌ Intel (will return false)
-    pub fn has_3dnow(&self) -> bool {
-        self.vendor == Vendor::Amd && self.edx.contains(ExtendedFunctionInfoEdx::THREEDNOW)
+    #[inline]
+    pub const fn has_3dnow(&self) -> bool {
+        matches!(self.vendor, Vendor::Amd) && self.edx.contains(ExtendedFunctionInfoEdx::THREEDNOW)
     }
 }
 
@@ -467,6 +516,50 @@ impl ProcessorBrandString {
             .unwrap_or("Invalid Processor Brand String")
             .trim()
     }
+
+    /// Nominal frequency parsed from the brand string.
+    ///
+    /// Understands brand strings ending in "@ x.xxGHz" (e.g., "Intel(R)
+    /// Core(TM) i5-3337U CPU @ 1.80GHz") or "xxxMHz" (e.g., "Intel(R)
+    /// Pentium(R) III CPU family 1133MHz"). This is the marketed base
+    /// frequency, useful as a fallback on CPUs that don't implement LEAF=0x15
+    /// or LEAF=0x16. The returned value is always marked with
+    /// [`FrequencySource::BrandString`].
+    pub fn nominal_frequency(&self) -> Option<FrequencyEstimate> {
+        let brand = self.as_str();
+        let (brand, multiplier) = if let Some(brand) = brand.strip_suffix("GHz") {
+            (brand, 1_000_000_000)
+        } else if let Some(brand) = brand.strip_suffix("MHz") {
+            (brand, 1_000_000)
+        } else {
+            return None;
+        };
+
+        let brand = brand.trim_end();
+        let start = brand
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| !c.is_ascii_digit() && c != '.')
+            .map_or(0, |(idx, c)| idx + c.len_utf8());
+        let (int, fraction) = match brand[start..].split_once('.') {
+            Some((int, fraction)) => (int, fraction),
+            None => (&brand[start..], ""),
+        };
+        if int.is_empty() {
+            return None;
+        }
+
+        let mut hz = int.parse::<u64>().ok()?.checked_mul(multiplier)?;
+        let mut scale = multiplier / 10;
+        for digit in fraction.bytes() {
+            if !digit.is_ascii_digit() {
+                return None;
+            }
+            hz = hz.checked_add((digit - b'0') as u64 * scale)?;
+            scale /= 10;
+        }
+        Some(FrequencyEstimate::new(hz, FrequencySource::BrandString))
+    }
 }
 
 impl Debug for ProcessorBrandString {
@@ -763,12 +856,14 @@ impl L2And3CacheTlbInfo {
 
 /// Info about cache Associativity.
 #[derive(PartialEq, Eq, Debug)]
+#[non_exhaustive]
 pub enum Associativity {
     Disabled,
     DirectMapped,
     NWay(u8),
     FullyAssociative,
-    Unknown,
+    /// A reserved encoding or one that refers to another leaf, the raw value.
+    Unknown(u8),
 }
 
 impl Display for Associativity {
@@ -780,7 +875,9 @@ impl Display for Associativity {
                 return write!(f, "NWay({})", n);
             }
             Associativity::FullyAssociative => "Fully associative",
-            Associativity::Unknown => "Unknown (check leaf 0x8000_001d)",
+            Associativity::Unknown(n) => {
+                return write!(f, "Unknown ({:#x}, check leaf 0x8000_001d)", n);
+            }
         };
         f.write_str(s)
     }
@@ -808,14 +905,14 @@ impl Associativity {
             0x6 => Associativity::NWay(8),
             // 0x7 => SDM states: "See CPUID leaf 04H, sub-leaf 2"
             0x8 => Associativity::NWay(16),
-            0x9 => Associativity::Unknown, // Intel: Reserved, AMD: Value for all fields should be determined from Fn8000_001D
+            0x9 => Associativity::Unknown(n), // Intel: Reserved, AMD: Value for all fields should be determined from Fn8000_001D
             0xa => Associativity::NWay(32),
             0xb => Associativity::NWay(48),
             0xc => Associativity::NWay(64),
             0xd => Associativity::NWay(96),
             0xe => Associativity::NWay(128),
             0xF => Associativity::FullyAssociative,
-            _ => Associativity::Unknown,
+            _ => Associativity::Unknown(n),
         }
     }
 
@@ -1740,6 +1837,29 @@ impl MemoryEncryptionInfo {
         get_bits(self.ebx, 6, 11) as u8
     }
 
+    /// Mask to set in page table entries to map encrypted memory (`1 << c_bit_position`).
+    ///
+    /// Returns 0 if neither SME nor SEV is supported.
+    pub fn c_bit_mask(&self) -> u64 {
+        if self.has_sme() || self.has_sev() {
+            1u64 << self.c_bit_position()
+        } else {
+            0
+        }
+    }
+
+    /// Mask of the physical address bits that remain usable once memory encryption is
+    /// enabled, i.e., `physical_address_bits` (MAXPHYADDR, see
+    /// [`ProcessorCapacityAndFeatureInfo::physical_address_bits`]) lowered by
+    /// [`MemoryEncryptionInfo::physical_address_reduction`].
+    ///
+    /// The C-bit lies above this mask.
+    pub fn physical_address_mask_with_reduction(&self, physical_address_bits: u8) -> u64 {
+        let bits = physical_address_bits.saturating_sub(self.physical_address_reduction());
+        1u64.checked_shl(bits as u32)
+            .map_or(u64::MAX, |bit| bit - 1)
+    }
+
     /// Number of encrypted guests supported simultaneouslys
     pub fn max_encrypted_guests(&self) -> u32 {
         self.ecx
@@ -1751,6 +1871,45 @@ impl MemoryEncryptionInfo {
     }
 }
 
+/// Memory encryption of the guest we are running in, as returned by
+/// [`CpuId::sev_status`](crate::CpuId::sev_status).
+///
+/// Variants other than `NotEncrypted` carry the C-bit position, i.e., the page
+/// table entry bit that marks a page as encrypted.
+///
+/// # Platforms
+/// ✅ AMD ❌ Intel (always `NotEncrypted`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SevStatus {
+    /// Not running in an encrypted guest.
+    NotEncrypted,
+    /// Secure Encrypted Virtualization: guest memory is encrypted.
+    Sev { c_bit: u8 },
+    /// SEV with Encrypted State: the register state is encrypted too.
+    SevEs { c_bit: u8 },
+    /// SEV with Secure Nested Paging: adds memory integrity protection.
+    SevSnp { c_bit: u8 },
+}
+
+impl SevStatus {
+    /// Position of the C-bit in page table entries, `None` if not encrypted.
+    pub fn c_bit_position(&self) -> Option<u8> {
+        match *self {
+            SevStatus::NotEncrypted => None,
+            SevStatus::Sev { c_bit } | SevStatus::SevEs { c_bit } | SevStatus::SevSnp { c_bit } => {
+                Some(c_bit)
+            }
+        }
+    }
+
+    /// Mask to set in page table entries to map encrypted memory (0 if not encrypted).
+    pub fn encryption_mask(&self) -> u64 {
+        self.c_bit_position()
+            .and_then(|c_bit| 1u64.checked_shl(c_bit as u32))
+            .unwrap_or(0)
+    }
+}
+
 bitflags! {
     #[repr(transparent)]
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -1770,3 +1929,263 @@ bitflags! {
         const VTE = 1 << 16;
     }
 }
+
+/// Extended Feature Identification 2 (LEAF=0x8000_0021).
+///
+/// # Platforms
+/// ✅ AMD ❌ Intel
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExtendedFeatureIdentification2 {
+    eax: ExtendedFeatureIdentification2Eax,
+    ebx: u32,
+    /// Reserved
+    _ecx: u32,
+    /// Reserved
+    _edx: u32,
+}
+
+impl ExtendedFeatureIdentification2 {
+    pub(crate) fn new(data: CpuIdResult) -> Self {
+        Self {
+            eax: ExtendedFeatureIdentification2Eax::from_bits_truncate(data.eax),
+            ebx: data.ebx,
+            _ecx: data.ecx,
+            _edx: data.edx,
+        }
+    }
+
+    /// Processor ignores nested data breakpoints if set.
+    pub fn has_no_nested_data_bp(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::NO_NESTED_DATA_BP)
+    }
+
+    /// WRMSR to FS_BASE, GS_BASE and KernelGSBase is non-serializing if set.
+    pub fn has_fs_gs_base_non_serializing(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::FS_GS_BASE_NS)
+    }
+
+    /// LFENCE is always dispatch serializing if set.
+    pub fn has_lfence_always_serializing(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::LFENCE_ALWAYS_SERIALIZING)
+    }
+
+    /// SMM paging configuration lock supported if set.
+    pub fn has_smm_pg_cfg_lock(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::SMM_PG_CFG_LOCK)
+    }
+
+    /// VERW clears CPU buffers if set.
+    pub fn has_verw_clear(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::VERW_CLEAR)
+    }
+
+    /// Null segment selector loads also clear the destination segment register
+    /// base and limit if set.
+    pub fn has_null_select_clears_base(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::NULL_SELECT_CLEARS_BASE)
+    }
+
+    /// Upper Address Ignore is supported if set.
+    pub fn has_upper_address_ignore(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::UPPER_ADDRESS_IGNORE)
+    }
+
+    /// Automatic IBRS is supported if set.
+    pub fn has_automatic_ibrs(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::AUTOMATIC_IBRS)
+    }
+
+    /// The SMM_CTL MSR (C001_0116h) is not supported if set.
+    pub fn has_no_smm_ctl_msr(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::NO_SMM_CTL_MSR)
+    }
+
+    /// Fast short REP STOSB is supported if set.
+    pub fn has_fsrs(&self) -> bool {
+        self.eax.contains(ExtendedFeatureIdentification2Eax::FSRS)
+    }
+
+    /// Fast short REP CMPSB is supported if set.
+    pub fn has_fsrc(&self) -> bool {
+        self.eax.contains(ExtendedFeatureIdentification2Eax::FSRC)
+    }
+
+    /// The prefetch control MSR (C000_0108h) is supported if set.
+    pub fn has_prefetch_ctl_msr(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::PREFETCH_CTL_MSR)
+    }
+
+    /// CPUID can be disabled for user mode (CPL > 0) if set.
+    pub fn has_cpuid_user_dis(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::CPUID_USER_DIS)
+    }
+
+    /// Enhanced Predictive Store Forwarding is supported if set.
+    pub fn has_epsf(&self) -> bool {
+        self.eax.contains(ExtendedFeatureIdentification2Eax::EPSF)
+    }
+
+    /// PREFETCHIT0/1 instructions are supported if set.
+    pub fn has_prefetchi(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::PREFETCHI)
+    }
+
+    /// Enhanced Return Address Predictor Security is supported if set.
+    pub fn has_eraps(&self) -> bool {
+        self.eax.contains(ExtendedFeatureIdentification2Eax::ERAPS)
+    }
+
+    /// Selective Branch Predictor Barrier (PRED_CMD bit 7) is supported if set.
+    pub fn has_sbpb(&self) -> bool {
+        self.eax.contains(ExtendedFeatureIdentification2Eax::SBPB)
+    }
+
+    /// IBPB flushes all branch type predictions if set.
+    pub fn has_ibpb_brtype(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::IBPB_BRTYPE)
+    }
+
+    /// Processor is not affected by Speculative Return Stack Overflow if set.
+    pub fn has_srso_no(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::SRSO_NO)
+    }
+
+    /// Processor is not affected by SRSO across the user/kernel boundary if set.
+    pub fn has_srso_user_kernel_no(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::SRSO_USER_KERNEL_NO)
+    }
+
+    /// SRSO can be mitigated with the BP_CFG MSR (BpSpecReduce) if set.
+    pub fn has_srso_msr_fix(&self) -> bool {
+        self.eax
+            .contains(ExtendedFeatureIdentification2Eax::SRSO_MSR_FIX)
+    }
+
+    /// Size of a microcode patch in 16-byte units, 0 if not reported.
+    pub fn microcode_patch_size(&self) -> u16 {
+        get_bits(self.ebx, 0, 11) as u16
+    }
+
+    /// Return address predictor size (valid if [`Self::has_eraps`]).
+    pub fn rap_size(&self) -> u8 {
+        get_bits(self.ebx, 16, 23) as u8
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ExtendedFeatureIdentification2Eax: u32 {
+        const NO_NESTED_DATA_BP = 1 << 0;
+        const FS_GS_BASE_NS = 1 << 1;
+        const LFENCE_ALWAYS_SERIALIZING = 1 << 2;
+        const SMM_PG_CFG_LOCK = 1 << 3;
+        const VERW_CLEAR = 1 << 5;
+        const NULL_SELECT_CLEARS_BASE = 1 << 6;
+        const UPPER_ADDRESS_IGNORE = 1 << 7;
+        const AUTOMATIC_IBRS = 1 << 8;
+        const NO_SMM_CTL_MSR = 1 << 9;
+        const FSRS = 1 << 10;
+        const FSRC = 1 << 11;
+        const PREFETCH_CTL_MSR = 1 << 13;
+        const CPUID_USER_DIS = 1 << 17;
+        const EPSF = 1 << 18;
+        const PREFETCHI = 1 << 20;
+        const ERAPS = 1 << 24;
+        const SBPB = 1 << 27;
+        const IBPB_BRTYPE = 1 << 28;
+        const SRSO_NO = 1 << 29;
+        const SRSO_USER_KERNEL_NO = 1 << 30;
+        const SRSO_MSR_FIX = 1 << 31;
+    }
+}
+
+/// Extended Performance Monitoring and Debug (LEAF=0x8000_0022).
+///
+/// # Platforms
+/// ✅ AMD ❌ Intel
+#[derive(Debug, PartialEq, Eq)]
+pub struct AmdPerfMonDebugInfo {
+    eax: AmdPerfMonDebugInfoEax,
+    ebx: u32,
+    ecx: u32,
+    /// Reserved
+    _edx: u32,
+}
+
+impl AmdPerfMonDebugInfo {
+    pub(crate) fn new(data: CpuIdResult) -> Self {
+        Self {
+            eax: AmdPerfMonDebugInfoEax::from_bits_truncate(data.eax),
+            ebx: data.ebx,
+            ecx: data.ecx,
+            _edx: data.edx,
+        }
+    }
+
+    /// Performance Monitoring Version 2 is supported if set.
+    pub fn has_perfmon_v2(&self) -> bool {
+        self.eax.contains(AmdPerfMonDebugInfoEax::PERFMON_V2)
+    }
+
+    /// Last Branch Record Extension Version 2 (LbrV2) is supported if set.
+    pub fn has_lbr_v2(&self) -> bool {
+        self.eax.contains(AmdPerfMonDebugInfoEax::LBR_V2)
+    }
+
+    /// Freezing the core performance counters and LBR stack on a counter
+    /// overflow is supported if set.
+    pub fn has_lbr_and_pmc_freeze(&self) -> bool {
+        self.eax.contains(AmdPerfMonDebugInfoEax::LBR_PMC_FREEZE)
+    }
+
+    /// Number of core performance counters.
+    pub fn num_core_counters(&self) -> u8 {
+        get_bits(self.ebx, 0, 3) as u8
+    }
+
+    /// Number of entries of the LbrV2 stack.
+    pub fn lbr_v2_stack_size(&self) -> u8 {
+        get_bits(self.ebx, 4, 9) as u8
+    }
+
+    /// Number of northbridge (data fabric) performance counters.
+    pub fn num_northbridge_counters(&self) -> u8 {
+        get_bits(self.ebx, 10, 15) as u8
+    }
+
+    /// Number of unified memory controller (UMC) performance counters.
+    pub fn num_umc_counters(&self) -> u8 {
+        get_bits(self.ebx, 16, 21) as u8
+    }
+
+    /// Bit mask of the active unified memory controllers.
+    pub fn active_umc_mask(&self) -> u32 {
+        self.ecx
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct AmdPerfMonDebugInfoEax: u32 {
+        const PERFMON_V2 = 1 << 0;
+        const LBR_V2 = 1 << 1;
+        const LBR_PMC_FREEZE = 1 << 2;
+    }
+}
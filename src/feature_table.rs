@@ -0,0 +1,1482 @@
+//! Location and description of every feature flag the library knows about.
+//!
+//! [`FEATURES`] lists the flags of LEAF=0x01, LEAF=0x07 and LEAF=0x8000_0001 with
+//! the register and bit that enumerate them. The names are the ones returned by
+//! [`CpuId::features`](crate::CpuId::features), so tools like security scanners or
+//! documentation generators can be driven from this table:
+//!
+//! ```
+//! use raw_cpuid::feature_table::{self, Register};
+//!
+//! let avx2 = feature_table::find("avx2").unwrap();
+//! assert_eq!((avx2.leaf(), avx2.subleaf()), (0x7, 0x0));
+//! assert_eq!((avx2.register(), avx2.bit()), (Register::Ebx, 5));
+//! assert!(avx2.vendors().intel() && avx2.vendors().amd());
+//! ```
+//!
+//! The table is compiled in with the `feature-db` feature.
+
+use core::fmt::{self, Display, Formatter};
+
+use crate::CpuIdResult;
+
+use Register::*;
+use Vendors::*;
+
+/// Register of a [`CpuIdResult`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Register {
+    /// EAX
+    Eax,
+    /// EBX
+    Ebx,
+    /// ECX
+    Ecx,
+    /// EDX
+    Edx,
+}
+
+impl Register {
+    /// Value of this register in `result`.
+    pub const fn of(&self, result: CpuIdResult) -> u32 {
+        match self {
+            Register::Eax => result.eax,
+            Register::Ebx => result.ebx,
+            Register::Ecx => result.ecx,
+            Register::Edx => result.edx,
+        }
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Register::Eax => "eax",
+            Register::Ebx => "ebx",
+            Register::Ecx => "ecx",
+            Register::Edx => "edx",
+        })
+    }
+}
+
+/// Vendors that document a feature flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Vendors {
+    /// ✅ AMD ✅ Intel
+    Both,
+    /// ❌ AMD ✅ Intel
+    Intel,
+    /// ✅ AMD ❌ Intel
+    Amd,
+}
+
+impl Vendors {
+    /// Intel documents the flag.
+    pub const fn intel(&self) -> bool {
+        matches!(self, Vendors::Both | Vendors::Intel)
+    }
+
+    /// AMD documents the flag.
+    pub const fn amd(&self) -> bool {
+        matches!(self, Vendors::Both | Vendors::Amd)
+    }
+}
+
+/// A feature flag and the CPUID bit that enumerates it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FeatureBit {
+    leaf: u32,
+    subleaf: u32,
+    register: Register,
+    bit: u8,
+    name: &'static str,
+    description: &'static str,
+    vendors: Vendors,
+}
+
+impl FeatureBit {
+    /// Leaf (EAX input).
+    pub const fn leaf(&self) -> u32 {
+        self.leaf
+    }
+
+    /// Sub-leaf (ECX input).
+    pub const fn subleaf(&self) -> u32 {
+        self.subleaf
+    }
+
+    /// Output register that holds the flag.
+    pub const fn register(&self) -> Register {
+        self.register
+    }
+
+    /// Bit position in [`FeatureBit::register`].
+    pub const fn bit(&self) -> u8 {
+        self.bit
+    }
+
+    /// Short name, as returned by [`CpuId::features`](crate::CpuId::features)
+    /// (e.g., "SSE41").
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// One line description of the feature.
+    pub const fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Vendors that document the flag.
+    pub const fn vendors(&self) -> Vendors {
+        self.vendors
+    }
+
+    /// Is the flag set in `result` (read from [`FeatureBit::leaf`] and
+    /// [`FeatureBit::subleaf`])?
+    pub const fn is_set(&self, result: CpuIdResult) -> bool {
+        self.register.of(result) & (1 << self.bit) != 0
+    }
+}
+
+impl Display for FeatureBit {
+    /// Formats as `NAME (leaf/subleaf.register[bit])`, e.g., `AVX2 (0x7/0x0.ebx[5])`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({:#x}/{:#x}.{}[{}])",
+            self.name, self.leaf, self.subleaf, self.register, self.bit
+        )
+    }
+}
+
+/// Find a flag by its name, ignoring ASCII case.
+pub fn find(name: &str) -> Option<&'static FeatureBit> {
+    FEATURES
+        .iter()
+        .find(|feature| feature.name.eq_ignore_ascii_case(name))
+}
+
+/// Find the flag enumerated by `bit` of `register` in `leaf`/`subleaf`.
+pub fn at(leaf: u32, subleaf: u32, register: Register, bit: u8) -> Option<&'static FeatureBit> {
+    FEATURES.iter().find(|feature| {
+        feature.leaf == leaf
+            && feature.subleaf == subleaf
+            && feature.register == register
+            && feature.bit == bit
+    })
+}
+
+/// All flags enumerated by `leaf`/`subleaf`.
+pub fn in_leaf(leaf: u32, subleaf: u32) -> impl Iterator<Item = &'static FeatureBit> {
+    FEATURES
+        .iter()
+        .filter(move |feature| feature.leaf == leaf && feature.subleaf == subleaf)
+}
+
+const fn bit(
+    leaf: u32,
+    subleaf: u32,
+    register: Register,
+    bit: u8,
+    name: &'static str,
+    description: &'static str,
+    vendors: Vendors,
+) -> FeatureBit {
+    FeatureBit {
+        leaf,
+        subleaf,
+        register,
+        bit,
+        name,
+        description,
+        vendors,
+    }
+}
+
+/// All known feature flags, ordered by leaf, sub-leaf, register and bit.
+pub const FEATURES: &[FeatureBit] = &[
+    // LEAF=0x01
+    bit(0x1, 0, Ecx, 0, "SSE3", "Streaming SIMD Extensions 3", Both),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        1,
+        "PCLMULQDQ",
+        "Carry-less multiplication (PCLMULQDQ)",
+        Both,
+    ),
+    bit(0x1, 0, Ecx, 2, "DTES64", "64-bit debug store area", Intel),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        3,
+        "MONITOR",
+        "MONITOR and MWAIT instructions",
+        Both,
+    ),
+    bit(0x1, 0, Ecx, 4, "DSCPL", "CPL qualified debug store", Intel),
+    bit(0x1, 0, Ecx, 5, "VMX", "Virtual machine extensions", Intel),
+    bit(0x1, 0, Ecx, 6, "SMX", "Safer mode extensions", Intel),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        7,
+        "EIST",
+        "Enhanced Intel SpeedStep technology",
+        Intel,
+    ),
+    bit(0x1, 0, Ecx, 8, "TM2", "Thermal monitor 2", Intel),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        9,
+        "SSSE3",
+        "Supplemental Streaming SIMD Extensions 3",
+        Both,
+    ),
+    bit(0x1, 0, Ecx, 10, "CNXTID", "L1 context ID", Intel),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        12,
+        "FMA",
+        "Fused multiply-add using YMM state",
+        Both,
+    ),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        13,
+        "CMPXCHG16B",
+        "CMPXCHG16B instruction",
+        Both,
+    ),
+    bit(0x1, 0, Ecx, 15, "PDCM", "IA32_PERF_CAPABILITIES MSR", Intel),
+    bit(0x1, 0, Ecx, 17, "PCID", "Process-context identifiers", Both),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        18,
+        "DCA",
+        "Prefetch from a memory mapped device",
+        Intel,
+    ),
+    bit(0x1, 0, Ecx, 19, "SSE41", "SSE4.1 instructions", Both),
+    bit(0x1, 0, Ecx, 20, "SSE42", "SSE4.2 instructions", Both),
+    bit(0x1, 0, Ecx, 21, "X2APIC", "x2APIC", Both),
+    bit(0x1, 0, Ecx, 22, "MOVBE", "MOVBE instruction", Both),
+    bit(0x1, 0, Ecx, 23, "POPCNT", "POPCNT instruction", Both),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        24,
+        "TSC_DEADLINE",
+        "APIC timer TSC deadline mode",
+        Intel,
+    ),
+    bit(0x1, 0, Ecx, 25, "AESNI", "AES instructions", Both),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        26,
+        "XSAVE",
+        "XSAVE/XRSTOR, XSETBV/XGETBV and XCR0",
+        Both,
+    ),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        27,
+        "OSXSAVE",
+        "OS has enabled XSAVE (CR4.OSXSAVE)",
+        Both,
+    ),
+    bit(0x1, 0, Ecx, 28, "AVX", "Advanced Vector Extensions", Both),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        29,
+        "F16C",
+        "16-bit floating-point conversion instructions",
+        Both,
+    ),
+    bit(0x1, 0, Ecx, 30, "RDRAND", "RDRAND instruction", Both),
+    bit(
+        0x1,
+        0,
+        Ecx,
+        31,
+        "HYPERVISOR",
+        "Running under a hypervisor",
+        Both,
+    ),
+    bit(
+        0x1,
+        0,
+        Edx,
+        0,
+        "FPU",
+        "x87 floating point unit on-chip",
+        Both,
+    ),
+    bit(
+        0x1,
+        0,
+        Edx,
+        1,
+        "VME",
+        "Virtual 8086 mode enhancements",
+        Both,
+    ),
+    bit(
+        0x1,
+        0,
+        Edx,
+        2,
+        "DE",
+        "Debugging extensions (I/O breakpoints)",
+        Both,
+    ),
+    bit(
+        0x1,
+        0,
+        Edx,
+        3,
+        "PSE",
+        "Page size extension (4 MiB pages)",
+        Both,
+    ),
+    bit(0x1, 0, Edx, 4, "TSC", "Time stamp counter (RDTSC)", Both),
+    bit(0x1, 0, Edx, 5, "MSR", "RDMSR and WRMSR instructions", Both),
+    bit(0x1, 0, Edx, 6, "PAE", "Physical address extension", Both),
+    bit(0x1, 0, Edx, 7, "MCE", "Machine check exception", Both),
+    bit(0x1, 0, Edx, 8, "CX8", "CMPXCHG8B instruction", Both),
+    bit(0x1, 0, Edx, 9, "APIC", "APIC on-chip", Both),
+    bit(
+        0x1,
+        0,
+        Edx,
+        11,
+        "SEP",
+        "SYSENTER and SYSEXIT instructions",
+        Both,
+    ),
+    bit(0x1, 0, Edx, 12, "MTRR", "Memory type range registers", Both),
+    bit(0x1, 0, Edx, 13, "PGE", "Global pages (CR4.PGE)", Both),
+    bit(0x1, 0, Edx, 14, "MCA", "Machine check architecture", Both),
+    bit(
+        0x1,
+        0,
+        Edx,
+        15,
+        "CMOV",
+        "Conditional move instructions",
+        Both,
+    ),
+    bit(0x1, 0, Edx, 16, "PAT", "Page attribute table", Both),
+    bit(0x1, 0, Edx, 17, "PSE36", "36-bit page size extension", Both),
+    bit(0x1, 0, Edx, 18, "PSN", "Processor serial number", Intel),
+    bit(0x1, 0, Edx, 19, "CLFSH", "CLFLUSH instruction", Both),
+    bit(0x1, 0, Edx, 21, "DS", "Debug store", Intel),
+    bit(
+        0x1,
+        0,
+        Edx,
+        22,
+        "ACPI",
+        "Thermal monitor and software controlled clock",
+        Intel,
+    ),
+    bit(0x1, 0, Edx, 23, "MMX", "MMX technology", Both),
+    bit(
+        0x1,
+        0,
+        Edx,
+        24,
+        "FXSR",
+        "FXSAVE and FXRSTOR instructions",
+        Both,
+    ),
+    bit(0x1, 0, Edx, 25, "SSE", "Streaming SIMD Extensions", Both),
+    bit(0x1, 0, Edx, 26, "SSE2", "Streaming SIMD Extensions 2", Both),
+    bit(0x1, 0, Edx, 27, "SS", "Self snoop", Intel),
+    bit(
+        0x1,
+        0,
+        Edx,
+        28,
+        "HTT",
+        "Max APIC IDs field (LEAF=0x01 EBX[23:16]) is valid",
+        Both,
+    ),
+    bit(0x1, 0, Edx, 29, "TM", "Thermal monitor", Intel),
+    bit(0x1, 0, Edx, 31, "PBE", "Pending break enable", Intel),
+    // LEAF=0x07, sub-leaf 0
+    bit(
+        0x7,
+        0,
+        Ebx,
+        0,
+        "FSGSBASE",
+        "RDFSBASE/RDGSBASE/WRFSBASE/WRGSBASE",
+        Both,
+    ),
+    bit(0x7, 0, Ebx, 1, "ADJUST_MSR", "IA32_TSC_ADJUST MSR", Intel),
+    bit(0x7, 0, Ebx, 2, "SGX", "Software Guard Extensions", Intel),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        3,
+        "BMI1",
+        "Bit manipulation instructions 1",
+        Both,
+    ),
+    bit(0x7, 0, Ebx, 4, "HLE", "Hardware lock elision", Intel),
+    bit(0x7, 0, Ebx, 5, "AVX2", "Advanced Vector Extensions 2", Both),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        6,
+        "FDP",
+        "x87 FPU data pointer updated only on exceptions",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        7,
+        "SMEP",
+        "Supervisor-mode execution prevention",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        8,
+        "BMI2",
+        "Bit manipulation instructions 2",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        9,
+        "REP_MOVSB_STOSB",
+        "Enhanced REP MOVSB/STOSB",
+        Both,
+    ),
+    bit(0x7, 0, Ebx, 10, "INVPCID", "INVPCID instruction", Both),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        11,
+        "RTM",
+        "Restricted transactional memory",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        12,
+        "RDTM",
+        "Resource director technology monitoring",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        13,
+        "DEPRECATE_FPU_CS_DS",
+        "FPU CS and FPU DS are deprecated",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        14,
+        "MPX",
+        "Memory protection extensions",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        15,
+        "RDTA",
+        "Resource director technology allocation",
+        Both,
+    ),
+    bit(0x7, 0, Ebx, 16, "AVX512F", "AVX-512 foundation", Both),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        17,
+        "AVX512DQ",
+        "AVX-512 doubleword and quadword instructions",
+        Both,
+    ),
+    bit(0x7, 0, Ebx, 18, "RDSEED", "RDSEED instruction", Both),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        19,
+        "ADX",
+        "Multi-precision add-carry instructions",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        20,
+        "SMAP",
+        "Supervisor-mode access prevention",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        21,
+        "AVX512_IFMA",
+        "AVX-512 integer fused multiply-add",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        23,
+        "CLFLUSHOPT",
+        "CLFLUSHOPT instruction",
+        Both,
+    ),
+    bit(0x7, 0, Ebx, 24, "CLWB", "CLWB instruction", Both),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        25,
+        "PROCESSOR_TRACE",
+        "Intel processor trace",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        26,
+        "AVX512PF",
+        "AVX-512 prefetch (Xeon Phi)",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        27,
+        "AVX512ER",
+        "AVX-512 exponential and reciprocal (Xeon Phi)",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        28,
+        "AVX512CD",
+        "AVX-512 conflict detection",
+        Both,
+    ),
+    bit(0x7, 0, Ebx, 29, "SHA", "SHA extensions", Both),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        30,
+        "AVX512BW",
+        "AVX-512 byte and word instructions",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ebx,
+        31,
+        "AVX512VL",
+        "AVX-512 vector length extensions",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        0,
+        "PREFETCHWT1",
+        "PREFETCHWT1 instruction (Xeon Phi)",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        1,
+        "AVX512VBMI",
+        "AVX-512 vector byte manipulation",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        2,
+        "UMIP",
+        "User-mode instruction prevention",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        3,
+        "PKU",
+        "Protection keys for user-mode pages",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        4,
+        "OSPKE",
+        "OS has enabled protection keys (CR4.PKE)",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        5,
+        "WAITPKG",
+        "TPAUSE, UMONITOR and UMWAIT instructions",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        6,
+        "AVX512VBMI2",
+        "AVX-512 vector byte manipulation 2",
+        Both,
+    ),
+    bit(0x7, 0, Ecx, 7, "CETSS", "CET shadow stack", Both),
+    bit(0x7, 0, Ecx, 8, "GFNI", "Galois field instructions", Both),
+    bit(0x7, 0, Ecx, 9, "VAES", "Vector AES instructions", Both),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        10,
+        "VPCLMULQDQ",
+        "Vector carry-less multiplication",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        11,
+        "AVX512VNNI",
+        "AVX-512 vector neural network instructions",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        12,
+        "AVX512BITALG",
+        "AVX-512 bit algorithms",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        13,
+        "TMEEN",
+        "Total memory encryption MSRs",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        14,
+        "AVX512VPOPCNTDQ",
+        "AVX-512 doubleword and quadword POPCNT",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        16,
+        "LA57",
+        "57-bit linear addresses and five-level paging",
+        Both,
+    ),
+    bit(0x7, 0, Ecx, 22, "RDPID", "RDPID instruction", Both),
+    bit(0x7, 0, Ecx, 23, "KL", "Key Locker", Intel),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        24,
+        "BUS_LOCK_DETECT",
+        "OS bus-lock detection",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        25,
+        "CLDEMOTE",
+        "Cache line demote instruction",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        27,
+        "MOVDIRI",
+        "Direct store instruction",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        28,
+        "MOVDIR64B",
+        "64-byte direct store instruction",
+        Intel,
+    ),
+    bit(0x7, 0, Ecx, 29, "ENQCMD", "Enqueue stores", Intel),
+    bit(0x7, 0, Ecx, 30, "SGX_LC", "SGX launch configuration", Intel),
+    bit(
+        0x7,
+        0,
+        Ecx,
+        31,
+        "PKS",
+        "Protection keys for supervisor-mode pages",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        2,
+        "AVX512_4VNNIW",
+        "AVX-512 4-iteration neural network (Xeon Phi)",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        3,
+        "AVX512_4FMAPS",
+        "AVX-512 4-iteration multiply-add (Xeon Phi)",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        8,
+        "AVX512_VP2INTERSECT",
+        "AVX-512 VP2INTERSECT instructions",
+        Both,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        10,
+        "MD_CLEAR",
+        "VERW clears CPU buffers (MDS mitigation)",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        11,
+        "RTM_ALWAYS_ABORT",
+        "XBEGIN always aborts",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        13,
+        "TSX_FORCE_ABORT",
+        "IA32_TSX_FORCE_ABORT MSR",
+        Intel,
+    ),
+    bit(0x7, 0, Edx, 14, "SERIALIZE", "SERIALIZE instruction", Intel),
+    bit(0x7, 0, Edx, 15, "HYBRID", "Hybrid processor", Intel),
+    bit(
+        0x7,
+        0,
+        Edx,
+        16,
+        "TSXLDTRK",
+        "TSX suspend load address tracking",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        20,
+        "CET_IBT",
+        "CET indirect branch tracking",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        22,
+        "AMX_BF16",
+        "AMX bfloat16 tile operations",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        23,
+        "AVX512_FP16",
+        "AVX-512 half precision instructions",
+        Intel,
+    ),
+    bit(0x7, 0, Edx, 24, "AMX_TILE", "AMX tile architecture", Intel),
+    bit(
+        0x7,
+        0,
+        Edx,
+        25,
+        "AMX_INT8",
+        "AMX 8-bit integer tile operations",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        26,
+        "IBRS_IBPB",
+        "Indirect branch restricted speculation and predictor barrier",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        27,
+        "STIBP",
+        "Single thread indirect branch predictors",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        28,
+        "L1D_FLUSH",
+        "IA32_FLUSH_CMD MSR (L1D flush)",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        29,
+        "ARCH_CAPABILITIES",
+        "IA32_ARCH_CAPABILITIES MSR",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        30,
+        "CORE_CAPABILITIES",
+        "IA32_CORE_CAPABILITIES MSR",
+        Intel,
+    ),
+    bit(
+        0x7,
+        0,
+        Edx,
+        31,
+        "SSBD",
+        "Speculative store bypass disable",
+        Intel,
+    ),
+    // LEAF=0x07, sub-leaf 1
+    bit(0x7, 1, Eax, 0, "SHA512", "SHA512 instructions", Intel),
+    bit(0x7, 1, Eax, 1, "SM3", "SM3 hash instructions", Intel),
+    bit(0x7, 1, Eax, 2, "SM4", "SM4 cipher instructions", Intel),
+    bit(
+        0x7,
+        1,
+        Eax,
+        3,
+        "RAO_INT",
+        "Remote atomic integer instructions",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Eax,
+        4,
+        "AVX_VNNI",
+        "VEX-encoded vector neural network instructions",
+        Both,
+    ),
+    bit(
+        0x7,
+        1,
+        Eax,
+        5,
+        "AVX512_BF16",
+        "AVX-512 bfloat16 instructions",
+        Both,
+    ),
+    bit(
+        0x7,
+        1,
+        Eax,
+        6,
+        "LASS",
+        "Linear address space separation",
+        Intel,
+    ),
+    bit(0x7, 1, Eax, 7, "CMPCCXADD", "CMPccXADD instruction", Intel),
+    bit(
+        0x7,
+        1,
+        Eax,
+        8,
+        "ARCH_PERFMON_EXT",
+        "Architectural performance monitoring extended leaf (0x23)",
+        Intel,
+    ),
+    bit(0x7, 1, Eax, 10, "FZRM", "Fast zero-length REP MOVSB", Intel),
+    bit(0x7, 1, Eax, 11, "FSRS", "Fast short REP STOSB", Intel),
+    bit(
+        0x7,
+        1,
+        Eax,
+        12,
+        "FSRCRS",
+        "Fast short REP CMPSB and REP SCASB",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Eax,
+        17,
+        "FRED",
+        "Flexible return and event delivery",
+        Intel,
+    ),
+    bit(0x7, 1, Eax, 18, "LKGS", "LKGS instruction", Intel),
+    bit(
+        0x7,
+        1,
+        Eax,
+        19,
+        "WRMSRNS",
+        "Non-serializing WRMSR instruction",
+        Intel,
+    ),
+    bit(0x7, 1, Eax, 20, "NMI_SRC", "NMI-source reporting", Intel),
+    bit(
+        0x7,
+        1,
+        Eax,
+        21,
+        "AMX_FP16",
+        "AMX FP16 tile operations",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Eax,
+        22,
+        "HRESET",
+        "HRESET instruction and IA32_HRESET_ENABLE MSR",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Eax,
+        23,
+        "AVX_IFMA",
+        "VEX-encoded integer fused multiply-add instructions",
+        Intel,
+    ),
+    bit(0x7, 1, Eax, 26, "LAM", "Linear address masking", Intel),
+    bit(
+        0x7,
+        1,
+        Eax,
+        27,
+        "MSRLIST",
+        "RDMSRLIST and WRMSRLIST instructions",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Ebx,
+        0,
+        "PPIN",
+        "IA32_PPIN and IA32_PPIN_CTL MSRs",
+        Intel,
+    ),
+    bit(0x7, 1, Ebx, 1, "PBNDKB", "PBNDKB instruction", Intel),
+    bit(
+        0x7,
+        1,
+        Edx,
+        4,
+        "AVX_VNNI_INT8",
+        "AVX VNNI 8-bit integer instructions",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Edx,
+        5,
+        "AVX_NE_CONVERT",
+        "AVX no-exception FP conversion instructions",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Edx,
+        8,
+        "AMX_COMPLEX",
+        "AMX complex number tile operations",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Edx,
+        10,
+        "AVX_VNNI_INT16",
+        "AVX VNNI 16-bit integer instructions",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Edx,
+        14,
+        "PREFETCHI",
+        "PREFETCHIT0 and PREFETCHIT1 instructions",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Edx,
+        15,
+        "USER_MSR",
+        "URDMSR and UWRMSR instructions",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Edx,
+        17,
+        "UIRET_UIF",
+        "UIRET sets UIF from the stack",
+        Intel,
+    ),
+    bit(
+        0x7,
+        1,
+        Edx,
+        18,
+        "CET_SSS",
+        "Supervisor shadow stacks can be enabled safely",
+        Intel,
+    ),
+    bit(0x7, 1, Edx, 19, "AVX10", "Intel AVX10 instructions", Intel),
+    bit(
+        0x7,
+        1,
+        Edx,
+        21,
+        "APX_F",
+        "Advanced performance extensions foundation",
+        Intel,
+    ),
+    // LEAF=0x07, sub-leaf 2
+    bit(
+        0x7,
+        2,
+        Edx,
+        0,
+        "PSFD",
+        "IA32_SPEC_CTRL.PSFD: disable fast store forwarding predictor",
+        Intel,
+    ),
+    bit(
+        0x7,
+        2,
+        Edx,
+        1,
+        "IPRED_CTRL",
+        "IA32_SPEC_CTRL.IPRED_DIS_U/S: restrict indirect branch prediction",
+        Intel,
+    ),
+    bit(
+        0x7,
+        2,
+        Edx,
+        2,
+        "RRSBA_CTRL",
+        "IA32_SPEC_CTRL.RRSBA_DIS_U/S: disable RRSBA behavior",
+        Intel,
+    ),
+    bit(
+        0x7,
+        2,
+        Edx,
+        3,
+        "DDPD_U",
+        "IA32_SPEC_CTRL.DDPD_U: disable data dependent prefetcher",
+        Intel,
+    ),
+    bit(
+        0x7,
+        2,
+        Edx,
+        4,
+        "BHI_CTRL",
+        "IA32_SPEC_CTRL.BHI_DIS_S: branch history injection mitigation",
+        Intel,
+    ),
+    bit(
+        0x7,
+        2,
+        Edx,
+        5,
+        "MCDT_NO",
+        "No MXCSR configuration dependent timing",
+        Intel,
+    ),
+    // LEAF=0x8000_0001
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        0,
+        "LAHF_SAHF",
+        "LAHF and SAHF in 64-bit mode",
+        Both,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        1,
+        "CMP_LEGACY",
+        "Core multi-processing legacy mode",
+        Amd,
+    ),
+    bit(0x8000_0001, 0, Ecx, 2, "SVM", "Secure virtual machine", Amd),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        3,
+        "EXT_APIC_SPACE",
+        "Extended APIC register space",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        4,
+        "ALTMOVCR8",
+        "LOCK MOV CR0 means MOV CR8",
+        Amd,
+    ),
+    bit(0x8000_0001, 0, Ecx, 5, "LZCNT", "LZCNT instruction", Both),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        6,
+        "SSE4A",
+        "EXTRQ, INSERTQ, MOVNTSS and MOVNTSD",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        7,
+        "MISALIGNSSE",
+        "Misaligned SSE mode",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        8,
+        "PREFETCHW",
+        "PREFETCH and PREFETCHW instructions",
+        Both,
+    ),
+    bit(0x8000_0001, 0, Ecx, 9, "OSVW", "OS visible workaround", Amd),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        10,
+        "IBS",
+        "Instruction based sampling",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        11,
+        "XOP",
+        "Extended operation support",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        12,
+        "SKINIT",
+        "SKINIT and STGI instructions",
+        Amd,
+    ),
+    bit(0x8000_0001, 0, Ecx, 13, "WDT", "Watchdog timer", Amd),
+    bit(0x8000_0001, 0, Ecx, 15, "LWP", "Lightweight profiling", Amd),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        16,
+        "FMA4",
+        "Four-operand fused multiply-add",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        21,
+        "TBM",
+        "Trailing bit manipulation",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        22,
+        "TOPEXT",
+        "Topology extensions",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        23,
+        "PERFCTREXT",
+        "Core performance counter extensions",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        24,
+        "PERFCTREXTNB",
+        "NB performance counter extensions",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        26,
+        "DATABRKPEXT",
+        "Data breakpoint extension",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        27,
+        "PERFTSC",
+        "Performance time-stamp counter",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        28,
+        "PERFCTREXTLLC",
+        "L3 performance counter extensions",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        29,
+        "MONITORX",
+        "MONITORX and MWAITX instructions",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Ecx,
+        30,
+        "ADDRMASKEXT",
+        "Breakpoint address mask extension",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Edx,
+        11,
+        "SYSCALL_SYSRET",
+        "SYSCALL and SYSRET instructions",
+        Both,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Edx,
+        20,
+        "EXECUTE_DISABLE",
+        "No-execute page protection",
+        Both,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Edx,
+        22,
+        "MMXEXT",
+        "AMD extensions to MMX instructions",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Edx,
+        24,
+        "FFXSR",
+        "FXSAVE and FXRSTOR optimizations",
+        Amd,
+    ),
+    bit(0x8000_0001, 0, Edx, 26, "GIB_PAGES", "1 GiB pages", Both),
+    bit(
+        0x8000_0001,
+        0,
+        Edx,
+        27,
+        "RDTSCP",
+        "RDTSCP instruction",
+        Both,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Edx,
+        29,
+        "I64BIT_MODE",
+        "64-bit long mode",
+        Both,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Edx,
+        30,
+        "THREEDNOWEXT",
+        "AMD extensions to 3DNow! instructions",
+        Amd,
+    ),
+    bit(
+        0x8000_0001,
+        0,
+        Edx,
+        31,
+        "THREEDNOW",
+        "3DNow! instructions",
+        Amd,
+    ),
+];
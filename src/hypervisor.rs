@@ -0,0 +1,419 @@
+//! Builders for the hypervisor leafs (LEAF=0x4000_0000 and up) of guest CPU profiles.
+//!
+//! A VMM that exposes enlightenments has to report a coherent block of leafs: the
+//! vendor signature and maximum leaf in LEAF=0x4000_0000 followed by the
+//! interface, feature and recommendation leafs the guest looks for. [`HyperVProfile`]
+//! and [`KvmProfile`] generate that block from a small config, and
+//! [`CpuIdSnapshot::with_hypervisor_leafs`] installs it into a guest profile:
+//!
+//! ```
+//! use raw_cpuid::hypervisor::{KvmFeatures, KvmProfile};
+//! use raw_cpuid::{CpuId, Hypervisor};
+//!
+//! let kvm = KvmProfile {
+//!     features: KvmFeatures::CLOCKSOURCE2 | KvmFeatures::PV_EOI,
+//!     tsc_frequency_khz: Some(2_000_000),
+//!     ..Default::default()
+//! };
+//! let guest = CpuId::new().snapshot().with_hypervisor_leafs(kvm.leafs());
+//!
+//! let cpuid = guest.cpuid();
+//! # if cpuid.get_feature_info().is_some() {
+//! let info = cpuid.get_hypervisor_info().unwrap();
+//! assert_eq!(info.identify(), Hypervisor::KVM);
+//! assert_eq!(info.tsc_frequency(), Some(2_000_000));
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+use bitflags::bitflags;
+
+use crate::{CpuIdResult, CpuIdSnapshot, EAX_HYPERVISOR_INFO};
+
+/// Interface signature "Hv#1" of the Hyper-V TLFS (LEAF=0x4000_0001 EAX).
+const HYPERV_INTERFACE: u32 = 0x3123_7648;
+
+/// Leaf with the (virtual) TSC and APIC frequencies, see [`crate::HypervisorInfo`].
+const EAX_HYPERVISOR_TIMING: u32 = 0x4000_0010;
+
+/// Hypervisor bit of LEAF=0x01 ECX.
+const CPUID_ECX_HYPERVISOR: u32 = 1 << 31;
+
+/// Split a 12 character vendor signature into EBX, ECX and EDX.
+fn signature(sig: &[u8; 12]) -> (u32, u32, u32) {
+    let reg = |idx: usize| u32::from_le_bytes([sig[idx], sig[idx + 1], sig[idx + 2], sig[idx + 3]]);
+    (reg(0), reg(4), reg(8))
+}
+
+fn leaf(leaf: u32, eax: u32, ebx: u32, ecx: u32, edx: u32) -> (u32, u32, CpuIdResult) {
+    (leaf, 0, CpuIdResult { eax, ebx, ecx, edx })
+}
+
+bitflags! {
+    /// Partition privilege mask of Hyper-V (LEAF=0x4000_0003 EBX:EAX).
+    #[repr(transparent)]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct HyperVPrivileges: u64 {
+        /// Virtual processor run time MSR (HV_X64_MSR_VP_RUNTIME).
+        const ACCESS_VP_RUNTIME_REG = 1 << 0;
+        /// Partition reference counter MSR (HV_X64_MSR_TIME_REF_COUNT).
+        const ACCESS_PARTITION_REFERENCE_COUNTER = 1 << 1;
+        /// Synthetic interrupt controller MSRs.
+        const ACCESS_SYNIC_REGS = 1 << 2;
+        /// Synthetic timer MSRs.
+        const ACCESS_SYNTHETIC_TIMER_REGS = 1 << 3;
+        /// APIC access MSRs (EOI, ICR, TPR) and the VP assist page.
+        const ACCESS_INTR_CTRL_REGS = 1 << 4;
+        /// Hypercall page and guest OS ID MSRs.
+        const ACCESS_HYPERCALL_MSRS = 1 << 5;
+        /// Virtual processor index MSR.
+        const ACCESS_VP_INDEX = 1 << 6;
+        /// Reset MSR.
+        const ACCESS_RESET_REG = 1 << 7;
+        /// Statistics MSRs.
+        const ACCESS_STATS_REG = 1 << 8;
+        /// Partition reference TSC page.
+        const ACCESS_PARTITION_REFERENCE_TSC = 1 << 9;
+        /// Guest idle MSR.
+        const ACCESS_GUEST_IDLE_REG = 1 << 10;
+        /// TSC and APIC frequency MSRs.
+        const ACCESS_FREQUENCY_REGS = 1 << 11;
+        /// Synthetic debug MSRs.
+        const ACCESS_DEBUG_REGS = 1 << 12;
+        /// Reenlightenment control MSRs.
+        const ACCESS_REENLIGHTENMENT_CONTROLS = 1 << 13;
+
+        // EBX flags
+
+        /// HvCreatePartition hypercall.
+        const CREATE_PARTITIONS = 1 << 32;
+        /// HvGetPartitionId hypercall.
+        const ACCESS_PARTITION_ID = 1 << 33;
+        /// Memory pool hypercalls.
+        const ACCESS_MEMORY_POOL = 1 << 34;
+        /// HvPostMessage hypercall.
+        const POST_MESSAGES = 1 << 36;
+        /// HvSignalEvent hypercall.
+        const SIGNAL_EVENTS = 1 << 37;
+        /// HvCreatePort hypercall.
+        const CREATE_PORT = 1 << 38;
+        /// HvConnectPort hypercall.
+        const CONNECT_PORT = 1 << 39;
+        /// Statistics pages.
+        const ACCESS_STATS = 1 << 40;
+        /// Debugging hypercalls.
+        const DEBUGGING = 1 << 43;
+        /// CPU management hypercalls.
+        const CPU_MANAGEMENT = 1 << 44;
+        /// Virtual Secure Mode.
+        const ACCESS_VSM = 1 << 48;
+        /// HvGetVpRegisters and HvSetVpRegisters hypercalls.
+        const ACCESS_VP_REGISTERS = 1 << 49;
+        /// Extended hypercalls.
+        const ENABLE_EXTENDED_HYPERCALLS = 1 << 52;
+        /// HvStartVirtualProcessor hypercall.
+        const START_VIRTUAL_PROCESSOR = 1 << 53;
+    }
+}
+
+bitflags! {
+    /// Miscellaneous features of Hyper-V (LEAF=0x4000_0003 EDX).
+    #[repr(transparent)]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct HyperVFeatures: u32 {
+        /// Guest debugging support.
+        const GUEST_DEBUGGING = 1 << 1;
+        /// Performance monitor support.
+        const PERFORMANCE_MONITOR = 1 << 2;
+        /// Physical CPU dynamic partitioning events.
+        const CPU_DYNAMIC_PARTITIONING = 1 << 3;
+        /// Hypercall input parameters can be passed in XMM registers.
+        const XMM_HYPERCALL_INPUT = 1 << 4;
+        /// Virtual guest idle state.
+        const GUEST_IDLE = 1 << 5;
+        /// Hypervisor sleep state.
+        const HYPERVISOR_SLEEP_STATE = 1 << 6;
+        /// NUMA distances can be queried.
+        const NUMA_DISTANCE_QUERY = 1 << 7;
+        /// Timer frequencies can be determined.
+        const TIMER_FREQUENCIES_QUERY = 1 << 8;
+        /// Synthetic machine check injection.
+        const SYNTHETIC_MACHINE_CHECK = 1 << 9;
+        /// Guest crash MSRs.
+        const GUEST_CRASH_MSRS = 1 << 10;
+        /// Debug MSRs.
+        const DEBUG_MSRS = 1 << 11;
+        /// Non-privileged instruction execution prevention.
+        const NPIEP = 1 << 12;
+        /// The guest can disable the hypervisor.
+        const DISABLE_HYPERVISOR = 1 << 13;
+        /// Extended GVA ranges for HvFlushVirtualAddressList.
+        const EXTENDED_GVA_RANGES_FLUSH = 1 << 14;
+        /// Hypercall output parameters can be returned in XMM registers.
+        const XMM_HYPERCALL_OUTPUT = 1 << 15;
+        /// Synthetic interrupt source polling mode.
+        const SINT_POLLING_MODE = 1 << 17;
+        /// Hypercall MSR lock.
+        const HYPERCALL_MSR_LOCK = 1 << 18;
+        /// Direct synthetic timers.
+        const DIRECT_SYNTHETIC_TIMERS = 1 << 19;
+    }
+}
+
+bitflags! {
+    /// Implementation recommendations of Hyper-V (LEAF=0x4000_0004 EAX).
+    #[repr(transparent)]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct HyperVRecommendations: u32 {
+        /// Use a hypercall for address space switches instead of MOV to CR3.
+        const ADDRESS_SPACE_SWITCH_HYPERCALL = 1 << 0;
+        /// Use a hypercall for local TLB flushes instead of INVLPG or MOV to CR3.
+        const LOCAL_TLB_FLUSH_HYPERCALL = 1 << 1;
+        /// Use a hypercall for remote TLB flushes instead of inter-processor interrupts.
+        const REMOTE_TLB_FLUSH_HYPERCALL = 1 << 2;
+        /// Use MSRs to access the APIC EOI, ICR and TPR registers.
+        const APIC_ACCESS_MSRS = 1 << 3;
+        /// Use the hypervisor MSR to reset the system.
+        const SYSTEM_RESET_MSR = 1 << 4;
+        /// Use relaxed timing (disable watchdog timeouts).
+        const RELAXED_TIMING = 1 << 5;
+        /// Use DMA remapping.
+        const DMA_REMAPPING = 1 << 6;
+        /// Use interrupt remapping.
+        const INTERRUPT_REMAPPING = 1 << 7;
+        /// Use x2APIC MSRs.
+        const X2APIC_MSRS = 1 << 8;
+        /// Don't use AutoEOI.
+        const DEPRECATE_AUTO_EOI = 1 << 9;
+        /// Use the HvCallSendSyntheticClusterIpi hypercall.
+        const SYNTHETIC_CLUSTER_IPI = 1 << 10;
+        /// Use the extended processor masks of the flush and IPI hypercalls.
+        const EX_PROCESSOR_MASKS = 1 << 11;
+        /// The guest runs nested inside a Hyper-V partition.
+        const NESTED = 1 << 12;
+        /// Use INT for MBEC system calls.
+        const INT_FOR_MBEC_SYSCALLS = 1 << 13;
+        /// Use the enlightened VMCS interface.
+        const ENLIGHTENED_VMCS = 1 << 14;
+    }
+}
+
+/// Config of the Hyper-V enlightenments reported to a guest (LEAF=0x4000_0000
+/// to LEAF=0x4000_0005).
+///
+/// The signature is "Microsoft Hv" and the interface "Hv#1", as defined by the
+/// Hypervisor Top Level Functional Specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HyperVProfile {
+    /// Build number (LEAF=0x4000_0002 EAX).
+    pub build_number: u32,
+    /// Major version (LEAF=0x4000_0002 `EBX[31:16]`).
+    pub major_version: u16,
+    /// Minor version (LEAF=0x4000_0002 `EBX[15:0]`).
+    pub minor_version: u16,
+    /// Partition privileges (LEAF=0x4000_0003 EAX and EBX).
+    pub privileges: HyperVPrivileges,
+    /// Miscellaneous features (LEAF=0x4000_0003 EDX).
+    pub features: HyperVFeatures,
+    /// Recommendations (LEAF=0x4000_0004 EAX).
+    pub recommendations: HyperVRecommendations,
+    /// Number of spinlock retries before notifying the hypervisor, `None` to
+    /// never notify (LEAF=0x4000_0004 EBX).
+    pub spinlock_retries: Option<u32>,
+    /// Maximum number of virtual processors (LEAF=0x4000_0005 EAX).
+    pub max_vcpus: u32,
+    /// Maximum number of logical processors (LEAF=0x4000_0005 EBX).
+    pub max_logical_processors: u32,
+}
+
+impl Default for HyperVProfile {
+    /// Version 10.0 with the clock, SynIC and APIC enlightenments most guests use.
+    fn default() -> Self {
+        Self {
+            build_number: 0,
+            major_version: 10,
+            minor_version: 0,
+            privileges: HyperVPrivileges::ACCESS_VP_RUNTIME_REG
+                | HyperVPrivileges::ACCESS_PARTITION_REFERENCE_COUNTER
+                | HyperVPrivileges::ACCESS_SYNIC_REGS
+                | HyperVPrivileges::ACCESS_SYNTHETIC_TIMER_REGS
+                | HyperVPrivileges::ACCESS_INTR_CTRL_REGS
+                | HyperVPrivileges::ACCESS_HYPERCALL_MSRS
+                | HyperVPrivileges::ACCESS_VP_INDEX
+                | HyperVPrivileges::ACCESS_PARTITION_REFERENCE_TSC,
+            features: HyperVFeatures::empty(),
+            recommendations: HyperVRecommendations::APIC_ACCESS_MSRS
+                | HyperVRecommendations::RELAXED_TIMING,
+            spinlock_retries: None,
+            max_vcpus: 0,
+            max_logical_processors: 0,
+        }
+    }
+}
+
+impl HyperVProfile {
+    /// The leafs LEAF=0x4000_0000 to LEAF=0x4000_0005 described by this config.
+    pub fn leafs(&self) -> Vec<(u32, u32, CpuIdResult)> {
+        let (ebx, ecx, edx) = signature(b"Microsoft Hv");
+        let privileges = self.privileges.bits();
+        Vec::from([
+            leaf(EAX_HYPERVISOR_INFO, 0x4000_0005, ebx, ecx, edx),
+            leaf(0x4000_0001, HYPERV_INTERFACE, 0, 0, 0),
+            leaf(
+                0x4000_0002,
+                self.build_number,
+                (self.major_version as u32) << 16 | self.minor_version as u32,
+                0,
+                0,
+            ),
+            leaf(
+                0x4000_0003,
+                privileges as u32,
+                (privileges >> 32) as u32,
+                0,
+                self.features.bits(),
+            ),
+            leaf(
+                0x4000_0004,
+                self.recommendations.bits(),
+                self.spinlock_retries.unwrap_or(u32::MAX),
+                0,
+                0,
+            ),
+            leaf(
+                0x4000_0005,
+                self.max_vcpus,
+                self.max_logical_processors,
+                0,
+                0,
+            ),
+        ])
+    }
+}
+
+bitflags! {
+    /// Paravirtual features of KVM (LEAF=0x4000_0001 EAX).
+    #[repr(transparent)]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct KvmFeatures: u32 {
+        /// kvmclock at the old MSRs 0x11 and 0x12.
+        const CLOCKSOURCE = 1 << 0;
+        /// No delays are needed on PIO operations.
+        const NOP_IO_DELAY = 1 << 1;
+        /// Deprecated MMU operations.
+        const MMU_OP = 1 << 2;
+        /// kvmclock at the new MSRs 0x4b564d00 and 0x4b564d01.
+        const CLOCKSOURCE2 = 1 << 3;
+        /// Asynchronous page faults.
+        const ASYNC_PF = 1 << 4;
+        /// Steal time accounting.
+        const STEAL_TIME = 1 << 5;
+        /// Paravirtual end of interrupt.
+        const PV_EOI = 1 << 6;
+        /// Paravirtual spinlocks (kick a halted vCPU).
+        const PV_UNHALT = 1 << 7;
+        /// Paravirtual TLB flushes.
+        const PV_TLB_FLUSH = 1 << 9;
+        /// Asynchronous page faults delivered as #PF vmexits.
+        const ASYNC_PF_VMEXIT = 1 << 10;
+        /// Paravirtual IPIs.
+        const PV_SEND_IPI = 1 << 11;
+        /// Host-side halt polling control.
+        const POLL_CONTROL = 1 << 12;
+        /// Paravirtual sched yield.
+        const PV_SCHED_YIELD = 1 << 13;
+        /// Asynchronous page fault "page ready" notifications as interrupts.
+        const ASYNC_PF_INT = 1 << 14;
+        /// Extended destination IDs in MSI address bits 11-5.
+        const MSI_EXT_DEST_ID = 1 << 15;
+        /// KVM_HC_MAP_GPA_RANGE hypercall.
+        const HC_MAP_GPA_RANGE = 1 << 16;
+        /// Migration control MSR.
+        const MIGRATION_CONTROL = 1 << 17;
+        /// kvmclock is stable across vCPUs (PVCLOCK_TSC_STABLE_BIT).
+        const CLOCKSOURCE_STABLE_BIT = 1 << 24;
+    }
+}
+
+bitflags! {
+    /// Hints of KVM to the guest (LEAF=0x4000_0001 EDX).
+    #[repr(transparent)]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct KvmHints: u32 {
+        /// vCPUs are never preempted for an unlimited time.
+        const REALTIME = 1 << 0;
+    }
+}
+
+/// Config of the KVM paravirtual interface reported to a guest (LEAF=0x4000_0000,
+/// LEAF=0x4000_0001 and optionally LEAF=0x4000_0010).
+///
+/// The signature is "KVMKVMKVM\0\0\0".
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct KvmProfile {
+    /// Paravirtual features (LEAF=0x4000_0001 EAX).
+    pub features: KvmFeatures,
+    /// Hints (LEAF=0x4000_0001 EDX).
+    pub hints: KvmHints,
+    /// (Virtual) TSC frequency in kHz (LEAF=0x4000_0010 EAX).
+    ///
+    /// The timing leaf is only reported if this or `apic_frequency_khz` is set.
+    pub tsc_frequency_khz: Option<u32>,
+    /// (Virtual) bus (local APIC timer) frequency in kHz (LEAF=0x4000_0010 EBX).
+    pub apic_frequency_khz: Option<u32>,
+}
+
+impl KvmProfile {
+    /// The leafs LEAF=0x4000_0000, LEAF=0x4000_0001 and (if a frequency is set)
+    /// LEAF=0x4000_0010 described by this config.
+    pub fn leafs(&self) -> Vec<(u32, u32, CpuIdResult)> {
+        let timing = self.tsc_frequency_khz.is_some() || self.apic_frequency_khz.is_some();
+        let max_leaf = if timing {
+            EAX_HYPERVISOR_TIMING
+        } else {
+            0x4000_0001
+        };
+
+        let (ebx, ecx, edx) = signature(b"KVMKVMKVM\0\0\0");
+        let mut leafs = Vec::from([
+            leaf(EAX_HYPERVISOR_INFO, max_leaf, ebx, ecx, edx),
+            leaf(0x4000_0001, self.features.bits(), 0, 0, self.hints.bits()),
+        ]);
+        if timing {
+            leafs.push(leaf(
+                EAX_HYPERVISOR_TIMING,
+                self.tsc_frequency_khz.unwrap_or(0),
+                self.apic_frequency_khz.unwrap_or(0),
+                0,
+                0,
+            ));
+        }
+        leafs
+    }
+}
+
+impl CpuIdSnapshot {
+    /// A copy of this snapshot that reports `leafs` as the hypervisor leafs.
+    ///
+    /// All leafs in the hypervisor range (LEAF=0x4000_0000 to LEAF=0x4FFF_FFFF)
+    /// of this snapshot are replaced by `leafs`, and the hypervisor bit of
+    /// LEAF=0x01 is set so guests look for them. Use it with
+    /// [`HyperVProfile::leafs`] or [`KvmProfile::leafs`].
+    pub fn with_hypervisor_leafs<I>(&self, leafs: I) -> CpuIdSnapshot
+    where
+        I: IntoIterator<Item = (u32, u32, CpuIdResult)>,
+    {
+        let is_hypervisor_leaf = |leaf: u32| (EAX_HYPERVISOR_INFO..=0x4fff_ffff).contains(&leaf);
+        self.entries()
+            .iter()
+            .filter(|&&(leaf, _, _)| !is_hypervisor_leaf(leaf))
+            .map(|&(leaf, subleaf, mut res)| {
+                if leaf == 0x1 {
+                    res.ecx |= CPUID_ECX_HYPERVISOR;
+                }
+                (leaf, subleaf, res)
+            })
+            .chain(leafs)
+            .collect()
+    }
+}
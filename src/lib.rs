@@ -52,14 +52,32 @@
 #[macro_use]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "build-helper")]
+pub mod build_helper;
+#[cfg(feature = "alloc")]
+pub mod codegen;
+pub mod defined_bits;
 #[cfg(feature = "display")]
 pub mod display;
 mod extended;
+#[cfg(feature = "feature-db")]
+pub mod feature_table;
+#[cfg(feature = "alloc")]
+pub mod hypervisor;
+#[cfg(feature = "alloc")]
+pub mod system;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "uarch-db")]
+pub mod uarch;
 
 use bitflags::bitflags;
+use core::cell::Cell;
 use core::fmt::{self, Debug, Formatter};
+use core::iter::FusedIterator;
 use core::mem::size_of;
 use core::slice;
 use core::str;
@@ -67,6 +85,9 @@ use core::str;
 #[cfg(feature = "serialize")]
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, sync::Arc, vec::Vec};
+
 pub use extended::*;
 
 /// Uses Rust's `cpuid` function from the `arch` module.
@@ -82,6 +103,7 @@ pub mod native_cpuid {
     #[cfg(all(target_arch = "x86_64", not(target_env = "sgx")))]
     use core::arch::x86_64 as arch;
 
+    #[allow(unused_unsafe)] // `__cpuid_count` is safe on newer toolchains
     pub fn cpuid_count(a: u32, c: u32) -> CpuIdResult {
         // Safety: CPUID is supported on all x86_64 CPUs and all x86 CPUs with
         // SSE, but not by SGX.
@@ -147,7 +169,8 @@ fn get_bits(r: u32, from: u32, to: u32) -> u32 {
 macro_rules! check_flag {
     ($doc:meta, $fun:ident, $flags:ident, $flag:expr) => {
         #[$doc]
-        pub fn $fun(&self) -> bool {
+        #[inline]
+        pub const fn $fun(&self) -> bool {
             self.$flags.contains($flag)
         }
     };
@@ -162,7 +185,8 @@ macro_rules! is_bit_set {
 macro_rules! check_bit_fn {
     ($doc:meta, $fun:ident, $field:ident, $bit:expr) => {
         #[$doc]
-        pub fn $fun(&self) -> bool {
+        #[inline]
+        pub const fn $fun(&self) -> bool {
             is_bit_set!(self.$field, $bit)
         }
     };
@@ -187,10 +211,90 @@ where
     }
 }
 
+/// Number of results a [CachedCpuIdReader] can remember.
+const CACHED_CPUID_ENTRIES: usize = 128;
+
+/// A reader that remembers the results of another [CpuIdReader].
+///
+/// Every (leaf, sub-leaf) pair is forwarded to the wrapped reader only once.
+/// This helps with readers that are expensive to query, e.g., the native
+/// reader inside a VM (every CPUID instruction traps to the hypervisor) or a
+/// reader that fetches the values from a remote machine. The cache has a fixed
+/// size; once it is full, queries for new pairs are forwarded uncached.
+///
+/// The reader is not [Clone] (a copy would start out with an empty cache),
+/// it's only a [CpuIdReader] by reference. Pass a reference to [CpuId] so all
+/// structs and iterators obtained from it share one cache:
+///
+/// ```
+/// use raw_cpuid::{CachedCpuIdReader, CpuId, CpuIdReaderNative};
+///
+/// let reader = CachedCpuIdReader::new(CpuIdReaderNative);
+/// let cpuid = CpuId::with_cpuid_reader(&reader);
+/// for cache in cpuid.get_cache_parameters().into_iter().flatten() {
+///     println!("L{} {} cache", cache.level(), cache.cache_type());
+/// }
+/// ```
+pub struct CachedCpuIdReader<R: CpuIdReader> {
+    read: R,
+    entries: [Cell<Option<(u32, u32, CpuIdResult)>>; CACHED_CPUID_ENTRIES],
+}
+
+impl<R: CpuIdReader> CachedCpuIdReader<R> {
+    /// Wrap `read`, the cache starts out empty.
+    pub fn new(read: R) -> Self {
+        Self {
+            read,
+            entries: core::array::from_fn(|_| Cell::new(None)),
+        }
+    }
+
+    /// Forget all cached results.
+    pub fn clear(&self) {
+        self.entries.iter().for_each(|entry| entry.set(None));
+    }
+
+    /// Return the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.read
+    }
+}
+
+impl<R: CpuIdReader> CpuIdReader for &CachedCpuIdReader<R> {
+    fn cpuid2(&self, eax: u32, ecx: u32) -> CpuIdResult {
+        for entry in self.entries.iter() {
+            match entry.get() {
+                Some((leaf, subleaf, res)) if leaf == eax && subleaf == ecx => return res,
+                Some(_) => continue,
+                None => {
+                    let res = self.read.cpuid2(eax, ecx);
+                    entry.set(Some((eax, ecx, res)));
+                    return res;
+                }
+            }
+        }
+
+        self.read.cpuid2(eax, ecx)
+    }
+}
+
+impl<R: CpuIdReader> Debug for CachedCpuIdReader<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedCpuIdReader")
+            .field(
+                "cached",
+                &self.entries.iter().filter(|e| e.get().is_some()).count(),
+            )
+            .finish()
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 enum Vendor {
     Intel,
     Amd,
+    /// VIA/Centaur and Zhaoxin ("CentaurHauls" and "  Shanghai  ").
+    Centaur,
     Unknown(u32, u32, u32),
 }
 
@@ -205,6 +309,7 @@ impl Vendor {
         match vi.as_str() {
             "GenuineIntel" => Vendor::Intel,
             "AuthenticAMD" => Vendor::Amd,
+            "CentaurHauls" | "  Shanghai  " => Vendor::Centaur,
             _ => Vendor::Unknown(res.ebx, res.ecx, res.edx),
         }
     }
@@ -223,6 +328,14 @@ pub struct CpuId<R: CpuIdReader> {
     supported_leafs: u32,
     /// How many extended leafs are supported (e.g., leafs with EAX > EAX_EXTENDED_FUNCTION_INFO)
     supported_extended_leafs: u32,
+    /// Cached LEAF=0x00.
+    vendor_leaf: CpuIdResult,
+    /// Cached LEAF=0x01.
+    feature_leaf: CpuIdResult,
+    /// Cached LEAF=0x07 (sub-leaf 0, 1 and 2).
+    extended_feature_leaf: [CpuIdResult; 3],
+    /// Cached LEAF=0x8000_0001.
+    extended_processor_leaf: CpuIdResult,
 }
 
 #[cfg(any(
@@ -245,6 +358,74 @@ impl CpuId<CpuIdReaderNative> {
     pub fn new() -> Self {
         CpuId::default()
     }
+
+    /// A process-wide `CpuId` instance, created on first use.
+    ///
+    /// Lets all users in a binary share one detection instead of each creating
+    /// its own. Initialization uses an atomic flag (no locks or allocations, so
+    /// it works in `no_std`): threads calling this concurrently with the first
+    /// call spin until the leafs cached by [`CpuId::new`] have been read.
+    pub fn the() -> &'static Self {
+        static THE: GlobalCpuId = GlobalCpuId::new();
+        THE.get()
+    }
+}
+
+/// One-time initialized storage for [`CpuId::the`].
+#[cfg(any(
+    all(target_arch = "x86", not(target_env = "sgx"), target_feature = "sse"),
+    all(target_arch = "x86_64", not(target_env = "sgx"))
+))]
+struct GlobalCpuId {
+    state: core::sync::atomic::AtomicU8,
+    cpuid: core::cell::UnsafeCell<core::mem::MaybeUninit<CpuId<CpuIdReaderNative>>>,
+}
+
+// SAFETY: `cpuid` is written once by the thread that moves `state` out of
+// `UNINIT`, and only read after `READY` has been published.
+#[cfg(any(
+    all(target_arch = "x86", not(target_env = "sgx"), target_feature = "sse"),
+    all(target_arch = "x86_64", not(target_env = "sgx"))
+))]
+unsafe impl Sync for GlobalCpuId {}
+
+#[cfg(any(
+    all(target_arch = "x86", not(target_env = "sgx"), target_feature = "sse"),
+    all(target_arch = "x86_64", not(target_env = "sgx"))
+))]
+impl GlobalCpuId {
+    const UNINIT: u8 = 0;
+    const BUSY: u8 = 1;
+    const READY: u8 = 2;
+
+    const fn new() -> Self {
+        Self {
+            state: core::sync::atomic::AtomicU8::new(Self::UNINIT),
+            cpuid: core::cell::UnsafeCell::new(core::mem::MaybeUninit::uninit()),
+        }
+    }
+
+    fn get(&self) -> &CpuId<CpuIdReaderNative> {
+        use core::sync::atomic::Ordering;
+
+        loop {
+            match self.state.compare_exchange(
+                Self::UNINIT,
+                Self::BUSY,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    // SAFETY: We are the only thread that got past `UNINIT`.
+                    unsafe { (*self.cpuid.get()).write(CpuId::new()) };
+                    self.state.store(Self::READY, Ordering::Release);
+                }
+                // SAFETY: Initialized before `READY` was stored (and never changed after).
+                Err(Self::READY) => return unsafe { (*self.cpuid.get()).assume_init_ref() },
+                Err(_) => core::hint::spin_loop(),
+            }
+        }
+    }
 }
 
 /// Low-level data-structure to store result of cpuid instruction.
@@ -302,7 +483,13 @@ const EAX_TIME_STAMP_COUNTER_INFO: u32 = 0x15;
 const EAX_FREQUENCY_INFO: u32 = 0x16;
 const EAX_SOC_VENDOR_INFO: u32 = 0x17;
 const EAX_DETERMINISTIC_ADDRESS_TRANSLATION_INFO: u32 = 0x18;
+const EAX_HYBRID_INFO: u32 = 0x1A;
+const EAX_PCONFIG_INFO: u32 = 0x1B;
+const EAX_TILE_INFO: u32 = 0x1D;
+const EAX_TMUL_INFO: u32 = 0x1E;
 const EAX_EXTENDED_TOPOLOGY_INFO_V2: u32 = 0x1F;
+const EAX_HRESET_INFO: u32 = 0x20;
+const EAX_PERFORMANCE_MONITOR_EXT_INFO: u32 = 0x23;
 
 /// Hypervisor leaf
 const EAX_HYPERVISOR_INFO: u32 = 0x4000_0000;
@@ -322,6 +509,8 @@ const EAX_PERFORMANCE_OPTIMIZATION_INFO: u32 = 0x8000_001A;
 const EAX_CACHE_PARAMETERS_AMD: u32 = 0x8000_001D;
 const EAX_PROCESSOR_TOPOLOGY_INFO: u32 = 0x8000_001E;
 const EAX_MEMORY_ENCRYPTION_INFO: u32 = 0x8000_001F;
+const EAX_EXTENDED_FEATURE_IDENTIFICATION_2: u32 = 0x8000_0021;
+const EAX_AMD_PERFMON_DEBUG_INFO: u32 = 0x8000_0022;
 const EAX_SVM_FEATURES: u32 = 0x8000_000A;
 
 impl<R: CpuIdReader> CpuId<R> {
@@ -329,15 +518,59 @@ impl<R: CpuIdReader> CpuId<R> {
     ///
     /// This is useful for example when testing code or if we want to interpose
     /// on the CPUID calls this library makes.
+    ///
+    /// # Note
+    /// The leafs that are queried most often (LEAF=0x00, 0x01, 0x07 and
+    /// 0x8000_0001) are read once here and answered from memory afterwards,
+    /// so feature checks in hot paths don't execute CPUID (a serializing
+    /// instruction and a VM exit in guests). Since LEAF=0x01 also reports the
+    /// APIC ID of the current core, create a new `CpuId` after migrating to a
+    /// different core.
     pub fn with_cpuid_reader(cpuid_fn: R) -> Self {
+        const NOT_SUPPORTED: CpuIdResult = CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        };
+
         let vendor_leaf = cpuid_fn.cpuid1(EAX_VENDOR_INFO);
         let extended_leaf = cpuid_fn.cpuid1(EAX_EXTENDED_FUNCTION_INFO);
-        CpuId {
+        let mut cpuid = CpuId {
             supported_leafs: vendor_leaf.eax,
             supported_extended_leafs: extended_leaf.eax,
             vendor: Vendor::from_vendor_leaf(vendor_leaf),
+            vendor_leaf,
+            feature_leaf: NOT_SUPPORTED,
+            extended_feature_leaf: [NOT_SUPPORTED; 3],
+            extended_processor_leaf: NOT_SUPPORTED,
             read: cpuid_fn,
+        };
+
+        if cpuid.leaf_is_supported(EAX_FEATURE_INFO) {
+            cpuid.feature_leaf = cpuid.read.cpuid1(EAX_FEATURE_INFO);
+        }
+        if cpuid.leaf_is_supported(EAX_STRUCTURED_EXTENDED_FEATURE_INFO) {
+            let res = cpuid.read.cpuid2(EAX_STRUCTURED_EXTENDED_FEATURE_INFO, 0);
+            // Sub-leaf 2 is only valid if sub-leaf 0 EAX (the max. sub-leaf) says so
+            let res2 = if res.eax >= 2 {
+                cpuid.read.cpuid2(EAX_STRUCTURED_EXTENDED_FEATURE_INFO, 2)
+            } else {
+                NOT_SUPPORTED
+            };
+            cpuid.extended_feature_leaf = [
+                res,
+                cpuid.read.cpuid2(EAX_STRUCTURED_EXTENDED_FEATURE_INFO, 1),
+                res2,
+            ];
+        }
+        if cpuid.leaf_is_supported(EAX_EXTENDED_PROCESSOR_AND_FEATURE_IDENTIFIERS) {
+            cpuid.extended_processor_leaf = cpuid
+                .read
+                .cpuid1(EAX_EXTENDED_PROCESSOR_AND_FEATURE_IDENTIFIERS);
         }
+
+        cpuid
     }
 
     /// See [`CpuId::with_cpuid_reader`].
@@ -350,6 +583,7 @@ impl<R: CpuIdReader> CpuId<R> {
     }
 
     /// Check if a non extended leaf  (`val`) is supported.
+    #[inline]
     fn leaf_is_supported(&self, val: u32) -> bool {
         // Exclude reserved functions/leafs on AMD
         if self.vendor == Vendor::Amd && ((0x2..=0x4).contains(&val) || (0x8..=0xa).contains(&val))
@@ -371,9 +605,10 @@ impl<R: CpuIdReader> CpuId<R> {
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
+    #[inline]
     pub fn get_vendor_info(&self) -> Option<VendorInfo> {
         if self.leaf_is_supported(EAX_VENDOR_INFO) {
-            let res = self.read.cpuid1(EAX_VENDOR_INFO);
+            let res = self.vendor_leaf;
             Some(VendorInfo {
                 ebx: res.ebx,
                 ecx: res.ecx,
@@ -388,9 +623,10 @@ impl<R: CpuIdReader> CpuId<R> {
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
+    #[inline]
     pub fn get_feature_info(&self) -> Option<FeatureInfo> {
         if self.leaf_is_supported(EAX_FEATURE_INFO) {
-            let res = self.read.cpuid1(EAX_FEATURE_INFO);
+            let res = self.feature_leaf;
             Some(FeatureInfo {
                 vendor: self.vendor,
                 eax: res.eax,
@@ -404,6 +640,161 @@ impl<R: CpuIdReader> CpuId<R> {
         }
     }
 
+    /// Identify the micro-architecture from the vendor and the display family, model and
+    /// stepping (LEAF=0x00 and LEAF=0x01).
+    ///
+    /// If the family/model combination is not known, or leaf 0x01 is not supported (as on
+    /// some parts with reduced CPUID), this falls back to guessing from the processor brand
+    /// string (LEAF=0x8000_0002..=0x8000_0004), see [`uarch::MicroArchitecture::confidence`].
+    ///
+    /// Inside a virtual machine that reports a synthetic or unknown family/model the result
+    /// is a best-effort guess, see [`uarch::identify_virtualized`].
+    ///
+    /// Returns `None` if the vendor is not known or neither method identifies the processor.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[cfg(feature = "uarch-db")]
+    pub fn get_microarchitecture(&self) -> Option<uarch::MicroArchitecture> {
+        let vendor = uarch::CpuVendor::from_vendor_str(self.get_vendor_info()?.as_str())?;
+        let info = self.get_feature_info();
+        let virtualized = info.as_ref().is_some_and(|info| info.has_hypervisor());
+
+        let from_signature = info.and_then(|info| {
+            let (family, model, stepping) = (info.family_id(), info.model_id(), info.stepping_id());
+            if virtualized && uarch::is_synthetic_signature(vendor, family, model, stepping) {
+                return None;
+            }
+            uarch::identify_micro_architecture(vendor, family, model, stepping)
+        });
+
+        from_signature.or_else(|| {
+            let brand = self.get_processor_brand_string();
+            let brand = brand.as_ref().map(|brand| brand.as_str());
+            if virtualized {
+                uarch::identify_virtualized(vendor, brand, &self.get_cpu_capabilities())
+            } else {
+                uarch::identify_from_brand_string(vendor, brand?)
+            }
+        })
+    }
+
+    /// Bundle the SIMD and crypto feature bits of leaf 0x01 and 0x07 (sub-leaf 0).
+    ///
+    /// This does not execute CPUID: it uses the leafs read when this `CpuId` was created.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub fn get_cpu_capabilities(&self) -> CpuCapabilities {
+        CpuCapabilities::from_leafs(self.feature_leaf, self.extended_feature_leaf[0])
+    }
+
+    /// The highest x86-64 psABI micro-architecture level supported by the CPU
+    /// (LEAF=0x01, 0x07 and 0x8000_0001).
+    ///
+    /// Returns `None` if not even the baseline (x86-64-v1) features are present.
+    ///
+    /// This only checks the CPUID feature bits (OSXSAVE included). It does not check
+    /// XCR0 to confirm that the OS saves the AVX and AVX-512 register state.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn x86_64_abi_level(&self) -> Option<X86_64AbiLevel> {
+        let requirements = self.x86_64_abi_requirements();
+        [
+            X86_64AbiLevel::V1,
+            X86_64AbiLevel::V2,
+            X86_64AbiLevel::V3,
+            X86_64AbiLevel::V4,
+        ]
+        .iter()
+        .copied()
+        .take_while(|level| {
+            requirements
+                .iter()
+                .filter(|(required_by, _, _)| required_by == level)
+                .all(|(_, _, present)| *present)
+        })
+        .last()
+    }
+
+    /// Names (as spelled in the psABI) of the features that are missing to reach the
+    /// level after [`CpuId::x86_64_abi_level`].
+    ///
+    /// Returns an empty iterator if the CPU already supports x86-64-v4.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn x86_64_abi_missing_features(&self) -> impl Iterator<Item = &'static str> {
+        let next = match self.x86_64_abi_level() {
+            Some(level) => level.next(),
+            None => Some(X86_64AbiLevel::V1),
+        };
+
+        IntoIterator::into_iter(self.x86_64_abi_requirements())
+            .filter(move |(required_by, _, present)| Some(*required_by) == next && !present)
+            .map(|(_, name, _)| name)
+    }
+
+    /// All features required by the psABI levels, and whether the CPU has them.
+    fn x86_64_abi_requirements(&self) -> [(X86_64AbiLevel, &'static str, bool); 29] {
+        use X86_64AbiLevel::*;
+
+        let finfo = self.get_feature_info();
+        let f = |has: fn(&FeatureInfo) -> bool| finfo.as_ref().is_some_and(has);
+        let efinfo = self.get_extended_feature_info();
+        let e = |has: fn(&ExtendedFeatures) -> bool| efinfo.as_ref().is_some_and(has);
+        let epinfo = self.get_extended_processor_and_feature_identifiers();
+        let p = |has: fn(&ExtendedProcessorFeatureIdentifiers) -> bool| {
+            epinfo.as_ref().is_some_and(has)
+        };
+
+        [
+            (V1, "CMOV", f(FeatureInfo::has_cmov)),
+            (V1, "CX8", f(FeatureInfo::has_cmpxchg8b)),
+            (V1, "FPU", f(FeatureInfo::has_fpu)),
+            (V1, "FXSR", f(FeatureInfo::has_fxsave_fxstor)),
+            (V1, "MMX", f(FeatureInfo::has_mmx)),
+            (
+                V1,
+                "SCE",
+                p(ExtendedProcessorFeatureIdentifiers::has_syscall_sysret),
+            ),
+            (V1, "SSE", f(FeatureInfo::has_sse)),
+            (V1, "SSE2", f(FeatureInfo::has_sse2)),
+            (V2, "CMPXCHG16B", f(FeatureInfo::has_cmpxchg16b)),
+            (
+                V2,
+                "LAHF-SAHF",
+                p(ExtendedProcessorFeatureIdentifiers::has_lahf_sahf),
+            ),
+            (V2, "POPCNT", f(FeatureInfo::has_popcnt)),
+            (V2, "SSE3", f(FeatureInfo::has_sse3)),
+            (V2, "SSE4_1", f(FeatureInfo::has_sse41)),
+            (V2, "SSE4_2", f(FeatureInfo::has_sse42)),
+            (V2, "SSSE3", f(FeatureInfo::has_ssse3)),
+            (V3, "AVX", f(FeatureInfo::has_avx)),
+            (V3, "AVX2", e(ExtendedFeatures::has_avx2)),
+            (V3, "BMI1", e(ExtendedFeatures::has_bmi1)),
+            (V3, "BMI2", e(ExtendedFeatures::has_bmi2)),
+            (V3, "F16C", f(FeatureInfo::has_f16c)),
+            (V3, "FMA", f(FeatureInfo::has_fma)),
+            (
+                V3,
+                "LZCNT",
+                p(ExtendedProcessorFeatureIdentifiers::has_lzcnt),
+            ),
+            (V3, "MOVBE", f(FeatureInfo::has_movbe)),
+            (V3, "OSXSAVE", f(FeatureInfo::has_oxsave)),
+            (V4, "AVX512F", e(ExtendedFeatures::has_avx512f)),
+            (V4, "AVX512BW", e(ExtendedFeatures::has_avx512bw)),
+            (V4, "AVX512CD", e(ExtendedFeatures::has_avx512cd)),
+            (V4, "AVX512DQ", e(ExtendedFeatures::has_avx512dq)),
+            (V4, "AVX512VL", e(ExtendedFeatures::has_avx512vl)),
+        ]
+    }
+
     /// Query basic information about caches (LEAF=0x02).
     ///
     /// # Platforms
@@ -423,6 +814,36 @@ impl<R: CpuIdReader> CpuId<R> {
         }
     }
 
+    /// Query cache and TLB information, following the leaf 2 redirections
+    /// (LEAF=0x02, 0x04 and 0x18).
+    ///
+    /// Descriptor 0xFF in leaf 2 means cache parameters must be read from leaf
+    /// 4 and descriptor 0xFE means TLB information must be read from leaf 0x18.
+    /// The returned [`CacheTlbInfo`] gives access to the remaining leaf 2
+    /// descriptors and to the leafs that were referred to.
+    ///
+    /// # Platforms
+    /// ❌ AMD ✅ Intel
+    pub fn get_cache_and_tlb_info(&self) -> Option<CacheTlbInfo<R>> {
+        let descriptors = self.get_cache_info()?;
+        let cache_parameters = if descriptors.uses_leaf4() {
+            self.get_cache_parameters()
+        } else {
+            None
+        };
+        let tlb_info = if descriptors.uses_leaf18() {
+            self.get_deterministic_address_translation_info()
+        } else {
+            None
+        };
+
+        Some(CacheTlbInfo {
+            descriptors,
+            cache_parameters,
+            tlb_info,
+        })
+    }
+
     /// Retrieve serial number of processor (LEAF=0x03).
     ///
     /// # Platforms
@@ -430,7 +851,7 @@ impl<R: CpuIdReader> CpuId<R> {
     pub fn get_processor_serial(&self) -> Option<ProcessorSerial> {
         if self.leaf_is_supported(EAX_PROCESSOR_SERIAL) {
             // upper 64-96 bits are in res1.eax:
-            let res1 = self.read.cpuid1(EAX_FEATURE_INFO);
+            let res1 = self.feature_leaf;
             let res = self.read.cpuid1(EAX_PROCESSOR_SERIAL);
             Some(ProcessorSerial {
                 ecx: res.ecx,
@@ -461,6 +882,8 @@ impl<R: CpuIdReader> CpuId<R> {
                 } else {
                     EAX_CACHE_PARAMETERS
                 },
+                caches: [None; MAX_CACHE_SUBLEAVES as usize],
+                loaded: false,
                 current: 0,
             })
         } else {
@@ -476,6 +899,7 @@ impl<R: CpuIdReader> CpuId<R> {
         if self.leaf_is_supported(EAX_MONITOR_MWAIT_INFO) {
             let res = self.read.cpuid1(EAX_MONITOR_MWAIT_INFO);
             Some(MonitorMwaitInfo {
+                vendor: self.vendor,
                 eax: res.eax,
                 ebx: res.ebx,
                 ecx: res.ecx,
@@ -496,8 +920,8 @@ impl<R: CpuIdReader> CpuId<R> {
             Some(ThermalPowerInfo {
                 eax: ThermalPowerFeaturesEax::from_bits_truncate(res.eax),
                 ebx: res.ebx,
-                ecx: ThermalPowerFeaturesEcx::from_bits_truncate(res.ecx),
-                _edx: res.edx,
+                ecx: ThermalPowerFeaturesEcx::from_bits_retain(res.ecx),
+                edx: res.edx,
             })
         } else {
             None
@@ -508,19 +932,20 @@ impl<R: CpuIdReader> CpuId<R> {
     ///
     /// # Platforms
     /// 🟡 AMD ✅ Intel
+    #[inline]
     pub fn get_extended_feature_info(&self) -> Option<ExtendedFeatures> {
         if self.leaf_is_supported(EAX_STRUCTURED_EXTENDED_FEATURE_INFO) {
-            let res = self.read.cpuid1(EAX_STRUCTURED_EXTENDED_FEATURE_INFO);
-            let res1 = self.read.cpuid2(EAX_STRUCTURED_EXTENDED_FEATURE_INFO, 1);
+            let [res, res1, res2] = self.extended_feature_leaf;
             Some(ExtendedFeatures {
                 _eax: res.eax,
                 ebx: ExtendedFeaturesEbx::from_bits_truncate(res.ebx),
                 ecx: ExtendedFeaturesEcx::from_bits_truncate(res.ecx),
                 edx: ExtendedFeaturesEdx::from_bits_truncate(res.edx),
                 eax1: ExtendedFeaturesEax1::from_bits_truncate(res1.eax),
-                _ebx1: res1.ebx,
+                ebx1: ExtendedFeaturesEbx1::from_bits_truncate(res1.ebx),
                 _ecx1: res1.ecx,
                 edx1: ExtendedFeaturesEdx1::from_bits_truncate(res1.edx),
+                edx2: ExtendedFeaturesEdx2::from_bits_truncate(res2.edx),
             })
         } else {
             None
@@ -550,7 +975,7 @@ impl<R: CpuIdReader> CpuId<R> {
             Some(PerformanceMonitoringInfo {
                 eax: res.eax,
                 ebx: PerformanceMonitoringFeaturesEbx::from_bits_truncate(res.ebx),
-                _ecx: res.ecx,
+                ecx: res.ecx,
                 edx: res.edx,
             })
         } else {
@@ -572,6 +997,7 @@ impl<R: CpuIdReader> CpuId<R> {
                 read: self.read.clone(),
                 level: 0,
                 is_v2: false,
+                done: false,
             })
         } else {
             None
@@ -588,12 +1014,37 @@ impl<R: CpuIdReader> CpuId<R> {
                 read: self.read.clone(),
                 level: 0,
                 is_v2: true,
+                done: false,
             })
         } else {
             None
         }
     }
 
+    /// Topology levels from LEAF=0x1F if the processor enumerates it, from
+    /// LEAF=0x0B otherwise.
+    ///
+    /// Intel deprecates LEAF=0x0B in favor of LEAF=0x1F, which adds the module,
+    /// tile, die and die group levels. LEAF=0x1F only counts as enumerated if its
+    /// first sub-leaf reports processors (some processors and hypervisors support
+    /// the leaf but leave it empty).
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn get_topology_info(&self) -> Option<ExtendedTopologyIter<R>> {
+        let has_v2 = self.leaf_is_supported(EAX_EXTENDED_TOPOLOGY_INFO_V2)
+            && get_bits(
+                self.read.cpuid2(EAX_EXTENDED_TOPOLOGY_INFO_V2, 0).ebx,
+                0,
+                15,
+            ) != 0;
+        if has_v2 {
+            self.get_extended_topology_info_v2()
+        } else {
+            self.get_extended_topology_info()
+        }
+    }
+
     /// Information for saving/restoring extended register state (LEAF=0x0D).
     ///
     /// # Platforms
@@ -610,8 +1061,8 @@ impl<R: CpuIdReader> CpuId<R> {
                 _edx: res.edx,
                 eax1: res1.eax,
                 ebx1: res1.ebx,
-                ecx1: ExtendedStateInfoXSSFlags::from_bits_truncate(res1.ecx),
-                _edx1: res1.edx,
+                ecx1: ExtendedStateInfoXSSFlags::from_bits_retain(res1.ecx),
+                edx1: res1.edx,
             })
         } else {
             None
@@ -666,13 +1117,13 @@ impl<R: CpuIdReader> CpuId<R> {
                 Some(SgxInfo {
                     read: self.read.clone(),
                     eax: res.eax,
-                    ebx: res.ebx,
+                    miscselect: SgxMiscSelect::from_bits_retain(res.ebx),
                     _ecx: res.ecx,
                     edx: res.edx,
-                    eax1: res1.eax,
-                    ebx1: res1.ebx,
-                    ecx1: res1.ecx,
-                    edx1: res1.edx,
+                    attributes: SgxAttributes::from_bits_retain(
+                        res1.eax as u64 | (res1.ebx as u64) << 32,
+                    ),
+                    xfrm: res1.ecx as u64 | (res1.edx as u64) << 32,
                 })
             } else {
                 None
@@ -694,10 +1145,10 @@ impl<R: CpuIdReader> CpuId<R> {
             };
 
             Some(ProcessorTraceInfo {
-                _eax: res.eax,
+                eax: res.eax,
                 ebx: res.ebx,
                 ecx: res.ecx,
-                _edx: res.edx,
+                edx: res.edx,
                 leaf1: res1,
             })
         } else {
@@ -712,10 +1163,17 @@ impl<R: CpuIdReader> CpuId<R> {
     pub fn get_tsc_info(&self) -> Option<TscInfo> {
         if self.leaf_is_supported(EAX_TIME_STAMP_COUNTER_INFO) {
             let res = self.read.cpuid2(EAX_TIME_STAMP_COUNTER_INFO, 0);
+            let crystal = match self.get_feature_info() {
+                Some(info) if self.vendor == Vendor::Intel => {
+                    nominal_crystal_clock(info.family_id(), info.model_id())
+                }
+                _ => 0,
+            };
             Some(TscInfo {
                 eax: res.eax,
                 ebx: res.ebx,
                 ecx: res.ecx,
+                crystal,
             })
         } else {
             None
@@ -777,6 +1235,143 @@ impl<R: CpuIdReader> CpuId<R> {
         }
     }
 
+    /// Core type of the current logical processor of a hybrid processor
+    /// (LEAF=0x1A).
+    ///
+    /// Returns `None` on processors that are not hybrid (the leaf reads as zero).
+    /// The result depends on the core the calling thread runs on, pin the thread
+    /// to query a specific core.
+    ///
+    /// # Platforms
+    /// ❌ AMD ✅ Intel
+    pub fn get_hybrid_info(&self) -> Option<HybridInfo> {
+        if self.leaf_is_supported(EAX_HYBRID_INFO) {
+            let res = self.read.cpuid1(EAX_HYBRID_INFO);
+            if res.eax != 0 {
+                return Some(HybridInfo { eax: res.eax });
+            }
+        }
+        None
+    }
+
+    /// Targets of the PCONFIG instruction, e.g., MKTME (LEAF=0x1B).
+    ///
+    /// # Platforms
+    /// ❌ AMD ✅ Intel
+    pub fn get_pconfig_info(&self) -> Option<PconfigTargetIter<R>> {
+        if self.leaf_is_supported(EAX_PCONFIG_INFO) {
+            Some(PconfigTargetIter {
+                read: self.read.clone(),
+                subleaf: 0,
+                register: 0,
+                res: None,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// AMX tile palettes (LEAF=0x1D).
+    ///
+    /// Returns `None` if AMX is not supported, see
+    /// [`ExtendedFeatures::has_amx_tile`].
+    ///
+    /// # Platforms
+    /// ❌ AMD ✅ Intel
+    pub fn get_tile_info(&self) -> Option<TileInfo<R>> {
+        if self.leaf_is_supported(EAX_TILE_INFO) && self.has_amx_tile() {
+            let max_palette = self.read.cpuid2(EAX_TILE_INFO, 0).eax;
+            if max_palette > 0 {
+                return Some(TileInfo {
+                    read: self.read.clone(),
+                    max_palette,
+                });
+            }
+        }
+        None
+    }
+
+    /// Leafs 0x1D and 0x1E are only valid if CPUID.(EAX=07H, ECX=0H):EDX[AMX-TILE] = 1.
+    fn has_amx_tile(&self) -> bool {
+        self.get_extended_feature_info()
+            .is_some_and(|info| info.has_amx_tile())
+    }
+
+    /// Dimensions of the AMX matrix multiply unit (LEAF=0x1E).
+    ///
+    /// # Platforms
+    /// ❌ AMD ✅ Intel
+    pub fn get_tmul_info(&self) -> Option<TmulInfo> {
+        if self.leaf_is_supported(EAX_TMUL_INFO) && self.has_amx_tile() {
+            let res = self.read.cpuid2(EAX_TMUL_INFO, 0);
+            if res.ebx != 0 {
+                return Some(TmulInfo { ebx: res.ebx });
+            }
+        }
+        None
+    }
+
+    /// History reset capabilities of the HRESET instruction (LEAF=0x20).
+    ///
+    /// # Platforms
+    /// ❌ AMD ✅ Intel
+    pub fn get_hreset_info(&self) -> Option<HresetInfo> {
+        // Leaf 20H is valid if CPUID.(EAX=07H, ECX=1H):EAX[HRESET] = 1.
+        self.get_extended_feature_info().and_then(|info| {
+            if self.leaf_is_supported(EAX_HRESET_INFO) && info.has_hreset() {
+                let res = self.read.cpuid2(EAX_HRESET_INFO, 0);
+                Some(HresetInfo {
+                    eax: res.eax,
+                    ebx: HresetCapabilities::from_bits_retain(res.ebx),
+                })
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Extended architectural performance monitoring: which counters and
+    /// architectural events exist (LEAF=0x23).
+    ///
+    /// Unlike [`CpuId::get_performance_monitoring_info`], counters are enumerated
+    /// as bitmaps, so they don't have to be contiguous.
+    ///
+    /// # Platforms
+    /// ❌ AMD ✅ Intel
+    pub fn get_performance_monitoring_ext_info(&self) -> Option<PerformanceMonitoringExtInfo> {
+        // Leaf 23H is valid if CPUID.(EAX=07H, ECX=1H):EAX[ARCHPERFMONEXT] = 1.
+        self.get_extended_feature_info().and_then(|info| {
+            if self.leaf_is_supported(EAX_PERFORMANCE_MONITOR_EXT_INFO)
+                && info.has_arch_perfmon_ext()
+            {
+                let res = self.read.cpuid2(EAX_PERFORMANCE_MONITOR_EXT_INFO, 0);
+                let subleaf = |subleaf: u32| {
+                    if is_bit_set!(res.eax, subleaf) {
+                        self.read.cpuid2(EAX_PERFORMANCE_MONITOR_EXT_INFO, subleaf)
+                    } else {
+                        CpuIdResult {
+                            eax: 0,
+                            ebx: 0,
+                            ecx: 0,
+                            edx: 0,
+                        }
+                    }
+                };
+                let counters = subleaf(1);
+                let events = subleaf(3);
+                Some(PerformanceMonitoringExtInfo {
+                    eax: res.eax,
+                    ebx: res.ebx,
+                    general_counters: counters.eax,
+                    fixed_counters: counters.ebx,
+                    events: ArchitecturalEvents::from_bits_retain(events.eax),
+                })
+            } else {
+                None
+            }
+        })
+    }
+
     /// Returns information provided by the hypervisor, if running
     /// in a virtual environment (LEAF=0x4000_00xx).
     ///
@@ -804,20 +1399,34 @@ impl<R: CpuIdReader> CpuId<R> {
     ///
     /// # Platforms
     /// ✅ AMD 🟡 Intel
+    #[inline]
     pub fn get_extended_processor_and_feature_identifiers(
         &self,
     ) -> Option<ExtendedProcessorFeatureIdentifiers> {
         if self.leaf_is_supported(EAX_EXTENDED_PROCESSOR_AND_FEATURE_IDENTIFIERS) {
             Some(ExtendedProcessorFeatureIdentifiers::new(
                 self.vendor,
-                self.read
-                    .cpuid1(EAX_EXTENDED_PROCESSOR_AND_FEATURE_IDENTIFIERS),
+                self.extended_processor_leaf,
             ))
         } else {
             None
         }
     }
 
+    /// Support for the MONITORX/MWAITX instructions (LEAF=0x8000_0001).
+    ///
+    /// Shorthand for
+    /// [`ExtendedProcessorFeatureIdentifiers::has_monitorx_mwaitx`], the
+    /// monitor-line sizes are enumerated by
+    /// [`get_monitor_mwait_info`](CpuId::get_monitor_mwait_info).
+    ///
+    /// # Platforms
+    /// ✅ AMD ❌ Intel (will return false)
+    pub fn has_monitorx_mwaitx(&self) -> bool {
+        self.get_extended_processor_and_feature_identifiers()
+            .is_some_and(|info| info.has_monitorx_mwaitx())
+    }
+
     /// Retrieve processor brand string (LEAF=0x8000_000{2..4}).
     ///
     /// # Platforms
@@ -900,7 +1509,7 @@ impl<R: CpuIdReader> CpuId<R> {
     pub fn get_svm_info(&self) -> Option<SvmFeatures> {
         let has_svm = self
             .get_extended_processor_and_feature_identifiers()
-            .map_or(false, |f| f.has_svm());
+            .is_some_and(|f| f.has_svm());
         if has_svm && self.leaf_is_supported(EAX_SVM_FEATURES) {
             Some(SvmFeatures::new(self.read.cpuid1(EAX_SVM_FEATURES)))
         } else {
@@ -961,466 +1570,1615 @@ impl<R: CpuIdReader> CpuId<R> {
             None
         }
     }
-}
 
-impl<R: CpuIdReader> Debug for CpuId<R> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("CpuId")
-            .field("vendor", &self.vendor)
-            // .field("supported_leafs", &(self.supported_leafs as *const u32))
-            // .field("supported_extended_leafs", &(self.supported_extended_leafs as *const u32))
-            .field("vendor_info", &self.get_vendor_info())
-            .field("feature_info", &self.get_feature_info())
-            .field("cache_info", &self.get_cache_info())
-            .field("processor_serial", &self.get_processor_serial())
-            .field("cache_parameters", &self.get_cache_parameters())
-            .field("monitor_mwait_info", &self.get_monitor_mwait_info())
-            .field("thermal_power_info", &self.get_thermal_power_info())
-            .field("extended_feature_info", &self.get_extended_feature_info())
-            .field(
-                "direct_cache_access_info",
-                &self.get_direct_cache_access_info(),
-            )
-            .field(
-                "performance_monitoring_info",
-                &self.get_performance_monitoring_info(),
-            )
-            .field("extended_topology_info", &self.get_extended_topology_info())
-            .field("extended_state_info", &self.get_extended_state_info())
-            .field("rdt_monitoring_info", &self.get_rdt_monitoring_info())
-            .field("rdt_allocation_info", &self.get_rdt_allocation_info())
-            .field("sgx_info", &self.get_sgx_info())
-            .field("processor_trace_info", &self.get_processor_trace_info())
-            .field("tsc_info", &self.get_tsc_info())
-            .field(
-                "processor_frequency_info",
-                &self.get_processor_frequency_info(),
-            )
-            .field(
-                "deterministic_address_translation_info",
-                &self.get_deterministic_address_translation_info(),
-            )
-            .field("soc_vendor_info", &self.get_soc_vendor_info())
-            .field("hypervisor_info", &self.get_hypervisor_info())
-            .field(
-                "extended_processor_and_feature_identifiers",
-                &self.get_extended_processor_and_feature_identifiers(),
-            )
-            .field("processor_brand_string", &self.get_processor_brand_string())
-            .field("l1_cache_and_tlb_info", &self.get_l1_cache_and_tlb_info())
-            .field(
-                "l2_l3_cache_and_tlb_info",
-                &self.get_l2_l3_cache_and_tlb_info(),
-            )
-            .field(
-                "advanced_power_mgmt_info",
-                &self.get_advanced_power_mgmt_info(),
-            )
-            .field(
-                "processor_capacity_feature_info",
-                &self.get_processor_capacity_feature_info(),
-            )
-            .field("svm_info", &self.get_svm_info())
-            .field("tlb_1gb_page_info", &self.get_tlb_1gb_page_info())
-            .field(
-                "performance_optimization_info",
-                &self.get_performance_optimization_info(),
-            )
-            .field(
-                "processor_topology_info",
-                &self.get_processor_topology_info(),
-            )
-            .field("memory_encryption_info", &self.get_memory_encryption_info())
-            .finish()
+    /// Extended Feature Identification 2 (LEAF=0x8000_0021)
+    ///
+    /// # Platforms
+    /// ✅ AMD ❌ Intel (reserved)
+    pub fn get_extended_feature_identification_2(&self) -> Option<ExtendedFeatureIdentification2> {
+        if self.leaf_is_supported(EAX_EXTENDED_FEATURE_IDENTIFICATION_2) {
+            Some(ExtendedFeatureIdentification2::new(
+                self.read.cpuid1(EAX_EXTENDED_FEATURE_IDENTIFICATION_2),
+            ))
+        } else {
+            None
+        }
     }
-}
 
-/// Vendor Info String (LEAF=0x0)
-///
-/// A string that can be for example "AuthenticAMD" or "GenuineIntel".
-///
-/// # Technical Background
-///
-/// The vendor info is a 12-byte (96 bit) long string stored in `ebx`, `edx` and
-/// `ecx` by the corresponding `cpuid` instruction.
-///
-/// # Platforms
-/// ✅ AMD ✅ Intel
-#[derive(PartialEq, Eq)]
-#[repr(C)]
-pub struct VendorInfo {
-    ebx: u32,
-    edx: u32,
-    ecx: u32,
-}
+    /// Extended performance monitoring and debug capabilities (LEAF=0x8000_0022)
+    ///
+    /// # Platforms
+    /// ✅ AMD ❌ Intel (reserved)
+    pub fn get_amd_perfmon_debug_info(&self) -> Option<AmdPerfMonDebugInfo> {
+        if self.leaf_is_supported(EAX_AMD_PERFMON_DEBUG_INFO) {
+            Some(AmdPerfMonDebugInfo::new(
+                self.read.cpuid1(EAX_AMD_PERFMON_DEBUG_INFO),
+            ))
+        } else {
+            None
+        }
+    }
 
-impl VendorInfo {
-    /// Return vendor identification as human readable string.
-    pub fn as_str(&self) -> &str {
-        let brand_string_start = self as *const VendorInfo as *const u8;
-        let slice = unsafe {
-            // Safety: VendorInfo is laid out with repr(C) and exactly
-            // 12 byte long without any padding.
-            slice::from_raw_parts(brand_string_start, size_of::<VendorInfo>())
+    /// Memory encryption of the guest we are running in (LEAF=0x01 and 0x8000_001F).
+    ///
+    /// Combines the hypervisor bit with the strongest SEV mode and the C-bit
+    /// position enumerated by [`get_memory_encryption_info`](CpuId::get_memory_encryption_info).
+    /// On bare metal this is always [`SevStatus::NotEncrypted`], the leaf then
+    /// describes what the processor can offer to guests.
+    ///
+    /// Only SEV-SNP guests can trust CPUID values, the authoritative source for
+    /// the active mode is the `SEV_STATUS` MSR (0xC001_0131).
+    ///
+    /// # Platforms
+    /// ✅ AMD ❌ Intel (always `NotEncrypted`)
+    pub fn sev_status(&self) -> SevStatus {
+        let in_guest = self
+            .get_feature_info()
+            .is_some_and(|info| info.has_hypervisor());
+        let info = match self.get_memory_encryption_info() {
+            Some(info) if in_guest && self.vendor == Vendor::Amd => info,
+            _ => return SevStatus::NotEncrypted,
         };
 
-        str::from_utf8(slice).unwrap_or("InvalidVendorString")
+        let c_bit = info.c_bit_position();
+        if info.has_sev_snp() {
+            SevStatus::SevSnp { c_bit }
+        } else if info.has_sev_es() {
+            SevStatus::SevEs { c_bit }
+        } else if info.has_sev() {
+            SevStatus::Sev { c_bit }
+        } else {
+            SevStatus::NotEncrypted
+        }
     }
 
-    #[deprecated(
-        since = "10.0.0",
-        note = "Use idiomatic function name `as_str` instead"
-    )]
-    pub fn as_string(&self) -> &str {
-        self.as_str()
+    /// APIC ID of the logical processor executing CPUID (LEAF=0x1F, 0x0B or 0x01).
+    ///
+    /// Returns the full 32-bit x2APIC ID if LEAF=0x1F or LEAF=0x0B enumerates the
+    /// topology, and the initial APIC ID of LEAF=0x01 otherwise. The latter is only
+    /// 8 bits wide, so it is truncated (and not unique) on systems with more than
+    /// 255 logical processors.
+    ///
+    /// Returns `None` if neither leaf is supported.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn apic_id(&self) -> Option<u32> {
+        let x2apic = self
+            .get_topology_info()
+            .and_then(|mut levels| levels.next());
+        match x2apic {
+            Some(level) => Some(level.x2apic_id()),
+            None => self
+                .get_feature_info()
+                .map(|info| info.initial_local_apic_id() as u32),
+        }
     }
-}
 
-impl Debug for VendorInfo {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("VendorInfo")
-            .field("brand_string", &self.as_str())
-            .finish()
+    /// Number of logical processors in the package (LEAF=0x8000_0008 on AMD,
+    /// the outermost level of LEAF=0x1F or 0x0B, or LEAF=0x01).
+    ///
+    /// Returns 1 if none of these leafs report a count.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn logical_processors_per_package(&self) -> usize {
+        if self.vendor == Vendor::Amd {
+            if let Some(info) = self.get_processor_capacity_feature_info() {
+                return info.num_phys_threads();
+            }
+        }
+        let outermost = self.get_topology_info().and_then(|levels| levels.last());
+        if let Some(level) = outermost {
+            if level.processors() > 0 {
+                return level.processors() as usize;
+            }
+        }
+        self.get_feature_info()
+            .filter(|info| info.has_htt())
+            .map_or(1, |info| (info.max_logical_processor_ids() as usize).max(1))
     }
-}
 
-impl fmt::Display for VendorInfo {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.as_str())
+    /// Whether the vendor of LEAF=0x00 agrees with the other leafs that identify
+    /// the vendor (LEAF=0x8000_0000 and LEAF=0x01).
+    ///
+    /// AMD and Hygon repeat the vendor string in LEAF=0x8000_0000 (Intel reports
+    /// zeroes), it must be identical if it is present. With the `uarch-db` feature
+    /// the display family and model must also not belong to a known processor of
+    /// a different vendor unless it is a known one of the reported vendor (generic
+    /// models of hypervisors are ignored).
+    ///
+    /// Some VMs and engineering samples report mismatched vendors, which silently
+    /// breaks decoding that depends on the vendor (e.g., which cache leafs are
+    /// used).
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn vendor_consistent(&self) -> bool {
+        if self.supported_extended_leafs >= EAX_EXTENDED_FUNCTION_INFO {
+            let res = self.read.cpuid1(EAX_EXTENDED_FUNCTION_INFO);
+            let leaf0 = self.vendor_leaf;
+            if (res.ebx, res.ecx, res.edx) != (0, 0, 0)
+                && (res.ebx, res.ecx, res.edx) != (leaf0.ebx, leaf0.ecx, leaf0.edx)
+            {
+                return false;
+            }
+        }
+
+        #[cfg(feature = "uarch-db")]
+        if let (Some(vendor), Some(info)) = (
+            uarch::CpuVendor::from_vendor_leaf(self.vendor_leaf),
+            self.get_feature_info(),
+        ) {
+            let (family, model, stepping) = (info.family_id(), info.model_id(), info.stepping_id());
+            let known = |vendor| {
+                uarch::identify_micro_architecture(vendor, family, model, stepping).is_some()
+            };
+            let synthetic = info.has_hypervisor()
+                && uarch::is_synthetic_signature(vendor, family, model, stepping);
+            if !synthetic
+                && !known(vendor)
+                && uarch::CpuVendor::ALL
+                    .iter()
+                    .any(|&(other, _, _)| other != vendor && known(other))
+            {
+                return false;
+            }
+        }
+
+        true
     }
-}
 
-/// Iterates over cache information (LEAF=0x02).
-///
-/// This will just return an index into a static table of cache descriptions
-/// (see [CACHE_INFO_TABLE](crate::CACHE_INFO_TABLE)).
-///
-/// # Platforms
-/// ❌ AMD ✅ Intel
-#[derive(PartialEq, Eq, Clone)]
-pub struct CacheInfoIter {
-    current: u32,
-    eax: u32,
-    ebx: u32,
-    ecx: u32,
-    edx: u32,
-}
+    /// Whether transactional memory (TSX) is available and usable (LEAF=0x07).
+    ///
+    /// Combines the HLE and RTM bits with the indicators that RTM transactions
+    /// always, or may, abort. Lock-elision code should only take the RTM path if
+    /// [`TsxInfo::rtm_status`] is [`RtmStatus::Available`].
+    ///
+    /// # Platforms
+    /// ❌ AMD ✅ Intel
+    pub fn tsx_info(&self) -> TsxInfo {
+        let leaf7 = if self.leaf_is_supported(EAX_STRUCTURED_EXTENDED_FEATURE_INFO) {
+            self.extended_feature_leaf[0]
+        } else {
+            CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            }
+        };
+        TsxInfo {
+            ebx: ExtendedFeaturesEbx::from_bits_truncate(leaf7.ebx),
+            edx: ExtendedFeaturesEdx::from_bits_truncate(leaf7.edx),
+        }
+    }
 
-impl Iterator for CacheInfoIter {
-    type Item = CacheInfo;
+    /// Size of a cache line in bytes (LEAF=0x01, 0x04 or 0x8000_001D, 0x8000_0005
+    /// and 0x8000_0006).
+    ///
+    /// Reconciles all leafs that report a line size. The result is taken from
+    /// the most specific source that reports one: the L1 data cache parameters,
+    /// then the AMD L1 data cache info, the CLFLUSH line size and finally the
+    /// L2 line size. [`CacheLineSize::has_conflict`] tells whether any of the
+    /// other sources disagree (e.g., processors with sectored 128 byte L2
+    /// lines).
+    ///
+    /// Returns `None` if no leaf reports a line size.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn cache_line_size(&self) -> Option<CacheLineSize> {
+        let l1d = self.get_cache_parameters().and_then(|mut caches| {
+            caches
+                .find(|cache| {
+                    cache.level() == 1
+                        && matches!(cache.cache_type(), CacheType::Data | CacheType::Unified)
+                })
+                .map(|cache| cache.coherency_line_size())
+        });
+        let amd_l1d = match self.get_l1_cache_and_tlb_info() {
+            Some(info) if self.vendor == Vendor::Amd => Some(info.dcache_line_size() as usize),
+            _ => None,
+        };
+        let clflush = self
+            .get_feature_info()
+            .filter(|info| info.has_clflush())
+            .map(|info| info.cflush_cache_line_size() as usize * 8);
+        let l2 = self
+            .get_l2_l3_cache_and_tlb_info()
+            .map(|info| info.l2cache_line_size() as usize);
+
+        let sources = [l1d, amd_l1d, clflush, l2];
+        let mut sizes = sources.iter().flatten().copied().filter(|&size| size != 0);
+        let bytes = sizes.next()?;
+        Some(CacheLineSize {
+            bytes,
+            conflict: sizes.any(|size| size != bytes),
+        })
+    }
 
-    /// Iterate over all cache information.
-    fn next(&mut self) -> Option<CacheInfo> {
-        // Every byte of the 4 register values returned by cpuid
-        // can contain information about a cache (except the
-        // very first one).
-        if self.current >= 4 * 4 {
-            return None;
+    /// Supported page sizes and paging extensions (LEAF=0x01, 0x07 and 0x8000_0001).
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn paging_info(&self) -> PagingInfo {
+        PagingInfo {
+            edx_ecx: FeatureInfoFlags::from_bits_truncate(
+                ((self.feature_leaf.edx as u64) << 32) | (self.feature_leaf.ecx as u64),
+            ),
+            ecx7: ExtendedFeaturesEcx::from_bits_truncate(self.extended_feature_leaf[0].ecx),
+            pdpe1gb: self
+                .get_extended_processor_and_feature_identifiers()
+                .is_some_and(|info| info.has_1gib_pages()),
         }
-        let reg_index = self.current % 4;
-        let byte_index = self.current / 4;
+    }
 
-        let reg = match reg_index {
-            0 => self.eax,
-            1 => self.ebx,
-            2 => self.ecx,
-            3 => self.edx,
-            _ => unreachable!(),
+    /// Best-effort base, maximum, bus and TSC frequencies of the processor.
+    ///
+    /// Combines the TSC/core crystal clock ratio (LEAF=0x15), the processor
+    /// frequency information (LEAF=0x16), the hypervisor timing leaf
+    /// (LEAF=0x4000_0010) and the frequency in the processor brand string.
+    /// Every value records which of these sources it was derived from.
+    ///
+    /// # Platforms
+    /// 🟡 AMD ✅ Intel
+    pub fn frequency(&self) -> FrequencyInfo {
+        let tsc_info = self.get_tsc_info();
+        let freq_info = self.get_processor_frequency_info();
+        let hv_info = self.get_hypervisor_info();
+        let brand = self
+            .get_processor_brand_string()
+            .and_then(|brand| brand.nominal_frequency());
+
+        let from_leaf = |freq: Option<Frequency>| {
+            freq.map(|freq| FrequencyEstimate::new(freq.as_hz(), FrequencySource::FrequencyLeaf))
+        };
+        let from_khz = |khz: Option<u32>, source| match khz {
+            Some(khz) if khz != 0 => Some(FrequencyEstimate::new(khz as u64 * KHZ_TO_HZ, source)),
+            _ => None,
         };
+        let from_brand_string = || brand;
+
+        let base = freq_info
+            .as_ref()
+            .and_then(|f| from_leaf(f.processor_base_frequency()))
+            .or_else(from_brand_string);
+        let max = freq_info
+            .as_ref()
+            .and_then(|f| from_leaf(f.processor_max_frequency()));
+        let bus = freq_info
+            .as_ref()
+            .and_then(|f| from_leaf(f.bus_frequency()))
+            .or_else(|| {
+                from_khz(
+                    hv_info.as_ref().and_then(|hv| hv.apic_frequency()),
+                    FrequencySource::Hypervisor,
+                )
+            });
 
-        let byte = match byte_index {
-            0 => reg,
-            1 => reg >> 8,
-            2 => reg >> 16,
-            3 => reg >> 24,
-            _ => unreachable!(),
-        } as u8;
+        // Inside a VM the hypervisor leaf describes the (possibly scaled)
+        // virtual TSC, so it takes precedence over the host's leaf 0x15.
+        let tsc = from_khz(
+            hv_info.as_ref().and_then(|hv| hv.tsc_frequency()),
+            FrequencySource::Hypervisor,
+        )
+        .or_else(|| {
+            let tsc_info = tsc_info.as_ref()?;
+            match tsc_info.tsc_frequency() {
+                Some(hz) => {
+                    let source = tsc_info.crystal_frequency()?.source();
+                    Some(FrequencyEstimate::new(hz, source))
+                }
+                // Models missing from the crystal clock table enumerate the
+                // ratio but not the crystal, the TSC runs at the base frequency:
+                None if tsc_info.numerator() != 0 && tsc_info.denominator() != 0 => freq_info
+                    .as_ref()
+                    .and_then(|f| from_leaf(f.processor_base_frequency())),
+                None => None,
+            }
+        })
+        .or_else(from_brand_string);
 
-        if byte == 0 {
-            self.current += 1;
-            return self.next();
+        FrequencyInfo {
+            base,
+            max,
+            bus,
+            tsc,
+        }
+    }
+}
+
+/// Upper bound for sub-leafs we read from leafs that enumerate until they
+/// report an invalid entry (or report their own maximum).
+#[cfg(feature = "alloc")]
+const MAX_SUBLEAFS: u32 = 64;
+
+/// Upper bound for the number of leafs we read in each range (basic,
+/// hypervisor, extended), in case a reader reports a bogus maximum.
+#[cfg(feature = "alloc")]
+const MAX_LEAFS_PER_RANGE: u32 = 0x100;
+
+#[cfg(feature = "alloc")]
+impl<R: CpuIdReader> CpuId<R> {
+    /// Read every supported leaf and sub-leaf once, in ascending order of
+    /// (leaf, sub-leaf), and pass the results to `f`.
+    ///
+    /// Sub-leafs are read until the leaf reports an invalid entry (which is
+    /// passed on as well) or until the maximum sub-leaf the leaf reports.
+    fn for_each_leaf<F: FnMut(u32, u32, CpuIdResult)>(&self, mut f: F) {
+        let mut read = |leaf: u32, subleaf: u32| {
+            let res = self.read.cpuid2(leaf, subleaf);
+            f(leaf, subleaf, res);
+            res
+        };
+
+        let max_basic = self.supported_leafs.min(MAX_LEAFS_PER_RANGE - 1);
+        for leaf in 0..=max_basic {
+            match leaf {
+                EAX_CACHE_PARAMETERS | EAX_SGX => {
+                    for subleaf in 0..MAX_SUBLEAFS {
+                        let res = read(leaf, subleaf);
+                        // SGX sub-leaf 0 and 1 are always valid, EPC sections start at 2
+                        if (leaf == EAX_CACHE_PARAMETERS || subleaf >= 2)
+                            && get_bits(res.eax, 0, 3) == 0
+                        {
+                            break;
+                        }
+                    }
+                }
+                EAX_PERFORMANCE_MONITOR_EXT_INFO => {
+                    // Sub-leaf 0 EAX is a bit vector of the valid sub-leafs
+                    let valid = read(leaf, 0).eax;
+                    for subleaf in 1..u32::BITS {
+                        if is_bit_set!(valid, subleaf) {
+                            read(leaf, subleaf);
+                        }
+                    }
+                }
+                EAX_PCONFIG_INFO => {
+                    for subleaf in 0..MAX_SUBLEAFS {
+                        if get_bits(read(leaf, subleaf).eax, 0, 11) != 1 {
+                            break;
+                        }
+                    }
+                }
+                EAX_EXTENDED_TOPOLOGY_INFO | EAX_EXTENDED_TOPOLOGY_INFO_V2 => {
+                    for subleaf in 0..MAX_SUBLEAFS {
+                        if get_bits(read(leaf, subleaf).ecx, 8, 15) == 0 {
+                            break;
+                        }
+                    }
+                }
+                EAX_STRUCTURED_EXTENDED_FEATURE_INFO
+                | EAX_TRACE_INFO
+                | EAX_SOC_VENDOR_INFO
+                | EAX_DETERMINISTIC_ADDRESS_TRANSLATION_INFO
+                | EAX_TILE_INFO => {
+                    let max = read(leaf, 0).eax;
+                    // Brand string of the SoC vendor leaf is in sub-leaf 1..=3
+                    let max = if leaf == EAX_SOC_VENDOR_INFO {
+                        max.max(3)
+                    } else {
+                        max
+                    };
+                    for subleaf in 1..=max.min(MAX_SUBLEAFS - 1) {
+                        read(leaf, subleaf);
+                    }
+                }
+                EAX_EXTENDED_STATE_INFO => {
+                    for subleaf in 0..MAX_SUBLEAFS {
+                        read(leaf, subleaf);
+                    }
+                }
+                EAX_RDT_MONITORING | EAX_RDT_ALLOCATION => {
+                    for subleaf in 0..4 {
+                        read(leaf, subleaf);
+                    }
+                }
+                _ => {
+                    read(leaf, 0);
+                }
+            }
         }
 
-        for cache_info in CACHE_INFO_TABLE.iter() {
-            if cache_info.num == byte {
-                self.current += 1;
-                return Some(*cache_info);
+        if self.get_feature_info().is_some_and(|f| f.has_hypervisor()) {
+            let max_hypervisor = read(EAX_HYPERVISOR_INFO, 0).eax.clamp(
+                EAX_HYPERVISOR_INFO,
+                EAX_HYPERVISOR_INFO + MAX_LEAFS_PER_RANGE - 1,
+            );
+            for leaf in EAX_HYPERVISOR_INFO + 1..=max_hypervisor {
+                read(leaf, 0);
             }
         }
 
-        None
+        if self.supported_extended_leafs >= EAX_EXTENDED_FUNCTION_INFO {
+            let max_extended = self
+                .supported_extended_leafs
+                .min(EAX_EXTENDED_FUNCTION_INFO + MAX_LEAFS_PER_RANGE - 1);
+            for leaf in EAX_EXTENDED_FUNCTION_INFO..=max_extended {
+                if leaf == EAX_CACHE_PARAMETERS_AMD {
+                    for subleaf in 0..MAX_SUBLEAFS {
+                        if get_bits(read(leaf, subleaf).eax, 0, 4) == 0 {
+                            break;
+                        }
+                    }
+                } else {
+                    read(leaf, 0);
+                }
+            }
+        }
     }
 }
 
-impl Debug for CacheInfoIter {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        let mut debug = f.debug_list();
-        self.clone().for_each(|ref item| {
-            debug.entry(item);
-        });
-        debug.finish()
+/// Convenience functions that return collections (requires the `alloc` feature).
+#[cfg(feature = "alloc")]
+impl<R: CpuIdReader> CpuId<R> {
+    /// Names of all feature flags that are set in LEAF=0x01, LEAF=0x07 and
+    /// LEAF=0x8000_0001 (e.g., "SSE3", "AVX2", "LAHF_SAHF").
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn features(&self) -> Vec<&'static str> {
+        let mut features = Vec::new();
+        if let Some(finfo) = self.get_feature_info() {
+            features.extend(finfo.edx_ecx.iter_names().map(|(name, _)| name));
+        }
+        if let Some(efinfo) = self.get_extended_feature_info() {
+            features.extend(efinfo.ebx.iter_names().map(|(name, _)| name));
+            features.extend(efinfo.ecx.iter_names().map(|(name, _)| name));
+            features.extend(efinfo.edx.iter_names().map(|(name, _)| name));
+            features.extend(efinfo.eax1.iter_names().map(|(name, _)| name));
+            features.extend(efinfo.ebx1.iter_names().map(|(name, _)| name));
+            features.extend(efinfo.edx1.iter_names().map(|(name, _)| name));
+            features.extend(efinfo.edx2.iter_names().map(|(name, _)| name));
+        }
+        if let Some(einfo) = self.get_extended_processor_and_feature_identifiers() {
+            features.extend(einfo.flag_names());
+        }
+        features
     }
-}
 
-/// What type of cache are we dealing with?
-#[derive(Copy, Clone, Debug)]
-pub enum CacheInfoType {
-    General,
-    Cache,
-    TLB,
-    STLB,
-    DTLB,
-    Prefetch,
-}
+    /// All caches in the hierarchy, sorted by level and type (LEAF=0x04 or 0x8000_001D).
+    ///
+    /// # Platforms
+    /// 🟡 AMD ✅ Intel
+    pub fn caches(&self) -> Vec<CacheParameter> {
+        self.get_cache_parameters()
+            .map(|caches| caches.collect())
+            .unwrap_or_default()
+    }
 
-/// Describes any kind of cache (TLB, Data and Instruction caches plus prefetchers).
-#[derive(Copy, Clone)]
-pub struct CacheInfo {
-    /// Number as retrieved from cpuid
-    pub num: u8,
-    /// Cache type
-    pub typ: CacheInfoType,
-}
+    /// Cache sizes summed per package, e.g., the L3 of the whole socket or
+    /// the L2 of all cores (LEAF=0x04 or 0x8000_001D, and the leafs of
+    /// [`CpuId::logical_processors_per_package`]).
+    ///
+    /// The number of instances of a cache is the number of logical processors
+    /// in the package divided by the number of logical processors sharing the
+    /// cache. On hybrid processors the cache leafs describe the core type that
+    /// executed CPUID, so the totals of the per-core levels are estimates.
+    ///
+    /// # Platforms
+    /// 🟡 AMD ✅ Intel
+    pub fn cache_totals(&self) -> Vec<CacheTotal> {
+        let logical = self.logical_processors_per_package();
+        self.caches()
+            .iter()
+            .map(|cache| {
+                let threads = cache.max_cores_for_cache().min(logical);
+                CacheTotal {
+                    key: cache.key(),
+                    size: cache.associativity()
+                        * cache.physical_line_partitions()
+                        * cache.coherency_line_size()
+                        * cache.sets(),
+                    threads,
+                    instances: logical.div_ceil(threads),
+                }
+            })
+            .collect()
+    }
 
-impl CacheInfo {
-    /// Description of the cache (from Intel Manual)
-    pub fn desc(&self) -> &'static str {
-        match self.num {
-            0x00 => "Null descriptor, this byte contains no information",
-            0x01 => "Instruction TLB: 4 KByte pages, 4-way set associative, 32 entries",
-            0x02 => "Instruction TLB: 4 MByte pages, fully associative, 2 entries",
-            0x03 => "Data TLB: 4 KByte pages, 4-way set associative, 64 entries",
-            0x04 => "Data TLB: 4 MByte pages, 4-way set associative, 8 entries",
-            0x05 => "Data TLB1: 4 MByte pages, 4-way set associative, 32 entries",
-            0x06 => "1st-level instruction cache: 8 KBytes, 4-way set associative, 32 byte line size",
-            0x08 => "1st-level instruction cache: 16 KBytes, 4-way set associative, 32 byte line size",
-            0x09 => "1st-level instruction cache: 32KBytes, 4-way set associative, 64 byte line size",
-            0x0A => "1st-level data cache: 8 KBytes, 2-way set associative, 32 byte line size",
-            0x0B => "Instruction TLB: 4 MByte pages, 4-way set associative, 4 entries",
-            0x0C => "1st-level data cache: 16 KBytes, 4-way set associative, 32 byte line size",
-            0x0D => "1st-level data cache: 16 KBytes, 4-way set associative, 64 byte line size",
-            0x0E => "1st-level data cache: 24 KBytes, 6-way set associative, 64 byte line size",
-            0x1D => "2nd-level cache: 128 KBytes, 2-way set associative, 64 byte line size",
-            0x21 => "2nd-level cache: 256 KBytes, 8-way set associative, 64 byte line size",
-            0x22 => "3rd-level cache: 512 KBytes, 4-way set associative, 64 byte line size, 2 lines per sector",
-            0x23 => "3rd-level cache: 1 MBytes, 8-way set associative, 64 byte line size, 2 lines per sector",
-            0x24 => "2nd-level cache: 1 MBytes, 16-way set associative, 64 byte line size",
-            0x25 => "3rd-level cache: 2 MBytes, 8-way set associative, 64 byte line size, 2 lines per sector",
-            0x29 => "3rd-level cache: 4 MBytes, 8-way set associative, 64 byte line size, 2 lines per sector",
-            0x2C => "1st-level data cache: 32 KBytes, 8-way set associative, 64 byte line size",
-            0x30 => "1st-level instruction cache: 32 KBytes, 8-way set associative, 64 byte line size",
-            0x40 => "No 2nd-level cache or, if processor contains a valid 2nd-level cache, no 3rd-level cache",
-            0x41 => "2nd-level cache: 128 KBytes, 4-way set associative, 32 byte line size",
-            0x42 => "2nd-level cache: 256 KBytes, 4-way set associative, 32 byte line size",
-            0x43 => "2nd-level cache: 512 KBytes, 4-way set associative, 32 byte line size",
-            0x44 => "2nd-level cache: 1 MByte, 4-way set associative, 32 byte line size",
-            0x45 => "2nd-level cache: 2 MByte, 4-way set associative, 32 byte line size",
-            0x46 => "3rd-level cache: 4 MByte, 4-way set associative, 64 byte line size",
-            0x47 => "3rd-level cache: 8 MByte, 8-way set associative, 64 byte line size",
-            0x48 => "2nd-level cache: 3MByte, 12-way set associative, 64 byte line size",
-            0x49 => "3rd-level cache: 4MB, 16-way set associative, 64-byte line size (Intel Xeon processor MP, Family 0FH, Model 06H); 2nd-level cache: 4 MByte, 16-way set ssociative, 64 byte line size",
-            0x4A => "3rd-level cache: 6MByte, 12-way set associative, 64 byte line size",
-            0x4B => "3rd-level cache: 8MByte, 16-way set associative, 64 byte line size",
-            0x4C => "3rd-level cache: 12MByte, 12-way set associative, 64 byte line size",
-            0x4D => "3rd-level cache: 16MByte, 16-way set associative, 64 byte line size",
-            0x4E => "2nd-level cache: 6MByte, 24-way set associative, 64 byte line size",
-            0x4F => "Instruction TLB: 4 KByte pages, 32 entries",
-            0x50 => "Instruction TLB: 4 KByte and 2-MByte or 4-MByte pages, 64 entries",
-            0x51 => "Instruction TLB: 4 KByte and 2-MByte or 4-MByte pages, 128 entries",
-            0x52 => "Instruction TLB: 4 KByte and 2-MByte or 4-MByte pages, 256 entries",
-            0x55 => "Instruction TLB: 2-MByte or 4-MByte pages, fully associative, 7 entries",
-            0x56 => "Data TLB0: 4 MByte pages, 4-way set associative, 16 entries",
-            0x57 => "Data TLB0: 4 KByte pages, 4-way associative, 16 entries",
-            0x59 => "Data TLB0: 4 KByte pages, fully associative, 16 entries",
-            0x5A => "Data TLB0: 2-MByte or 4 MByte pages, 4-way set associative, 32 entries",
-            0x5B => "Data TLB: 4 KByte and 4 MByte pages, 64 entries",
-            0x5C => "Data TLB: 4 KByte and 4 MByte pages,128 entries",
-            0x5D => "Data TLB: 4 KByte and 4 MByte pages,256 entries",
-            0x60 => "1st-level data cache: 16 KByte, 8-way set associative, 64 byte line size",
-            0x61 => "Instruction TLB: 4 KByte pages, fully associative, 48 entries",
-            0x63 => "Data TLB: 2 MByte or 4 MByte pages, 4-way set associative, 32 entries and a separate array with 1 GByte pages, 4-way set associative, 4 entries",
-            0x64 => "Data TLB: 4 KByte pages, 4-way set associative, 512 entries",
-            0x66 => "1st-level data cache: 8 KByte, 4-way set associative, 64 byte line size",
-            0x67 => "1st-level data cache: 16 KByte, 4-way set associative, 64 byte line size",
-            0x68 => "1st-level data cache: 32 KByte, 4-way set associative, 64 byte line size",
-            0x6A => "uTLB: 4 KByte pages, 8-way set associative, 64 entries",
-            0x6B => "DTLB: 4 KByte pages, 8-way set associative, 256 entries",
-            0x6C => "DTLB: 2M/4M pages, 8-way set associative, 128 entries",
-            0x6D => "DTLB: 1 GByte pages, fully associative, 16 entries",
-            0x70 => "Trace cache: 12 K-μop, 8-way set associative",
-            0x71 => "Trace cache: 16 K-μop, 8-way set associative",
-            0x72 => "Trace cache: 32 K-μop, 8-way set associative",
-            0x76 => "Instruction TLB: 2M/4M pages, fully associative, 8 entries",
-            0x78 => "2nd-level cache: 1 MByte, 4-way set associative, 64byte line size",
-            0x79 => "2nd-level cache: 128 KByte, 8-way set associative, 64 byte line size, 2 lines per sector",
-            0x7A => "2nd-level cache: 256 KByte, 8-way set associative, 64 byte line size, 2 lines per sector",
-            0x7B => "2nd-level cache: 512 KByte, 8-way set associative, 64 byte line size, 2 lines per sector",
-            0x7C => "2nd-level cache: 1 MByte, 8-way set associative, 64 byte line size, 2 lines per sector",
-            0x7D => "2nd-level cache: 2 MByte, 8-way set associative, 64byte line size",
-            0x7F => "2nd-level cache: 512 KByte, 2-way set associative, 64-byte line size",
-            0x80 => "2nd-level cache: 512 KByte, 8-way set associative, 64-byte line size",
-            0x82 => "2nd-level cache: 256 KByte, 8-way set associative, 32 byte line size",
-            0x83 => "2nd-level cache: 512 KByte, 8-way set associative, 32 byte line size",
-            0x84 => "2nd-level cache: 1 MByte, 8-way set associative, 32 byte line size",
-            0x85 => "2nd-level cache: 2 MByte, 8-way set associative, 32 byte line size",
-            0x86 => "2nd-level cache: 512 KByte, 4-way set associative, 64 byte line size",
-            0x87 => "2nd-level cache: 1 MByte, 8-way set associative, 64 byte line size",
-            0xA0 => "DTLB: 4k pages, fully associative, 32 entries",
-            0xB0 => "Instruction TLB: 4 KByte pages, 4-way set associative, 128 entries",
-            0xB1 => "Instruction TLB: 2M pages, 4-way, 8 entries or 4M pages, 4-way, 4 entries",
-            0xB2 => "Instruction TLB: 4KByte pages, 4-way set associative, 64 entries",
-            0xB3 => "Data TLB: 4 KByte pages, 4-way set associative, 128 entries",
-            0xB4 => "Data TLB1: 4 KByte pages, 4-way associative, 256 entries",
-            0xB5 => "Instruction TLB: 4KByte pages, 8-way set associative, 64 entries",
-            0xB6 => "Instruction TLB: 4KByte pages, 8-way set associative, 128 entries",
-            0xBA => "Data TLB1: 4 KByte pages, 4-way associative, 64 entries",
-            0xC0 => "Data TLB: 4 KByte and 4 MByte pages, 4-way associative, 8 entries",
-            0xC1 => "Shared 2nd-Level TLB: 4 KByte/2MByte pages, 8-way associative, 1024 entries",
-            0xC2 => "DTLB: 2 MByte/$MByte pages, 4-way associative, 16 entries",
-            0xC3 => "Shared 2nd-Level TLB: 4 KByte /2 MByte pages, 6-way associative, 1536 entries. Also 1GBbyte pages, 4-way, 16 entries.",
-            0xC4 => "DTLB: 2M/4M Byte pages, 4-way associative, 32 entries",
-            0xCA => "Shared 2nd-Level TLB: 4 KByte pages, 4-way associative, 512 entries",
-            0xD0 => "3rd-level cache: 512 KByte, 4-way set associative, 64 byte line size",
-            0xD1 => "3rd-level cache: 1 MByte, 4-way set associative, 64 byte line size",
-            0xD2 => "3rd-level cache: 2 MByte, 4-way set associative, 64 byte line size",
-            0xD6 => "3rd-level cache: 1 MByte, 8-way set associative, 64 byte line size",
-            0xD7 => "3rd-level cache: 2 MByte, 8-way set associative, 64 byte line size",
-            0xD8 => "3rd-level cache: 4 MByte, 8-way set associative, 64 byte line size",
-            0xDC => "3rd-level cache: 1.5 MByte, 12-way set associative, 64 byte line size",
-            0xDD => "3rd-level cache: 3 MByte, 12-way set associative, 64 byte line size",
-            0xDE => "3rd-level cache: 6 MByte, 12-way set associative, 64 byte line size",
-            0xE2 => "3rd-level cache: 2 MByte, 16-way set associative, 64 byte line size",
-            0xE3 => "3rd-level cache: 4 MByte, 16-way set associative, 64 byte line size",
-            0xE4 => "3rd-level cache: 8 MByte, 16-way set associative, 64 byte line size",
-            0xEA => "3rd-level cache: 12MByte, 24-way set associative, 64 byte line size",
-            0xEB => "3rd-level cache: 18MByte, 24-way set associative, 64 byte line size",
-            0xEC => "3rd-level cache: 24MByte, 24-way set associative, 64 byte line size",
-            0xF0 => "64-Byte prefetching",
-            0xF1 => "128-Byte prefetching",
-            0xFE => "CPUID leaf 2 does not report TLB descriptor information; use CPUID leaf 18H to query TLB and other address translation parameters.",
-            0xFF => "CPUID leaf 2 does not report cache descriptor information, use CPUID leaf 4 to query cache parameters",
-            _ => "Unknown cache type!"
+    /// All levels of the processor topology, see [`CpuId::get_topology_info`].
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn topology_levels(&self) -> Vec<ExtendedTopologyLevel> {
+        self.get_topology_info()
+            .map(|levels| levels.collect())
+            .unwrap_or_default()
+    }
+
+    /// Human readable report of everything this library can decode about the
+    /// processor.
+    pub fn report(&self) -> String {
+        format!("{:#?}", self)
+    }
+
+    /// Read all leafs and sub-leafs in a single ordered pass.
+    ///
+    /// The returned snapshot answers all further queries from memory, so
+    /// decoding everything doesn't interleave CPUID instructions with other
+    /// work and all values are consistent with each other (e.g., across a
+    /// frequency change).
+    ///
+    /// # Example
+    /// ```
+    /// use raw_cpuid::CpuId;
+    ///
+    /// let snapshot = CpuId::new().snapshot();
+    /// let cpuid = snapshot.cpuid();
+    /// println!("{:?}", cpuid.get_feature_info());
+    /// println!("{:?}", cpuid.get_cache_parameters());
+    /// ```
+    pub fn snapshot(&self) -> CpuIdSnapshot {
+        let mut entries = Vec::new();
+        self.for_each_leaf(|leaf, subleaf, res| entries.push((leaf, subleaf, res)));
+        CpuIdSnapshot {
+            entries: entries.into(),
         }
     }
 }
 
-impl Debug for CacheInfo {
+/// An immutable copy of all CPUID leafs, see [CpuId::snapshot].
+///
+/// Implements [CpuIdReader]; leafs that were not part of the snapshot read as
+/// all zeroes (which is how CPUs report invalid sub-leafs).
+///
+/// The entries are reference counted, cloning a snapshot (e.g., to hand the
+/// same CPU profile to many vCPU threads) doesn't copy them.
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq)]
+pub struct CpuIdSnapshot {
+    /// Sorted by (leaf, sub-leaf).
+    entries: Arc<[(u32, u32, CpuIdResult)]>,
+}
+
+#[cfg(feature = "alloc")]
+impl CpuIdSnapshot {
+    /// A [CpuId] that decodes the values of this snapshot.
+    pub fn cpuid(&self) -> CpuId<&CpuIdSnapshot> {
+        CpuId::with_cpuid_reader(self)
+    }
+
+    /// Result for `leaf` and `subleaf`, if it is part of the snapshot.
+    pub fn get(&self, leaf: u32, subleaf: u32) -> Option<CpuIdResult> {
+        self.entries
+            .binary_search_by_key(&(leaf, subleaf), |&(l, s, _)| (l, s))
+            .ok()
+            .map(|idx| self.entries[idx].2)
+    }
+
+    /// All (leaf, sub-leaf, result) entries, sorted by leaf and sub-leaf.
+    pub fn entries(&self) -> &[(u32, u32, CpuIdResult)] {
+        &self.entries
+    }
+
+    /// A copy of this snapshot with all reserved bits of the feature leafs cleared,
+    /// see [`defined_bits`].
+    ///
+    /// Use this before exposing (part of) a host profile to a guest.
+    pub fn sanitize(&self) -> CpuIdSnapshot {
+        CpuIdSnapshot {
+            entries: self
+                .entries
+                .iter()
+                .map(
+                    |&(leaf, subleaf, res)| match defined_bits::find(leaf, subleaf) {
+                        Some(mask) => (leaf, subleaf, mask.apply(res)),
+                        None => (leaf, subleaf, res),
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    /// The (leaf, sub-leaf) pairs whose results differ from `other`, with the result
+    /// in `self` and in `other` (`None` if the pair is missing), sorted by leaf and
+    /// sub-leaf.
+    pub fn diff(
+        &self,
+        other: &CpuIdSnapshot,
+    ) -> Vec<(u32, u32, Option<CpuIdResult>, Option<CpuIdResult>)> {
+        let mut keys: Vec<(u32, u32)> = self
+            .entries
+            .iter()
+            .chain(other.entries.iter())
+            .map(|&(leaf, subleaf, _)| (leaf, subleaf))
+            .collect();
+        keys.sort_unstable();
+        keys.dedup();
+        keys.into_iter()
+            .map(|(leaf, subleaf)| {
+                (
+                    leaf,
+                    subleaf,
+                    self.get(leaf, subleaf),
+                    other.get(leaf, subleaf),
+                )
+            })
+            .filter(|(_, _, ours, theirs)| ours != theirs)
+            .collect()
+    }
+}
+
+/// Build a snapshot from (leaf, sub-leaf, result) entries, e.g., parsed from a file.
+///
+/// The entries don't need to be sorted; for duplicates the last one wins.
+#[cfg(feature = "alloc")]
+impl core::iter::FromIterator<(u32, u32, CpuIdResult)> for CpuIdSnapshot {
+    fn from_iter<I: IntoIterator<Item = (u32, u32, CpuIdResult)>>(iter: I) -> Self {
+        let mut entries: Vec<(u32, u32, CpuIdResult)> = iter.into_iter().collect();
+        // Stable sort keeps the input order of duplicates, keep the last of them
+        entries.sort_by_key(|&(leaf, subleaf, _)| (leaf, subleaf));
+        entries.reverse();
+        entries.dedup_by_key(|&mut (leaf, subleaf, _)| (leaf, subleaf));
+        entries.reverse();
+        CpuIdSnapshot {
+            entries: entries.into(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CpuIdReader for CpuIdSnapshot {
+    fn cpuid2(&self, eax: u32, ecx: u32) -> CpuIdResult {
+        self.get(eax, ecx).unwrap_or(CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CpuIdReader for &CpuIdSnapshot {
+    fn cpuid2(&self, eax: u32, ecx: u32) -> CpuIdResult {
+        (*self).cpuid2(eax, ecx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Debug for CpuIdSnapshot {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("CacheInfo")
-            .field("typ", &self.typ)
-            .field("desc", &self.desc())
-            .finish()
+        let mut debug = f.debug_map();
+        for (leaf, subleaf, res) in self.entries.iter() {
+            debug.entry(&(leaf, subleaf), res);
+        }
+        debug.finish()
     }
 }
 
-impl fmt::Display for CacheInfo {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let typ = match self.typ {
-            CacheInfoType::General => "N/A",
-            CacheInfoType::Cache => "Cache",
-            CacheInfoType::TLB => "TLB",
-            CacheInfoType::STLB => "STLB",
-            CacheInfoType::DTLB => "DTLB",
-            CacheInfoType::Prefetch => "Prefetcher",
-        };
+/// Serialized as a list of `(leaf, sub-leaf, result)` entries.
+#[cfg(all(feature = "alloc", feature = "serialize"))]
+impl serde::Serialize for CpuIdSnapshot {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.entries().serialize(serializer)
+    }
+}
 
-        write!(f, "{:x}:\t {}: {}", self.num, typ, self.desc())
+#[cfg(all(feature = "alloc", feature = "serialize"))]
+impl<'de> serde::Deserialize<'de> for CpuIdSnapshot {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<(u32, u32, CpuIdResult)>::deserialize(deserializer)
+            .map(|entries| entries.into_iter().collect())
     }
 }
 
-/// This table is taken from Intel manual (Section CPUID instruction).
-pub const CACHE_INFO_TABLE: [CacheInfo; 108] = [
-    CacheInfo {
-        num: 0x00,
-        typ: CacheInfoType::General,
-    },
-    CacheInfo {
-        num: 0x01,
-        typ: CacheInfoType::TLB,
-    },
-    CacheInfo {
-        num: 0x02,
-        typ: CacheInfoType::TLB,
-    },
-    CacheInfo {
-        num: 0x03,
-        typ: CacheInfoType::TLB,
-    },
-    CacheInfo {
-        num: 0x04,
-        typ: CacheInfoType::TLB,
-    },
-    CacheInfo {
-        num: 0x05,
-        typ: CacheInfoType::TLB,
-    },
-    CacheInfo {
-        num: 0x06,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x08,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x09,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x0A,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x0B,
-        typ: CacheInfoType::TLB,
-    },
-    CacheInfo {
-        num: 0x0C,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x0D,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x0E,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x21,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x22,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x23,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x24,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x25,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x29,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x2C,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x30,
-        typ: CacheInfoType::Cache,
-    },
-    CacheInfo {
-        num: 0x40,
-        typ: CacheInfoType::Cache,
+/// A reader that records every (leaf, sub-leaf) pair queried through it.
+///
+/// Useful to find out which leafs some code depends on, and to turn them into
+/// a minimal fixture with [RecordingReader::snapshot] that replays exactly
+/// these values.
+///
+/// Like [CachedCpuIdReader], pass a reference to [CpuId] so the structs and
+/// iterators obtained from it record into the same log:
+///
+/// ```
+/// use raw_cpuid::{CpuId, CpuIdReaderNative, RecordingReader};
+///
+/// let reader = RecordingReader::new(CpuIdReaderNative);
+/// let cpuid = CpuId::with_cpuid_reader(&reader);
+/// cpuid.get_feature_info();
+/// for (leaf, subleaf) in reader.queries() {
+///     println!("({:#x}, {:#x})", leaf, subleaf);
+/// }
+/// let fixture = reader.snapshot();
+/// assert_eq!(
+///     format!("{:?}", fixture.cpuid().get_feature_info()),
+///     format!("{:?}", cpuid.get_feature_info())
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct RecordingReader<R: CpuIdReader> {
+    read: R,
+    /// In the order of the first query.
+    recorded: core::cell::RefCell<Vec<(u32, u32, CpuIdResult)>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<R: CpuIdReader> RecordingReader<R> {
+    /// Wrap `read`, the log starts out empty.
+    pub fn new(read: R) -> Self {
+        Self {
+            read,
+            recorded: core::cell::RefCell::new(Vec::new()),
+        }
+    }
+
+    /// All distinct (leaf, sub-leaf) pairs queried so far, in the order of
+    /// their first query.
+    pub fn queries(&self) -> Vec<(u32, u32)> {
+        self.recorded
+            .borrow()
+            .iter()
+            .map(|&(leaf, subleaf, _)| (leaf, subleaf))
+            .collect()
+    }
+
+    /// The recorded results as a snapshot, i.e., a minimal dump that answers
+    /// all queries made so far the same way.
+    pub fn snapshot(&self) -> CpuIdSnapshot {
+        self.recorded.borrow().iter().copied().collect()
+    }
+
+    /// Forget all recorded queries.
+    pub fn clear(&self) {
+        self.recorded.borrow_mut().clear();
+    }
+
+    /// Return the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.read
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R: CpuIdReader> CpuIdReader for RecordingReader<R> {
+    fn cpuid2(&self, eax: u32, ecx: u32) -> CpuIdResult {
+        let res = self.read.cpuid2(eax, ecx);
+        let mut recorded = self.recorded.borrow_mut();
+        if !recorded
+            .iter()
+            .any(|&(leaf, subleaf, _)| leaf == eax && subleaf == ecx)
+        {
+            recorded.push((eax, ecx, res));
+        }
+        res
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R: CpuIdReader> CpuIdReader for &RecordingReader<R> {
+    fn cpuid2(&self, eax: u32, ecx: u32) -> CpuIdResult {
+        (*self).cpuid2(eax, ecx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R: CpuIdReader> Debug for RecordingReader<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordingReader")
+            .field("queries", &self.queries())
+            .finish()
+    }
+}
+
+impl<R: CpuIdReader> Debug for CpuId<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CpuId")
+            .field("vendor", &self.vendor)
+            // .field("supported_leafs", &(self.supported_leafs as *const u32))
+            // .field("supported_extended_leafs", &(self.supported_extended_leafs as *const u32))
+            .field("vendor_info", &self.get_vendor_info())
+            .field("feature_info", &self.get_feature_info())
+            .field("cache_info", &self.get_cache_info())
+            .field("processor_serial", &self.get_processor_serial())
+            .field("cache_parameters", &self.get_cache_parameters())
+            .field("monitor_mwait_info", &self.get_monitor_mwait_info())
+            .field("thermal_power_info", &self.get_thermal_power_info())
+            .field("extended_feature_info", &self.get_extended_feature_info())
+            .field(
+                "direct_cache_access_info",
+                &self.get_direct_cache_access_info(),
+            )
+            .field(
+                "performance_monitoring_info",
+                &self.get_performance_monitoring_info(),
+            )
+            .field("extended_topology_info", &self.get_extended_topology_info())
+            .field(
+                "extended_topology_info_v2",
+                &self.get_extended_topology_info_v2(),
+            )
+            .field("extended_state_info", &self.get_extended_state_info())
+            .field("rdt_monitoring_info", &self.get_rdt_monitoring_info())
+            .field("rdt_allocation_info", &self.get_rdt_allocation_info())
+            .field("sgx_info", &self.get_sgx_info())
+            .field("processor_trace_info", &self.get_processor_trace_info())
+            .field("tsc_info", &self.get_tsc_info())
+            .field(
+                "processor_frequency_info",
+                &self.get_processor_frequency_info(),
+            )
+            .field(
+                "deterministic_address_translation_info",
+                &self.get_deterministic_address_translation_info(),
+            )
+            .field("soc_vendor_info", &self.get_soc_vendor_info())
+            .field("hybrid_info", &self.get_hybrid_info())
+            .field("pconfig_info", &self.get_pconfig_info())
+            .field("tile_info", &self.get_tile_info())
+            .field("tmul_info", &self.get_tmul_info())
+            .field("hreset_info", &self.get_hreset_info())
+            .field(
+                "performance_monitoring_ext_info",
+                &self.get_performance_monitoring_ext_info(),
+            )
+            .field("hypervisor_info", &self.get_hypervisor_info())
+            .field(
+                "extended_processor_and_feature_identifiers",
+                &self.get_extended_processor_and_feature_identifiers(),
+            )
+            .field("processor_brand_string", &self.get_processor_brand_string())
+            .field("l1_cache_and_tlb_info", &self.get_l1_cache_and_tlb_info())
+            .field(
+                "l2_l3_cache_and_tlb_info",
+                &self.get_l2_l3_cache_and_tlb_info(),
+            )
+            .field(
+                "advanced_power_mgmt_info",
+                &self.get_advanced_power_mgmt_info(),
+            )
+            .field(
+                "processor_capacity_feature_info",
+                &self.get_processor_capacity_feature_info(),
+            )
+            .field("svm_info", &self.get_svm_info())
+            .field("tlb_1gb_page_info", &self.get_tlb_1gb_page_info())
+            .field(
+                "performance_optimization_info",
+                &self.get_performance_optimization_info(),
+            )
+            .field(
+                "processor_topology_info",
+                &self.get_processor_topology_info(),
+            )
+            .field("memory_encryption_info", &self.get_memory_encryption_info())
+            .field(
+                "extended_feature_identification_2",
+                &self.get_extended_feature_identification_2(),
+            )
+            .field("amd_perfmon_debug_info", &self.get_amd_perfmon_debug_info())
+            .field("frequency", &self.frequency())
+            .finish()
+    }
+}
+
+/// Vendor Info String (LEAF=0x0)
+///
+/// A string that can be for example "AuthenticAMD" or "GenuineIntel".
+///
+/// # Technical Background
+///
+/// The vendor info is a 12-byte (96 bit) long string stored in `ebx`, `edx` and
+/// `ecx` by the corresponding `cpuid` instruction.
+///
+/// # Platforms
+/// ✅ AMD ✅ Intel
+#[derive(PartialEq, Eq)]
+#[repr(C)]
+pub struct VendorInfo {
+    ebx: u32,
+    edx: u32,
+    ecx: u32,
+}
+
+impl VendorInfo {
+    /// Return vendor identification as human readable string.
+    pub fn as_str(&self) -> &str {
+        let brand_string_start = self as *const VendorInfo as *const u8;
+        let slice = unsafe {
+            // Safety: VendorInfo is laid out with repr(C) and exactly
+            // 12 byte long without any padding.
+            slice::from_raw_parts(brand_string_start, size_of::<VendorInfo>())
+        };
+
+        str::from_utf8(slice).unwrap_or("InvalidVendorString")
+    }
+
+    #[deprecated(
+        since = "10.0.0",
+        note = "Use idiomatic function name `as_str` instead"
+    )]
+    pub fn as_string(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Debug for VendorInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("VendorInfo")
+            .field("brand_string", &self.as_str())
+            .finish()
+    }
+}
+
+impl fmt::Display for VendorInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Iterates over cache information (LEAF=0x02).
+///
+/// This will just return an index into a static table of cache descriptions
+/// (see `CACHE_INFO_TABLE`, which requires the `leaf2-table` feature).
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+#[derive(PartialEq, Eq, Clone)]
+pub struct CacheInfoIter {
+    current: u32,
+    eax: u32,
+    ebx: u32,
+    ecx: u32,
+    edx: u32,
+}
+
+impl CacheInfoIter {
+    /// Does leaf 2 report descriptor 0xFF, i.e., cache parameters have to be
+    /// queried with leaf 4 ([`CpuId::get_cache_parameters`])?
+    pub fn uses_leaf4(&self) -> bool {
+        self.has_descriptor(0xff)
+    }
+
+    /// Does leaf 2 report descriptor 0xFE, i.e., TLB information has to be
+    /// queried with leaf 0x18
+    /// ([`CpuId::get_deterministic_address_translation_info`])?
+    pub fn uses_leaf18(&self) -> bool {
+        self.has_descriptor(0xfe)
+    }
+
+    fn has_descriptor(&self, num: u8) -> bool {
+        // Look at all descriptors, independent of the iteration state.
+        CacheInfoIter {
+            current: 1,
+            ..self.clone()
+        }
+        .any(|c| c.num == num)
+    }
+}
+
+impl Iterator for CacheInfoIter {
+    type Item = CacheInfo;
+
+    /// Iterate over all cache information.
+    ///
+    /// Descriptors that are not in `CACHE_INFO_TABLE` are returned with type
+    /// [CacheInfoType::Unknown].
+    fn next(&mut self) -> Option<CacheInfo> {
+        // Every byte of the 4 register values returned by cpuid
+        // can contain information about a cache (except the
+        // very first one).
+        while self.current < 4 * 4 {
+            let reg_index = self.current % 4;
+            let byte_index = self.current / 4;
+            self.current += 1;
+
+            let reg = match reg_index {
+                0 => self.eax,
+                1 => self.ebx,
+                2 => self.ecx,
+                3 => self.edx,
+                _ => unreachable!(),
+            };
+            let byte = (reg >> (byte_index * 8)) as u8;
+
+            if byte != 0 {
+                return Some(CacheInfo::from_descriptor(byte));
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for CacheInfoIter {}
+
+impl Debug for CacheInfoIter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut debug = f.debug_list();
+        self.clone().for_each(|ref item| {
+            debug.entry(item);
+        });
+        debug.finish()
+    }
+}
+
+/// Cache and TLB information with the leaf 2 redirections resolved.
+///
+/// Returned by [`CpuId::get_cache_and_tlb_info`].
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+pub struct CacheTlbInfo<R: CpuIdReader> {
+    descriptors: CacheInfoIter,
+    cache_parameters: Option<CacheParametersIter<R>>,
+    tlb_info: Option<DatIter<R>>,
+}
+
+impl<R: CpuIdReader> CacheTlbInfo<R> {
+    /// Leaf 2 descriptors, without the 0xFF and 0xFE redirection descriptors.
+    pub fn descriptors(&self) -> impl Iterator<Item = CacheInfo> {
+        self.descriptors
+            .clone()
+            .filter(|c| c.num != 0xff && c.num != 0xfe)
+    }
+
+    /// Cache parameters from leaf 4, if leaf 2 refers to it (descriptor 0xFF).
+    pub fn cache_parameters(&self) -> Option<CacheParametersIter<R>> {
+        self.cache_parameters.clone()
+    }
+
+    /// TLB information from leaf 0x18, if leaf 2 refers to it (descriptor 0xFE).
+    pub fn tlb_info(&self) -> Option<DatIter<R>> {
+        self.tlb_info.clone()
+    }
+}
+
+impl<R: CpuIdReader> Debug for CacheTlbInfo<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CacheTlbInfo")
+            .field("descriptors", &self.descriptors)
+            .field("cache_parameters", &self.cache_parameters)
+            .field("tlb_info", &self.tlb_info)
+            .finish()
+    }
+}
+
+/// What type of cache are we dealing with?
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub enum CacheInfoType {
+    General,
+    Cache,
+    TLB,
+    STLB,
+    DTLB,
+    Prefetch,
+    /// Descriptor is not listed in the Intel manual.
+    Unknown,
+}
+
+/// Describes any kind of cache (TLB, Data and Instruction caches plus prefetchers).
+#[derive(Copy, Clone)]
+pub struct CacheInfo {
+    /// Number as retrieved from cpuid
+    pub num: u8,
+    /// Cache type
+    pub typ: CacheInfoType,
+}
+
+impl CacheInfo {
+    /// Look up a leaf 2 descriptor byte in `CACHE_INFO_TABLE`.
+    ///
+    /// Returns an entry with type [CacheInfoType::Unknown] if the descriptor
+    /// is not in the table.
+    ///
+    /// Without the `leaf2-table` feature every descriptor is unknown.
+    pub fn from_descriptor(num: u8) -> CacheInfo {
+        #[cfg(feature = "leaf2-table")]
+        {
+            let idx = CACHE_INFO_LOOKUP[num as usize];
+            if idx != NO_CACHE_INFO {
+                return CACHE_INFO_TABLE[idx as usize];
+            }
+        }
+
+        CacheInfo {
+            num,
+            typ: CacheInfoType::Unknown,
+        }
+    }
+
+    /// Description of the cache.
+    ///
+    /// Always "Unknown cache type!" because the `leaf2-table` feature is disabled.
+    #[cfg(not(feature = "leaf2-table"))]
+    pub fn desc(&self) -> &'static str {
+        "Unknown cache type!"
+    }
+
+    /// Description of the cache (from Intel Manual)
+    #[cfg(feature = "leaf2-table")]
+    pub fn desc(&self) -> &'static str {
+        match self.num {
+            0x00 => "Null descriptor, this byte contains no information",
+            0x01 => "Instruction TLB: 4 KByte pages, 4-way set associative, 32 entries",
+            0x02 => "Instruction TLB: 4 MByte pages, fully associative, 2 entries",
+            0x03 => "Data TLB: 4 KByte pages, 4-way set associative, 64 entries",
+            0x04 => "Data TLB: 4 MByte pages, 4-way set associative, 8 entries",
+            0x05 => "Data TLB1: 4 MByte pages, 4-way set associative, 32 entries",
+            0x06 => "1st-level instruction cache: 8 KBytes, 4-way set associative, 32 byte line size",
+            0x08 => "1st-level instruction cache: 16 KBytes, 4-way set associative, 32 byte line size",
+            0x09 => "1st-level instruction cache: 32KBytes, 4-way set associative, 64 byte line size",
+            0x0A => "1st-level data cache: 8 KBytes, 2-way set associative, 32 byte line size",
+            0x0B => "Instruction TLB: 4 MByte pages, 4-way set associative, 4 entries",
+            0x0C => "1st-level data cache: 16 KBytes, 4-way set associative, 32 byte line size",
+            0x0D => "1st-level data cache: 16 KBytes, 4-way set associative, 64 byte line size",
+            0x0E => "1st-level data cache: 24 KBytes, 6-way set associative, 64 byte line size",
+            0x1D => "2nd-level cache: 128 KBytes, 2-way set associative, 64 byte line size",
+            0x21 => "2nd-level cache: 256 KBytes, 8-way set associative, 64 byte line size",
+            0x22 => "3rd-level cache: 512 KBytes, 4-way set associative, 64 byte line size, 2 lines per sector",
+            0x23 => "3rd-level cache: 1 MBytes, 8-way set associative, 64 byte line size, 2 lines per sector",
+            0x24 => "2nd-level cache: 1 MBytes, 16-way set associative, 64 byte line size",
+            0x25 => "3rd-level cache: 2 MBytes, 8-way set associative, 64 byte line size, 2 lines per sector",
+            0x29 => "3rd-level cache: 4 MBytes, 8-way set associative, 64 byte line size, 2 lines per sector",
+            0x2C => "1st-level data cache: 32 KBytes, 8-way set associative, 64 byte line size",
+            0x30 => "1st-level instruction cache: 32 KBytes, 8-way set associative, 64 byte line size",
+            0x40 => "No 2nd-level cache or, if processor contains a valid 2nd-level cache, no 3rd-level cache",
+            0x41 => "2nd-level cache: 128 KBytes, 4-way set associative, 32 byte line size",
+            0x42 => "2nd-level cache: 256 KBytes, 4-way set associative, 32 byte line size",
+            0x43 => "2nd-level cache: 512 KBytes, 4-way set associative, 32 byte line size",
+            0x44 => "2nd-level cache: 1 MByte, 4-way set associative, 32 byte line size",
+            0x45 => "2nd-level cache: 2 MByte, 4-way set associative, 32 byte line size",
+            0x46 => "3rd-level cache: 4 MByte, 4-way set associative, 64 byte line size",
+            0x47 => "3rd-level cache: 8 MByte, 8-way set associative, 64 byte line size",
+            0x48 => "2nd-level cache: 3MByte, 12-way set associative, 64 byte line size",
+            0x49 => "3rd-level cache: 4MB, 16-way set associative, 64-byte line size (Intel Xeon processor MP, Family 0FH, Model 06H); 2nd-level cache: 4 MByte, 16-way set ssociative, 64 byte line size",
+            0x4A => "3rd-level cache: 6MByte, 12-way set associative, 64 byte line size",
+            0x4B => "3rd-level cache: 8MByte, 16-way set associative, 64 byte line size",
+            0x4C => "3rd-level cache: 12MByte, 12-way set associative, 64 byte line size",
+            0x4D => "3rd-level cache: 16MByte, 16-way set associative, 64 byte line size",
+            0x4E => "2nd-level cache: 6MByte, 24-way set associative, 64 byte line size",
+            0x4F => "Instruction TLB: 4 KByte pages, 32 entries",
+            0x50 => "Instruction TLB: 4 KByte and 2-MByte or 4-MByte pages, 64 entries",
+            0x51 => "Instruction TLB: 4 KByte and 2-MByte or 4-MByte pages, 128 entries",
+            0x52 => "Instruction TLB: 4 KByte and 2-MByte or 4-MByte pages, 256 entries",
+            0x55 => "Instruction TLB: 2-MByte or 4-MByte pages, fully associative, 7 entries",
+            0x56 => "Data TLB0: 4 MByte pages, 4-way set associative, 16 entries",
+            0x57 => "Data TLB0: 4 KByte pages, 4-way associative, 16 entries",
+            0x59 => "Data TLB0: 4 KByte pages, fully associative, 16 entries",
+            0x5A => "Data TLB0: 2-MByte or 4 MByte pages, 4-way set associative, 32 entries",
+            0x5B => "Data TLB: 4 KByte and 4 MByte pages, 64 entries",
+            0x5C => "Data TLB: 4 KByte and 4 MByte pages,128 entries",
+            0x5D => "Data TLB: 4 KByte and 4 MByte pages,256 entries",
+            0x60 => "1st-level data cache: 16 KByte, 8-way set associative, 64 byte line size",
+            0x61 => "Instruction TLB: 4 KByte pages, fully associative, 48 entries",
+            0x63 => "Data TLB: 2 MByte or 4 MByte pages, 4-way set associative, 32 entries and a separate array with 1 GByte pages, 4-way set associative, 4 entries",
+            0x64 => "Data TLB: 4 KByte pages, 4-way set associative, 512 entries",
+            0x66 => "1st-level data cache: 8 KByte, 4-way set associative, 64 byte line size",
+            0x67 => "1st-level data cache: 16 KByte, 4-way set associative, 64 byte line size",
+            0x68 => "1st-level data cache: 32 KByte, 4-way set associative, 64 byte line size",
+            0x6A => "uTLB: 4 KByte pages, 8-way set associative, 64 entries",
+            0x6B => "DTLB: 4 KByte pages, 8-way set associative, 256 entries",
+            0x6C => "DTLB: 2M/4M pages, 8-way set associative, 128 entries",
+            0x6D => "DTLB: 1 GByte pages, fully associative, 16 entries",
+            0x70 => "Trace cache: 12 K-μop, 8-way set associative",
+            0x71 => "Trace cache: 16 K-μop, 8-way set associative",
+            0x72 => "Trace cache: 32 K-μop, 8-way set associative",
+            0x76 => "Instruction TLB: 2M/4M pages, fully associative, 8 entries",
+            0x78 => "2nd-level cache: 1 MByte, 4-way set associative, 64byte line size",
+            0x79 => "2nd-level cache: 128 KByte, 8-way set associative, 64 byte line size, 2 lines per sector",
+            0x7A => "2nd-level cache: 256 KByte, 8-way set associative, 64 byte line size, 2 lines per sector",
+            0x7B => "2nd-level cache: 512 KByte, 8-way set associative, 64 byte line size, 2 lines per sector",
+            0x7C => "2nd-level cache: 1 MByte, 8-way set associative, 64 byte line size, 2 lines per sector",
+            0x7D => "2nd-level cache: 2 MByte, 8-way set associative, 64byte line size",
+            0x7F => "2nd-level cache: 512 KByte, 2-way set associative, 64-byte line size",
+            0x80 => "2nd-level cache: 512 KByte, 8-way set associative, 64-byte line size",
+            0x82 => "2nd-level cache: 256 KByte, 8-way set associative, 32 byte line size",
+            0x83 => "2nd-level cache: 512 KByte, 8-way set associative, 32 byte line size",
+            0x84 => "2nd-level cache: 1 MByte, 8-way set associative, 32 byte line size",
+            0x85 => "2nd-level cache: 2 MByte, 8-way set associative, 32 byte line size",
+            0x86 => "2nd-level cache: 512 KByte, 4-way set associative, 64 byte line size",
+            0x87 => "2nd-level cache: 1 MByte, 8-way set associative, 64 byte line size",
+            0xA0 => "DTLB: 4k pages, fully associative, 32 entries",
+            0xB0 => "Instruction TLB: 4 KByte pages, 4-way set associative, 128 entries",
+            0xB1 => "Instruction TLB: 2M pages, 4-way, 8 entries or 4M pages, 4-way, 4 entries",
+            0xB2 => "Instruction TLB: 4KByte pages, 4-way set associative, 64 entries",
+            0xB3 => "Data TLB: 4 KByte pages, 4-way set associative, 128 entries",
+            0xB4 => "Data TLB1: 4 KByte pages, 4-way associative, 256 entries",
+            0xB5 => "Instruction TLB: 4KByte pages, 8-way set associative, 64 entries",
+            0xB6 => "Instruction TLB: 4KByte pages, 8-way set associative, 128 entries",
+            0xBA => "Data TLB1: 4 KByte pages, 4-way associative, 64 entries",
+            0xC0 => "Data TLB: 4 KByte and 4 MByte pages, 4-way associative, 8 entries",
+            0xC1 => "Shared 2nd-Level TLB: 4 KByte/2MByte pages, 8-way associative, 1024 entries",
+            0xC2 => "DTLB: 4 KByte/2 MByte pages, 4-way associative, 16 entries",
+            0xC3 => "Shared 2nd-Level TLB: 4 KByte /2 MByte pages, 6-way associative, 1536 entries. Also 1GBbyte pages, 4-way, 16 entries.",
+            0xC4 => "DTLB: 2M/4M Byte pages, 4-way associative, 32 entries",
+            0xCA => "Shared 2nd-Level TLB: 4 KByte pages, 4-way associative, 512 entries",
+            0xD0 => "3rd-level cache: 512 KByte, 4-way set associative, 64 byte line size",
+            0xD1 => "3rd-level cache: 1 MByte, 4-way set associative, 64 byte line size",
+            0xD2 => "3rd-level cache: 2 MByte, 4-way set associative, 64 byte line size",
+            0xD6 => "3rd-level cache: 1 MByte, 8-way set associative, 64 byte line size",
+            0xD7 => "3rd-level cache: 2 MByte, 8-way set associative, 64 byte line size",
+            0xD8 => "3rd-level cache: 4 MByte, 8-way set associative, 64 byte line size",
+            0xDC => "3rd-level cache: 1.5 MByte, 12-way set associative, 64 byte line size",
+            0xDD => "3rd-level cache: 3 MByte, 12-way set associative, 64 byte line size",
+            0xDE => "3rd-level cache: 6 MByte, 12-way set associative, 64 byte line size",
+            0xE2 => "3rd-level cache: 2 MByte, 16-way set associative, 64 byte line size",
+            0xE3 => "3rd-level cache: 4 MByte, 16-way set associative, 64 byte line size",
+            0xE4 => "3rd-level cache: 8 MByte, 16-way set associative, 64 byte line size",
+            0xEA => "3rd-level cache: 12MByte, 24-way set associative, 64 byte line size",
+            0xEB => "3rd-level cache: 18MByte, 24-way set associative, 64 byte line size",
+            0xEC => "3rd-level cache: 24MByte, 24-way set associative, 64 byte line size",
+            0xF0 => "64-Byte prefetching",
+            0xF1 => "128-Byte prefetching",
+            0xFE => "CPUID leaf 2 does not report TLB descriptor information; use CPUID leaf 18H to query TLB and other address translation parameters.",
+            0xFF => "CPUID leaf 2 does not report cache descriptor information, use CPUID leaf 4 to query cache parameters",
+            _ => "Unknown cache type!"
+        }
+    }
+
+    /// Cache level (1-3), 2 for shared second-level TLBs and 1 for all other
+    /// TLBs.
+    ///
+    /// Descriptors that describe two structures (e.g. 0x49, 0x63, 0xB1 and
+    /// 0xC3) report the numeric fields of the first one.
+    pub fn level(&self) -> Option<u8> {
+        self.params().map(|p| p.level)
+    }
+
+    /// Cache size in bytes (caches only, trace caches are sized in μops).
+    pub fn size(&self) -> Option<u64> {
+        self.params()
+            .filter(|p| p.size_kb != 0)
+            .map(|p| p.size_kb as u64 * 1024)
+    }
+
+    /// Associativity of the cache or TLB, if the descriptor specifies one.
+    pub fn associativity(&self) -> Option<Associativity> {
+        match self.params()?.ways {
+            0 => None,
+            DESCRIPTOR_FULLY_ASSOCIATIVE => Some(Associativity::FullyAssociative),
+            n => Some(Associativity::NWay(n)),
+        }
+    }
+
+    /// Cache line size in bytes (caches only).
+    pub fn line_size(&self) -> Option<u16> {
+        self.params()
+            .filter(|p| p.line_size != 0)
+            .map(|p| p.line_size)
+    }
+
+    /// Number of TLB entries (TLBs only).
+    pub fn entries(&self) -> Option<u16> {
+        self.params().filter(|p| p.entries != 0).map(|p| p.entries)
+    }
+
+    /// Does this TLB hold entries for the given page size?
+    pub fn supports_page_size(&self, size: PageSize) -> bool {
+        let bit = match size {
+            PageSize::Small4K => DESCRIPTOR_PAGE_4K,
+            PageSize::Huge2M => DESCRIPTOR_PAGE_2M,
+            PageSize::Huge4M => DESCRIPTOR_PAGE_4M,
+            PageSize::Huge1G => DESCRIPTOR_PAGE_1G,
+        };
+        self.params().is_some_and(|p| p.pages & bit != 0)
+    }
+
+    /// Page sizes this TLB holds entries for (empty for caches).
+    pub fn page_sizes(&self) -> impl Iterator<Item = PageSize> {
+        let info = *self;
+        IntoIterator::into_iter([
+            PageSize::Small4K,
+            PageSize::Huge2M,
+            PageSize::Huge4M,
+            PageSize::Huge1G,
+        ])
+        .filter(move |&size| info.supports_page_size(size))
+    }
+
+    #[cfg(not(feature = "leaf2-table"))]
+    fn params(&self) -> Option<DescriptorParams> {
+        None
+    }
+
+    /// Numeric properties of the descriptor (from Intel Manual).
+    #[cfg(feature = "leaf2-table")]
+    fn params(&self) -> Option<DescriptorParams> {
+        use DescriptorParams as D;
+        const P4K: u8 = DESCRIPTOR_PAGE_4K;
+        const P2M: u8 = DESCRIPTOR_PAGE_2M;
+        const P4M: u8 = DESCRIPTOR_PAGE_4M;
+        const P1G: u8 = DESCRIPTOR_PAGE_1G;
+        const FULL: u8 = DESCRIPTOR_FULLY_ASSOCIATIVE;
+
+        let params = match self.num {
+            0x01 => D::tlb(1, P4K, 4, 32),
+            0x02 => D::tlb(1, P4M, FULL, 2),
+            0x03 => D::tlb(1, P4K, 4, 64),
+            0x04 => D::tlb(1, P4M, 4, 8),
+            0x05 => D::tlb(1, P4M, 4, 32),
+            0x06 => D::cache(1, 8, 4, 32),
+            0x08 => D::cache(1, 16, 4, 32),
+            0x09 => D::cache(1, 32, 4, 64),
+            0x0A => D::cache(1, 8, 2, 32),
+            0x0B => D::tlb(1, P4M, 4, 4),
+            0x0C => D::cache(1, 16, 4, 32),
+            0x0D => D::cache(1, 16, 4, 64),
+            0x0E => D::cache(1, 24, 6, 64),
+            0x1D => D::cache(2, 128, 2, 64),
+            0x21 => D::cache(2, 256, 8, 64),
+            0x22 => D::cache(3, 512, 4, 64),
+            0x23 => D::cache(3, 1024, 8, 64),
+            0x24 => D::cache(2, 1024, 16, 64),
+            0x25 => D::cache(3, 2048, 8, 64),
+            0x29 => D::cache(3, 4096, 8, 64),
+            0x2C => D::cache(1, 32, 8, 64),
+            0x30 => D::cache(1, 32, 8, 64),
+            0x41 => D::cache(2, 128, 4, 32),
+            0x42 => D::cache(2, 256, 4, 32),
+            0x43 => D::cache(2, 512, 4, 32),
+            0x44 => D::cache(2, 1024, 4, 32),
+            0x45 => D::cache(2, 2048, 4, 32),
+            0x46 => D::cache(3, 4096, 4, 64),
+            0x47 => D::cache(3, 8192, 8, 64),
+            0x48 => D::cache(2, 3072, 12, 64),
+            0x49 => D::cache(3, 4096, 16, 64),
+            0x4A => D::cache(3, 6144, 12, 64),
+            0x4B => D::cache(3, 8192, 16, 64),
+            0x4C => D::cache(3, 12288, 12, 64),
+            0x4D => D::cache(3, 16384, 16, 64),
+            0x4E => D::cache(2, 6144, 24, 64),
+            0x4F => D::tlb(1, P4K, 0, 32),
+            0x50 => D::tlb(1, P4K | P2M | P4M, 0, 64),
+            0x51 => D::tlb(1, P4K | P2M | P4M, 0, 128),
+            0x52 => D::tlb(1, P4K | P2M | P4M, 0, 256),
+            0x55 => D::tlb(1, P2M | P4M, FULL, 7),
+            0x56 => D::tlb(1, P4M, 4, 16),
+            0x57 => D::tlb(1, P4K, 4, 16),
+            0x59 => D::tlb(1, P4K, FULL, 16),
+            0x5A => D::tlb(1, P2M | P4M, 4, 32),
+            0x5B => D::tlb(1, P4K | P4M, 0, 64),
+            0x5C => D::tlb(1, P4K | P4M, 0, 128),
+            0x5D => D::tlb(1, P4K | P4M, 0, 256),
+            0x60 => D::cache(1, 16, 8, 64),
+            0x61 => D::tlb(1, P4K, FULL, 48),
+            0x63 => D::tlb(1, P2M | P4M, 4, 32),
+            0x64 => D::tlb(1, P4K, 4, 512),
+            0x66 => D::cache(1, 8, 4, 64),
+            0x67 => D::cache(1, 16, 4, 64),
+            0x68 => D::cache(1, 32, 4, 64),
+            0x6A => D::tlb(1, P4K, 8, 64),
+            0x6B => D::tlb(1, P4K, 8, 256),
+            0x6C => D::tlb(1, P2M | P4M, 8, 128),
+            0x6D => D::tlb(1, P1G, FULL, 16),
+            0x70..=0x72 => D::cache(1, 0, 8, 0),
+            0x76 => D::tlb(1, P2M | P4M, FULL, 8),
+            0x78 => D::cache(2, 1024, 4, 64),
+            0x79 => D::cache(2, 128, 8, 64),
+            0x7A => D::cache(2, 256, 8, 64),
+            0x7B => D::cache(2, 512, 8, 64),
+            0x7C => D::cache(2, 1024, 8, 64),
+            0x7D => D::cache(2, 2048, 8, 64),
+            0x7F => D::cache(2, 512, 2, 64),
+            0x80 => D::cache(2, 512, 8, 64),
+            0x82 => D::cache(2, 256, 8, 32),
+            0x83 => D::cache(2, 512, 8, 32),
+            0x84 => D::cache(2, 1024, 8, 32),
+            0x85 => D::cache(2, 2048, 8, 32),
+            0x86 => D::cache(2, 512, 4, 64),
+            0x87 => D::cache(2, 1024, 8, 64),
+            0xA0 => D::tlb(1, P4K, FULL, 32),
+            0xB0 => D::tlb(1, P4K, 4, 128),
+            0xB1 => D::tlb(1, P2M, 4, 8),
+            0xB2 => D::tlb(1, P4K, 4, 64),
+            0xB3 => D::tlb(1, P4K, 4, 128),
+            0xB4 => D::tlb(1, P4K, 4, 256),
+            0xB5 => D::tlb(1, P4K, 8, 64),
+            0xB6 => D::tlb(1, P4K, 8, 128),
+            0xBA => D::tlb(1, P4K, 4, 64),
+            0xC0 => D::tlb(1, P4K | P4M, 4, 8),
+            0xC1 => D::tlb(2, P4K | P2M, 8, 1024),
+            0xC2 => D::tlb(1, P4K | P2M, 4, 16),
+            0xC3 => D::tlb(2, P4K | P2M, 6, 1536),
+            0xC4 => D::tlb(1, P2M | P4M, 4, 32),
+            0xCA => D::tlb(2, P4K, 4, 512),
+            0xD0 => D::cache(3, 512, 4, 64),
+            0xD1 => D::cache(3, 1024, 4, 64),
+            0xD2 => D::cache(3, 2048, 4, 64),
+            0xD6 => D::cache(3, 1024, 8, 64),
+            0xD7 => D::cache(3, 2048, 8, 64),
+            0xD8 => D::cache(3, 4096, 8, 64),
+            0xDC => D::cache(3, 1536, 12, 64),
+            0xDD => D::cache(3, 3072, 12, 64),
+            0xDE => D::cache(3, 6144, 12, 64),
+            0xE2 => D::cache(3, 2048, 16, 64),
+            0xE3 => D::cache(3, 4096, 16, 64),
+            0xE4 => D::cache(3, 8192, 16, 64),
+            0xEA => D::cache(3, 12288, 24, 64),
+            0xEB => D::cache(3, 18432, 24, 64),
+            0xEC => D::cache(3, 24576, 24, 64),
+            _ => return None,
+        };
+        Some(params)
+    }
+}
+
+const DESCRIPTOR_PAGE_4K: u8 = 1 << 0;
+const DESCRIPTOR_PAGE_2M: u8 = 1 << 1;
+const DESCRIPTOR_PAGE_4M: u8 = 1 << 2;
+const DESCRIPTOR_PAGE_1G: u8 = 1 << 3;
+const DESCRIPTOR_FULLY_ASSOCIATIVE: u8 = u8::MAX;
+
+/// Numeric properties of a leaf 2 descriptor, a zero field is not specified.
+#[derive(Copy, Clone)]
+struct DescriptorParams {
+    level: u8,
+    size_kb: u32,
+    /// Number of ways or [DESCRIPTOR_FULLY_ASSOCIATIVE].
+    ways: u8,
+    line_size: u16,
+    /// Bitmap of `DESCRIPTOR_PAGE_*`.
+    pages: u8,
+    entries: u16,
+}
+
+#[cfg(feature = "leaf2-table")]
+impl DescriptorParams {
+    const fn cache(level: u8, size_kb: u32, ways: u8, line_size: u16) -> DescriptorParams {
+        DescriptorParams {
+            level,
+            size_kb,
+            ways,
+            line_size,
+            pages: 0,
+            entries: 0,
+        }
+    }
+
+    const fn tlb(level: u8, pages: u8, ways: u8, entries: u16) -> DescriptorParams {
+        DescriptorParams {
+            level,
+            size_kb: 0,
+            ways,
+            line_size: 0,
+            pages,
+            entries,
+        }
+    }
+}
+
+impl Debug for CacheInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CacheInfo")
+            .field("typ", &self.typ)
+            .field("desc", &self.desc())
+            .finish()
+    }
+}
+
+impl fmt::Display for CacheInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let typ = match self.typ {
+            CacheInfoType::General => "N/A",
+            CacheInfoType::Cache => "Cache",
+            CacheInfoType::TLB => "TLB",
+            CacheInfoType::STLB => "STLB",
+            CacheInfoType::DTLB => "DTLB",
+            CacheInfoType::Prefetch => "Prefetcher",
+            CacheInfoType::Unknown => "Unknown",
+        };
+
+        write!(f, "{:x}:\t {}: {}", self.num, typ, self.desc())
+    }
+}
+
+/// Marks descriptors without an entry in [CACHE_INFO_LOOKUP].
+#[cfg(feature = "leaf2-table")]
+const NO_CACHE_INFO: u8 = u8::MAX;
+
+/// Maps every descriptor byte to its index in [CACHE_INFO_TABLE].
+#[cfg(feature = "leaf2-table")]
+const CACHE_INFO_LOOKUP: [u8; 256] = {
+    let mut lookup = [NO_CACHE_INFO; 256];
+    let mut idx = 0;
+    while idx < CACHE_INFO_TABLE.len() {
+        lookup[CACHE_INFO_TABLE[idx].num as usize] = idx as u8;
+        idx += 1;
+    }
+    lookup
+};
+
+/// This table is taken from Intel manual (Section CPUID instruction).
+#[cfg(feature = "leaf2-table")]
+pub const CACHE_INFO_TABLE: [CacheInfo; 108] = [
+    CacheInfo {
+        num: 0x00,
+        typ: CacheInfoType::General,
+    },
+    CacheInfo {
+        num: 0x01,
+        typ: CacheInfoType::TLB,
+    },
+    CacheInfo {
+        num: 0x02,
+        typ: CacheInfoType::TLB,
+    },
+    CacheInfo {
+        num: 0x03,
+        typ: CacheInfoType::TLB,
+    },
+    CacheInfo {
+        num: 0x04,
+        typ: CacheInfoType::TLB,
+    },
+    CacheInfo {
+        num: 0x05,
+        typ: CacheInfoType::TLB,
+    },
+    CacheInfo {
+        num: 0x06,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x08,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x09,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x0A,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x0B,
+        typ: CacheInfoType::TLB,
+    },
+    CacheInfo {
+        num: 0x0C,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x0D,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x0E,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x21,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x22,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x23,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x24,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x25,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x29,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x2C,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x30,
+        typ: CacheInfoType::Cache,
+    },
+    CacheInfo {
+        num: 0x40,
+        typ: CacheInfoType::Cache,
     },
     CacheInfo {
         num: 0x41,
@@ -1764,2227 +3522,3760 @@ pub const CACHE_INFO_TABLE: [CacheInfo; 108] = [
     },
 ];
 
-/// Processor Serial Number (LEAF=0x3).
+/// Processor Serial Number (LEAF=0x3).
+///
+/// # Deprecated
+///
+/// Processor serial number (PSN) is not supported in the Pentium 4 processor or
+/// later. On all models, use the PSN flag (returned using CPUID) to check for
+/// PSN support before accessing the feature.
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+#[derive(PartialEq, Eq)]
+pub struct ProcessorSerial {
+    /// Lower bits
+    ecx: u32,
+    /// Middle bits
+    edx: u32,
+    /// Upper bits (come from leaf 0x1)
+    eax: u32,
+}
+
+impl ProcessorSerial {
+    /// Bits 00-31 of 96 bit processor serial number.
+    ///
+    /// (Available in Pentium III processor only; otherwise, the value in this register is reserved.)
+    pub fn serial_lower(&self) -> u32 {
+        self.ecx
+    }
+
+    /// Bits 32-63 of 96 bit processor serial number.
+    ///
+    /// (Available in Pentium III processor only; otherwise, the value in this register is reserved.)
+    pub fn serial_middle(&self) -> u32 {
+        self.edx
+    }
+
+    /// Bits 64-96 of 96 bit processor serial number.
+    pub fn serial_upper(&self) -> u32 {
+        self.eax
+    }
+
+    /// Combination of bits 00-31 and 32-63 of 96 bit processor serial number.
+    pub fn serial(&self) -> u64 {
+        (self.serial_lower() as u64) | (self.serial_middle() as u64) << 32
+    }
+
+    /// 96 bit processor serial number.
+    pub fn serial_all(&self) -> u128 {
+        (self.serial_lower() as u128)
+            | ((self.serial_middle() as u128) << 32)
+            | ((self.serial_upper() as u128) << 64)
+    }
+}
+
+impl Debug for ProcessorSerial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ProcessorSerial")
+            .field("serial_upper", &self.serial_upper())
+            .field("serial_lower", &self.serial_lower())
+            .field("serial_middle", &self.serial_middle())
+            .finish()
+    }
+}
+
+/// Formats the 96 bit serial number as six dash separated groups of four hex
+/// digits, most significant first (e.g., `0000-0686-0000-0000-0000-0000`).
+impl fmt::Display for ProcessorSerial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let serial = self.serial_all();
+        for group in (0..6).rev() {
+            let digits = (serial >> (group * 16)) as u16;
+            if group == 5 {
+                write!(f, "{:04X}", digits)?;
+            } else {
+                write!(f, "-{:04X}", digits)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Processor and Processor Feature Identifiers (LEAF=0x01).
+///
+/// # Platforms
+/// ✅ AMD ✅ Intel
+pub struct FeatureInfo {
+    vendor: Vendor,
+    eax: u32,
+    ebx: u32,
+    edx_ecx: FeatureInfoFlags,
+}
+
+impl FeatureInfo {
+    /// Version Information: Extended Family
+    pub fn extended_family_id(&self) -> u8 {
+        get_bits(self.eax, 20, 27) as u8
+    }
+
+    /// Version Information: Extended Model
+    pub fn extended_model_id(&self) -> u8 {
+        get_bits(self.eax, 16, 19) as u8
+    }
+
+    /// Version Information: Family
+    pub fn base_family_id(&self) -> u8 {
+        get_bits(self.eax, 8, 11) as u8
+    }
+
+    /// Version Information: Model
+    pub fn base_model_id(&self) -> u8 {
+        get_bits(self.eax, 4, 7) as u8
+    }
+
+    /// Display family, composed from the base and extended family.
+    ///
+    /// Same as [`FeatureInfo::display_family`] but saturates at 0xFF.
+    pub fn family_id(&self) -> u8 {
+        self.display_family().min(u8::MAX as u16) as u8
+    }
+
+    /// Display model, composed from the base and extended model.
+    ///
+    /// Same as [`FeatureInfo::display_model`].
+    pub fn model_id(&self) -> u8 {
+        self.display_model()
+    }
+
+    /// DisplayFamily as defined by the Intel and AMD manuals.
+    ///
+    /// The extended family is only added if the base family is 0xF (on Intel, AMD,
+    /// Centaur and Zhaoxin; always for other vendors).
+    pub fn display_family(&self) -> u16 {
+        let base_family_id = self.base_family_id();
+        let use_extended = match self.vendor {
+            Vendor::Intel | Vendor::Amd | Vendor::Centaur => base_family_id == 0xf,
+            Vendor::Unknown(..) => true,
+        };
+
+        if use_extended {
+            base_family_id as u16 + self.extended_family_id() as u16
+        } else {
+            base_family_id as u16
+        }
+    }
+
+    /// DisplayModel as defined by the Intel and AMD manuals.
+    ///
+    /// The extended model is used for base family 0xF on AMD, for base family 0x6 and
+    /// 0xF on Intel, and for base family 0x6, 0x7 and 0xF on Centaur and Zhaoxin (whose
+    /// family 0x7 parts report e.g. model 0x1B). Other vendors always use it.
+    pub fn display_model(&self) -> u8 {
+        let base_family_id = self.base_family_id();
+        let use_extended = match self.vendor {
+            Vendor::Amd => base_family_id == 0xf,
+            Vendor::Intel => base_family_id == 0x6 || base_family_id == 0xf,
+            Vendor::Centaur => matches!(base_family_id, 0x6 | 0x7 | 0xf),
+            Vendor::Unknown(..) => true,
+        };
+
+        if use_extended {
+            (self.extended_model_id() << 4) | self.base_model_id()
+        } else {
+            self.base_model_id()
+        }
+    }
+
+    /// Version Information: Stepping ID
+    pub fn stepping_id(&self) -> u8 {
+        get_bits(self.eax, 0, 3) as u8
+    }
+
+    /// Version Information: Processor Type
+    ///
+    /// Only meaningful on Intel, reserved (0) on AMD.
+    pub fn processor_type(&self) -> ProcessorType {
+        match get_bits(self.eax, 12, 13) {
+            0b00 => ProcessorType::OriginalOem,
+            0b01 => ProcessorType::OverDrive,
+            0b10 => ProcessorType::DualProcessor,
+            _ => ProcessorType::Reserved,
+        }
+    }
+
+    /// Brand Index
+    pub fn brand_index(&self) -> u8 {
+        get_bits(self.ebx, 0, 7) as u8
+    }
+
+    /// CLFLUSH line size (Value ∗ 8 = cache line size in bytes)
+    pub fn cflush_cache_line_size(&self) -> u8 {
+        get_bits(self.ebx, 8, 15) as u8
+    }
+
+    /// Initial APIC ID
+    pub fn initial_local_apic_id(&self) -> u8 {
+        get_bits(self.ebx, 24, 31) as u8
+    }
+
+    /// Maximum number of addressable IDs for logical processors in this physical package.
+    pub fn max_logical_processor_ids(&self) -> u8 {
+        get_bits(self.ebx, 16, 23) as u8
+    }
+
+    check_flag!(
+        doc = "Streaming SIMD Extensions 3 (SSE3). A value of 1 indicates the processor \
+               supports this technology.",
+        has_sse3,
+        edx_ecx,
+        FeatureInfoFlags::SSE3
+    );
+
+    check_flag!(
+        doc = "PCLMULQDQ. A value of 1 indicates the processor supports the PCLMULQDQ \
+               instruction",
+        has_pclmulqdq,
+        edx_ecx,
+        FeatureInfoFlags::PCLMULQDQ
+    );
+
+    check_flag!(
+        doc = "64-bit DS Area. A value of 1 indicates the processor supports DS area \
+               using 64-bit layout",
+        has_ds_area,
+        edx_ecx,
+        FeatureInfoFlags::DTES64
+    );
+
+    check_flag!(
+        doc = "MONITOR/MWAIT. A value of 1 indicates the processor supports this feature.",
+        has_monitor_mwait,
+        edx_ecx,
+        FeatureInfoFlags::MONITOR
+    );
+
+    check_flag!(
+        doc = "CPL Qualified Debug Store. A value of 1 indicates the processor supports \
+               the extensions to the  Debug Store feature to allow for branch message \
+               storage qualified by CPL.",
+        has_cpl,
+        edx_ecx,
+        FeatureInfoFlags::DSCPL
+    );
+
+    check_flag!(
+        doc = "Virtual Machine Extensions. A value of 1 indicates that the processor \
+               supports this technology.",
+        has_vmx,
+        edx_ecx,
+        FeatureInfoFlags::VMX
+    );
+
+    check_flag!(
+        doc = "Safer Mode Extensions. A value of 1 indicates that the processor supports \
+               this technology. See Chapter 5, Safer Mode Extensions Reference.",
+        has_smx,
+        edx_ecx,
+        FeatureInfoFlags::SMX
+    );
+
+    check_flag!(
+        doc = "Enhanced Intel SpeedStep® technology. A value of 1 indicates that the \
+               processor supports this technology.",
+        has_eist,
+        edx_ecx,
+        FeatureInfoFlags::EIST
+    );
+
+    check_flag!(
+        doc = "Thermal Monitor 2. A value of 1 indicates whether the processor supports \
+               this technology.",
+        has_tm2,
+        edx_ecx,
+        FeatureInfoFlags::TM2
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates the presence of the Supplemental Streaming SIMD \
+               Extensions 3 (SSSE3). A value of 0 indicates the instruction extensions \
+               are not present in the processor",
+        has_ssse3,
+        edx_ecx,
+        FeatureInfoFlags::SSSE3
+    );
+
+    check_flag!(
+        doc = "L1 Context ID. A value of 1 indicates the L1 data cache mode can be set \
+               to either adaptive mode or shared mode. A value of 0 indicates this \
+               feature is not supported. See definition of the IA32_MISC_ENABLE MSR Bit \
+               24 (L1 Data Cache Context Mode) for details.",
+        has_cnxtid,
+        edx_ecx,
+        FeatureInfoFlags::CNXTID
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates the processor supports FMA extensions using YMM \
+               state.",
+        has_fma,
+        edx_ecx,
+        FeatureInfoFlags::FMA
+    );
+
+    check_flag!(
+        doc = "CMPXCHG16B Available. A value of 1 indicates that the feature is \
+               available. See the CMPXCHG8B/CMPXCHG16B Compare and Exchange Bytes \
+               section. 14",
+        has_cmpxchg16b,
+        edx_ecx,
+        FeatureInfoFlags::CMPXCHG16B
+    );
+
+    check_flag!(
+        doc = "Perfmon and Debug Capability: A value of 1 indicates the processor \
+               supports the performance   and debug feature indication MSR \
+               IA32_PERF_CAPABILITIES.",
+        has_pdcm,
+        edx_ecx,
+        FeatureInfoFlags::PDCM
+    );
+
+    check_flag!(
+        doc = "Process-context identifiers. A value of 1 indicates that the processor \
+               supports PCIDs and the software may set CR4.PCIDE to 1.",
+        has_pcid,
+        edx_ecx,
+        FeatureInfoFlags::PCID
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates the processor supports the ability to prefetch \
+               data from a memory mapped device.",
+        has_dca,
+        edx_ecx,
+        FeatureInfoFlags::DCA
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that the processor supports SSE4.1.",
+        has_sse41,
+        edx_ecx,
+        FeatureInfoFlags::SSE41
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that the processor supports SSE4.2.",
+        has_sse42,
+        edx_ecx,
+        FeatureInfoFlags::SSE42
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that the processor supports x2APIC feature.",
+        has_x2apic,
+        edx_ecx,
+        FeatureInfoFlags::X2APIC
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that the processor supports MOVBE instruction.",
+        has_movbe,
+        edx_ecx,
+        FeatureInfoFlags::MOVBE
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that the processor supports the POPCNT instruction.",
+        has_popcnt,
+        edx_ecx,
+        FeatureInfoFlags::POPCNT
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that the processors local APIC timer supports \
+               one-shot operation using a TSC deadline value.",
+        has_tsc_deadline,
+        edx_ecx,
+        FeatureInfoFlags::TSC_DEADLINE
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that the processor supports the AESNI instruction \
+               extensions.",
+        has_aesni,
+        edx_ecx,
+        FeatureInfoFlags::AESNI
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that the processor supports the XSAVE/XRSTOR \
+               processor extended states feature, the XSETBV/XGETBV instructions, and \
+               XCR0.",
+        has_xsave,
+        edx_ecx,
+        FeatureInfoFlags::XSAVE
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that the OS has enabled XSETBV/XGETBV instructions \
+               to access XCR0, and support for processor extended state management using \
+               XSAVE/XRSTOR.",
+        has_oxsave,
+        edx_ecx,
+        FeatureInfoFlags::OSXSAVE
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates the processor supports the AVX instruction \
+               extensions.",
+        has_avx,
+        edx_ecx,
+        FeatureInfoFlags::AVX
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that processor supports 16-bit floating-point \
+               conversion instructions.",
+        has_f16c,
+        edx_ecx,
+        FeatureInfoFlags::F16C
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates that processor supports RDRAND instruction.",
+        has_rdrand,
+        edx_ecx,
+        FeatureInfoFlags::RDRAND
+    );
+
+    check_flag!(
+        doc = "A value of 1 indicates the indicates the presence of a hypervisor.",
+        has_hypervisor,
+        edx_ecx,
+        FeatureInfoFlags::HYPERVISOR
+    );
+
+    check_flag!(
+        doc = "Floating Point Unit On-Chip. The processor contains an x87 FPU.",
+        has_fpu,
+        edx_ecx,
+        FeatureInfoFlags::FPU
+    );
+
+    check_flag!(
+        doc = "Virtual 8086 Mode Enhancements. Virtual 8086 mode enhancements, including \
+               CR4.VME for controlling the feature, CR4.PVI for protected mode virtual \
+               interrupts, software interrupt indirection, expansion of the TSS with the \
+               software indirection bitmap, and EFLAGS.VIF and EFLAGS.VIP flags.",
+        has_vme,
+        edx_ecx,
+        FeatureInfoFlags::VME
+    );
+
+    check_flag!(
+        doc = "Debugging Extensions. Support for I/O breakpoints, including CR4.DE for \
+               controlling the feature, and optional trapping of accesses to DR4 and DR5.",
+        has_de,
+        edx_ecx,
+        FeatureInfoFlags::DE
+    );
+
+    check_flag!(
+        doc = "Page Size Extension. Large pages of size 4 MByte are supported, including \
+               CR4.PSE for controlling the feature, the defined dirty bit in PDE (Page \
+               Directory Entries), optional reserved bit trapping in CR3, PDEs, and PTEs.",
+        has_pse,
+        edx_ecx,
+        FeatureInfoFlags::PSE
+    );
+
+    check_flag!(
+        doc = "Time Stamp Counter. The RDTSC instruction is supported, including CR4.TSD \
+               for controlling privilege.",
+        has_tsc,
+        edx_ecx,
+        FeatureInfoFlags::TSC
+    );
+
+    check_flag!(
+        doc = "Model Specific Registers RDMSR and WRMSR Instructions. The RDMSR and \
+               WRMSR instructions are supported. Some of the MSRs are implementation \
+               dependent.",
+        has_msr,
+        edx_ecx,
+        FeatureInfoFlags::MSR
+    );
+
+    check_flag!(
+        doc = "Physical Address Extension. Physical addresses greater than 32 bits are \
+               supported: extended page table entry formats, an extra level in the page \
+               translation tables is defined, 2-MByte pages are supported instead of 4 \
+               Mbyte pages if PAE bit is 1.",
+        has_pae,
+        edx_ecx,
+        FeatureInfoFlags::PAE
+    );
+
+    check_flag!(
+        doc = "Machine Check Exception. Exception 18 is defined for Machine Checks, \
+               including CR4.MCE for controlling the feature. This feature does not \
+               define the model-specific implementations of machine-check error logging, \
+               reporting, and processor shutdowns. Machine Check exception handlers may \
+               have to depend on processor version to do model specific processing of \
+               the exception, or test for the presence of the Machine Check feature.",
+        has_mce,
+        edx_ecx,
+        FeatureInfoFlags::MCE
+    );
+
+    check_flag!(
+        doc = "CMPXCHG8B Instruction. The compare-and-exchange 8 bytes (64 bits) \
+               instruction is supported (implicitly locked and atomic).",
+        has_cmpxchg8b,
+        edx_ecx,
+        FeatureInfoFlags::CX8
+    );
+
+    check_flag!(
+        doc = "APIC On-Chip. The processor contains an Advanced Programmable Interrupt \
+               Controller (APIC), responding to memory mapped commands in the physical \
+               address range FFFE0000H to FFFE0FFFH (by default - some processors permit \
+               the APIC to be relocated).",
+        has_apic,
+        edx_ecx,
+        FeatureInfoFlags::APIC
+    );
+
+    check_flag!(
+        doc = "SYSENTER and SYSEXIT Instructions. The SYSENTER and SYSEXIT and \
+               associated MSRs are supported.",
+        has_sysenter_sysexit,
+        edx_ecx,
+        FeatureInfoFlags::SEP
+    );
+
+    check_flag!(
+        doc = "Memory Type Range Registers. MTRRs are supported. The MTRRcap MSR \
+               contains feature bits that describe what memory types are supported, how \
+               many variable MTRRs are supported, and whether fixed MTRRs are supported.",
+        has_mtrr,
+        edx_ecx,
+        FeatureInfoFlags::MTRR
+    );
+
+    check_flag!(
+        doc = "Page Global Bit. The global bit is supported in paging-structure entries \
+               that map a page, indicating TLB entries that are common to different \
+               processes and need not be flushed. The CR4.PGE bit controls this feature.",
+        has_pge,
+        edx_ecx,
+        FeatureInfoFlags::PGE
+    );
+
+    check_flag!(
+        doc = "Machine Check Architecture. A value of 1 indicates the Machine Check \
+               Architecture of reporting machine errors is supported. The MCG_CAP MSR \
+               contains feature bits describing how many banks of error reporting MSRs \
+               are supported.",
+        has_mca,
+        edx_ecx,
+        FeatureInfoFlags::MCA
+    );
+
+    check_flag!(
+        doc = "Conditional Move Instructions. The conditional move instruction CMOV is \
+               supported. In addition, if x87 FPU is present as indicated by the \
+               CPUID.FPU feature bit, then the FCOMI and FCMOV instructions are supported",
+        has_cmov,
+        edx_ecx,
+        FeatureInfoFlags::CMOV
+    );
+
+    check_flag!(
+        doc = "Page Attribute Table. Page Attribute Table is supported. This feature \
+               augments the Memory Type Range Registers (MTRRs), allowing an operating \
+               system to specify attributes of memory accessed through a linear address \
+               on a 4KB granularity.",
+        has_pat,
+        edx_ecx,
+        FeatureInfoFlags::PAT
+    );
+
+    check_flag!(
+        doc = "36-Bit Page Size Extension. 4-MByte pages addressing physical memory \
+               beyond 4 GBytes are supported with 32-bit paging. This feature indicates \
+               that upper bits of the physical address of a 4-MByte page are encoded in \
+               bits 20:13 of the page-directory entry. Such physical addresses are \
+               limited by MAXPHYADDR and may be up to 40 bits in size.",
+        has_pse36,
+        edx_ecx,
+        FeatureInfoFlags::PSE36
+    );
+
+    check_flag!(
+        doc = "Processor Serial Number. The processor supports the 96-bit processor \
+               identification number feature and the feature is enabled.",
+        has_psn,
+        edx_ecx,
+        FeatureInfoFlags::PSN
+    );
+
+    check_flag!(
+        doc = "CLFLUSH Instruction. CLFLUSH Instruction is supported.",
+        has_clflush,
+        edx_ecx,
+        FeatureInfoFlags::CLFSH
+    );
+
+    check_flag!(
+        doc = "Debug Store. The processor supports the ability to write debug \
+               information into a memory resident buffer. This feature is used by the \
+               branch trace store (BTS) and processor event-based sampling (PEBS) \
+               facilities (see Chapter 23, Introduction to Virtual-Machine Extensions, \
+               in the Intel® 64 and IA-32 Architectures Software Developers Manual, \
+               Volume 3C).",
+        has_ds,
+        edx_ecx,
+        FeatureInfoFlags::DS
+    );
+
+    check_flag!(
+        doc = "Thermal Monitor and Software Controlled Clock Facilities. The processor \
+               implements internal MSRs that allow processor temperature to be monitored \
+               and processor performance to be modulated in predefined duty cycles under \
+               software control.",
+        has_acpi,
+        edx_ecx,
+        FeatureInfoFlags::ACPI
+    );
+
+    check_flag!(
+        doc = "Intel MMX Technology. The processor supports the Intel MMX technology.",
+        has_mmx,
+        edx_ecx,
+        FeatureInfoFlags::MMX
+    );
+
+    check_flag!(
+        doc = "FXSAVE and FXRSTOR Instructions. The FXSAVE and FXRSTOR instructions are \
+               supported for fast save and restore of the floating point context. \
+               Presence of this bit also indicates that CR4.OSFXSR is available for an \
+               operating system to indicate that it supports the FXSAVE and FXRSTOR \
+               instructions.",
+        has_fxsave_fxstor,
+        edx_ecx,
+        FeatureInfoFlags::FXSR
+    );
+
+    check_flag!(
+        doc = "SSE. The processor supports the SSE extensions.",
+        has_sse,
+        edx_ecx,
+        FeatureInfoFlags::SSE
+    );
+
+    check_flag!(
+        doc = "SSE2. The processor supports the SSE2 extensions.",
+        has_sse2,
+        edx_ecx,
+        FeatureInfoFlags::SSE2
+    );
+
+    check_flag!(
+        doc = "Self Snoop. The processor supports the management of conflicting memory \
+               types by performing a snoop of its own cache structure for transactions \
+               issued to the bus.",
+        has_ss,
+        edx_ecx,
+        FeatureInfoFlags::SS
+    );
+
+    check_flag!(
+        doc = "Max APIC IDs reserved field is Valid. A value of 0 for HTT indicates \
+               there is only a single logical processor in the package and software \
+               should assume only a single APIC ID is reserved.  A value of 1 for HTT \
+               indicates the value in CPUID.1.EBX\\[23:16\\] (the Maximum number of \
+               addressable IDs for logical processors in this package) is valid for the \
+               package.",
+        has_htt,
+        edx_ecx,
+        FeatureInfoFlags::HTT
+    );
+
+    check_flag!(
+        doc = "Thermal Monitor. The processor implements the thermal monitor automatic \
+               thermal control circuitry (TCC).",
+        has_tm,
+        edx_ecx,
+        FeatureInfoFlags::TM
+    );
+
+    check_flag!(
+        doc = "Pending Break Enable. The processor supports the use of the FERR#/PBE# \
+               pin when the processor is in the stop-clock state (STPCLK# is asserted) \
+               to signal the processor that an interrupt is pending and that the \
+               processor should return to normal operation to handle the interrupt. Bit \
+               10 (PBE enable) in the IA32_MISC_ENABLE MSR enables this capability.",
+        has_pbe,
+        edx_ecx,
+        FeatureInfoFlags::PBE
+    );
+}
+
+/// Processor type as reported in leaf 1 EAX bits 13:12.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum ProcessorType {
+    /// Original OEM processor.
+    OriginalOem,
+    /// Intel OverDrive processor.
+    OverDrive,
+    /// Dual processor (not applicable to Intel486 processors).
+    DualProcessor,
+    /// Intel reserved encoding (0b11).
+    Reserved,
+}
+
+impl fmt::Display for ProcessorType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let t = match self {
+            ProcessorType::OriginalOem => "Original OEM",
+            ProcessorType::OverDrive => "OverDrive",
+            ProcessorType::DualProcessor => "Dual processor",
+            ProcessorType::Reserved => "Reserved",
+        };
+        f.write_str(t)
+    }
+}
+
+impl Debug for FeatureInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FeatureInfo")
+            .field("extended_family_id", &self.extended_family_id())
+            .field("extended_model_id", &self.extended_model_id())
+            .field("family_id", &self.family_id())
+            .field("model_id", &self.model_id())
+            .field("stepping_id", &self.stepping_id())
+            .field("processor_type", &self.processor_type())
+            .field("brand_index", &self.brand_index())
+            .field("cflush_cache_line_size", &self.cflush_cache_line_size())
+            .field("initial_local_apic_id", &self.initial_local_apic_id())
+            .field(
+                "max_logical_processor_ids",
+                &self.max_logical_processor_ids(),
+            )
+            .field("edx_ecx", &self.edx_ecx)
+            .finish()
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct FeatureInfoFlags: u64 {
+        // ECX flags
+
+        /// Streaming SIMD Extensions 3 (SSE3). A value of 1 indicates the processor supports this technology.
+        const SSE3 = 1 << 0;
+        /// PCLMULQDQ. A value of 1 indicates the processor supports the PCLMULQDQ instruction
+        const PCLMULQDQ = 1 << 1;
+        /// 64-bit DS Area. A value of 1 indicates the processor supports DS area using 64-bit layout
+        const DTES64 = 1 << 2;
+        /// MONITOR/MWAIT. A value of 1 indicates the processor supports this feature.
+        const MONITOR = 1 << 3;
+        /// CPL Qualified Debug Store. A value of 1 indicates the processor supports the extensions to the  Debug Store feature to allow for branch message storage qualified by CPL.
+        const DSCPL = 1 << 4;
+        /// Virtual Machine Extensions. A value of 1 indicates that the processor supports this technology.
+        const VMX = 1 << 5;
+        /// Safer Mode Extensions. A value of 1 indicates that the processor supports this technology. See Chapter 5, Safer Mode Extensions Reference.
+        const SMX = 1 << 6;
+        /// Enhanced Intel SpeedStep® technology. A value of 1 indicates that the processor supports this technology.
+        const EIST = 1 << 7;
+        /// Thermal Monitor 2. A value of 1 indicates whether the processor supports this technology.
+        const TM2 = 1 << 8;
+        /// A value of 1 indicates the presence of the Supplemental Streaming SIMD Extensions 3 (SSSE3). A value of 0 indicates the instruction extensions are not present in the processor
+        const SSSE3 = 1 << 9;
+        /// L1 Context ID. A value of 1 indicates the L1 data cache mode can be set to either adaptive mode or shared mode. A value of 0 indicates this feature is not supported. See definition of the IA32_MISC_ENABLE MSR Bit 24 (L1 Data Cache Context Mode) for details.
+        const CNXTID = 1 << 10;
+        /// A value of 1 indicates the processor supports FMA extensions using YMM state.
+        const FMA = 1 << 12;
+        /// CMPXCHG16B Available. A value of 1 indicates that the feature is available. See the CMPXCHG8B/CMPXCHG16B Compare and Exchange Bytes section. 14
+        const CMPXCHG16B = 1 << 13;
+        /// Perfmon and Debug Capability: A value of 1 indicates the processor supports the performance   and debug feature indication MSR IA32_PERF_CAPABILITIES.
+        const PDCM = 1 << 15;
+        /// Process-context identifiers. A value of 1 indicates that the processor supports PCIDs and the software may set CR4.PCIDE to 1.
+        const PCID = 1 << 17;
+        /// A value of 1 indicates the processor supports the ability to prefetch data from a memory mapped device.
+        const DCA = 1 << 18;
+        /// A value of 1 indicates that the processor supports SSE4.1.
+        const SSE41 = 1 << 19;
+        /// A value of 1 indicates that the processor supports SSE4.2.
+        const SSE42 = 1 << 20;
+        /// A value of 1 indicates that the processor supports x2APIC feature.
+        const X2APIC = 1 << 21;
+        /// A value of 1 indicates that the processor supports MOVBE instruction.
+        const MOVBE = 1 << 22;
+        /// A value of 1 indicates that the processor supports the POPCNT instruction.
+        const POPCNT = 1 << 23;
+        /// A value of 1 indicates that the processors local APIC timer supports one-shot operation using a TSC deadline value.
+        const TSC_DEADLINE = 1 << 24;
+        /// A value of 1 indicates that the processor supports the AESNI instruction extensions.
+        const AESNI = 1 << 25;
+        /// A value of 1 indicates that the processor supports the XSAVE/XRSTOR processor extended states feature, the XSETBV/XGETBV instructions, and XCR0.
+        const XSAVE = 1 << 26;
+        /// A value of 1 indicates that the OS has enabled XSETBV/XGETBV instructions to access XCR0, and support for processor extended state management using XSAVE/XRSTOR.
+        const OSXSAVE = 1 << 27;
+        /// A value of 1 indicates the processor supports the AVX instruction extensions.
+        const AVX = 1 << 28;
+        /// A value of 1 indicates that processor supports 16-bit floating-point conversion instructions.
+        const F16C = 1 << 29;
+        /// A value of 1 indicates that processor supports RDRAND instruction.
+        const RDRAND = 1 << 30;
+        /// A value of 1 indicates the indicates the presence of a hypervisor.
+        const HYPERVISOR = 1 << 31;
+
+
+        // EDX flags
+
+        /// Floating Point Unit On-Chip. The processor contains an x87 FPU.
+        const FPU = 1 << 32;
+        /// Virtual 8086 Mode Enhancements. Virtual 8086 mode enhancements, including CR4.VME for controlling the feature, CR4.PVI for protected mode virtual interrupts, software interrupt indirection, expansion of the TSS with the software indirection bitmap, and EFLAGS.VIF and EFLAGS.VIP flags.
+        const VME = 1 << (32 + 1);
+        /// Debugging Extensions. Support for I/O breakpoints, including CR4.DE for controlling the feature, and optional trapping of accesses to DR4 and DR5.
+        const DE = 1 << (32 + 2);
+        /// Page Size Extension. Large pages of size 4 MByte are supported, including CR4.PSE for controlling the feature, the defined dirty bit in PDE (Page Directory Entries), optional reserved bit trapping in CR3, PDEs, and PTEs.
+        const PSE = 1 << (32 + 3);
+        /// Time Stamp Counter. The RDTSC instruction is supported, including CR4.TSD for controlling privilege.
+        const TSC = 1 << (32 + 4);
+        /// Model Specific Registers RDMSR and WRMSR Instructions. The RDMSR and WRMSR instructions are supported. Some of the MSRs are implementation dependent.
+        const MSR = 1 << (32 + 5);
+        /// Physical Address Extension. Physical addresses greater than 32 bits are supported: extended page table entry formats, an extra level in the page translation tables is defined, 2-MByte pages are supported instead of 4 Mbyte pages if PAE bit is 1.
+        const PAE = 1 << (32 + 6);
+        /// Machine Check Exception. Exception 18 is defined for Machine Checks, including CR4.MCE for controlling the feature. This feature does not define the model-specific implementations of machine-check error logging, reporting, and processor shutdowns. Machine Check exception handlers may have to depend on processor version to do model specific processing of the exception, or test for the presence of the Machine Check feature.
+        const MCE = 1 << (32 + 7);
+        /// CMPXCHG8B Instruction. The compare-and-exchange 8 bytes (64 bits) instruction is supported (implicitly locked and atomic).
+        const CX8 = 1 << (32 + 8);
+        /// APIC On-Chip. The processor contains an Advanced Programmable Interrupt Controller (APIC), responding to memory mapped commands in the physical address range FFFE0000H to FFFE0FFFH (by default - some processors permit the APIC to be relocated).
+        const APIC = 1 << (32 + 9);
+        /// SYSENTER and SYSEXIT Instructions. The SYSENTER and SYSEXIT and associated MSRs are supported.
+        const SEP = 1 << (32 + 11);
+        /// Memory Type Range Registers. MTRRs are supported. The MTRRcap MSR contains feature bits that describe what memory types are supported, how many variable MTRRs are supported, and whether fixed MTRRs are supported.
+        const MTRR = 1 << (32 + 12);
+        /// Page Global Bit. The global bit is supported in paging-structure entries that map a page, indicating TLB entries that are common to different processes and need not be flushed. The CR4.PGE bit controls this feature.
+        const PGE = 1 << (32 + 13);
+        /// Machine Check Architecture. The Machine Check exArchitecture, which provides a compatible mechanism for error reporting in P6 family, Pentium 4, Intel Xeon processors, and future processors, is supported. The MCG_CAP MSR contains feature bits describing how many banks of error reporting MSRs are supported.
+        const MCA = 1 << (32 + 14);
+        /// Conditional Move Instructions. The conditional move instruction CMOV is supported. In addition, if x87 FPU is present as indicated by the CPUID.FPU feature bit, then the FCOMI and FCMOV instructions are supported
+        const CMOV = 1 << (32 + 15);
+        /// Page Attribute Table. Page Attribute Table is supported. This feature augments the Memory Type Range Registers (MTRRs), allowing an operating system to specify attributes of memory accessed through a linear address on a 4KB granularity.
+        const PAT = 1 << (32 + 16);
+        /// 36-Bit Page Size Extension. 4-MByte pages addressing physical memory beyond 4 GBytes are supported with 32-bit paging. This feature indicates that upper bits of the physical address of a 4-MByte page are encoded in bits 20:13 of the page-directory entry. Such physical addresses are limited by MAXPHYADDR and may be up to 40 bits in size.
+        const PSE36 = 1 << (32 + 17);
+        /// Processor Serial Number. The processor supports the 96-bit processor identification number feature and the feature is enabled.
+        const PSN = 1 << (32 + 18);
+        /// CLFLUSH Instruction. CLFLUSH Instruction is supported.
+        const CLFSH = 1 << (32 + 19);
+        /// Debug Store. The processor supports the ability to write debug information into a memory resident buffer. This feature is used by the branch trace store (BTS) and precise event-based sampling (PEBS) facilities (see Chapter 23, Introduction to Virtual-Machine Extensions, in the Intel® 64 and IA-32 Architectures Software Developers Manual, Volume 3C).
+        const DS = 1 << (32 + 21);
+        /// Thermal Monitor and Software Controlled Clock Facilities. The processor implements internal MSRs that allow processor temperature to be monitored and processor performance to be modulated in predefined duty cycles under software control.
+        const ACPI = 1 << (32 + 22);
+        /// Intel MMX Technology. The processor supports the Intel MMX technology.
+        const MMX = 1 << (32 + 23);
+        /// FXSAVE and FXRSTOR Instructions. The FXSAVE and FXRSTOR instructions are supported for fast save and restore of the floating point context. Presence of this bit also indicates that CR4.OSFXSR is available for an operating system to indicate that it supports the FXSAVE and FXRSTOR instructions.
+        const FXSR = 1 << (32 + 24);
+        /// SSE. The processor supports the SSE extensions.
+        const SSE = 1 << (32 + 25);
+        /// SSE2. The processor supports the SSE2 extensions.
+        const SSE2 = 1 << (32 + 26);
+        /// Self Snoop. The processor supports the management of conflicting memory types by performing a snoop of its own cache structure for transactions issued to the bus.
+        const SS = 1 << (32 + 27);
+        /// Max APIC IDs reserved field is Valid. A value of 0 for HTT indicates there is only a single logical processor in the package and software should assume only a single APIC ID is reserved.  A value of 1 for HTT indicates the value in CPUID.1.EBX[23:16] (the Maximum number of addressable IDs for logical processors in this package) is valid for the package.
+        const HTT = 1 << (32 + 28);
+        /// Thermal Monitor. The processor implements the thermal monitor automatic thermal control circuitry (TCC).
+        const TM = 1 << (32 + 29);
+        /// Pending Break Enable. The processor supports the use of the FERR#/PBE# pin when the processor is in the stop-clock state (STPCLK# is asserted) to signal the processor that an interrupt is pending and that the processor should return to normal operation to handle the interrupt. Bit 10 (PBE enable) in the IA32_MISC_ENABLE MSR enables this capability.
+        const PBE = 1 << (32 + 31);
+    }
+}
+
+/// Iterator over caches (LEAF=0x04).
 ///
-/// # Deprecated
+/// Yields a [CacheParameter] for each cache, ordered by their [CacheKey]
+/// (level first, then data before instruction before unified caches).
 ///
-/// Processor serial number (PSN) is not supported in the Pentium 4 processor or
-/// later. On all models, use the PSN flag (returned using CPUID) to check for
-/// PSN support before accessing the feature.
+/// # Platforms
+/// 🟡 AMD ✅ Intel
+#[derive(Clone, Copy)]
+pub struct CacheParametersIter<R: CpuIdReader> {
+    read: R,
+    leaf: u32,
+    /// Caches with their key and sub-leaf, read and sorted on the first call
+    /// to `next`.
+    caches: [Option<(CacheKey, u32, CacheParameter)>; MAX_CACHE_SUBLEAVES as usize],
+    /// Whether `caches` was filled in already.
+    loaded: bool,
+    /// Index of the next cache to return.
+    current: usize,
+}
+
+/// Upper bound of cache sub-leaves we look at, in case a (virtual) CPU never
+/// reports a null cache type.
+const MAX_CACHE_SUBLEAVES: u32 = 32;
+
+impl<R: CpuIdReader> CacheParametersIter<R> {
+    /// Read all cache sub-leaves (once) and sort them by key.
+    fn load(&mut self) {
+        for subleaf in 0..MAX_CACHE_SUBLEAVES {
+            let res = self.read.cpuid2(self.leaf, subleaf);
+            let cp = CacheParameter {
+                eax: res.eax,
+                ebx: res.ebx,
+                ecx: res.ecx,
+                edx: res.edx,
+            };
+
+            match cp.cache_type() {
+                CacheType::Null | CacheType::Unknown(_) => break,
+                _ => self.caches[subleaf as usize] = Some((cp.key(), subleaf, cp)),
+            }
+        }
+
+        // Sub-leaves are not guaranteed to be ordered, ties are broken by
+        // sub-leaf index. `None` sorts first, so skip over those.
+        self.caches
+            .sort_unstable_by_key(|cache| cache.map(|(key, subleaf, _)| (key, subleaf)));
+        self.current = self.caches.iter().take_while(|c| c.is_none()).count();
+        self.loaded = true;
+    }
+}
+
+impl<R: CpuIdReader> Iterator for CacheParametersIter<R> {
+    type Item = CacheParameter;
+
+    /// Iterate over all cache info subleafs for this CPU.
+    fn next(&mut self) -> Option<CacheParameter> {
+        if !self.loaded {
+            self.load();
+        }
+
+        let (_, _, cp) = (*self.caches.get(self.current)?)?;
+        self.current += 1;
+        Some(cp)
+    }
+}
+
+impl<R: CpuIdReader> FusedIterator for CacheParametersIter<R> {}
+
+impl<R: CpuIdReader> Debug for CacheParametersIter<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut debug = f.debug_list();
+        self.clone().for_each(|ref item| {
+            debug.entry(item);
+        });
+        debug.finish()
+    }
+}
+
+/// Information about an individual cache in the hierarchy.
 ///
 /// # Platforms
-/// ❌ AMD ✅ Intel
-#[derive(PartialEq, Eq)]
-pub struct ProcessorSerial {
-    /// Lower bits
+/// 🟡 AMD ✅ Intel
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct CacheParameter {
+    eax: u32,
+    ebx: u32,
     ecx: u32,
-    /// Middle bits
     edx: u32,
-    /// Upper bits (come from leaf 0x1)
-    eax: u32,
 }
 
-impl ProcessorSerial {
-    /// Bits 00-31 of 96 bit processor serial number.
+/// Info about a what a given cache caches (instructions, data, etc.)
+///
+/// Ordered so that data caches come before instruction caches and unified
+/// caches last.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum CacheType {
+    /// Null - No more caches
+    Null,
+    /// Data cache
+    Data,
+    /// Instruction cache
+    Instruction,
+    /// Data and Instruction cache
+    Unified,
+    /// 4-31 = Reserved, the raw encoding
+    Unknown(u8),
+}
+
+impl fmt::Display for CacheType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let typ = match self {
+            CacheType::Null => "Null",
+            CacheType::Data => "Data",
+            CacheType::Instruction => "Instruction",
+            CacheType::Unified => "Unified",
+            CacheType::Unknown(typ) => return write!(f, "Unknown ({})", typ),
+        };
+
+        f.write_str(typ)
+    }
+}
+
+/// Sort key of a cache in the hierarchy.
+///
+/// Orders caches by level first and [CacheType] second, i.e., L1d, L1i, L2,
+/// L3.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct CacheKey {
+    level: u8,
+    cache_type: CacheType,
+}
+
+impl CacheKey {
+    /// Cache Level (starts at 1)
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Cache Type
+    pub fn cache_type(&self) -> CacheType {
+        self.cache_type
+    }
+}
+
+/// Cache line size as returned by [`CpuId::cache_line_size`].
+///
+/// # Platforms
+/// ✅ AMD ✅ Intel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheLineSize {
+    bytes: usize,
+    conflict: bool,
+}
+
+impl CacheLineSize {
+    /// Line size in bytes.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// The leafs report different line sizes, [`bytes`](Self::bytes) is the one of
+    /// the most specific source.
+    pub fn has_conflict(&self) -> bool {
+        self.conflict
+    }
+}
+
+/// Size of one level of the cache hierarchy summed over the package, as
+/// returned by `CpuId::cache_totals` (with the `alloc` feature).
+///
+/// # Platforms
+/// ✅ AMD ✅ Intel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheTotal {
+    key: CacheKey,
+    size: usize,
+    threads: usize,
+    instances: usize,
+}
+
+impl CacheTotal {
+    /// Level and type of the cache.
+    pub fn key(&self) -> CacheKey {
+        self.key
+    }
+
+    /// Cache Level (starts at 1)
+    pub fn level(&self) -> u8 {
+        self.key.level()
+    }
+
+    /// Cache Type
+    pub fn cache_type(&self) -> CacheType {
+        self.key.cache_type()
+    }
+
+    /// Size of a single instance of the cache in bytes.
+    pub fn instance_size(&self) -> usize {
+        self.size
+    }
+
+    /// Number of logical processors sharing one instance of the cache.
+    pub fn threads_per_instance(&self) -> usize {
+        self.threads
+    }
+
+    /// Number of instances of the cache in the package, e.g., one L2 per core.
+    pub fn instances(&self) -> usize {
+        self.instances
+    }
+
+    /// Size of all instances of the cache in the package in bytes.
+    pub fn total_size(&self) -> usize {
+        self.size * self.instances
+    }
+}
+
+impl CacheParameter {
+    /// Key to order caches by their level and type.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn key(&self) -> CacheKey {
+        CacheKey {
+            level: self.level(),
+            cache_type: self.cache_type(),
+        }
+    }
+
+    /// Cache Type
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn cache_type(&self) -> CacheType {
+        let typ = get_bits(self.eax, 0, 4) as u8;
+        match typ {
+            0 => CacheType::Null,
+            1 => CacheType::Data,
+            2 => CacheType::Instruction,
+            3 => CacheType::Unified,
+            typ => CacheType::Unknown(typ),
+        }
+    }
+
+    /// Cache Level (starts at 1)
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn level(&self) -> u8 {
+        get_bits(self.eax, 5, 7) as u8
+    }
+
+    /// Self Initializing cache level (does not need SW initialization).
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn is_self_initializing(&self) -> bool {
+        get_bits(self.eax, 8, 8) == 1
+    }
+
+    /// Fully Associative cache
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn is_fully_associative(&self) -> bool {
+        get_bits(self.eax, 9, 9) == 1
+    }
+
+    /// Maximum number of addressable IDs for logical processors sharing this cache
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn max_cores_for_cache(&self) -> usize {
+        (get_bits(self.eax, 14, 25) + 1) as usize
+    }
+
+    /// Maximum number of addressable IDs for processor cores in the physical package
+    ///
+    /// # Platforms
+    /// ❌ AMD ✅ Intel
+    pub fn max_cores_for_package(&self) -> usize {
+        (get_bits(self.eax, 26, 31) + 1) as usize
+    }
+
+    /// System Coherency Line Size (Bits 11-00)
     ///
-    /// (Available in Pentium III processor only; otherwise, the value in this register is reserved.)
-    pub fn serial_lower(&self) -> u32 {
-        self.ecx
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn coherency_line_size(&self) -> usize {
+        (get_bits(self.ebx, 0, 11) + 1) as usize
     }
 
-    /// Bits 32-63 of 96 bit processor serial number.
+    /// Physical Line partitions (Bits 21-12)
     ///
-    /// (Available in Pentium III processor only; otherwise, the value in this register is reserved.)
-    pub fn serial_middle(&self) -> u32 {
-        self.edx
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn physical_line_partitions(&self) -> usize {
+        (get_bits(self.ebx, 12, 21) + 1) as usize
     }
 
-    /// Bits 64-96 of 96 bit processor serial number.
-    pub fn serial_upper(&self) -> u32 {
-        self.eax
+    /// Ways of associativity (Bits 31-22)
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn associativity(&self) -> usize {
+        (get_bits(self.ebx, 22, 31) + 1) as usize
     }
 
-    /// Combination of bits 00-31 and 32-63 of 96 bit processor serial number.
-    pub fn serial(&self) -> u64 {
-        (self.serial_lower() as u64) | (self.serial_middle() as u64) << 32
+    /// Number of Sets (Bits 31-00)
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn sets(&self) -> usize {
+        (self.ecx + 1) as usize
     }
 
-    /// 96 bit processor serial number.
-    pub fn serial_all(&self) -> u128 {
-        (self.serial_lower() as u128)
-            | ((self.serial_middle() as u128) << 32)
-            | ((self.serial_upper() as u128) << 64)
+    /// Write-Back Invalidate/Invalidate (Bit 0)
+    /// False: WBINVD/INVD from threads sharing this cache acts upon lower level caches for threads sharing this cache.
+    /// True: WBINVD/INVD is not guaranteed to act upon lower level caches of non-originating threads sharing this cache.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn is_write_back_invalidate(&self) -> bool {
+        get_bits(self.edx, 0, 0) == 1
+    }
+
+    /// Cache Inclusiveness (Bit 1)
+    /// False: Cache is not inclusive of lower cache levels.
+    /// True: Cache is inclusive of lower cache levels.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn is_inclusive(&self) -> bool {
+        get_bits(self.edx, 1, 1) == 1
+    }
+
+    /// Complex Cache Indexing (Bit 2)
+    /// False: Direct mapped cache.
+    /// True: A complex function is used to index the cache, potentially using all address bits.
+    ///
+    /// # Platforms
+    /// ❌ AMD ✅ Intel
+    pub fn has_complex_indexing(&self) -> bool {
+        get_bits(self.edx, 2, 2) == 1
     }
 }
 
-impl Debug for ProcessorSerial {
+impl Debug for CacheParameter {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("ProcessorSerial")
-            .field("serial_lower", &self.serial_lower())
-            .field("serial_middle", &self.serial_middle())
+        f.debug_struct("CacheParameter")
+            .field("cache_type", &self.cache_type())
+            .field("level", &self.level())
+            .field("is_self_initializing", &self.is_self_initializing())
+            .field("is_fully_associative", &self.is_fully_associative())
+            .field("max_cores_for_cache", &self.max_cores_for_cache())
+            .field("max_cores_for_package", &self.max_cores_for_package())
+            .field("coherency_line_size", &self.coherency_line_size())
+            .field("physical_line_partitions", &self.physical_line_partitions())
+            .field("associativity", &self.associativity())
+            .field("sets", &self.sets())
+            .field("is_write_back_invalidate", &self.is_write_back_invalidate())
+            .field("is_inclusive", &self.is_inclusive())
+            .field("has_complex_indexing", &self.has_complex_indexing())
             .finish()
     }
 }
 
-/// Processor and Processor Feature Identifiers (LEAF=0x01).
+/// Information about how monitor/mwait works on this CPU (LEAF=0x05).
 ///
 /// # Platforms
-/// ✅ AMD ✅ Intel
-pub struct FeatureInfo {
+/// 🟡 AMD ✅ Intel
+///
+/// On AMD the leaf only enumerates the monitor-line sizes and the extensions in
+/// ECX, EDX is reserved. The sizes also apply to MONITORX/MWAITX (see
+/// [`CpuId::has_monitorx_mwaitx`]).
+#[derive(Eq, PartialEq)]
+pub struct MonitorMwaitInfo {
     vendor: Vendor,
     eax: u32,
     ebx: u32,
-    edx_ecx: FeatureInfoFlags,
+    ecx: u32,
+    edx: u32,
 }
 
-impl FeatureInfo {
-    /// Version Information: Extended Family
-    pub fn extended_family_id(&self) -> u8 {
-        get_bits(self.eax, 20, 27) as u8
-    }
-
-    /// Version Information: Extended Model
-    pub fn extended_model_id(&self) -> u8 {
-        get_bits(self.eax, 16, 19) as u8
-    }
-
-    /// Version Information: Family
-    pub fn base_family_id(&self) -> u8 {
-        get_bits(self.eax, 8, 11) as u8
-    }
-
-    /// Version Information: Model
-    pub fn base_model_id(&self) -> u8 {
-        get_bits(self.eax, 4, 7) as u8
-    }
-
-    pub fn family_id(&self) -> u8 {
-        let base_family_id = self.base_family_id();
-        let extended_family_id = self.extended_family_id();
-        let just_use_base = (self.vendor == Vendor::Amd && base_family_id < 0xf)
-            || (self.vendor == Vendor::Intel && base_family_id != 0xf);
-
-        if just_use_base {
-            base_family_id
-        } else {
-            base_family_id + extended_family_id
-        }
-    }
-
-    pub fn model_id(&self) -> u8 {
-        let base_family_id = self.base_family_id();
-        let base_model_id = self.base_model_id();
-        let extended_model_id = self.extended_model_id();
-        let just_use_base = (self.vendor == Vendor::Amd && base_family_id < 0xf)
-            || (self.vendor == Vendor::Intel && base_family_id != 0xf && base_family_id != 0x6);
-
-        if just_use_base {
-            base_model_id
-        } else {
-            (extended_model_id << 4) | base_model_id
-        }
-    }
-
-    /// Version Information: Stepping ID
-    pub fn stepping_id(&self) -> u8 {
-        get_bits(self.eax, 0, 3) as u8
-    }
-
-    /// Brand Index
-    pub fn brand_index(&self) -> u8 {
-        get_bits(self.ebx, 0, 7) as u8
+impl MonitorMwaitInfo {
+    /// Smallest monitor-line size in bytes (default is processor's monitor granularity)
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn smallest_monitor_line(&self) -> u16 {
+        get_bits(self.eax, 0, 15) as u16
     }
 
-    /// CLFLUSH line size (Value ∗ 8 = cache line size in bytes)
-    pub fn cflush_cache_line_size(&self) -> u8 {
-        get_bits(self.ebx, 8, 15) as u8
+    /// Largest monitor-line size in bytes (default is processor's monitor granularity
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn largest_monitor_line(&self) -> u16 {
+        get_bits(self.ebx, 0, 15) as u16
     }
 
-    /// Initial APIC ID
-    pub fn initial_local_apic_id(&self) -> u8 {
-        get_bits(self.ebx, 24, 31) as u8
+    ///  Enumeration of Monitor-Mwait extensions (beyond EAX and EBX registers) supported
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn extensions_supported(&self) -> bool {
+        get_bits(self.ecx, 0, 0) == 1
     }
 
-    /// Maximum number of addressable IDs for logical processors in this physical package.
-    pub fn max_logical_processor_ids(&self) -> u8 {
-        get_bits(self.ebx, 16, 23) as u8
+    ///  Supports treating interrupts as break-event for MWAIT, even when interrupts disabled
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn interrupts_as_break_event(&self) -> bool {
+        get_bits(self.ecx, 1, 1) == 1
     }
 
-    check_flag!(
-        doc = "Streaming SIMD Extensions 3 (SSE3). A value of 1 indicates the processor \
-               supports this technology.",
-        has_sse3,
-        edx_ecx,
-        FeatureInfoFlags::SSE3
-    );
-
-    check_flag!(
-        doc = "PCLMULQDQ. A value of 1 indicates the processor supports the PCLMULQDQ \
-               instruction",
-        has_pclmulqdq,
-        edx_ecx,
-        FeatureInfoFlags::PCLMULQDQ
-    );
-
-    check_flag!(
-        doc = "64-bit DS Area. A value of 1 indicates the processor supports DS area \
-               using 64-bit layout",
-        has_ds_area,
-        edx_ecx,
-        FeatureInfoFlags::DTES64
-    );
-
-    check_flag!(
-        doc = "MONITOR/MWAIT. A value of 1 indicates the processor supports this feature.",
-        has_monitor_mwait,
-        edx_ecx,
-        FeatureInfoFlags::MONITOR
-    );
-
-    check_flag!(
-        doc = "CPL Qualified Debug Store. A value of 1 indicates the processor supports \
-               the extensions to the  Debug Store feature to allow for branch message \
-               storage qualified by CPL.",
-        has_cpl,
-        edx_ecx,
-        FeatureInfoFlags::DSCPL
-    );
-
-    check_flag!(
-        doc = "Virtual Machine Extensions. A value of 1 indicates that the processor \
-               supports this technology.",
-        has_vmx,
-        edx_ecx,
-        FeatureInfoFlags::VMX
-    );
-
-    check_flag!(
-        doc = "Safer Mode Extensions. A value of 1 indicates that the processor supports \
-               this technology. See Chapter 5, Safer Mode Extensions Reference.",
-        has_smx,
-        edx_ecx,
-        FeatureInfoFlags::SMX
-    );
-
-    check_flag!(
-        doc = "Enhanced Intel SpeedStep® technology. A value of 1 indicates that the \
-               processor supports this technology.",
-        has_eist,
-        edx_ecx,
-        FeatureInfoFlags::EIST
-    );
-
-    check_flag!(
-        doc = "Thermal Monitor 2. A value of 1 indicates whether the processor supports \
-               this technology.",
-        has_tm2,
-        edx_ecx,
-        FeatureInfoFlags::TM2
-    );
+    /// Number of C0 sub C-states supported using MWAIT (Bits 03 - 00)
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved, returns 0) ✅ Intel
+    pub fn supported_c0_states(&self) -> u16 {
+        self.sub_c_states(0, 3)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates the presence of the Supplemental Streaming SIMD \
-               Extensions 3 (SSSE3). A value of 0 indicates the instruction extensions \
-               are not present in the processor",
-        has_ssse3,
-        edx_ecx,
-        FeatureInfoFlags::SSSE3
-    );
+    /// Number of C1 sub C-states supported using MWAIT (Bits 07 - 04)
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved, returns 0) ✅ Intel
+    pub fn supported_c1_states(&self) -> u16 {
+        self.sub_c_states(4, 7)
+    }
 
-    check_flag!(
-        doc = "L1 Context ID. A value of 1 indicates the L1 data cache mode can be set \
-               to either adaptive mode or shared mode. A value of 0 indicates this \
-               feature is not supported. See definition of the IA32_MISC_ENABLE MSR Bit \
-               24 (L1 Data Cache Context Mode) for details.",
-        has_cnxtid,
-        edx_ecx,
-        FeatureInfoFlags::CNXTID
-    );
+    /// Number of C2 sub C-states supported using MWAIT (Bits 11 - 08)
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved, returns 0) ✅ Intel
+    pub fn supported_c2_states(&self) -> u16 {
+        self.sub_c_states(8, 11)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates the processor supports FMA extensions using YMM \
-               state.",
-        has_fma,
-        edx_ecx,
-        FeatureInfoFlags::FMA
-    );
+    /// Number of C3 sub C-states supported using MWAIT (Bits 15 - 12)
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved, returns 0) ✅ Intel
+    pub fn supported_c3_states(&self) -> u16 {
+        self.sub_c_states(12, 15)
+    }
 
-    check_flag!(
-        doc = "CMPXCHG16B Available. A value of 1 indicates that the feature is \
-               available. See the CMPXCHG8B/CMPXCHG16B Compare and Exchange Bytes \
-               section. 14",
-        has_cmpxchg16b,
-        edx_ecx,
-        FeatureInfoFlags::CMPXCHG16B
-    );
+    /// Number of C4 sub C-states supported using MWAIT (Bits 19 - 16)
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved, returns 0) ✅ Intel
+    pub fn supported_c4_states(&self) -> u16 {
+        self.sub_c_states(16, 19)
+    }
 
-    check_flag!(
-        doc = "Perfmon and Debug Capability: A value of 1 indicates the processor \
-               supports the performance   and debug feature indication MSR \
-               IA32_PERF_CAPABILITIES.",
-        has_pdcm,
-        edx_ecx,
-        FeatureInfoFlags::PDCM
-    );
+    /// Number of C5 sub C-states supported using MWAIT (Bits 23 - 20)
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved, returns 0) ✅ Intel
+    pub fn supported_c5_states(&self) -> u16 {
+        self.sub_c_states(20, 23)
+    }
 
-    check_flag!(
-        doc = "Process-context identifiers. A value of 1 indicates that the processor \
-               supports PCIDs and the software may set CR4.PCIDE to 1.",
-        has_pcid,
-        edx_ecx,
-        FeatureInfoFlags::PCID
-    );
+    /// Number of C6 sub C-states supported using MWAIT (Bits 27 - 24)
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved, returns 0) ✅ Intel
+    pub fn supported_c6_states(&self) -> u16 {
+        self.sub_c_states(24, 27)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates the processor supports the ability to prefetch \
-               data from a memory mapped device.",
-        has_dca,
-        edx_ecx,
-        FeatureInfoFlags::DCA
-    );
+    /// Number of C7 sub C-states supported using MWAIT (Bits 31 - 28)
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved, returns 0) ✅ Intel
+    pub fn supported_c7_states(&self) -> u16 {
+        self.sub_c_states(28, 31)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates that the processor supports SSE4.1.",
-        has_sse41,
-        edx_ecx,
-        FeatureInfoFlags::SSE41
-    );
+    /// Reads a C-state field of EDX, which is reserved on AMD.
+    fn sub_c_states(&self, start: u32, end: u32) -> u16 {
+        if self.vendor == Vendor::Amd {
+            0
+        } else {
+            get_bits(self.edx, start, end) as u16
+        }
+    }
+}
 
-    check_flag!(
-        doc = "A value of 1 indicates that the processor supports SSE4.2.",
-        has_sse42,
-        edx_ecx,
-        FeatureInfoFlags::SSE42
-    );
+impl Debug for MonitorMwaitInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("MonitorMwaitInfo");
+        ds.field("smallest_monitor_line", &self.smallest_monitor_line())
+            .field("largest_monitor_line", &self.largest_monitor_line())
+            .field("extensions_supported", &self.extensions_supported())
+            .field(
+                "interrupts_as_break_event",
+                &self.interrupts_as_break_event(),
+            );
+        if self.vendor != Vendor::Amd {
+            ds.field("supported_c0_states", &self.supported_c0_states())
+                .field("supported_c1_states", &self.supported_c1_states())
+                .field("supported_c2_states", &self.supported_c2_states())
+                .field("supported_c3_states", &self.supported_c3_states())
+                .field("supported_c4_states", &self.supported_c4_states())
+                .field("supported_c5_states", &self.supported_c5_states())
+                .field("supported_c6_states", &self.supported_c6_states())
+                .field("supported_c7_states", &self.supported_c7_states());
+        }
+        ds.finish()
+    }
+}
 
-    check_flag!(
-        doc = "A value of 1 indicates that the processor supports x2APIC feature.",
-        has_x2apic,
-        edx_ecx,
-        FeatureInfoFlags::X2APIC
-    );
+/// Query information about thermal and power management features of the CPU (LEAF=0x06).
+///
+/// # Platforms
+/// 🟡 AMD ✅ Intel
+pub struct ThermalPowerInfo {
+    eax: ThermalPowerFeaturesEax,
+    ebx: u32,
+    ecx: ThermalPowerFeaturesEcx,
+    edx: u32,
+}
 
-    check_flag!(
-        doc = "A value of 1 indicates that the processor supports MOVBE instruction.",
-        has_movbe,
-        edx_ecx,
-        FeatureInfoFlags::MOVBE
-    );
+impl ThermalPowerInfo {
+    /// Number of Interrupt Thresholds in Digital Thermal Sensor
+    ///
+    /// # Platforms
+    /// ❌ AMD (undefined/reserved) ✅ Intel
+    pub fn dts_irq_threshold(&self) -> u8 {
+        get_bits(self.ebx, 0, 3) as u8
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates that the processor supports the POPCNT instruction.",
-        has_popcnt,
-        edx_ecx,
-        FeatureInfoFlags::POPCNT
-    );
+    /// Digital temperature sensor is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_dts(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::DTS)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates that the processors local APIC timer supports \
-               one-shot operation using a TSC deadline value.",
-        has_tsc_deadline,
-        edx_ecx,
-        FeatureInfoFlags::TSC_DEADLINE
-    );
+    /// Intel Turbo Boost Technology Available (see description of
+    /// IA32_MISC_ENABLE\[38\]).
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_turbo_boost(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::TURBO_BOOST)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates that the processor supports the AESNI instruction \
-               extensions.",
-        has_aesni,
-        edx_ecx,
-        FeatureInfoFlags::AESNI
-    );
+    /// ARAT. APIC-Timer-always-running feature is supported if set.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn has_arat(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::ARAT)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates that the processor supports the XSAVE/XRSTOR \
-               processor extended states feature, the XSETBV/XGETBV instructions, and \
-               XCR0.",
-        has_xsave,
-        edx_ecx,
-        FeatureInfoFlags::XSAVE
-    );
+    /// PLN. Power limit notification controls are supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_pln(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::PLN)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates that the OS has enabled XSETBV/XGETBV instructions \
-               to access XCR0, and support for processor extended state management using \
-               XSAVE/XRSTOR.",
-        has_oxsave,
-        edx_ecx,
-        FeatureInfoFlags::OSXSAVE
-    );
+    /// ECMD. Clock modulation duty cycle extension is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_ecmd(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::ECMD)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates the processor supports the AVX instruction \
-               extensions.",
-        has_avx,
-        edx_ecx,
-        FeatureInfoFlags::AVX
-    );
+    /// PTM. Package thermal management is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_ptm(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::PTM)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates that processor supports 16-bit floating-point \
-               conversion instructions.",
-        has_f16c,
-        edx_ecx,
-        FeatureInfoFlags::F16C
-    );
+    /// HWP. HWP base registers (IA32_PM_ENABLE[bit 0], IA32_HWP_CAPABILITIES,
+    /// IA32_HWP_REQUEST, IA32_HWP_STATUS) are supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hwp(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::HWP)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates that processor supports RDRAND instruction.",
-        has_rdrand,
-        edx_ecx,
-        FeatureInfoFlags::RDRAND
-    );
+    /// HWP Notification. IA32_HWP_INTERRUPT MSR is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hwp_notification(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::HWP_NOTIFICATION)
+    }
 
-    check_flag!(
-        doc = "A value of 1 indicates the indicates the presence of a hypervisor.",
-        has_hypervisor,
-        edx_ecx,
-        FeatureInfoFlags::HYPERVISOR
-    );
+    /// HWP Activity Window. IA32_HWP_REQUEST[bits 41:32] is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hwp_activity_window(&self) -> bool {
+        self.eax
+            .contains(ThermalPowerFeaturesEax::HWP_ACTIVITY_WINDOW)
+    }
 
-    check_flag!(
-        doc = "Floating Point Unit On-Chip. The processor contains an x87 FPU.",
-        has_fpu,
-        edx_ecx,
-        FeatureInfoFlags::FPU
-    );
+    /// HWP Energy Performance Preference. IA32_HWP_REQUEST[bits 31:24] is
+    /// supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hwp_energy_performance_preference(&self) -> bool {
+        self.eax
+            .contains(ThermalPowerFeaturesEax::HWP_ENERGY_PERFORMANCE_PREFERENCE)
+    }
 
-    check_flag!(
-        doc = "Virtual 8086 Mode Enhancements. Virtual 8086 mode enhancements, including \
-               CR4.VME for controlling the feature, CR4.PVI for protected mode virtual \
-               interrupts, software interrupt indirection, expansion of the TSS with the \
-               software indirection bitmap, and EFLAGS.VIF and EFLAGS.VIP flags.",
-        has_vme,
-        edx_ecx,
-        FeatureInfoFlags::VME
-    );
+    /// HWP Package Level Request. IA32_HWP_REQUEST_PKG MSR is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hwp_package_level_request(&self) -> bool {
+        self.eax
+            .contains(ThermalPowerFeaturesEax::HWP_PACKAGE_LEVEL_REQUEST)
+    }
 
-    check_flag!(
-        doc = "Debugging Extensions. Support for I/O breakpoints, including CR4.DE for \
-               controlling the feature, and optional trapping of accesses to DR4 and DR5.",
-        has_de,
-        edx_ecx,
-        FeatureInfoFlags::DE
-    );
+    /// HDC. HDC base registers IA32_PKG_HDC_CTL, IA32_PM_CTL1,
+    /// IA32_THREAD_STALL MSRs are supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hdc(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::HDC)
+    }
 
-    check_flag!(
-        doc = "Page Size Extension. Large pages of size 4 MByte are supported, including \
-               CR4.PSE for controlling the feature, the defined dirty bit in PDE (Page \
-               Directory Entries), optional reserved bit trapping in CR3, PDEs, and PTEs.",
-        has_pse,
-        edx_ecx,
-        FeatureInfoFlags::PSE
-    );
+    /// Intel® Turbo Boost Max Technology 3.0 available.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_turbo_boost3(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::TURBO_BOOST_3)
+    }
 
-    check_flag!(
-        doc = "Time Stamp Counter. The RDTSC instruction is supported, including CR4.TSD \
-               for controlling privilege.",
-        has_tsc,
-        edx_ecx,
-        FeatureInfoFlags::TSC
-    );
+    /// HWP Capabilities. Highest Performance change is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hwp_capabilities(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::HWP_CAPABILITIES)
+    }
 
-    check_flag!(
-        doc = "Model Specific Registers RDMSR and WRMSR Instructions. The RDMSR and \
-               WRMSR instructions are supported. Some of the MSRs are implementation \
-               dependent.",
-        has_msr,
-        edx_ecx,
-        FeatureInfoFlags::MSR
-    );
+    /// HWP PECI override is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hwp_peci_override(&self) -> bool {
+        self.eax
+            .contains(ThermalPowerFeaturesEax::HWP_PECI_OVERRIDE)
+    }
 
-    check_flag!(
-        doc = "Physical Address Extension. Physical addresses greater than 32 bits are \
-               supported: extended page table entry formats, an extra level in the page \
-               translation tables is defined, 2-MByte pages are supported instead of 4 \
-               Mbyte pages if PAE bit is 1.",
-        has_pae,
-        edx_ecx,
-        FeatureInfoFlags::PAE
-    );
+    /// Flexible HWP is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_flexible_hwp(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::FLEXIBLE_HWP)
+    }
 
-    check_flag!(
-        doc = "Machine Check Exception. Exception 18 is defined for Machine Checks, \
-               including CR4.MCE for controlling the feature. This feature does not \
-               define the model-specific implementations of machine-check error logging, \
-               reporting, and processor shutdowns. Machine Check exception handlers may \
-               have to depend on processor version to do model specific processing of \
-               the exception, or test for the presence of the Machine Check feature.",
-        has_mce,
-        edx_ecx,
-        FeatureInfoFlags::MCE
-    );
+    /// Fast access mode for the IA32_HWP_REQUEST MSR is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hwp_fast_access_mode(&self) -> bool {
+        self.eax
+            .contains(ThermalPowerFeaturesEax::HWP_REQUEST_MSR_FAST_ACCESS)
+    }
 
-    check_flag!(
-        doc = "CMPXCHG8B Instruction. The compare-and-exchange 8 bytes (64 bits) \
-               instruction is supported (implicitly locked and atomic).",
-        has_cmpxchg8b,
-        edx_ecx,
-        FeatureInfoFlags::CX8
-    );
+    /// Ignoring Idle Logical Processor HWP request is supported if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_ignore_idle_processor_hwp_request(&self) -> bool {
+        self.eax
+            .contains(ThermalPowerFeaturesEax::IGNORE_IDLE_PROCESSOR_HWP_REQUEST)
+    }
 
-    check_flag!(
-        doc = "APIC On-Chip. The processor contains an Advanced Programmable Interrupt \
-               Controller (APIC), responding to memory mapped commands in the physical \
-               address range FFFE0000H to FFFE0FFFH (by default - some processors permit \
-               the APIC to be relocated).",
-        has_apic,
-        edx_ecx,
-        FeatureInfoFlags::APIC
-    );
+    /// Hardware Feedback Interface (HFI) is supported if set.
+    ///
+    /// The layout of the HFI table is described by
+    /// [`ThermalPowerInfo::hfi_table_size`] and
+    /// [`ThermalPowerInfo::hfi_table_row_index`].
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hw_feedback(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::HW_FEEDBACK)
+    }
 
-    check_flag!(
-        doc = "SYSENTER and SYSEXIT Instructions. The SYSENTER and SYSEXIT and \
-               associated MSRs are supported.",
-        has_sysenter_sysexit,
-        edx_ecx,
-        FeatureInfoFlags::SEP
-    );
+    /// Intel® Thread Director is supported if set.
+    ///
+    /// The HFI table then reports performance and efficiency capabilities for
+    /// each of the [`ThermalPowerInfo::thread_director_classes`] classes.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_thread_director(&self) -> bool {
+        self.eax.contains(ThermalPowerFeaturesEax::THREAD_DIRECTOR)
+    }
 
-    check_flag!(
-        doc = "Memory Type Range Registers. MTRRs are supported. The MTRRcap MSR \
-               contains feature bits that describe what memory types are supported, how \
-               many variable MTRRs are supported, and whether fixed MTRRs are supported.",
-        has_mtrr,
-        edx_ecx,
-        FeatureInfoFlags::MTRR
-    );
+    /// Hardware Coordination Feedback Capability
+    ///
+    /// Presence of IA32_MPERF and IA32_APERF.
+    ///
+    /// The capability to provide a measure of delivered processor performance
+    /// (since last reset of the counters), as a percentage of expected
+    /// processor performance at frequency specified in CPUID Brand String Bits
+    /// 02 - 01
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    pub fn has_hw_coord_feedback(&self) -> bool {
+        self.ecx
+            .contains(ThermalPowerFeaturesEcx::HW_COORD_FEEDBACK)
+    }
 
-    check_flag!(
-        doc = "Page Global Bit. The global bit is supported in paging-structure entries \
-               that map a page, indicating TLB entries that are common to different \
-               processes and need not be flushed. The CR4.PGE bit controls this feature.",
-        has_pge,
-        edx_ecx,
-        FeatureInfoFlags::PGE
-    );
+    /// The processor supports performance-energy bias preference if
+    /// CPUID.06H:ECX.SETBH[bit 3] is set and it also implies the presence of a
+    /// new architectural MSR called IA32_ENERGY_PERF_BIAS (1B0H)
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_energy_bias_pref(&self) -> bool {
+        self.ecx.contains(ThermalPowerFeaturesEcx::ENERGY_BIAS_PREF)
+    }
 
-    check_flag!(
-        doc = "Machine Check Architecture. A value of 1 indicates the Machine Check \
-               Architecture of reporting machine errors is supported. The MCG_CAP MSR \
-               contains feature bits describing how many banks of error reporting MSRs \
-               are supported.",
-        has_mca,
-        edx_ecx,
-        FeatureInfoFlags::MCA
-    );
+    /// Number of Intel® Thread Director classes supported by the processor.
+    /// Information for that many classes is written into the HFI table.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn thread_director_classes(&self) -> u8 {
+        get_bits(self.ecx.bits(), 8, 15) as u8
+    }
 
-    check_flag!(
-        doc = "Conditional Move Instructions. The conditional move instruction CMOV is \
-               supported. In addition, if x87 FPU is present as indicated by the \
-               CPUID.FPU feature bit, then the FCOMI and FCMOV instructions are supported",
-        has_cmov,
-        edx_ecx,
-        FeatureInfoFlags::CMOV
-    );
+    /// Performance capability reporting is supported in the HFI table if set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hfi_performance_reporting(&self) -> bool {
+        self.edx & 0x1 > 0
+    }
+
+    /// Energy efficiency capability reporting is supported in the HFI table if
+    /// set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn has_hfi_efficiency_reporting(&self) -> bool {
+        self.edx & 0x2 > 0
+    }
 
-    check_flag!(
-        doc = "Page Attribute Table. Page Attribute Table is supported. This feature \
-               augments the Memory Type Range Registers (MTRRs), allowing an operating \
-               system to specify attributes of memory accessed through a linear address \
-               on a 4KB granularity.",
-        has_pat,
-        edx_ecx,
-        FeatureInfoFlags::PAT
-    );
+    /// Size of the HFI table in number of 4 KiB pages.
+    ///
+    /// Only meaningful if [`ThermalPowerInfo::has_hw_feedback`] is set.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn hfi_table_size(&self) -> u8 {
+        get_bits(self.edx, 8, 11) as u8 + 1
+    }
 
-    check_flag!(
-        doc = "36-Bit Page Size Extension. 4-MByte pages addressing physical memory \
-               beyond 4 GBytes are supported with 32-bit paging. This feature indicates \
-               that upper bits of the physical address of a 4-MByte page are encoded in \
-               bits 20:13 of the page-directory entry. Such physical addresses are \
-               limited by MAXPHYADDR and may be up to 40 bits in size.",
-        has_pse36,
-        edx_ecx,
-        FeatureInfoFlags::PSE36
-    );
+    /// Index (starting at 0) of this logical processor's row in the HFI
+    /// table.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    pub fn hfi_table_row_index(&self) -> u16 {
+        get_bits(self.edx, 16, 31) as u16
+    }
+}
 
-    check_flag!(
-        doc = "Processor Serial Number. The processor supports the 96-bit processor \
-               identification number feature and the feature is enabled.",
-        has_psn,
-        edx_ecx,
-        FeatureInfoFlags::PSN
-    );
+impl Debug for ThermalPowerInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ThermalPowerInfo")
+            .field("dts_irq_threshold", &self.dts_irq_threshold())
+            .field("has_dts", &self.has_dts())
+            .field("has_arat", &self.has_arat())
+            .field("has_pln", &self.has_pln())
+            .field("has_ecmd", &self.has_ecmd())
+            .field("has_ptm", &self.has_ptm())
+            .field("has_hwp", &self.has_hwp())
+            .field("has_hwp_notification", &self.has_hwp_notification())
+            .field("has_hwp_activity_window", &self.has_hwp_activity_window())
+            .field(
+                "has_hwp_energy_performance_preference",
+                &self.has_hwp_energy_performance_preference(),
+            )
+            .field(
+                "has_hwp_package_level_request",
+                &self.has_hwp_package_level_request(),
+            )
+            .field("has_hdc", &self.has_hdc())
+            .field("has_turbo_boost3", &self.has_turbo_boost3())
+            .field("has_hwp_capabilities", &self.has_hwp_capabilities())
+            .field("has_hwp_peci_override", &self.has_hwp_peci_override())
+            .field("has_flexible_hwp", &self.has_flexible_hwp())
+            .field("has_hwp_fast_access_mode", &self.has_hwp_fast_access_mode())
+            .field(
+                "has_ignore_idle_processor_hwp_request",
+                &self.has_ignore_idle_processor_hwp_request(),
+            )
+            .field("has_hw_feedback", &self.has_hw_feedback())
+            .field("has_thread_director", &self.has_thread_director())
+            .field("has_hw_coord_feedback", &self.has_hw_coord_feedback())
+            .field("has_energy_bias_pref", &self.has_energy_bias_pref())
+            .field("thread_director_classes", &self.thread_director_classes())
+            .field(
+                "has_hfi_performance_reporting",
+                &self.has_hfi_performance_reporting(),
+            )
+            .field(
+                "has_hfi_efficiency_reporting",
+                &self.has_hfi_efficiency_reporting(),
+            )
+            .field("hfi_table_size", &self.hfi_table_size())
+            .field("hfi_table_row_index", &self.hfi_table_row_index())
+            .finish()
+    }
+}
 
-    check_flag!(
-        doc = "CLFLUSH Instruction. CLFLUSH Instruction is supported.",
-        has_clflush,
-        edx_ecx,
-        FeatureInfoFlags::CLFSH
-    );
+bitflags! {
+    struct ThermalPowerFeaturesEax: u32 {
+        /// Digital temperature sensor is supported if set. (Bit 00)
+        const DTS = 1 << 0;
+        /// Intel Turbo Boost Technology Available (see description of IA32_MISC_ENABLE[38]). (Bit 01)
+        const TURBO_BOOST = 1 << 1;
+        /// ARAT. APIC-Timer-always-running feature is supported if set. (Bit 02)
+        const ARAT = 1 << 2;
+        /// Bit 3: Reserved.
+        const RESERVED_3 = 1 << 3;
+        /// PLN. Power limit notification controls are supported if set. (Bit 04)
+        const PLN = 1 << 4;
+        /// ECMD. Clock modulation duty cycle extension is supported if set. (Bit 05)
+        const ECMD = 1 << 5;
+        /// PTM. Package thermal management is supported if set. (Bit 06)
+        const PTM = 1 << 6;
+        /// Bit 07: HWP. HWP base registers (IA32_PM_ENABLE[bit 0], IA32_HWP_CAPABILITIES, IA32_HWP_REQUEST, IA32_HWP_STATUS) are supported if set.
+        const HWP = 1 << 7;
+        /// Bit 08: HWP_Notification. IA32_HWP_INTERRUPT MSR is supported if set.
+        const HWP_NOTIFICATION = 1 << 8;
+        /// Bit 09: HWP_Activity_Window. IA32_HWP_REQUEST[bits 41:32] is supported if set.
+        const HWP_ACTIVITY_WINDOW = 1 << 9;
+        /// Bit 10: HWP_Energy_Performance_Preference. IA32_HWP_REQUEST[bits 31:24] is supported if set.
+        const HWP_ENERGY_PERFORMANCE_PREFERENCE = 1 << 10;
+        /// Bit 11: HWP_Package_Level_Request. IA32_HWP_REQUEST_PKG MSR is supported if set.
+        const HWP_PACKAGE_LEVEL_REQUEST = 1 << 11;
+        /// Bit 12: Reserved.
+        const RESERVED_12 = 1 << 12;
+        /// Bit 13: HDC. HDC base registers IA32_PKG_HDC_CTL, IA32_PM_CTL1, IA32_THREAD_STALL MSRs are supported if set.
+        const HDC = 1 << 13;
+        /// Bit 14: Intel® Turbo Boost Max Technology 3.0 available.
+        const TURBO_BOOST_3 = 1 << 14;
+        /// Bit 15: HWP Capabilities. Highest Performance change is supported if set.
+        const HWP_CAPABILITIES = 1 << 15;
+        /// Bit 16: HWP PECI override is supported if set.
+        const HWP_PECI_OVERRIDE = 1 << 16;
+        /// Bit 17: Flexible HWP is supported if set.
+        const FLEXIBLE_HWP = 1 << 17;
+        /// Bit 18: Fast access mode for the IA32_HWP_REQUEST MSR is supported if set.
+        const HWP_REQUEST_MSR_FAST_ACCESS = 1 << 18;
+        /// Bit 19: HW_FEEDBACK. Hardware Feedback Interface is supported if set.
+        const HW_FEEDBACK = 1 << 19;
+        /// Bit 20: Ignoring Idle Logical Processor HWP request is supported if set.
+        const IGNORE_IDLE_PROCESSOR_HWP_REQUEST = 1 << 20;
+        /// Bit 23: Intel® Thread Director is supported if set.
+        const THREAD_DIRECTOR = 1 << 23;
+        // Bits 31 - 24: Reserved
+    }
+}
 
-    check_flag!(
-        doc = "Debug Store. The processor supports the ability to write debug \
-               information into a memory resident buffer. This feature is used by the \
-               branch trace store (BTS) and processor event-based sampling (PEBS) \
-               facilities (see Chapter 23, Introduction to Virtual-Machine Extensions, \
-               in the Intel® 64 and IA-32 Architectures Software Developers Manual, \
-               Volume 3C).",
-        has_ds,
-        edx_ecx,
-        FeatureInfoFlags::DS
-    );
+bitflags! {
+    struct ThermalPowerFeaturesEcx: u32 {
+        const HW_COORD_FEEDBACK = 1 << 0;
 
-    check_flag!(
-        doc = "Thermal Monitor and Software Controlled Clock Facilities. The processor \
-               implements internal MSRs that allow processor temperature to be monitored \
-               and processor performance to be modulated in predefined duty cycles under \
-               software control.",
-        has_acpi,
-        edx_ecx,
-        FeatureInfoFlags::ACPI
-    );
+        /// The processor supports performance-energy bias preference if CPUID.06H:ECX.SETBH[bit 3] is set and it also implies the presence of a new architectural MSR called IA32_ENERGY_PERF_BIAS (1B0H)
+        const ENERGY_BIAS_PREF = 1 << 3;
 
-    check_flag!(
-        doc = "Intel MMX Technology. The processor supports the Intel MMX technology.",
-        has_mmx,
-        edx_ecx,
-        FeatureInfoFlags::MMX
-    );
+        // Bits 15 - 08: Number of Intel® Thread Director classes
+    }
+}
 
-    check_flag!(
-        doc = "FXSAVE and FXRSTOR Instructions. The FXSAVE and FXRSTOR instructions are \
-               supported for fast save and restore of the floating point context. \
-               Presence of this bit also indicates that CR4.OSFXSR is available for an \
-               operating system to indicate that it supports the FXSAVE and FXRSTOR \
-               instructions.",
-        has_fxsave_fxstor,
-        edx_ecx,
-        FeatureInfoFlags::FXSR
-    );
+/// Structured Extended Feature Identifiers (LEAF=0x07).
+///
+/// # Platforms
+/// 🟡 AMD ✅ Intel
+pub struct ExtendedFeatures {
+    _eax: u32,
+    ebx: ExtendedFeaturesEbx,
+    ecx: ExtendedFeaturesEcx,
+    edx: ExtendedFeaturesEdx,
+    eax1: ExtendedFeaturesEax1,
+    ebx1: ExtendedFeaturesEbx1,
+    _ecx1: u32,
+    edx1: ExtendedFeaturesEdx1,
+    edx2: ExtendedFeaturesEdx2,
+}
 
-    check_flag!(
-        doc = "SSE. The processor supports the SSE extensions.",
-        has_sse,
-        edx_ecx,
-        FeatureInfoFlags::SSE
-    );
+impl ExtendedFeatures {
+    /// FSGSBASE. Supports RDFSBASE/RDGSBASE/WRFSBASE/WRGSBASE if 1.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub const fn has_fsgsbase(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::FSGSBASE)
+    }
 
-    check_flag!(
-        doc = "SSE2. The processor supports the SSE2 extensions.",
-        has_sse2,
-        edx_ecx,
-        FeatureInfoFlags::SSE2
-    );
+    /// IA32_TSC_ADJUST MSR is supported if 1.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_tsc_adjust_msr(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::ADJUST_MSR)
+    }
 
-    check_flag!(
-        doc = "Self Snoop. The processor supports the management of conflicting memory \
-               types by performing a snoop of its own cache structure for transactions \
-               issued to the bus.",
-        has_ss,
-        edx_ecx,
-        FeatureInfoFlags::SS
-    );
+    /// BMI1
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub const fn has_bmi1(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::BMI1)
+    }
 
-    check_flag!(
-        doc = "Max APIC IDs reserved field is Valid. A value of 0 for HTT indicates \
-               there is only a single logical processor in the package and software \
-               should assume only a single APIC ID is reserved.  A value of 1 for HTT \
-               indicates the value in CPUID.1.EBX\\[23:16\\] (the Maximum number of \
-               addressable IDs for logical processors in this package) is valid for the \
-               package.",
-        has_htt,
-        edx_ecx,
-        FeatureInfoFlags::HTT
-    );
+    /// HLE
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_hle(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::HLE)
+    }
+
+    /// AVX2
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub const fn has_avx2(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX2)
+    }
+
+    /// FDP_EXCPTN_ONLY. x87 FPU Data Pointer updated only on x87 exceptions if
+    /// 1.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_fdp(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::FDP)
+    }
 
-    check_flag!(
-        doc = "Thermal Monitor. The processor implements the thermal monitor automatic \
-               thermal control circuitry (TCC).",
-        has_tm,
-        edx_ecx,
-        FeatureInfoFlags::TM
-    );
+    /// SMEP. Supports Supervisor-Mode Execution Prevention if 1.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub const fn has_smep(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::SMEP)
+    }
 
-    check_flag!(
-        doc = "Pending Break Enable. The processor supports the use of the FERR#/PBE# \
-               pin when the processor is in the stop-clock state (STPCLK# is asserted) \
-               to signal the processor that an interrupt is pending and that the \
-               processor should return to normal operation to handle the interrupt. Bit \
-               10 (PBE enable) in the IA32_MISC_ENABLE MSR enables this capability.",
-        has_pbe,
-        edx_ecx,
-        FeatureInfoFlags::PBE
-    );
-}
+    /// BMI2
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub const fn has_bmi2(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::BMI2)
+    }
 
-impl Debug for FeatureInfo {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("FeatureInfo")
-            .field("extended_family_id", &self.extended_family_id())
-            .field("extended_model_id", &self.extended_model_id())
-            .field("family_id", &self.family_id())
-            .field("model_id", &self.model_id())
-            .field("stepping_id", &self.stepping_id())
-            .field("brand_index", &self.brand_index())
-            .field("cflush_cache_line_size", &self.cflush_cache_line_size())
-            .field("initial_local_apic_id", &self.initial_local_apic_id())
-            .field(
-                "max_logical_processor_ids",
-                &self.max_logical_processor_ids(),
-            )
-            .field("edx_ecx", &self.edx_ecx)
-            .finish()
+    /// Supports Enhanced REP MOVSB/STOSB if 1.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_rep_movsb_stosb(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::REP_MOVSB_STOSB)
     }
-}
 
-bitflags! {
-    #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    struct FeatureInfoFlags: u64 {
-        // ECX flags
+    /// INVPCID. If 1, supports INVPCID instruction for system software that
+    /// manages process-context identifiers.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_invpcid(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::INVPCID)
+    }
 
-        /// Streaming SIMD Extensions 3 (SSE3). A value of 1 indicates the processor supports this technology.
-        const SSE3 = 1 << 0;
-        /// PCLMULQDQ. A value of 1 indicates the processor supports the PCLMULQDQ instruction
-        const PCLMULQDQ = 1 << 1;
-        /// 64-bit DS Area. A value of 1 indicates the processor supports DS area using 64-bit layout
-        const DTES64 = 1 << 2;
-        /// MONITOR/MWAIT. A value of 1 indicates the processor supports this feature.
-        const MONITOR = 1 << 3;
-        /// CPL Qualified Debug Store. A value of 1 indicates the processor supports the extensions to the  Debug Store feature to allow for branch message storage qualified by CPL.
-        const DSCPL = 1 << 4;
-        /// Virtual Machine Extensions. A value of 1 indicates that the processor supports this technology.
-        const VMX = 1 << 5;
-        /// Safer Mode Extensions. A value of 1 indicates that the processor supports this technology. See Chapter 5, Safer Mode Extensions Reference.
-        const SMX = 1 << 6;
-        /// Enhanced Intel SpeedStep® technology. A value of 1 indicates that the processor supports this technology.
-        const EIST = 1 << 7;
-        /// Thermal Monitor 2. A value of 1 indicates whether the processor supports this technology.
-        const TM2 = 1 << 8;
-        /// A value of 1 indicates the presence of the Supplemental Streaming SIMD Extensions 3 (SSSE3). A value of 0 indicates the instruction extensions are not present in the processor
-        const SSSE3 = 1 << 9;
-        /// L1 Context ID. A value of 1 indicates the L1 data cache mode can be set to either adaptive mode or shared mode. A value of 0 indicates this feature is not supported. See definition of the IA32_MISC_ENABLE MSR Bit 24 (L1 Data Cache Context Mode) for details.
-        const CNXTID = 1 << 10;
-        /// A value of 1 indicates the processor supports FMA extensions using YMM state.
-        const FMA = 1 << 12;
-        /// CMPXCHG16B Available. A value of 1 indicates that the feature is available. See the CMPXCHG8B/CMPXCHG16B Compare and Exchange Bytes section. 14
-        const CMPXCHG16B = 1 << 13;
-        /// Perfmon and Debug Capability: A value of 1 indicates the processor supports the performance   and debug feature indication MSR IA32_PERF_CAPABILITIES.
-        const PDCM = 1 << 15;
-        /// Process-context identifiers. A value of 1 indicates that the processor supports PCIDs and the software may set CR4.PCIDE to 1.
-        const PCID = 1 << 17;
-        /// A value of 1 indicates the processor supports the ability to prefetch data from a memory mapped device.
-        const DCA = 1 << 18;
-        /// A value of 1 indicates that the processor supports SSE4.1.
-        const SSE41 = 1 << 19;
-        /// A value of 1 indicates that the processor supports SSE4.2.
-        const SSE42 = 1 << 20;
-        /// A value of 1 indicates that the processor supports x2APIC feature.
-        const X2APIC = 1 << 21;
-        /// A value of 1 indicates that the processor supports MOVBE instruction.
-        const MOVBE = 1 << 22;
-        /// A value of 1 indicates that the processor supports the POPCNT instruction.
-        const POPCNT = 1 << 23;
-        /// A value of 1 indicates that the processors local APIC timer supports one-shot operation using a TSC deadline value.
-        const TSC_DEADLINE = 1 << 24;
-        /// A value of 1 indicates that the processor supports the AESNI instruction extensions.
-        const AESNI = 1 << 25;
-        /// A value of 1 indicates that the processor supports the XSAVE/XRSTOR processor extended states feature, the XSETBV/XGETBV instructions, and XCR0.
-        const XSAVE = 1 << 26;
-        /// A value of 1 indicates that the OS has enabled XSETBV/XGETBV instructions to access XCR0, and support for processor extended state management using XSAVE/XRSTOR.
-        const OSXSAVE = 1 << 27;
-        /// A value of 1 indicates the processor supports the AVX instruction extensions.
-        const AVX = 1 << 28;
-        /// A value of 1 indicates that processor supports 16-bit floating-point conversion instructions.
-        const F16C = 1 << 29;
-        /// A value of 1 indicates that processor supports RDRAND instruction.
-        const RDRAND = 1 << 30;
-        /// A value of 1 indicates the indicates the presence of a hypervisor.
-        const HYPERVISOR = 1 << 31;
+    /// RTM
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_rtm(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::RTM)
+    }
 
+    /// Supports Intel Resource Director Technology (RDT) Monitoring capability.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_rdtm(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::RDTM)
+    }
 
-        // EDX flags
+    /// Deprecates FPU CS and FPU DS values if 1.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_fpu_cs_ds_deprecated(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::DEPRECATE_FPU_CS_DS)
+    }
 
-        /// Floating Point Unit On-Chip. The processor contains an x87 FPU.
-        const FPU = 1 << 32;
-        /// Virtual 8086 Mode Enhancements. Virtual 8086 mode enhancements, including CR4.VME for controlling the feature, CR4.PVI for protected mode virtual interrupts, software interrupt indirection, expansion of the TSS with the software indirection bitmap, and EFLAGS.VIF and EFLAGS.VIP flags.
-        const VME = 1 << (32 + 1);
-        /// Debugging Extensions. Support for I/O breakpoints, including CR4.DE for controlling the feature, and optional trapping of accesses to DR4 and DR5.
-        const DE = 1 << (32 + 2);
-        /// Page Size Extension. Large pages of size 4 MByte are supported, including CR4.PSE for controlling the feature, the defined dirty bit in PDE (Page Directory Entries), optional reserved bit trapping in CR3, PDEs, and PTEs.
-        const PSE = 1 << (32 + 3);
-        /// Time Stamp Counter. The RDTSC instruction is supported, including CR4.TSD for controlling privilege.
-        const TSC = 1 << (32 + 4);
-        /// Model Specific Registers RDMSR and WRMSR Instructions. The RDMSR and WRMSR instructions are supported. Some of the MSRs are implementation dependent.
-        const MSR = 1 << (32 + 5);
-        /// Physical Address Extension. Physical addresses greater than 32 bits are supported: extended page table entry formats, an extra level in the page translation tables is defined, 2-MByte pages are supported instead of 4 Mbyte pages if PAE bit is 1.
-        const PAE = 1 << (32 + 6);
-        /// Machine Check Exception. Exception 18 is defined for Machine Checks, including CR4.MCE for controlling the feature. This feature does not define the model-specific implementations of machine-check error logging, reporting, and processor shutdowns. Machine Check exception handlers may have to depend on processor version to do model specific processing of the exception, or test for the presence of the Machine Check feature.
-        const MCE = 1 << (32 + 7);
-        /// CMPXCHG8B Instruction. The compare-and-exchange 8 bytes (64 bits) instruction is supported (implicitly locked and atomic).
-        const CX8 = 1 << (32 + 8);
-        /// APIC On-Chip. The processor contains an Advanced Programmable Interrupt Controller (APIC), responding to memory mapped commands in the physical address range FFFE0000H to FFFE0FFFH (by default - some processors permit the APIC to be relocated).
-        const APIC = 1 << (32 + 9);
-        /// SYSENTER and SYSEXIT Instructions. The SYSENTER and SYSEXIT and associated MSRs are supported.
-        const SEP = 1 << (32 + 11);
-        /// Memory Type Range Registers. MTRRs are supported. The MTRRcap MSR contains feature bits that describe what memory types are supported, how many variable MTRRs are supported, and whether fixed MTRRs are supported.
-        const MTRR = 1 << (32 + 12);
-        /// Page Global Bit. The global bit is supported in paging-structure entries that map a page, indicating TLB entries that are common to different processes and need not be flushed. The CR4.PGE bit controls this feature.
-        const PGE = 1 << (32 + 13);
-        /// Machine Check Architecture. The Machine Check exArchitecture, which provides a compatible mechanism for error reporting in P6 family, Pentium 4, Intel Xeon processors, and future processors, is supported. The MCG_CAP MSR contains feature bits describing how many banks of error reporting MSRs are supported.
-        const MCA = 1 << (32 + 14);
-        /// Conditional Move Instructions. The conditional move instruction CMOV is supported. In addition, if x87 FPU is present as indicated by the CPUID.FPU feature bit, then the FCOMI and FCMOV instructions are supported
-        const CMOV = 1 << (32 + 15);
-        /// Page Attribute Table. Page Attribute Table is supported. This feature augments the Memory Type Range Registers (MTRRs), allowing an operating system to specify attributes of memory accessed through a linear address on a 4KB granularity.
-        const PAT = 1 << (32 + 16);
-        /// 36-Bit Page Size Extension. 4-MByte pages addressing physical memory beyond 4 GBytes are supported with 32-bit paging. This feature indicates that upper bits of the physical address of a 4-MByte page are encoded in bits 20:13 of the page-directory entry. Such physical addresses are limited by MAXPHYADDR and may be up to 40 bits in size.
-        const PSE36 = 1 << (32 + 17);
-        /// Processor Serial Number. The processor supports the 96-bit processor identification number feature and the feature is enabled.
-        const PSN = 1 << (32 + 18);
-        /// CLFLUSH Instruction. CLFLUSH Instruction is supported.
-        const CLFSH = 1 << (32 + 19);
-        /// Debug Store. The processor supports the ability to write debug information into a memory resident buffer. This feature is used by the branch trace store (BTS) and precise event-based sampling (PEBS) facilities (see Chapter 23, Introduction to Virtual-Machine Extensions, in the Intel® 64 and IA-32 Architectures Software Developers Manual, Volume 3C).
-        const DS = 1 << (32 + 21);
-        /// Thermal Monitor and Software Controlled Clock Facilities. The processor implements internal MSRs that allow processor temperature to be monitored and processor performance to be modulated in predefined duty cycles under software control.
-        const ACPI = 1 << (32 + 22);
-        /// Intel MMX Technology. The processor supports the Intel MMX technology.
-        const MMX = 1 << (32 + 23);
-        /// FXSAVE and FXRSTOR Instructions. The FXSAVE and FXRSTOR instructions are supported for fast save and restore of the floating point context. Presence of this bit also indicates that CR4.OSFXSR is available for an operating system to indicate that it supports the FXSAVE and FXRSTOR instructions.
-        const FXSR = 1 << (32 + 24);
-        /// SSE. The processor supports the SSE extensions.
-        const SSE = 1 << (32 + 25);
-        /// SSE2. The processor supports the SSE2 extensions.
-        const SSE2 = 1 << (32 + 26);
-        /// Self Snoop. The processor supports the management of conflicting memory types by performing a snoop of its own cache structure for transactions issued to the bus.
-        const SS = 1 << (32 + 27);
-        /// Max APIC IDs reserved field is Valid. A value of 0 for HTT indicates there is only a single logical processor in the package and software should assume only a single APIC ID is reserved.  A value of 1 for HTT indicates the value in CPUID.1.EBX[23:16] (the Maximum number of addressable IDs for logical processors in this package) is valid for the package.
-        const HTT = 1 << (32 + 28);
-        /// Thermal Monitor. The processor implements the thermal monitor automatic thermal control circuitry (TCC).
-        const TM = 1 << (32 + 29);
-        /// Pending Break Enable. The processor supports the use of the FERR#/PBE# pin when the processor is in the stop-clock state (STPCLK# is asserted) to signal the processor that an interrupt is pending and that the processor should return to normal operation to handle the interrupt. Bit 10 (PBE enable) in the IA32_MISC_ENABLE MSR enables this capability.
-        const PBE = 1 << (32 + 31);
+    /// MPX. Supports Intel Memory Protection Extensions if 1.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_mpx(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::MPX)
     }
-}
 
-/// Iterator over caches (LEAF=0x04).
-///
-/// Yields a [CacheParameter] for each cache.
-///
-/// # Platforms
-/// 🟡 AMD ✅ Intel
-#[derive(Clone, Copy)]
-pub struct CacheParametersIter<R: CpuIdReader> {
-    read: R,
-    leaf: u32,
-    current: u32,
-}
+    /// Supports Intel Resource Director Technology (RDT) Allocation capability.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_rdta(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::RDTA)
+    }
 
-impl<R: CpuIdReader> Iterator for CacheParametersIter<R> {
-    type Item = CacheParameter;
+    /// Supports RDSEED.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub const fn has_rdseed(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::RDSEED)
+    }
 
-    /// Iterate over all cache info subleafs for this CPU.
+    /// Supports ADX.
     ///
-    /// # Note
-    /// cpuid is called every-time we advance the iterator to get information
-    /// about the next cache.
-    fn next(&mut self) -> Option<CacheParameter> {
-        let res = self.read.cpuid2(self.leaf, self.current);
-        let cp = CacheParameter {
-            eax: res.eax,
-            ebx: res.ebx,
-            ecx: res.ecx,
-            edx: res.edx,
-        };
-
-        match cp.cache_type() {
-            CacheType::Null => None,
-            CacheType::Reserved => None,
-            _ => {
-                self.current += 1;
-                Some(cp)
-            }
-        }
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub const fn has_adx(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::ADX)
     }
-}
 
-impl<R: CpuIdReader> Debug for CacheParametersIter<R> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        let mut debug = f.debug_list();
-        self.clone().for_each(|ref item| {
-            debug.entry(item);
-        });
-        debug.finish()
+    /// SMAP. Supports Supervisor-Mode Access Prevention (and the CLAC/STAC
+    /// instructions) if 1.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub const fn has_smap(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::SMAP)
     }
-}
 
-/// Information about an individual cache in the hierarchy.
-///
-/// # Platforms
-/// 🟡 AMD ✅ Intel
-#[derive(Copy, Clone, Eq, PartialEq)]
-pub struct CacheParameter {
-    eax: u32,
-    ebx: u32,
-    ecx: u32,
-    edx: u32,
-}
+    /// Supports CLFLUSHOPT.
+    ///
+    /// # Platforms
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub const fn has_clflushopt(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::CLFLUSHOPT)
+    }
 
-/// Info about a what a given cache caches (instructions, data, etc.)
-#[derive(PartialEq, Eq, Debug)]
-pub enum CacheType {
-    /// Null - No more caches
-    Null = 0,
-    /// Data cache
-    Data,
-    /// Instruction cache
-    Instruction,
-    /// Data and Instruction cache
-    Unified,
-    /// 4-31 = Reserved
-    Reserved,
-}
+    /// Supports Intel Processor Trace.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_processor_trace(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::PROCESSOR_TRACE)
+    }
 
-impl fmt::Display for CacheType {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let typ = match self {
-            CacheType::Null => "Null",
-            CacheType::Data => "Data",
-            CacheType::Instruction => "Instruction",
-            CacheType::Unified => "Unified",
-            CacheType::Reserved => "Reserved",
-        };
+    /// Supports SHA Instructions.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_sha(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::SHA)
+    }
 
-        f.write_str(typ)
+    /// Supports Intel® Software Guard Extensions (Intel® SGX Extensions).
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_sgx(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::SGX)
     }
-}
 
-impl CacheParameter {
-    /// Cache Type
+    /// Supports AVX512F.
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn cache_type(&self) -> CacheType {
-        let typ = get_bits(self.eax, 0, 4) as u8;
-        match typ {
-            0 => CacheType::Null,
-            1 => CacheType::Data,
-            2 => CacheType::Instruction,
-            3 => CacheType::Unified,
-            _ => CacheType::Reserved,
-        }
+    #[inline]
+    pub const fn has_avx512f(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512F)
     }
 
-    /// Cache Level (starts at 1)
+    /// Supports AVX512DQ.
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn level(&self) -> u8 {
-        get_bits(self.eax, 5, 7) as u8
+    #[inline]
+    pub const fn has_avx512dq(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512DQ)
     }
 
-    /// Self Initializing cache level (does not need SW initialization).
+    /// AVX512_IFMA
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn is_self_initializing(&self) -> bool {
-        get_bits(self.eax, 8, 8) == 1
+    #[inline]
+    pub const fn has_avx512_ifma(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512_IFMA)
     }
 
-    /// Fully Associative cache
+    /// AVX512PF
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn is_fully_associative(&self) -> bool {
-        get_bits(self.eax, 9, 9) == 1
+    #[inline]
+    pub const fn has_avx512pf(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512PF)
     }
 
-    /// Maximum number of addressable IDs for logical processors sharing this cache
+    /// AVX512ER
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn max_cores_for_cache(&self) -> usize {
-        (get_bits(self.eax, 14, 25) + 1) as usize
+    #[inline]
+    pub const fn has_avx512er(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512ER)
     }
 
-    /// Maximum number of addressable IDs for processor cores in the physical package
+    /// AVX512CD
     ///
     /// # Platforms
-    /// ❌ AMD ✅ Intel
-    pub fn max_cores_for_package(&self) -> usize {
-        (get_bits(self.eax, 26, 31) + 1) as usize
+    /// ✅ AMD ✅ Intel
+    #[inline]
+    pub const fn has_avx512cd(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512CD)
     }
 
-    /// System Coherency Line Size (Bits 11-00)
+    /// AVX512BW
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn coherency_line_size(&self) -> usize {
-        (get_bits(self.ebx, 0, 11) + 1) as usize
+    #[inline]
+    pub const fn has_avx512bw(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512BW)
     }
 
-    /// Physical Line partitions (Bits 21-12)
+    /// AVX512VL
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn physical_line_partitions(&self) -> usize {
-        (get_bits(self.ebx, 12, 21) + 1) as usize
+    #[inline]
+    pub const fn has_avx512vl(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512VL)
     }
 
-    /// Ways of associativity (Bits 31-22)
+    /// CLWB
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn associativity(&self) -> usize {
-        (get_bits(self.ebx, 22, 31) + 1) as usize
+    #[inline]
+    pub const fn has_clwb(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::CLWB)
     }
 
-    /// Number of Sets (Bits 31-00)
+    /// Has PREFETCHWT1 (Intel® Xeon Phi™ only).
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_prefetchwt1(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::PREFETCHWT1)
+    }
+
+    /// Supports user-mode instruction prevention if 1.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_umip(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::UMIP)
+    }
+
+    /// Supports protection keys for user-mode pages.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_pku(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::PKU)
+    }
+
+    /// OS has set CR4.PKE to enable protection keys (and the RDPKRU/WRPKRU
+    /// instructions.
     ///
     /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_ospke(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::OSPKE)
+    }
+
+    /// WAITPKG
+    ///
+    /// ❓ AMD ✅ Intel
+    #[inline]
+    pub const fn has_waitpkg(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::WAITPKG)
+    }
+
+    /// AVX512VBMI2
+    ///
     /// ✅ AMD ✅ Intel
-    pub fn sets(&self) -> usize {
-        (self.ecx + 1) as usize
+    #[inline]
+    pub const fn has_av512vbmi2(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::AVX512VBMI2)
+    }
+
+    /// Supports CET shadow stack features. Processors that set this bit define bits 0..2 of the
+    /// IA32_U_CET and IA32_S_CET MSRs. Enumerates support for the following MSRs:
+    /// IA32_INTERRUPT_SPP_TABLE_ADDR, IA32_PL3_SSP, IA32_PL2_SSP, IA32_PL1_SSP, and IA32_PL0_SSP.
+    ///
+    /// ❓ AMD ✅ Intel
+    #[inline]
+    pub const fn has_cet_ss(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::CETSS)
+    }
+
+    /// GFNI
+    ///
+    /// ❓ AMD ✅ Intel
+    #[inline]
+    pub const fn has_gfni(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::GFNI)
+    }
+
+    /// VAES
+    ///
+    /// ❓ AMD ✅ Intel
+    #[inline]
+    pub const fn has_vaes(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::VAES)
+    }
+
+    /// VPCLMULQDQ
+    ///
+    /// ❓ AMD ✅ Intel
+    #[inline]
+    pub const fn has_vpclmulqdq(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::VPCLMULQDQ)
     }
 
-    /// Write-Back Invalidate/Invalidate (Bit 0)
-    /// False: WBINVD/INVD from threads sharing this cache acts upon lower level caches for threads sharing this cache.
-    /// True: WBINVD/INVD is not guaranteed to act upon lower level caches of non-originating threads sharing this cache.
+    /// AVX512VNNI
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn is_write_back_invalidate(&self) -> bool {
-        get_bits(self.edx, 0, 0) == 1
+    #[inline]
+    pub const fn has_avx512vnni(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::AVX512VNNI)
     }
 
-    /// Cache Inclusiveness (Bit 1)
-    /// False: Cache is not inclusive of lower cache levels.
-    /// True: Cache is inclusive of lower cache levels.
+    /// AVX512BITALG
     ///
-    /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn is_inclusive(&self) -> bool {
-        get_bits(self.edx, 1, 1) == 1
+    #[inline]
+    pub const fn has_avx512bitalg(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::AVX512BITALG)
     }
 
-    /// Complex Cache Indexing (Bit 2)
-    /// False: Direct mapped cache.
-    /// True: A complex function is used to index the cache, potentially using all address bits.
+    /// Indicates the following MSRs are supported: IA32_TME_CAPABILITY, IA32_TME_ACTIVATE,
+    /// IA32_TME_EXCLUDE_MASK, and IA32_TME_EXCLUDE_BASE.
     ///
-    /// # Platforms
-    /// ❌ AMD ✅ Intel
-    pub fn has_complex_indexing(&self) -> bool {
-        get_bits(self.edx, 2, 2) == 1
-    }
-}
-
-impl Debug for CacheParameter {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("CacheParameter")
-            .field("cache_type", &self.cache_type())
-            .field("level", &self.level())
-            .field("is_self_initializing", &self.is_self_initializing())
-            .field("is_fully_associative", &self.is_fully_associative())
-            .field("max_cores_for_cache", &self.max_cores_for_cache())
-            .field("max_cores_for_package", &self.max_cores_for_package())
-            .field("coherency_line_size", &self.coherency_line_size())
-            .field("physical_line_partitions", &self.physical_line_partitions())
-            .field("associativity", &self.associativity())
-            .field("sets", &self.sets())
-            .field("is_write_back_invalidate", &self.is_write_back_invalidate())
-            .field("is_inclusive", &self.is_inclusive())
-            .field("has_complex_indexing", &self.has_complex_indexing())
-            .finish()
+    /// ❓ AMD ✅ Intel
+    #[inline]
+    pub const fn has_tme_en(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::TMEEN)
     }
-}
-
-/// Information about how monitor/mwait works on this CPU (LEAF=0x05).
-///
-/// # Platforms
-/// 🟡 AMD ✅ Intel
-#[derive(Eq, PartialEq)]
-pub struct MonitorMwaitInfo {
-    eax: u32,
-    ebx: u32,
-    ecx: u32,
-    edx: u32,
-}
 
-impl MonitorMwaitInfo {
-    /// Smallest monitor-line size in bytes (default is processor's monitor granularity)
+    /// AVX512VPOPCNTDQ
     ///
-    /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn smallest_monitor_line(&self) -> u16 {
-        get_bits(self.eax, 0, 15) as u16
+    #[inline]
+    pub const fn has_avx512vpopcntdq(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::AVX512VPOPCNTDQ)
     }
 
-    /// Largest monitor-line size in bytes (default is processor's monitor granularity
+    /// Supports 57-bit linear addresses and five-level paging if 1.
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
-    pub fn largest_monitor_line(&self) -> u16 {
-        get_bits(self.ebx, 0, 15) as u16
+    /// ❓ AMD ✅ Intel
+    #[inline]
+    pub const fn has_la57(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::LA57)
     }
 
-    ///  Enumeration of Monitor-Mwait extensions (beyond EAX and EBX registers) supported
+    /// RDPID and IA32_TSC_AUX are available.
+    ///
+    /// # Bug
+    /// The Intel manual lists RDPID as bit 22 in the ECX register, but AMD
+    /// lists it as bit 22 in the ebx register. We assumed that the AMD manual
+    /// was wrong and query ecx, let's see what happens.
     ///
     /// # Platforms
     /// ✅ AMD ✅ Intel
-    pub fn extensions_supported(&self) -> bool {
-        get_bits(self.ecx, 0, 0) == 1
+    #[inline]
+    pub const fn has_rdpid(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::RDPID)
     }
 
-    ///  Supports treating interrupts as break-event for MWAIT, even when interrupts disabled
+    /// Supports Key Locker (AES instructions with a wrapped key, see leaf 0x19).
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
-    pub fn interrupts_as_break_event(&self) -> bool {
-        get_bits(self.ecx, 1, 1) == 1
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_kl(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::KL)
     }
 
-    /// Number of C0 sub C-states supported using MWAIT (Bits 03 - 00)
+    /// Supports OS bus-lock detection (#DB after a bus lock).
     ///
     /// # Platforms
-    /// ❌ AMD (undefined/reserved) ✅ Intel
-    pub fn supported_c0_states(&self) -> u16 {
-        get_bits(self.edx, 0, 3) as u16
+    /// ❓ AMD ✅ Intel
+    #[inline]
+    pub const fn has_bus_lock_detect(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::BUS_LOCK_DETECT)
     }
 
-    /// Number of C1 sub C-states supported using MWAIT (Bits 07 - 04)
+    /// Supports the CLDEMOTE (cache line demote) instruction.
     ///
     /// # Platforms
-    /// ❌ AMD (undefined/reserved) ✅ Intel
-    pub fn supported_c1_states(&self) -> u16 {
-        get_bits(self.edx, 4, 7) as u16
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_cldemote(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::CLDEMOTE)
     }
 
-    /// Number of C2 sub C-states supported using MWAIT (Bits 11 - 08)
+    /// Supports the MOVDIRI (direct store of a doubleword or quadword) instruction.
     ///
     /// # Platforms
-    /// ❌ AMD (undefined/reserved) ✅ Intel
-    pub fn supported_c2_states(&self) -> u16 {
-        get_bits(self.edx, 8, 11) as u16
+    /// ❓ AMD ✅ Intel
+    #[inline]
+    pub const fn has_movdiri(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::MOVDIRI)
     }
 
-    /// Number of C3 sub C-states supported using MWAIT (Bits 15 - 12)
+    /// Supports the MOVDIR64B (direct store of 64 bytes) instruction.
     ///
     /// # Platforms
-    /// ❌ AMD (undefined/reserved) ✅ Intel
-    pub fn supported_c3_states(&self) -> u16 {
-        get_bits(self.edx, 12, 15) as u16
+    /// ❓ AMD ✅ Intel
+    #[inline]
+    pub const fn has_movdir64b(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::MOVDIR64B)
     }
 
-    /// Number of C4 sub C-states supported using MWAIT (Bits 19 - 16)
+    /// Supports Enqueue Stores (ENQCMD and ENQCMDS).
     ///
     /// # Platforms
-    /// ❌ AMD (undefined/reserved) ✅ Intel
-    pub fn supported_c4_states(&self) -> u16 {
-        get_bits(self.edx, 16, 19) as u16
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_enqcmd(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::ENQCMD)
     }
 
-    /// Number of C5 sub C-states supported using MWAIT (Bits 23 - 20)
+    /// Supports SGX Launch Configuration.
     ///
     /// # Platforms
-    /// ❌ AMD (undefined/reserved) ✅ Intel
-    pub fn supported_c5_states(&self) -> u16 {
-        get_bits(self.edx, 20, 23) as u16
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_sgx_lc(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::SGX_LC)
     }
 
-    /// Number of C6 sub C-states supported using MWAIT (Bits 27 - 24)
+    /// Supports protection keys for supervisor-mode pages (PKS).
     ///
     /// # Platforms
-    /// ❌ AMD (undefined/reserved) ✅ Intel
-    pub fn supported_c6_states(&self) -> u16 {
-        get_bits(self.edx, 24, 27) as u16
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_pks(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::PKS)
     }
 
-    /// Number of C7 sub C-states supported using MWAIT (Bits 31 - 28)
+    /// The value of MAWAU used by the BNDLDX and BNDSTX instructions in 64-bit mode.
     ///
     /// # Platforms
-    /// ❌ AMD (undefined/reserved) ✅ Intel
-    pub fn supported_c7_states(&self) -> u16 {
-        get_bits(self.edx, 28, 31) as u16
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub fn mawau_value(&self) -> u8 {
+        get_bits(self.ecx.bits(), 17, 21) as u8
     }
-}
 
-impl Debug for MonitorMwaitInfo {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("MonitorMwaitInfo")
-            .field("smallest_monitor_line", &self.smallest_monitor_line())
-            .field("largest_monitor_line", &self.largest_monitor_line())
-            .field("extensions_supported", &self.extensions_supported())
-            .field(
-                "interrupts_as_break_event",
-                &self.interrupts_as_break_event(),
-            )
-            .field("supported_c0_states", &self.supported_c0_states())
-            .field("supported_c1_states", &self.supported_c1_states())
-            .field("supported_c2_states", &self.supported_c2_states())
-            .field("supported_c3_states", &self.supported_c3_states())
-            .field("supported_c4_states", &self.supported_c4_states())
-            .field("supported_c5_states", &self.supported_c5_states())
-            .field("supported_c6_states", &self.supported_c6_states())
-            .field("supported_c7_states", &self.supported_c7_states())
-            .finish()
+    /// Supports AVX512_4VNNIW.
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_avx512_4vnniw(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::AVX512_4VNNIW)
     }
-}
-
-/// Query information about thermal and power management features of the CPU (LEAF=0x06).
-///
-/// # Platforms
-/// 🟡 AMD ✅ Intel
-pub struct ThermalPowerInfo {
-    eax: ThermalPowerFeaturesEax,
-    ebx: u32,
-    ecx: ThermalPowerFeaturesEcx,
-    _edx: u32,
-}
 
-impl ThermalPowerInfo {
-    /// Number of Interrupt Thresholds in Digital Thermal Sensor
+    /// Supports AVX512_4FMAPS.
     ///
     /// # Platforms
-    /// ❌ AMD (undefined/reserved) ✅ Intel
-    pub fn dts_irq_threshold(&self) -> u8 {
-        get_bits(self.ebx, 0, 3) as u8
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_avx512_4fmaps(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::AVX512_4FMAPS)
     }
 
-    /// Digital temperature sensor is supported if set.
+    /// Supports AVX512_VP2INTERSECT.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_dts(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::DTS)
+    #[inline]
+    pub const fn has_avx512_vp2intersect(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::AVX512_VP2INTERSECT)
     }
 
-    /// Intel Turbo Boost Technology Available (see description of
-    /// IA32_MISC_ENABLE\[38\]).
+    /// RTM_ALWAYS_ABORT: every XBEGIN aborts immediately, even if RTM is enumerated.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_turbo_boost(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::TURBO_BOOST)
+    #[inline]
+    pub const fn has_rtm_always_abort(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::RTM_ALWAYS_ABORT)
     }
 
-    /// ARAT. APIC-Timer-always-running feature is supported if set.
+    /// Supports the IA32_TSX_FORCE_ABORT MSR, which can make all RTM transactions abort.
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
-    pub fn has_arat(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::ARAT)
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_tsx_force_abort(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::TSX_FORCE_ABORT)
     }
 
-    /// PLN. Power limit notification controls are supported if set.
+    /// Supports the SERIALIZE instruction.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_pln(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::PLN)
+    #[inline]
+    pub const fn has_serialize(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::SERIALIZE)
     }
 
-    /// ECMD. Clock modulation duty cycle extension is supported if set.
+    /// The processor is a hybrid part (it has different core types), see
+    /// [`CpuId::get_hybrid_info`].
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_ecmd(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::ECMD)
+    #[inline]
+    pub const fn has_hybrid(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::HYBRID)
     }
 
-    /// PTM. Package thermal management is supported if set.
+    /// Supports TSX suspend/resume of load address tracking (XSUSLDTRK, XRESLDTRK).
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_ptm(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::PTM)
+    #[inline]
+    pub const fn has_tsxldtrk(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::TSXLDTRK)
     }
 
-    /// HWP. HWP base registers (IA32_PM_ENABLE[bit 0], IA32_HWP_CAPABILITIES,
-    /// IA32_HWP_REQUEST, IA32_HWP_STATUS) are supported if set.
+    /// Supports AMX_BF16.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_hwp(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::HWP)
+    #[inline]
+    pub const fn has_amx_bf16(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::AMX_BF16)
     }
 
-    /// HWP Notification. IA32_HWP_INTERRUPT MSR is supported if set.
+    /// Supports AVX512_FP16.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_hwp_notification(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::HWP_NOTIFICATION)
+    #[inline]
+    pub const fn has_avx512_fp16(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::AVX512_FP16)
     }
 
-    /// HWP Activity Window. IA32_HWP_REQUEST[bits 41:32] is supported if set.
+    /// Supports AMX_TILE.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_hwp_activity_window(&self) -> bool {
-        self.eax
-            .contains(ThermalPowerFeaturesEax::HWP_ACTIVITY_WINDOW)
+    #[inline]
+    pub const fn has_amx_tile(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::AMX_TILE)
     }
 
-    /// HWP Energy Performance Preference. IA32_HWP_REQUEST[bits 31:24] is
-    /// supported if set.
+    /// Supports AMX_INT8.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_hwp_energy_performance_preference(&self) -> bool {
-        self.eax
-            .contains(ThermalPowerFeaturesEax::HWP_ENERGY_PERFORMANCE_PREFERENCE)
+    #[inline]
+    pub const fn has_amx_int8(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::AMX_INT8)
     }
 
-    /// HWP Package Level Request. IA32_HWP_REQUEST_PKG MSR is supported if set.
+    /// VERW clears the CPU buffers affected by microarchitectural data sampling (MDS).
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_hwp_package_level_request(&self) -> bool {
-        self.eax
-            .contains(ThermalPowerFeaturesEax::HWP_PACKAGE_LEVEL_REQUEST)
+    #[inline]
+    pub const fn has_md_clear(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::MD_CLEAR)
     }
 
-    /// HDC. HDC base registers IA32_PKG_HDC_CTL, IA32_PM_CTL1,
-    /// IA32_THREAD_STALL MSRs are supported if set.
+    /// Supports Indirect Branch Restricted Speculation (IBRS) and the Indirect Branch Predictor Barrier (IBPB).
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_hdc(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::HDC)
+    #[inline]
+    pub const fn has_ibrs_ibpb(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::IBRS_IBPB)
     }
 
-    /// Intel® Turbo Boost Max Technology 3.0 available.
+    /// Supports Single Thread Indirect Branch Predictors (STIBP).
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_turbo_boost3(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::TURBO_BOOST_3)
+    #[inline]
+    pub const fn has_stibp(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::STIBP)
     }
 
-    /// HWP Capabilities. Highest Performance change is supported if set.
+    /// Supports the IA32_FLUSH_CMD MSR (L1D_FLUSH).
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_hwp_capabilities(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::HWP_CAPABILITIES)
+    #[inline]
+    pub const fn has_l1d_flush(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::L1D_FLUSH)
     }
 
-    /// HWP PECI override is supported if set.
+    /// Supports CET indirect branch tracking (ENDBRANCH). Processors that set this bit
+    /// define bits 2..5 and 10..63 of the IA32_U_CET and IA32_S_CET MSRs.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_hwp_peci_override(&self) -> bool {
-        self.eax
-            .contains(ThermalPowerFeaturesEax::HWP_PECI_OVERRIDE)
+    #[inline]
+    pub const fn has_cet_ibt(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::CET_IBT)
     }
 
-    /// Flexible HWP is supported if set.
+    /// Supports the IA32_ARCH_CAPABILITIES MSR.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_flexible_hwp(&self) -> bool {
-        self.eax.contains(ThermalPowerFeaturesEax::FLEXIBLE_HWP)
+    #[inline]
+    pub const fn has_arch_capabilities(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::ARCH_CAPABILITIES)
     }
 
-    /// Fast access mode for the IA32_HWP_REQUEST MSR is supported if set.
+    /// Supports the IA32_CORE_CAPABILITIES MSR.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_hwp_fast_access_mode(&self) -> bool {
-        self.eax
-            .contains(ThermalPowerFeaturesEax::HWP_REQUEST_MSR_FAST_ACCESS)
+    #[inline]
+    pub const fn has_core_capabilities(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::CORE_CAPABILITIES)
     }
 
-    /// Ignoring Idle Logical Processor HWP request is supported if set.
+    /// Supports Speculative Store Bypass Disable (SSBD).
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_ignore_idle_processor_hwp_request(&self) -> bool {
-        self.eax
-            .contains(ThermalPowerFeaturesEax::IGNORE_IDLE_PROCESSOR_HWP_REQUEST)
+    #[inline]
+    pub const fn has_ssbd(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::SSBD)
     }
 
-    /// Hardware Coordination Feedback Capability
-    ///
-    /// Presence of IA32_MPERF and IA32_APERF.
-    ///
-    /// The capability to provide a measure of delivered processor performance
-    /// (since last reset of the counters), as a percentage of expected
-    /// processor performance at frequency specified in CPUID Brand String Bits
-    /// 02 - 01
+    /// Supports AVX_VNNI.
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
-    pub fn has_hw_coord_feedback(&self) -> bool {
-        self.ecx
-            .contains(ThermalPowerFeaturesEcx::HW_COORD_FEEDBACK)
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_avx_vnni(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::AVX_VNNI)
     }
 
-    /// The processor supports performance-energy bias preference if
-    /// CPUID.06H:ECX.SETBH[bit 3] is set and it also implies the presence of a
-    /// new architectural MSR called IA32_ENERGY_PERF_BIAS (1B0H)
+    /// Supports AVX512_BF16.
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
-    pub fn has_energy_bias_pref(&self) -> bool {
-        self.ecx.contains(ThermalPowerFeaturesEcx::ENERGY_BIAS_PREF)
+    #[inline]
+    pub const fn has_avx512_bf16(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::AVX512_BF16)
     }
-}
 
-impl Debug for ThermalPowerInfo {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("ThermalPowerInfo")
-            .field("dts_irq_threshold", &self.dts_irq_threshold())
-            .field("has_dts", &self.has_dts())
-            .field("has_arat", &self.has_arat())
-            .field("has_pln", &self.has_pln())
-            .field("has_ecmd", &self.has_ecmd())
-            .field("has_ptm", &self.has_ptm())
-            .field("has_hwp", &self.has_hwp())
-            .field("has_hwp_notification", &self.has_hwp_notification())
-            .field("has_hwp_activity_window", &self.has_hwp_activity_window())
-            .field(
-                "has_hwp_energy_performance_preference",
-                &self.has_hwp_energy_performance_preference(),
-            )
-            .field(
-                "has_hwp_package_level_request",
-                &self.has_hwp_package_level_request(),
-            )
-            .field("has_hdc", &self.has_hdc())
-            .field("has_turbo_boost3", &self.has_turbo_boost3())
-            .field("has_hwp_capabilities", &self.has_hwp_capabilities())
-            .field("has_hwp_peci_override", &self.has_hwp_peci_override())
-            .field("has_flexible_hwp", &self.has_flexible_hwp())
-            .field("has_hwp_fast_access_mode", &self.has_hwp_fast_access_mode())
-            .field(
-                "has_ignore_idle_processor_hwp_request",
-                &self.has_ignore_idle_processor_hwp_request(),
-            )
-            .field("has_hw_coord_feedback", &self.has_hw_coord_feedback())
-            .field("has_energy_bias_pref", &self.has_energy_bias_pref())
-            .finish()
+    /// Supports Fast zero-length REP MOVSB
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_fzrm(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::FZRM)
     }
-}
 
-bitflags! {
-    struct ThermalPowerFeaturesEax: u32 {
-        /// Digital temperature sensor is supported if set. (Bit 00)
-        const DTS = 1 << 0;
-        /// Intel Turbo Boost Technology Available (see description of IA32_MISC_ENABLE[38]). (Bit 01)
-        const TURBO_BOOST = 1 << 1;
-        /// ARAT. APIC-Timer-always-running feature is supported if set. (Bit 02)
-        const ARAT = 1 << 2;
-        /// Bit 3: Reserved.
-        const RESERVED_3 = 1 << 3;
-        /// PLN. Power limit notification controls are supported if set. (Bit 04)
-        const PLN = 1 << 4;
-        /// ECMD. Clock modulation duty cycle extension is supported if set. (Bit 05)
-        const ECMD = 1 << 5;
-        /// PTM. Package thermal management is supported if set. (Bit 06)
-        const PTM = 1 << 6;
-        /// Bit 07: HWP. HWP base registers (IA32_PM_ENABLE[bit 0], IA32_HWP_CAPABILITIES, IA32_HWP_REQUEST, IA32_HWP_STATUS) are supported if set.
-        const HWP = 1 << 7;
-        /// Bit 08: HWP_Notification. IA32_HWP_INTERRUPT MSR is supported if set.
-        const HWP_NOTIFICATION = 1 << 8;
-        /// Bit 09: HWP_Activity_Window. IA32_HWP_REQUEST[bits 41:32] is supported if set.
-        const HWP_ACTIVITY_WINDOW = 1 << 9;
-        /// Bit 10: HWP_Energy_Performance_Preference. IA32_HWP_REQUEST[bits 31:24] is supported if set.
-        const HWP_ENERGY_PERFORMANCE_PREFERENCE = 1 << 10;
-        /// Bit 11: HWP_Package_Level_Request. IA32_HWP_REQUEST_PKG MSR is supported if set.
-        const HWP_PACKAGE_LEVEL_REQUEST = 1 << 11;
-        /// Bit 12: Reserved.
-        const RESERVED_12 = 1 << 12;
-        /// Bit 13: HDC. HDC base registers IA32_PKG_HDC_CTL, IA32_PM_CTL1, IA32_THREAD_STALL MSRs are supported if set.
-        const HDC = 1 << 13;
-        /// Bit 14: Intel® Turbo Boost Max Technology 3.0 available.
-        const TURBO_BOOST_3 = 1 << 14;
-        /// Bit 15: HWP Capabilities. Highest Performance change is supported if set.
-        const HWP_CAPABILITIES = 1 << 15;
-        /// Bit 16: HWP PECI override is supported if set.
-        const HWP_PECI_OVERRIDE = 1 << 16;
-        /// Bit 17: Flexible HWP is supported if set.
-        const FLEXIBLE_HWP = 1 << 17;
-        /// Bit 18: Fast access mode for the IA32_HWP_REQUEST MSR is supported if set.
-        const HWP_REQUEST_MSR_FAST_ACCESS = 1 << 18;
-        /// Bit 19: Reserved.
-        const RESERVED_19 = 1 << 19;
-        /// Bit 20: Ignoring Idle Logical Processor HWP request is supported if set.
-        const IGNORE_IDLE_PROCESSOR_HWP_REQUEST = 1 << 20;
-        // Bits 31 - 21: Reserved
+    /// Supports Fast Short REP STOSB
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_fsrs(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::FSRS)
     }
-}
-
-bitflags! {
-    struct ThermalPowerFeaturesEcx: u32 {
-        const HW_COORD_FEEDBACK = 1 << 0;
 
-        /// The processor supports performance-energy bias preference if CPUID.06H:ECX.SETBH[bit 3] is set and it also implies the presence of a new architectural MSR called IA32_ENERGY_PERF_BIAS (1B0H)
-        const ENERGY_BIAS_PREF = 1 << 3;
+    /// Supports the Architectural Performance Monitoring Extended Leaf (EAX = 23H)
+    ///
+    /// # Platforms
+    /// ❌ AMD (reserved) ✅ Intel
+    #[inline]
+    pub const fn has_arch_perfmon_ext(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::ARCH_PERFMON_EXT)
     }
-}
-
-/// Structured Extended Feature Identifiers (LEAF=0x07).
-///
-/// # Platforms
-/// 🟡 AMD ✅ Intel
-pub struct ExtendedFeatures {
-    _eax: u32,
-    ebx: ExtendedFeaturesEbx,
-    ecx: ExtendedFeaturesEcx,
-    edx: ExtendedFeaturesEdx,
-    eax1: ExtendedFeaturesEax1,
-    _ebx1: u32,
-    _ecx1: u32,
-    edx1: ExtendedFeaturesEdx1,
-}
 
-impl ExtendedFeatures {
-    /// FSGSBASE. Supports RDFSBASE/RDGSBASE/WRFSBASE/WRGSBASE if 1.
+    /// Supports Fast Short REP CMPSB, REP SCASB
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_fsgsbase(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::FSGSBASE)
+    pub const fn has_fsrcrs(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::FSRCRS)
     }
 
-    /// IA32_TSC_ADJUST MSR is supported if 1.
+    /// Supports HRESET
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_tsc_adjust_msr(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::ADJUST_MSR)
+    pub const fn has_hreset(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::HRESET)
     }
 
-    /// BMI1
+    /// Supports SHA512 instructions (VSHA512MSG1, VSHA512MSG2, VSHA512RNDS2)
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_bmi1(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::BMI1)
+    pub const fn has_sha512(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::SHA512)
     }
 
-    /// HLE
+    /// Supports SM3 instructions (VSM3MSG1, VSM3MSG2, VSM3RNDS2)
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_hle(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::HLE)
+    pub const fn has_sm3(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::SM3)
     }
 
-    /// AVX2
+    /// Supports SM4 instructions (VSM4KEY4, VSM4RNDS4)
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_avx2(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::AVX2)
+    pub const fn has_sm4(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::SM4)
     }
 
-    /// FDP_EXCPTN_ONLY. x87 FPU Data Pointer updated only on x87 exceptions if
-    /// 1.
+    /// Supports RAO-INT (remote atomic) instructions (AADD, AAND, AOR, AXOR)
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_fdp(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::FDP)
+    pub const fn has_rao_int(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::RAO_INT)
     }
 
-    /// SMEP. Supports Supervisor-Mode Execution Prevention if 1.
+    /// Supports Linear Address Space Separation
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_smep(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::SMEP)
+    pub const fn has_lass(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::LASS)
     }
 
-    /// BMI2
+    /// Supports CMPccXADD instruction
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_bmi2(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::BMI2)
+    pub const fn has_cmpccxadd(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::CMPCCXADD)
     }
 
-    /// Supports Enhanced REP MOVSB/STOSB if 1.
+    /// Supports Flexible Return and Event Delivery
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_rep_movsb_stosb(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::REP_MOVSB_STOSB)
+    pub const fn has_fred(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::FRED)
     }
 
-    /// INVPCID. If 1, supports INVPCID instruction for system software that
-    /// manages process-context identifiers.
+    /// Supports LKGS instruction
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_invpcid(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::INVPCID)
+    pub const fn has_lkgs(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::LKGS)
     }
 
-    /// RTM
+    /// Supports WRMSRNS (non-serializing write to MSRs) instruction
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_rtm(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::RTM)
+    pub const fn has_wrmsrns(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::WRMSRNS)
     }
 
-    /// Supports Intel Resource Director Technology (RDT) Monitoring capability.
+    /// Supports NMI-source reporting
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_rdtm(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::RDTM)
+    pub const fn has_nmi_src(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::NMI_SRC)
     }
 
-    /// Deprecates FPU CS and FPU DS values if 1.
+    /// Supports AMX-FP16 (tile operations on FP16 numbers)
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_fpu_cs_ds_deprecated(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::DEPRECATE_FPU_CS_DS)
+    pub const fn has_amx_fp16(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::AMX_FP16)
     }
 
-    /// MPX. Supports Intel Memory Protection Extensions if 1.
+    /// Supports AVX-IFMA instructions
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_mpx(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::MPX)
+    pub const fn has_avx_ifma(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::AVX_IFMA)
     }
 
-    /// Supports Intel Resource Director Technology (RDT) Allocation capability.
+    /// Supports Linear Address Masking
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_rdta(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::RDTA)
+    pub const fn has_lam(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::LAM)
     }
 
-    /// Supports RDSEED.
+    /// Supports RDMSRLIST and WRMSRLIST instructions
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_rdseed(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::RDSEED)
+    pub const fn has_msrlist(&self) -> bool {
+        self.eax1.contains(ExtendedFeaturesEax1::MSRLIST)
     }
 
-    /// Supports ADX.
+    /// Supports IA32_PPIN and IA32_PPIN_CTL MSRs (protected processor inventory number)
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_adx(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::ADX)
+    pub const fn has_ppin(&self) -> bool {
+        self.ebx1.contains(ExtendedFeaturesEbx1::PPIN)
     }
 
-    /// SMAP. Supports Supervisor-Mode Access Prevention (and the CLAC/STAC
-    /// instructions) if 1.
+    /// Supports PBNDKB instruction
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_smap(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::SMAP)
+    pub const fn has_pbndkb(&self) -> bool {
+        self.ebx1.contains(ExtendedFeaturesEbx1::PBNDKB)
     }
 
-    /// Supports CLFLUSHOPT.
+    /// Supports CET_SSS
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_clflushopt(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::CLFLUSHOPT)
+    pub const fn has_cet_sss(&self) -> bool {
+        self.edx1.contains(ExtendedFeaturesEdx1::CET_SSS)
     }
 
-    /// Supports Intel Processor Trace.
+    /// Supports AVX-VNNI-INT8 instructions
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_processor_trace(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::PROCESSOR_TRACE)
+    pub const fn has_avx_vnni_int8(&self) -> bool {
+        self.edx1.contains(ExtendedFeaturesEdx1::AVX_VNNI_INT8)
     }
 
-    /// Supports SHA Instructions.
+    /// Supports AVX-NE-CONVERT instructions
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_sha(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::SHA)
+    pub const fn has_avx_ne_convert(&self) -> bool {
+        self.edx1.contains(ExtendedFeaturesEdx1::AVX_NE_CONVERT)
     }
 
-    /// Supports Intel® Software Guard Extensions (Intel® SGX Extensions).
+    /// Supports AMX-COMPLEX instructions
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_sgx(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::SGX)
+    pub const fn has_amx_complex(&self) -> bool {
+        self.edx1.contains(ExtendedFeaturesEdx1::AMX_COMPLEX)
     }
 
-    /// Supports AVX512F.
+    /// Supports AVX-VNNI-INT16 instructions
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_avx512f(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::AVX512F)
+    pub const fn has_avx_vnni_int16(&self) -> bool {
+        self.edx1.contains(ExtendedFeaturesEdx1::AVX_VNNI_INT16)
     }
 
-    /// Supports AVX512DQ.
+    /// Supports PREFETCHIT0 and PREFETCHIT1 instructions
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_avx512dq(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::AVX512DQ)
+    pub const fn has_prefetchi(&self) -> bool {
+        self.edx1.contains(ExtendedFeaturesEdx1::PREFETCHI)
     }
 
-    /// AVX512_IFMA
+    /// Supports URDMSR and UWRMSR instructions
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_avx512_ifma(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::AVX512_IFMA)
+    pub const fn has_user_msr(&self) -> bool {
+        self.edx1.contains(ExtendedFeaturesEdx1::USER_MSR)
     }
 
-    /// AVX512PF
+    /// UIRET sets UIF from the RFLAGS image on the stack
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_avx512pf(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::AVX512PF)
+    pub const fn has_uiret_uif(&self) -> bool {
+        self.edx1.contains(ExtendedFeaturesEdx1::UIRET_UIF)
     }
 
-    /// AVX512ER
+    /// Supports Intel AVX10
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_avx512er(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::AVX512ER)
+    pub const fn has_avx10(&self) -> bool {
+        self.edx1.contains(ExtendedFeaturesEdx1::AVX10)
     }
 
-    /// AVX512CD
+    /// Supports Intel APX (Advanced Performance Extensions) foundation: 16 additional
+    /// general-purpose registers (R16 to R31), three-operand forms and conditional
+    /// instructions.
+    ///
+    /// The extended GPRs are XSAVE state component 19, check
+    /// [`ExtendedStateInfo::xcr0_supports_apx`] too before using them.
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_avx512cd(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::AVX512CD)
+    pub const fn has_apx_f(&self) -> bool {
+        self.edx1.contains(ExtendedFeaturesEdx1::APX_F)
     }
 
-    /// AVX512BW
+    /// Supports IA32_SPEC_CTRL.PSFD (disable Fast Store Forwarding Predictor)
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_avx512bw(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::AVX512BW)
+    pub const fn has_psfd(&self) -> bool {
+        self.edx2.contains(ExtendedFeaturesEdx2::PSFD)
     }
 
-    /// AVX512VL
+    /// Supports IA32_SPEC_CTRL.IPRED_DIS_{U,S} (restrict indirect branch predictors)
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_avx512vl(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::AVX512VL)
+    pub const fn has_ipred_ctrl(&self) -> bool {
+        self.edx2.contains(ExtendedFeaturesEdx2::IPRED_CTRL)
     }
 
-    /// CLWB
+    /// Supports IA32_SPEC_CTRL.RRSBA_DIS_{U,S} (disable alternate predictors on RSB underflow)
     ///
     /// # Platforms
-    /// ✅ AMD ✅ Intel
+    /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_clwb(&self) -> bool {
-        self.ebx.contains(ExtendedFeaturesEbx::CLWB)
+    pub const fn has_rrsba_ctrl(&self) -> bool {
+        self.edx2.contains(ExtendedFeaturesEdx2::RRSBA_CTRL)
     }
 
-    /// Has PREFETCHWT1 (Intel® Xeon Phi™ only).
+    /// Supports IA32_SPEC_CTRL.DDPD_U (disable Data Dependent Prefetcher in user mode)
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_prefetchwt1(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::PREFETCHWT1)
+    pub const fn has_ddpd_u(&self) -> bool {
+        self.edx2.contains(ExtendedFeaturesEdx2::DDPD_U)
     }
 
-    /// Supports user-mode instruction prevention if 1.
+    /// Supports IA32_SPEC_CTRL.BHI_DIS_S (Branch History Injection mitigation)
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_umip(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::UMIP)
+    pub const fn has_bhi_ctrl(&self) -> bool {
+        self.edx2.contains(ExtendedFeaturesEdx2::BHI_CTRL)
     }
 
-    /// Supports protection keys for user-mode pages.
+    /// Processor does not exhibit MXCSR Configuration Dependent Timing
     ///
     /// # Platforms
     /// ❌ AMD (reserved) ✅ Intel
     #[inline]
-    pub const fn has_pku(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::PKU)
+    pub const fn has_mcdt_no(&self) -> bool {
+        self.edx2.contains(ExtendedFeaturesEdx2::MCDT_NO)
     }
 
-    /// OS has set CR4.PKE to enable protection keys (and the RDPKRU/WRPKRU
-    /// instructions.
+    /// The AVX-512 subsets, grouped for dispatching on a whole generation.
     ///
     /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
+    /// ✅ AMD ✅ Intel
     #[inline]
-    pub const fn has_ospke(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::OSPKE)
+    pub const fn avx512_profile(&self) -> Avx512Profile {
+        Avx512Profile {
+            ebx: self.ebx,
+            ecx: self.ecx,
+            edx: self.edx,
+            eax1: self.eax1,
+        }
+    }
+}
+
+impl Debug for ExtendedFeatures {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ExtendedFeatures")
+            .field("ebx", &self.ebx)
+            .field("ecx", &self.ecx)
+            .field("mawau_value", &self.mawau_value())
+            .finish()
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ExtendedFeaturesEbx: u32 {
+        /// FSGSBASE. Supports RDFSBASE/RDGSBASE/WRFSBASE/WRGSBASE if 1. (Bit 00)
+        const FSGSBASE = 1 << 0;
+        /// IA32_TSC_ADJUST MSR is supported if 1. (Bit 01)
+        const ADJUST_MSR = 1 << 1;
+        /// Bit 02: SGX. Supports Intel® Software Guard Extensions (Intel® SGX Extensions) if 1.
+        const SGX = 1 << 2;
+        /// BMI1 (Bit 03)
+        const BMI1 = 1 << 3;
+        /// HLE (Bit 04)
+        const HLE = 1 << 4;
+        /// AVX2 (Bit 05)
+        const AVX2 = 1 << 5;
+        /// FDP_EXCPTN_ONLY. x87 FPU Data Pointer updated only on x87 exceptions if 1.
+        const FDP = 1 << 6;
+        /// SMEP. Supports Supervisor-Mode Execution Prevention if 1. (Bit 07)
+        const SMEP = 1 << 7;
+        /// BMI2 (Bit 08)
+        const BMI2 = 1 << 8;
+        /// Supports Enhanced REP MOVSB/STOSB if 1. (Bit 09)
+        const REP_MOVSB_STOSB = 1 << 9;
+        /// INVPCID. If 1, supports INVPCID instruction for system software that manages process-context identifiers. (Bit 10)
+        const INVPCID = 1 << 10;
+        /// RTM (Bit 11)
+        const RTM = 1 << 11;
+        /// Supports Intel Resource Director Technology (RDT) Monitoring. (Bit 12)
+        const RDTM = 1 << 12;
+        /// Deprecates FPU CS and FPU DS values if 1. (Bit 13)
+        const DEPRECATE_FPU_CS_DS = 1 << 13;
+        /// Deprecates FPU CS and FPU DS values if 1. (Bit 14)
+        const MPX = 1 << 14;
+        /// Supports Intel Resource Director Technology (RDT) Allocation capability if 1.
+        const RDTA = 1 << 15;
+        /// Bit 16: AVX512F.
+        const AVX512F = 1 << 16;
+        /// Bit 17: AVX512DQ.
+        const AVX512DQ = 1 << 17;
+        /// Supports RDSEED.
+        const RDSEED = 1 << 18;
+        /// Supports ADX.
+        const ADX = 1 << 19;
+        /// SMAP. Supports Supervisor-Mode Access Prevention (and the CLAC/STAC instructions) if 1.
+        const SMAP = 1 << 20;
+        /// Bit 21: AVX512_IFMA.
+        const AVX512_IFMA = 1 << 21;
+        // Bit 22: Reserved.
+        /// Bit 23: CLFLUSHOPT
+        const CLFLUSHOPT = 1 << 23;
+        /// Bit 24: CLWB.
+        const CLWB = 1 << 24;
+        /// Bit 25: Intel Processor Trace
+        const PROCESSOR_TRACE = 1 << 25;
+        /// Bit 26: AVX512PF. (Intel® Xeon Phi™ only.)
+        const AVX512PF = 1 << 26;
+        /// Bit 27: AVX512ER. (Intel® Xeon Phi™ only.)
+        const AVX512ER = 1 << 27;
+        /// Bit 28: AVX512CD.
+        const AVX512CD = 1 << 28;
+        /// Bit 29: Intel SHA Extensions
+        const SHA = 1 << 29;
+        /// Bit 30: AVX512BW.
+        const AVX512BW = 1 << 30;
+        /// Bit 31: AVX512VL.
+        const AVX512VL = 1 << 31;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ExtendedFeaturesEcx: u32 {
+        /// Bit 0: Prefetch WT1. (Intel® Xeon Phi™ only).
+        const PREFETCHWT1 = 1 << 0;
+        // Bit 01: AVX512_VBMI
+        const AVX512VBMI = 1 << 1;
+        /// Bit 02: UMIP. Supports user-mode instruction prevention if 1.
+        const UMIP = 1 << 2;
+        /// Bit 03: PKU. Supports protection keys for user-mode pages if 1.
+        const PKU = 1 << 3;
+        /// Bit 04: OSPKE. If 1, OS has set CR4.PKE to enable protection keys (and the RDPKRU/WRPKRU instruc-tions).
+        const OSPKE = 1 << 4;
+        /// Bit 5: WAITPKG
+        const WAITPKG = 1 << 5;
+        /// Bit 6: AV512_VBMI2
+        const AVX512VBMI2 = 1 << 6;
+        /// Bit 7: CET_SS. Supports CET shadow stack features if 1. Processors that set this bit define bits 0..2 of the
+        /// IA32_U_CET and IA32_S_CET MSRs. Enumerates support for the following MSRs:
+        /// IA32_INTERRUPT_SPP_TABLE_ADDR, IA32_PL3_SSP, IA32_PL2_SSP, IA32_PL1_SSP, and IA32_PL0_SSP.
+        const CETSS = 1 << 7;
+        /// Bit 8: GFNI
+        const GFNI = 1 << 8;
+        /// Bit 9: VAES
+        const VAES = 1 << 9;
+        /// Bit 10: VPCLMULQDQ
+        const VPCLMULQDQ = 1 << 10;
+        /// Bit 11: AVX512_VNNI
+        const AVX512VNNI = 1 << 11;
+        /// Bit 12: AVX512_BITALG
+        const AVX512BITALG = 1 << 12;
+        /// Bit 13: TME_EN. If 1, the following MSRs are supported: IA32_TME_CAPABILITY, IA32_TME_ACTIVATE,
+        /// IA32_TME_EXCLUDE_MASK, and IA32_TME_EXCLUDE_BASE.
+        const TMEEN = 1 << 13;
+        /// Bit 14: AVX512_VPOPCNTDQ
+        const AVX512VPOPCNTDQ = 1 << 14;
+
+        // Bit 15: Reserved.
+
+        /// Bit 16: Supports 57-bit linear addresses and five-level paging if 1.
+        const LA57 = 1 << 16;
+
+        // Bits 21 - 17: The value of MAWAU used by the BNDLDX and BNDSTX instructions in 64-bit mode
+
+        /// Bit 22: RDPID. RDPID and IA32_TSC_AUX are available if 1.
+        const RDPID = 1 << 22;
+
+        /// Bit 23: KL. Supports Key Locker if 1.
+        const KL = 1 << 23;
+
+        /// Bit 24: BUS_LOCK_DETECT. Supports OS bus-lock detection if 1.
+        const BUS_LOCK_DETECT = 1 << 24;
+
+        /// Bit 25: CLDEMOTE. Supports cache line demote if 1.
+        const CLDEMOTE = 1 << 25;
+
+        // Bit 26: Reserved.
+
+        /// Bit 27: MOVDIRI. Supports MOVDIRI if 1.
+        const MOVDIRI = 1 << 27;
+
+        /// Bit 28: MOVDIR64B. Supports MOVDIR64B if 1.
+        const MOVDIR64B = 1 << 28;
+
+        /// Bit 29: ENQCMD. Supports Enqueue Stores if 1.
+        const ENQCMD = 1 << 29;
+
+        /// Bit 30: SGX_LC. Supports SGX Launch Configuration if 1.
+        const SGX_LC = 1 << 30;
+
+        /// Bit 31: PKS. Supports protection keys for supervisor-mode pages if 1.
+        const PKS = 1 << 31;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ExtendedFeaturesEdx: u32 {
+        /// Bit 02: AVX512_4VNNIW. (Intel® Xeon Phi™ only).
+        const AVX512_4VNNIW = 1 << 2;
+        /// Bit 03: AVX512_4FMAPS. (Intel® Xeon Phi™ only).
+        const AVX512_4FMAPS = 1 << 3;
+        /// Bit 08: AVX512_VP2INTERSECT.
+        const AVX512_VP2INTERSECT = 1 << 8;
+        /// Bit 10: MD_CLEAR. If 1, VERW clears the CPU buffers affected by MDS (microarchitectural data sampling).
+        const MD_CLEAR = 1 << 10;
+        /// Bit 11: RTM_ALWAYS_ABORT. If 1, any execution of XBEGIN immediately aborts and transitions to the specified fallback address.
+        const RTM_ALWAYS_ABORT = 1 << 11;
+        /// Bit 13: TSX_FORCE_ABORT. If 1, the processor supports the IA32_TSX_FORCE_ABORT MSR.
+        const TSX_FORCE_ABORT = 1 << 13;
+        /// Bit 14: SERIALIZE. If 1, the processor supports the SERIALIZE instruction.
+        const SERIALIZE = 1 << 14;
+        /// Bit 15: Hybrid. If 1, the processor is identified as a hybrid part. If CPUID.0.MAXLEAF ≥ 1AH and CPUID.1A.EAX ≠ 0, then the Native Model ID Enumeration Leaf 1AH exists.
+        const HYBRID = 1 << 15;
+        /// Bit 16: TSXLDTRK. If 1, the processor supports Intel TSX suspend/resume of load address tracking.
+        const TSXLDTRK = 1 << 16;
+        /// Bit 20: CET_IBT. If 1, the processor supports CET indirect branch tracking.
+        const CET_IBT = 1 << 20;
+        /// Bit 22: AMX-BF16. If 1, the processor supports tile computational operations on bfloat16 numbers.
+        const AMX_BF16 = 1 << 22;
+        /// Bit 23: AVX512_FP16.
+        const AVX512_FP16 = 1 << 23;
+        /// Bit 24: AMX-TILE. If 1, the processor supports tile architecture
+        const AMX_TILE = 1 << 24;
+        /// Bit 25: AMX-INT8. If 1, the processor supports tile computational operations on 8-bit integers.
+        const AMX_INT8 = 1 << 25;
+        /// Bit 26: IBRS_IBPB. If 1, the processor supports indirect branch restricted speculation (IBRS) and the indirect branch predictor barrier (IBPB).
+        const IBRS_IBPB = 1 << 26;
+        /// Bit 27: STIBP. If 1, the processor supports single thread indirect branch predictors (STIBP).
+        const STIBP = 1 << 27;
+        /// Bit 28: L1D_FLUSH. If 1, the processor supports the L1D_FLUSH command of the IA32_FLUSH_CMD MSR.
+        const L1D_FLUSH = 1 << 28;
+        /// Bit 29: ARCH_CAPABILITIES. If 1, the processor supports the IA32_ARCH_CAPABILITIES MSR.
+        const ARCH_CAPABILITIES = 1 << 29;
+        /// Bit 30: CORE_CAPABILITIES. If 1, the processor supports the IA32_CORE_CAPABILITIES MSR.
+        const CORE_CAPABILITIES = 1 << 30;
+        /// Bit 31: SSBD. If 1, the processor supports speculative store bypass disable (SSBD).
+        const SSBD = 1 << 31;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ExtendedFeaturesEax1: u32 {
+        // Some of the Unimplemented bits are reserved and maybe release in future CPUs, see Intel SDM for future features (Date of comment: 07.17.2024)
+        /// Bit 00: SHA512. Supports the SHA512 instructions.
+        const SHA512 = 1 << 0;
+        /// Bit 01: SM3. Supports the SM3 instructions.
+        const SM3 = 1 << 1;
+        /// Bit 02: SM4. Supports the SM4 instructions.
+        const SM4 = 1 << 2;
+        /// Bit 03: RAO-INT. Supports the RAO-INT instructions.
+        const RAO_INT = 1 << 3;
+        /// Bit 04: AVX_VNNI. AVX (VEX-encoded) versions of the Vector Neural Network Instructions.
+        const AVX_VNNI = 1 << 4;
+        /// Bit 05: AVX512_BF16. Vector Neural Network Instructions supporting BFLOAT16 inputs and conversion instructions from IEEE single precision.
+        const AVX512_BF16 = 1 << 5;
+        /// Bit 06: LASS. Supports linear address space separation.
+        const LASS = 1 << 6;
+        /// Bit 07: CMPCCXADD. Supports the CMPccXADD instruction.
+        const CMPCCXADD = 1 << 7;
+        /// Bit 08: ARCHPERFMONEXT. If 1, the Architectural Performance Monitoring Extended Leaf (EAX = 23H) is valid.
+        const ARCH_PERFMON_EXT = 1 << 8;
+        /// Bit 10: If 1, supports fast zero-length REP MOVSB.
+        const FZRM = 1 << 10;
+        /// Bit 11: If 1, supports fast short REP STOSB.
+        const FSRS = 1 << 11;
+        /// Bit 12: If 1, supports fast short REP CMPSB, REP SCASB.
+        const FSRCRS = 1 << 12;
+        /// Bit 17: FRED. Supports flexible return and event delivery.
+        const FRED = 1 << 17;
+        /// Bit 18: LKGS. Supports the LKGS instruction.
+        const LKGS = 1 << 18;
+        /// Bit 19: WRMSRNS. Supports the WRMSRNS instruction.
+        const WRMSRNS = 1 << 19;
+        /// Bit 20: NMI_SRC. Supports NMI-source reporting.
+        const NMI_SRC = 1 << 20;
+        /// Bit 21: AMX-FP16. Supports tile computational operations on FP16 numbers.
+        const AMX_FP16 = 1 << 21;
+        /// Bit 22:  If 1, supports history reset via the HRESET instruction and the IA32_HRESET_ENABLE MSR. When set, indicates that the Processor History Reset Leaf (EAX = 20H) is valid.
+        const HRESET = 1 << 22;
+        /// Bit 23: AVX-IFMA. Supports the VEX-encoded VPMADD52HUQ and VPMADD52LUQ instructions.
+        const AVX_IFMA = 1 << 23;
+        /// Bit 26: LAM. Supports linear address masking.
+        const LAM = 1 << 26;
+        /// Bit 27: MSRLIST. Supports the RDMSRLIST and WRMSRLIST instructions and the IA32_BARRIER MSR.
+        const MSRLIST = 1 << 27;
     }
+}
 
-    /// WAITPKG
-    ///
-    /// ❓ AMD ✅ Intel
-    #[inline]
-    pub const fn has_waitpkg(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::WAITPKG)
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ExtendedFeaturesEbx1: u32 {
+        /// Bit 00: PPIN. Supports the IA32_PPIN and IA32_PPIN_CTL MSRs.
+        const PPIN = 1 << 0;
+        /// Bit 01: PBNDKB. Supports the PBNDKB instruction and the TSE_CAPABILITY MSR.
+        const PBNDKB = 1 << 1;
     }
+}
 
-    /// AVX512VBMI2
-    ///
-    /// ✅ AMD ✅ Intel
-    #[inline]
-    pub const fn has_av512vbmi2(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::AVX512VBMI2)
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ExtendedFeaturesEdx2: u32 {
+        /// Bit 00: PSFD. Supports the IA32_SPEC_CTRL.PSFD bit to disable fast store forwarding predictor.
+        const PSFD = 1 << 0;
+        /// Bit 01: IPRED_CTRL. Supports the IA32_SPEC_CTRL.IPRED_DIS_U and IPRED_DIS_S bits to restrict indirect branch prediction.
+        const IPRED_CTRL = 1 << 1;
+        /// Bit 02: RRSBA_CTRL. Supports the IA32_SPEC_CTRL.RRSBA_DIS_U and RRSBA_DIS_S bits to disable RRSBA behavior.
+        const RRSBA_CTRL = 1 << 2;
+        /// Bit 03: DDPD_U. Supports the IA32_SPEC_CTRL.DDPD_U bit to disable the data dependent prefetcher.
+        const DDPD_U = 1 << 3;
+        /// Bit 04: BHI_CTRL. Supports the IA32_SPEC_CTRL.BHI_DIS_S bit to mitigate branch history injection.
+        const BHI_CTRL = 1 << 4;
+        /// Bit 05: MCDT_NO. The processor does not exhibit MXCSR configuration dependent timing.
+        const MCDT_NO = 1 << 5;
     }
+}
 
-    /// Supports CET shadow stack features. Processors that set this bit define bits 0..2 of the
-    /// IA32_U_CET and IA32_S_CET MSRs. Enumerates support for the following MSRs:
-    /// IA32_INTERRUPT_SPP_TABLE_ADDR, IA32_PL3_SSP, IA32_PL2_SSP, IA32_PL1_SSP, and IA32_PL0_SSP.
-    ///
-    /// ❓ AMD ✅ Intel
-    #[inline]
-    pub const fn has_cet_ss(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::CETSS)
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ExtendedFeaturesEdx1: u32 {
+        // Some of the Unimplemented bits are reserved and maybe release in future CPUs, see Intel SDM for future features (Date of comment: 07.17.2024)
+        /// Bit 04: AVX-VNNI-INT8. Supports the AVX-VNNI-INT8 instructions.
+        const AVX_VNNI_INT8 = 1 << 4;
+        /// Bit 05: AVX-NE-CONVERT. Supports the AVX-NE-CONVERT instructions.
+        const AVX_NE_CONVERT = 1 << 5;
+        /// Bit 08: AMX-COMPLEX. Supports the AMX-COMPLEX instructions.
+        const AMX_COMPLEX = 1 << 8;
+        /// Bit 10: AVX-VNNI-INT16. Supports the AVX-VNNI-INT16 instructions.
+        const AVX_VNNI_INT16 = 1 << 10;
+        /// Bit 14: PREFETCHI. Supports the PREFETCHIT0/1 instructions.
+        const PREFETCHI = 1 << 14;
+        /// Bit 15: USER_MSR. Supports the URDMSR and UWRMSR instructions.
+        const USER_MSR = 1 << 15;
+        /// Bit 17: UIRET_UIF. UIRET sets UIF to the value of bit 1 of the RFLAGS image loaded from the stack.
+        const UIRET_UIF = 1 << 17;
+        /// Bit 18: CET_SSS. If 1, indicates that an operating system can enable supervisor shadow stacks as long as it ensures that a supervisor shadow stack cannot become prematurely busy due to page faults
+        const CET_SSS = 1 << 18;
+        /// Bit 19: AVX10. Supports the Intel AVX10 instructions, the Intel AVX10 Converged Vector ISA Leaf (EAX = 24H) is valid.
+        const AVX10 = 1 << 19;
+        /// Bit 21: APX_F. Supports the Intel Advanced Performance Extensions foundation.
+        const APX_F = 1 << 21;
     }
+}
 
-    /// GFNI
-    ///
-    /// ❓ AMD ✅ Intel
-    #[inline]
-    pub const fn has_gfni(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::GFNI)
-    }
+/// Transactional memory (TSX) support as returned by [`CpuId::tsx_info`].
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TsxInfo {
+    ebx: ExtendedFeaturesEbx,
+    edx: ExtendedFeaturesEdx,
+}
 
-    /// VAES
-    ///
-    /// ❓ AMD ✅ Intel
-    #[inline]
-    pub const fn has_vaes(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::VAES)
+/// Whether RTM transactions can commit, see [`TsxInfo::rtm_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RtmStatus {
+    /// RTM is not enumerated.
+    NotSupported,
+    /// Every XBEGIN aborts (RTM_ALWAYS_ABORT), e.g., after a microcode update
+    /// disabled TSX.
+    AlwaysAborts,
+    /// RTM is enumerated, but the IA32_TSX_FORCE_ABORT MSR exists and is likely
+    /// set to abort all transactions (the default of microcode that reuses the
+    /// TSX hardware for a performance counter erratum).
+    LikelyForceAborted,
+    /// RTM is enumerated and no abort mechanism is.
+    ///
+    /// If [`TsxInfo::has_arch_capabilities`] is set, the OS can still disable
+    /// RTM through IA32_TSX_CTRL. It usually clears the RTM bit at the same
+    /// time, which CPUID reflects.
+    Available,
+}
+
+impl TsxInfo {
+    /// Hardware Lock Elision (XACQUIRE/XRELEASE prefixes) is enumerated.
+    pub fn has_hle(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::HLE)
     }
 
-    /// VPCLMULQDQ
-    ///
-    /// ❓ AMD ✅ Intel
-    #[inline]
-    pub const fn has_vpclmulqdq(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::VPCLMULQDQ)
+    /// Restricted Transactional Memory (XBEGIN/XEND) is enumerated.
+    pub fn has_rtm(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::RTM)
     }
 
-    /// AVX512VNNI
-    ///
-    /// # Platforms
-    /// ✅ AMD ✅ Intel
-    #[inline]
-    pub const fn has_avx512vnni(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::AVX512VNNI)
+    /// Every XBEGIN aborts immediately (RTM_ALWAYS_ABORT).
+    pub fn has_rtm_always_abort(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::RTM_ALWAYS_ABORT)
     }
 
-    /// AVX512BITALG
-    ///
-    /// ✅ AMD ✅ Intel
-    #[inline]
-    pub const fn has_avx512bitalg(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::AVX512BITALG)
+    /// The IA32_TSX_FORCE_ABORT MSR is supported.
+    pub fn has_tsx_force_abort(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::TSX_FORCE_ABORT)
     }
 
-    /// Indicates the following MSRs are supported: IA32_TME_CAPABILITY, IA32_TME_ACTIVATE,
-    /// IA32_TME_EXCLUDE_MASK, and IA32_TME_EXCLUDE_BASE.
-    ///
-    /// ❓ AMD ✅ Intel
-    #[inline]
-    pub const fn has_tme_en(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::TMEEN)
+    /// The IA32_ARCH_CAPABILITIES MSR is supported (which enumerates IA32_TSX_CTRL).
+    pub fn has_arch_capabilities(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::ARCH_CAPABILITIES)
     }
 
-    /// AVX512VPOPCNTDQ
-    ///
-    /// ✅ AMD ✅ Intel
-    #[inline]
-    pub const fn has_avx512vpopcntdq(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::AVX512VPOPCNTDQ)
+    /// Whether RTM transactions can be expected to commit.
+    pub fn rtm_status(&self) -> RtmStatus {
+        if self.has_rtm_always_abort() {
+            RtmStatus::AlwaysAborts
+        } else if !self.has_rtm() {
+            RtmStatus::NotSupported
+        } else if self.has_tsx_force_abort() {
+            RtmStatus::LikelyForceAborted
+        } else {
+            RtmStatus::Available
+        }
     }
+}
 
-    /// Supports 57-bit linear addresses and five-level paging if 1.
-    ///
-    /// # Platforms
-    /// ❓ AMD ✅ Intel
-    #[inline]
-    pub const fn has_la57(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::LA57)
+impl Debug for TsxInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TsxInfo")
+            .field("has_hle", &self.has_hle())
+            .field("has_rtm", &self.has_rtm())
+            .field("has_rtm_always_abort", &self.has_rtm_always_abort())
+            .field("has_tsx_force_abort", &self.has_tsx_force_abort())
+            .field("has_arch_capabilities", &self.has_arch_capabilities())
+            .field("rtm_status", &self.rtm_status())
+            .finish()
     }
+}
 
-    /// RDPID and IA32_TSC_AUX are available.
-    ///
-    /// # Bug
-    /// The Intel manual lists RDPID as bit 22 in the ECX register, but AMD
-    /// lists it as bit 22 in the ebx register. We assumed that the AMD manual
-    /// was wrong and query ecx, let's see what happens.
-    ///
-    /// # Platforms
-    /// ✅ AMD ✅ Intel
-    #[inline]
-    pub const fn has_rdpid(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::RDPID)
-    }
+/// The AVX-512 subsets as returned by [`ExtendedFeatures::avx512_profile`].
+///
+/// This only checks the CPUID bits. Whether the OS saves the opmask and ZMM state
+/// is reported by [`ExtendedStateInfo`] (XCR0).
+///
+/// # Platforms
+/// ✅ AMD ✅ Intel
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Avx512Profile {
+    ebx: ExtendedFeaturesEbx,
+    ecx: ExtendedFeaturesEcx,
+    edx: ExtendedFeaturesEdx,
+    eax1: ExtendedFeaturesEax1,
+}
 
-    /// Supports SGX Launch Configuration.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_sgx_lc(&self) -> bool {
-        self.ecx.contains(ExtendedFeaturesEcx::SGX_LC)
+impl Avx512Profile {
+    /// AVX512F, the foundation all other subsets require.
+    pub const fn has_f(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512F)
     }
 
-    /// The value of MAWAU used by the BNDLDX and BNDSTX instructions in 64-bit mode.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub fn mawau_value(&self) -> u8 {
-        get_bits(self.ecx.bits(), 17, 21) as u8
+    /// AVX512CD (conflict detection).
+    pub const fn has_cd(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512CD)
     }
 
-    /// Supports AVX512_4VNNIW.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_avx512_4vnniw(&self) -> bool {
-        self.edx.contains(ExtendedFeaturesEdx::AVX512_4VNNIW)
+    /// AVX512BW (byte and word instructions).
+    pub const fn has_bw(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512BW)
     }
 
-    /// Supports AVX512_4FMAPS.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_avx512_4fmaps(&self) -> bool {
-        self.edx.contains(ExtendedFeaturesEdx::AVX512_4FMAPS)
+    /// AVX512DQ (doubleword and quadword instructions).
+    pub const fn has_dq(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512DQ)
     }
 
-    /// Supports AVX512_VP2INTERSECT.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_avx512_vp2intersect(&self) -> bool {
-        self.edx.contains(ExtendedFeaturesEdx::AVX512_VP2INTERSECT)
+    /// AVX512VL (128- and 256-bit vector lengths).
+    pub const fn has_vl(&self) -> bool {
+        self.ebx.contains(ExtendedFeaturesEbx::AVX512VL)
     }
 
-    /// Supports AMX_BF16.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_amx_bf16(&self) -> bool {
-        self.edx.contains(ExtendedFeaturesEdx::AMX_BF16)
+    /// AVX512_VBMI (vector byte manipulation).
+    pub const fn has_vbmi(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::AVX512VBMI)
     }
 
-    /// Supports AVX512_FP16.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_avx512_fp16(&self) -> bool {
-        self.edx.contains(ExtendedFeaturesEdx::AVX512_FP16)
+    /// AVX512_VBMI2 (vector byte manipulation 2).
+    pub const fn has_vbmi2(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::AVX512VBMI2)
     }
 
-    /// Supports AMX_TILE.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_amx_tile(&self) -> bool {
-        self.edx.contains(ExtendedFeaturesEdx::AMX_TILE)
+    /// AVX512_VNNI (vector neural network instructions).
+    pub const fn has_vnni(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::AVX512VNNI)
     }
 
-    /// Supports AMX_INT8.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_amx_int8(&self) -> bool {
-        self.edx.contains(ExtendedFeaturesEdx::AMX_INT8)
+    /// AVX512_BITALG (bit algorithms).
+    pub const fn has_bitalg(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::AVX512BITALG)
     }
 
-    /// Supports AVX_VNNI.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_avx_vnni(&self) -> bool {
-        self.eax1.contains(ExtendedFeaturesEax1::AVX_VNNI)
+    /// AVX512_VPOPCNTDQ (population count of doublewords and quadwords).
+    pub const fn has_vpopcntdq(&self) -> bool {
+        self.ecx.contains(ExtendedFeaturesEcx::AVX512VPOPCNTDQ)
     }
 
-    /// Supports AVX512_BF16.
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_avx512_bf16(&self) -> bool {
+    /// AVX512_BF16 (bfloat16 conversions and dot products).
+    pub const fn has_bf16(&self) -> bool {
         self.eax1.contains(ExtendedFeaturesEax1::AVX512_BF16)
     }
 
-    /// Supports Fast zero-length REP MOVSB
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_fzrm(&self) -> bool {
-        self.eax1.contains(ExtendedFeaturesEax1::FZRM)
-    }
-
-    /// Supports Fast Short REP STOSB
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_fsrs(&self) -> bool {
-        self.eax1.contains(ExtendedFeaturesEax1::FSRS)
+    /// AVX512_FP16 (half-precision arithmetic).
+    pub const fn has_fp16(&self) -> bool {
+        self.edx.contains(ExtendedFeaturesEdx::AVX512_FP16)
     }
 
-    /// Supports Fast Short REP CMPSB, REP SCASB
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_fsrcrs(&self) -> bool {
-        self.eax1.contains(ExtendedFeaturesEax1::FSRCRS)
+    /// F, CD, BW, DQ and VL: the subsets of Skylake-SP and every later AVX-512
+    /// processor (also the AVX-512 part of x86-64-v4).
+    pub const fn has_avx512_skylake_set(&self) -> bool {
+        self.has_f() && self.has_cd() && self.has_bw() && self.has_dq() && self.has_vl()
     }
 
-    /// Supports HRESET
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_hreset(&self) -> bool {
-        self.eax1.contains(ExtendedFeaturesEax1::HRESET)
+    /// The Skylake set plus VBMI, VBMI2, VNNI, BITALG and VPOPCNTDQ: the subsets
+    /// of Ice Lake and later Intel processors and of AMD Zen 4 and later.
+    pub const fn has_avx512_icelake_set(&self) -> bool {
+        self.has_avx512_skylake_set()
+            && self.has_vbmi()
+            && self.has_vbmi2()
+            && self.has_vnni()
+            && self.has_bitalg()
+            && self.has_vpopcntdq()
     }
+}
 
-    /// Supports CET_SSS
-    ///
-    /// # Platforms
-    /// ❌ AMD (reserved) ✅ Intel
-    #[inline]
-    pub const fn has_cet_sss(&self) -> bool {
-        self.edx1.contains(ExtendedFeaturesEdx1::CET_SSS)
+impl Debug for Avx512Profile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Avx512Profile")
+            .field("has_f", &self.has_f())
+            .field("has_cd", &self.has_cd())
+            .field("has_bw", &self.has_bw())
+            .field("has_dq", &self.has_dq())
+            .field("has_vl", &self.has_vl())
+            .field("has_vbmi", &self.has_vbmi())
+            .field("has_vbmi2", &self.has_vbmi2())
+            .field("has_vnni", &self.has_vnni())
+            .field("has_bitalg", &self.has_bitalg())
+            .field("has_vpopcntdq", &self.has_vpopcntdq())
+            .field("has_bf16", &self.has_bf16())
+            .field("has_fp16", &self.has_fp16())
+            .finish()
     }
 }
 
-impl Debug for ExtendedFeatures {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("ExtendedFeatures")
-            .field("ebx", &self.ebx)
-            .field("ecx", &self.ecx)
-            .field("mawau_value", &self.mawau_value())
-            .finish()
-    }
+/// The SIMD and crypto feature bits most dispatchers check, bundled in one value.
+///
+/// This combines the feature flags from leaf 0x01 and leaf 0x07 (sub-leaf 0) so that
+/// libraries doing runtime dispatch can query everything with a single struct instead
+/// of constructing [`FeatureInfo`] and [`ExtendedFeatures`] separately.
+///
+/// Use [`CpuId::get_cpu_capabilities`] to build it from the leafs an existing `CpuId`
+/// already read, or [`CpuCapabilities::with_cpuid_reader`] to fetch it with at most
+/// three CPUID invocations (leafs 0x00, 0x01 and 0x07).
+///
+/// Note that these bits only tell whether the processor implements the instructions.
+/// Whether the OS saves the wider register state (XCR0) needs to be checked separately.
+///
+/// # Platforms
+/// ✅ AMD ✅ Intel
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CpuCapabilities {
+    edx_ecx: FeatureInfoFlags,
+    ebx7: ExtendedFeaturesEbx,
+    ecx7: ExtendedFeaturesEcx,
+    edx7: ExtendedFeaturesEdx,
 }
 
-bitflags! {
-    #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    struct ExtendedFeaturesEbx: u32 {
-        /// FSGSBASE. Supports RDFSBASE/RDGSBASE/WRFSBASE/WRGSBASE if 1. (Bit 00)
-        const FSGSBASE = 1 << 0;
-        /// IA32_TSC_ADJUST MSR is supported if 1. (Bit 01)
-        const ADJUST_MSR = 1 << 1;
-        /// Bit 02: SGX. Supports Intel® Software Guard Extensions (Intel® SGX Extensions) if 1.
-        const SGX = 1 << 2;
-        /// BMI1 (Bit 03)
-        const BMI1 = 1 << 3;
-        /// HLE (Bit 04)
-        const HLE = 1 << 4;
-        /// AVX2 (Bit 05)
-        const AVX2 = 1 << 5;
-        /// FDP_EXCPTN_ONLY. x87 FPU Data Pointer updated only on x87 exceptions if 1.
-        const FDP = 1 << 6;
-        /// SMEP. Supports Supervisor-Mode Execution Prevention if 1. (Bit 07)
-        const SMEP = 1 << 7;
-        /// BMI2 (Bit 08)
-        const BMI2 = 1 << 8;
-        /// Supports Enhanced REP MOVSB/STOSB if 1. (Bit 09)
-        const REP_MOVSB_STOSB = 1 << 9;
-        /// INVPCID. If 1, supports INVPCID instruction for system software that manages process-context identifiers. (Bit 10)
-        const INVPCID = 1 << 10;
-        /// RTM (Bit 11)
-        const RTM = 1 << 11;
-        /// Supports Intel Resource Director Technology (RDT) Monitoring. (Bit 12)
-        const RDTM = 1 << 12;
-        /// Deprecates FPU CS and FPU DS values if 1. (Bit 13)
-        const DEPRECATE_FPU_CS_DS = 1 << 13;
-        /// Deprecates FPU CS and FPU DS values if 1. (Bit 14)
-        const MPX = 1 << 14;
-        /// Supports Intel Resource Director Technology (RDT) Allocation capability if 1.
-        const RDTA = 1 << 15;
-        /// Bit 16: AVX512F.
-        const AVX512F = 1 << 16;
-        /// Bit 17: AVX512DQ.
-        const AVX512DQ = 1 << 17;
-        /// Supports RDSEED.
-        const RDSEED = 1 << 18;
-        /// Supports ADX.
-        const ADX = 1 << 19;
-        /// SMAP. Supports Supervisor-Mode Access Prevention (and the CLAC/STAC instructions) if 1.
-        const SMAP = 1 << 20;
-        /// Bit 21: AVX512_IFMA.
-        const AVX512_IFMA = 1 << 21;
-        // Bit 22: Reserved.
-        /// Bit 23: CLFLUSHOPT
-        const CLFLUSHOPT = 1 << 23;
-        /// Bit 24: CLWB.
-        const CLWB = 1 << 24;
-        /// Bit 25: Intel Processor Trace
-        const PROCESSOR_TRACE = 1 << 25;
-        /// Bit 26: AVX512PF. (Intel® Xeon Phi™ only.)
-        const AVX512PF = 1 << 26;
-        /// Bit 27: AVX512ER. (Intel® Xeon Phi™ only.)
-        const AVX512ER = 1 << 27;
-        /// Bit 28: AVX512CD.
-        const AVX512CD = 1 << 28;
-        /// Bit 29: Intel SHA Extensions
-        const SHA = 1 << 29;
-        /// Bit 30: AVX512BW.
-        const AVX512BW = 1 << 30;
-        /// Bit 31: AVX512VL.
-        const AVX512VL = 1 << 31;
+#[cfg(any(
+    all(target_arch = "x86", not(target_env = "sgx"), target_feature = "sse"),
+    all(target_arch = "x86_64", not(target_env = "sgx"))
+))]
+impl Default for CpuCapabilities {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-bitflags! {
-    #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    struct ExtendedFeaturesEcx: u32 {
-        /// Bit 0: Prefetch WT1. (Intel® Xeon Phi™ only).
-        const PREFETCHWT1 = 1 << 0;
-        // Bit 01: AVX512_VBMI
-        const AVX512VBMI = 1 << 1;
-        /// Bit 02: UMIP. Supports user-mode instruction prevention if 1.
-        const UMIP = 1 << 2;
-        /// Bit 03: PKU. Supports protection keys for user-mode pages if 1.
-        const PKU = 1 << 3;
-        /// Bit 04: OSPKE. If 1, OS has set CR4.PKE to enable protection keys (and the RDPKRU/WRPKRU instruc-tions).
-        const OSPKE = 1 << 4;
-        /// Bit 5: WAITPKG
-        const WAITPKG = 1 << 5;
-        /// Bit 6: AV512_VBMI2
-        const AVX512VBMI2 = 1 << 6;
-        /// Bit 7: CET_SS. Supports CET shadow stack features if 1. Processors that set this bit define bits 0..2 of the
-        /// IA32_U_CET and IA32_S_CET MSRs. Enumerates support for the following MSRs:
-        /// IA32_INTERRUPT_SPP_TABLE_ADDR, IA32_PL3_SSP, IA32_PL2_SSP, IA32_PL1_SSP, and IA32_PL0_SSP.
-        const CETSS = 1 << 7;
-        /// Bit 8: GFNI
-        const GFNI = 1 << 8;
-        /// Bit 9: VAES
-        const VAES = 1 << 9;
-        /// Bit 10: VPCLMULQDQ
-        const VPCLMULQDQ = 1 << 10;
-        /// Bit 11: AVX512_VNNI
-        const AVX512VNNI = 1 << 11;
-        /// Bit 12: AVX512_BITALG
-        const AVX512BITALG = 1 << 12;
-        /// Bit 13: TME_EN. If 1, the following MSRs are supported: IA32_TME_CAPABILITY, IA32_TME_ACTIVATE,
-        /// IA32_TME_EXCLUDE_MASK, and IA32_TME_EXCLUDE_BASE.
-        const TMEEN = 1 << 13;
-        /// Bit 14: AVX512_VPOPCNTDQ
-        const AVX512VPOPCNTDQ = 1 << 14;
+impl CpuCapabilities {
+    /// Read the capabilities of the current processor.
+    #[cfg(any(
+        all(target_arch = "x86", not(target_env = "sgx"), target_feature = "sse"),
+        all(target_arch = "x86_64", not(target_env = "sgx"))
+    ))]
+    pub fn new() -> Self {
+        Self::with_cpuid_reader(CpuIdReaderNative)
+    }
 
-        // Bit 15: Reserved.
+    /// Read the capabilities using a custom reader.
+    ///
+    /// This executes leaf 0x00 to find the maximum supported leaf, then leaf 0x01 and,
+    /// if supported, leaf 0x07 (sub-leaf 0).
+    pub fn with_cpuid_reader<R: CpuIdReader>(cpuid_fn: R) -> Self {
+        const NOT_SUPPORTED: CpuIdResult = CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        };
 
-        /// Bit 16: Supports 57-bit linear addresses and five-level paging if 1.
-        const LA57 = 1 << 16;
+        let max_leaf = cpuid_fn.cpuid1(EAX_VENDOR_INFO).eax;
+        let leaf1 = if max_leaf >= EAX_FEATURE_INFO {
+            cpuid_fn.cpuid1(EAX_FEATURE_INFO)
+        } else {
+            NOT_SUPPORTED
+        };
+        let leaf7 = if max_leaf >= EAX_STRUCTURED_EXTENDED_FEATURE_INFO {
+            cpuid_fn.cpuid2(EAX_STRUCTURED_EXTENDED_FEATURE_INFO, 0)
+        } else {
+            NOT_SUPPORTED
+        };
+        Self::from_leafs(leaf1, leaf7)
+    }
 
-        // Bits 21 - 17: The value of MAWAU used by the BNDLDX and BNDSTX instructions in 64-bit mode
+    fn from_leafs(leaf1: CpuIdResult, leaf7: CpuIdResult) -> Self {
+        CpuCapabilities {
+            edx_ecx: FeatureInfoFlags::from_bits_truncate(
+                ((leaf1.edx as u64) << 32) | (leaf1.ecx as u64),
+            ),
+            ebx7: ExtendedFeaturesEbx::from_bits_truncate(leaf7.ebx),
+            ecx7: ExtendedFeaturesEcx::from_bits_truncate(leaf7.ecx),
+            edx7: ExtendedFeaturesEdx::from_bits_truncate(leaf7.edx),
+        }
+    }
 
-        /// Bit 22: RDPID. RDPID and IA32_TSC_AUX are available if 1.
-        const RDPID = 1 << 22;
+    /// Combine the features of `self` and `other`.
+    #[cfg(feature = "uarch-db")]
+    pub(crate) const fn union(self, other: CpuCapabilities) -> CpuCapabilities {
+        CpuCapabilities {
+            edx_ecx: self.edx_ecx.union(other.edx_ecx),
+            ebx7: self.ebx7.union(other.ebx7),
+            ecx7: self.ecx7.union(other.ecx7),
+            edx7: self.edx7.union(other.edx7),
+        }
+    }
+
+    /// Features that are set in `self` but not in `other`.
+    ///
+    /// For example `expected.difference(&actual)` lists the features that are hidden or
+    /// missing on the running processor.
+    pub fn difference(&self, other: &CpuCapabilities) -> CpuCapabilities {
+        CpuCapabilities {
+            edx_ecx: self.edx_ecx.difference(other.edx_ecx),
+            ebx7: self.ebx7.difference(other.ebx7),
+            ecx7: self.ecx7.difference(other.ecx7),
+            edx7: self.edx7.difference(other.edx7),
+        }
+    }
 
-        // Bits 29 - 23: Reserved.
+    /// Is no feature bit set?
+    pub fn is_empty(&self) -> bool {
+        self.edx_ecx.is_empty()
+            && self.ebx7.is_empty()
+            && self.ecx7.is_empty()
+            && self.edx7.is_empty()
+    }
 
-        /// Bit 30: SGX_LC. Supports SGX Launch Configuration if 1.
-        const SGX_LC = 1 << 30;
+    /// Names of all feature bits that are set (e.g., "SSE41", "AVX512F").
+    pub fn iter_names(&self) -> impl Iterator<Item = &'static str> {
+        let edx_ecx = self.edx_ecx.iter_names().map(|(name, _)| name);
+        let ebx7 = self.ebx7.iter_names().map(|(name, _)| name);
+        let ecx7 = self.ecx7.iter_names().map(|(name, _)| name);
+        let edx7 = self.edx7.iter_names().map(|(name, _)| name);
+        edx_ecx.chain(ebx7).chain(ecx7).chain(edx7)
     }
+
+    check_flag!(
+        doc = "SSE (leaf 0x01).",
+        has_sse,
+        edx_ecx,
+        FeatureInfoFlags::SSE
+    );
+    check_flag!(
+        doc = "SSE2 (leaf 0x01).",
+        has_sse2,
+        edx_ecx,
+        FeatureInfoFlags::SSE2
+    );
+    check_flag!(
+        doc = "SSE3 (leaf 0x01).",
+        has_sse3,
+        edx_ecx,
+        FeatureInfoFlags::SSE3
+    );
+    check_flag!(
+        doc = "SSSE3 (leaf 0x01).",
+        has_ssse3,
+        edx_ecx,
+        FeatureInfoFlags::SSSE3
+    );
+    check_flag!(
+        doc = "SSE4.1 (leaf 0x01).",
+        has_sse41,
+        edx_ecx,
+        FeatureInfoFlags::SSE41
+    );
+    check_flag!(
+        doc = "SSE4.2 (leaf 0x01).",
+        has_sse42,
+        edx_ecx,
+        FeatureInfoFlags::SSE42
+    );
+    check_flag!(
+        doc = "AVX (leaf 0x01).",
+        has_avx,
+        edx_ecx,
+        FeatureInfoFlags::AVX
+    );
+    check_flag!(
+        doc = "FMA3 (leaf 0x01).",
+        has_fma,
+        edx_ecx,
+        FeatureInfoFlags::FMA
+    );
+    check_flag!(
+        doc = "F16C (leaf 0x01).",
+        has_f16c,
+        edx_ecx,
+        FeatureInfoFlags::F16C
+    );
+    check_flag!(
+        doc = "POPCNT (leaf 0x01).",
+        has_popcnt,
+        edx_ecx,
+        FeatureInfoFlags::POPCNT
+    );
+    check_flag!(
+        doc = "MOVBE (leaf 0x01).",
+        has_movbe,
+        edx_ecx,
+        FeatureInfoFlags::MOVBE
+    );
+    check_flag!(
+        doc = "AES-NI (leaf 0x01).",
+        has_aesni,
+        edx_ecx,
+        FeatureInfoFlags::AESNI
+    );
+    check_flag!(
+        doc = "PCLMULQDQ (leaf 0x01).",
+        has_pclmulqdq,
+        edx_ecx,
+        FeatureInfoFlags::PCLMULQDQ
+    );
+    check_flag!(
+        doc = "AVX2 (leaf 0x07).",
+        has_avx2,
+        ebx7,
+        ExtendedFeaturesEbx::AVX2
+    );
+    check_flag!(
+        doc = "BMI1 (leaf 0x07).",
+        has_bmi1,
+        ebx7,
+        ExtendedFeaturesEbx::BMI1
+    );
+    check_flag!(
+        doc = "BMI2 (leaf 0x07).",
+        has_bmi2,
+        ebx7,
+        ExtendedFeaturesEbx::BMI2
+    );
+    check_flag!(
+        doc = "ADX (leaf 0x07).",
+        has_adx,
+        ebx7,
+        ExtendedFeaturesEbx::ADX
+    );
+    check_flag!(
+        doc = "SHA (leaf 0x07).",
+        has_sha,
+        ebx7,
+        ExtendedFeaturesEbx::SHA
+    );
+    check_flag!(
+        doc = "VAES (leaf 0x07).",
+        has_vaes,
+        ecx7,
+        ExtendedFeaturesEcx::VAES
+    );
+    check_flag!(
+        doc = "GFNI (leaf 0x07).",
+        has_gfni,
+        ecx7,
+        ExtendedFeaturesEcx::GFNI
+    );
+    check_flag!(
+        doc = "VPCLMULQDQ (leaf 0x07).",
+        has_vpclmulqdq,
+        ecx7,
+        ExtendedFeaturesEcx::VPCLMULQDQ
+    );
+    check_flag!(
+        doc = "AVX512F (leaf 0x07).",
+        has_avx512f,
+        ebx7,
+        ExtendedFeaturesEbx::AVX512F
+    );
+    check_flag!(
+        doc = "AVX512DQ (leaf 0x07).",
+        has_avx512dq,
+        ebx7,
+        ExtendedFeaturesEbx::AVX512DQ
+    );
+    check_flag!(
+        doc = "AVX512_IFMA (leaf 0x07).",
+        has_avx512_ifma,
+        ebx7,
+        ExtendedFeaturesEbx::AVX512_IFMA
+    );
+    check_flag!(
+        doc = "AVX512PF (leaf 0x07).",
+        has_avx512pf,
+        ebx7,
+        ExtendedFeaturesEbx::AVX512PF
+    );
+    check_flag!(
+        doc = "AVX512ER (leaf 0x07).",
+        has_avx512er,
+        ebx7,
+        ExtendedFeaturesEbx::AVX512ER
+    );
+    check_flag!(
+        doc = "AVX512CD (leaf 0x07).",
+        has_avx512cd,
+        ebx7,
+        ExtendedFeaturesEbx::AVX512CD
+    );
+    check_flag!(
+        doc = "AVX512BW (leaf 0x07).",
+        has_avx512bw,
+        ebx7,
+        ExtendedFeaturesEbx::AVX512BW
+    );
+    check_flag!(
+        doc = "AVX512VL (leaf 0x07).",
+        has_avx512vl,
+        ebx7,
+        ExtendedFeaturesEbx::AVX512VL
+    );
+    check_flag!(
+        doc = "AVX512_VBMI (leaf 0x07).",
+        has_avx512vbmi,
+        ecx7,
+        ExtendedFeaturesEcx::AVX512VBMI
+    );
+    check_flag!(
+        doc = "AVX512_VBMI2 (leaf 0x07).",
+        has_avx512vbmi2,
+        ecx7,
+        ExtendedFeaturesEcx::AVX512VBMI2
+    );
+    check_flag!(
+        doc = "AVX512_VNNI (leaf 0x07).",
+        has_avx512vnni,
+        ecx7,
+        ExtendedFeaturesEcx::AVX512VNNI
+    );
+    check_flag!(
+        doc = "AVX512_BITALG (leaf 0x07).",
+        has_avx512bitalg,
+        ecx7,
+        ExtendedFeaturesEcx::AVX512BITALG
+    );
+    check_flag!(
+        doc = "AVX512_VPOPCNTDQ (leaf 0x07).",
+        has_avx512vpopcntdq,
+        ecx7,
+        ExtendedFeaturesEcx::AVX512VPOPCNTDQ
+    );
+    check_flag!(
+        doc = "AVX512_4VNNIW (leaf 0x07).",
+        has_avx512_4vnniw,
+        edx7,
+        ExtendedFeaturesEdx::AVX512_4VNNIW
+    );
+    check_flag!(
+        doc = "AVX512_4FMAPS (leaf 0x07).",
+        has_avx512_4fmaps,
+        edx7,
+        ExtendedFeaturesEdx::AVX512_4FMAPS
+    );
+    check_flag!(
+        doc = "AVX512_VP2INTERSECT (leaf 0x07).",
+        has_avx512_vp2intersect,
+        edx7,
+        ExtendedFeaturesEdx::AVX512_VP2INTERSECT
+    );
+    check_flag!(
+        doc = "AVX512_FP16 (leaf 0x07).",
+        has_avx512_fp16,
+        edx7,
+        ExtendedFeaturesEdx::AVX512_FP16
+    );
 }
 
-bitflags! {
-    #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    struct ExtendedFeaturesEdx: u32 {
-        /// Bit 02: AVX512_4VNNIW. (Intel® Xeon Phi™ only).
-        const AVX512_4VNNIW = 1 << 2;
-        /// Bit 03: AVX512_4FMAPS. (Intel® Xeon Phi™ only).
-        const AVX512_4FMAPS = 1 << 3;
-        /// Bit 08: AVX512_VP2INTERSECT.
-        const AVX512_VP2INTERSECT = 1 << 8;
-        /// Bit 22: AMX-BF16. If 1, the processor supports tile computational operations on bfloat16 numbers.
-        const AMX_BF16 = 1 << 22;
-        /// Bit 23: AVX512_FP16.
-        const AVX512_FP16 = 1 << 23;
-        /// Bit 24: AMX-TILE. If 1, the processor supports tile architecture
-        const AMX_TILE = 1 << 24;
-        /// Bit 25: AMX-INT8. If 1, the processor supports tile computational operations on 8-bit integers.
-        const AMX_INT8 = 1 << 25;
+impl Debug for CpuCapabilities {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CpuCapabilities")
+            .field("edx_ecx", &self.edx_ecx)
+            .field("ebx7", &self.ebx7)
+            .field("ecx7", &self.ecx7)
+            .field("edx7", &self.edx7)
+            .finish()
     }
 }
 
-bitflags! {
-    #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    struct ExtendedFeaturesEax1: u32 {
-        // Some of the Unimplemented bits are reserved and maybe release in future CPUs, see Intel SDM for future features (Date of comment: 07.17.2024)
-        /// Bit 04: AVX_VNNI. AVX (VEX-encoded) versions of the Vector Neural Network Instructions.
-        const AVX_VNNI = 1 << 4;
-        /// Bit 05: AVX512_BF16. Vector Neural Network Instructions supporting BFLOAT16 inputs and conversion instructions from IEEE single precision.
-        const AVX512_BF16 = 1 << 5;
-        /// Bit 10: If 1, supports fast zero-length REP MOVSB.
-        const FZRM = 1 << 10;
-        /// Bit 11: If 1, supports fast short REP STOSB.
-        const FSRS = 1 << 11;
-        /// Bit 12: If 1, supports fast short REP CMPSB, REP SCASB.
-        const FSRCRS = 1 << 12;
-        /// Bit 22:  If 1, supports history reset via the HRESET instruction and the IA32_HRESET_ENABLE MSR. When set, indicates that the Processor History Reset Leaf (EAX = 20H) is valid.
-        const HRESET = 1 << 22;
+/// Micro-architecture levels of the x86-64 psABI.
+///
+/// Each level includes all the features of the previous levels. See
+/// <https://gitlab.com/x86-psABIs/x86-64-ABI> for the definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum X86_64AbiLevel {
+    /// Baseline x86-64 (CMOV, CX8, FPU, FXSR, MMX, SCE, SSE, SSE2).
+    V1,
+    /// Adds CMPXCHG16B, LAHF-SAHF, POPCNT, SSE3, SSE4.1, SSE4.2, SSSE3.
+    V2,
+    /// Adds AVX, AVX2, BMI1, BMI2, F16C, FMA, LZCNT, MOVBE, OSXSAVE.
+    V3,
+    /// Adds AVX512F, AVX512BW, AVX512CD, AVX512DQ, AVX512VL.
+    V4,
+}
+
+impl X86_64AbiLevel {
+    /// The next higher level.
+    pub fn next(&self) -> Option<X86_64AbiLevel> {
+        match self {
+            X86_64AbiLevel::V1 => Some(X86_64AbiLevel::V2),
+            X86_64AbiLevel::V2 => Some(X86_64AbiLevel::V3),
+            X86_64AbiLevel::V3 => Some(X86_64AbiLevel::V4),
+            X86_64AbiLevel::V4 => None,
+        }
     }
 }
 
-bitflags! {
-    #[repr(transparent)]
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    struct ExtendedFeaturesEdx1: u32 {
-        // Some of the Unimplemented bits are reserved and maybe release in future CPUs, see Intel SDM for future features (Date of comment: 07.17.2024)
-        /// Bit 18: CET_SSS. If 1, indicates that an operating system can enable supervisor shadow stacks as long as it ensures that a supervisor shadow stack cannot become prematurely busy due to page faults
-        const CET_SSS = 1 << 18;
+impl fmt::Display for X86_64AbiLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let level = match self {
+            X86_64AbiLevel::V1 => 1,
+            X86_64AbiLevel::V2 => 2,
+            X86_64AbiLevel::V3 => 3,
+            X86_64AbiLevel::V4 => 4,
+        };
+        write!(f, "x86-64-v{}", level)
     }
 }
 
@@ -4013,12 +7304,16 @@ impl Debug for DirectCacheAccessInfo {
 
 /// Info about performance monitoring -- how many counters etc. (LEAF=0x0A)
 ///
+/// Newer capabilities of the performance monitoring unit such as the
+/// UnitMask2 and EQ fields of IA32_PERFEVTSELx are enumerated in
+/// [`PerformanceMonitoringExtInfo`] (LEAF=0x23).
+///
 /// # Platforms
 /// ❌ AMD ✅ Intel
 pub struct PerformanceMonitoringInfo {
     eax: u32,
     ebx: PerformanceMonitoringFeaturesEbx,
-    _ecx: u32,
+    ecx: u32,
     edx: u32,
 }
 
@@ -4053,6 +7348,26 @@ impl PerformanceMonitoringInfo {
         get_bits(self.edx, 5, 12) as u8
     }
 
+    /// Supported fixed-function counters bit mask (if Version ID > 4), bit n
+    /// is set if IA32_FIXED_CTRn is supported.
+    ///
+    /// Prefer [`PerformanceMonitoringInfo::is_fixed_counter_supported`] which
+    /// also takes the counters enumerated in EDX into account.
+    pub fn fixed_counter_bitmap(&self) -> u32 {
+        self.ecx
+    }
+
+    /// Is fixed-function counter IA32_FIXED_CTR`n` supported?
+    ///
+    /// A counter is supported if its bit is set in
+    /// [`PerformanceMonitoringInfo::fixed_counter_bitmap`] or if it is among
+    /// the first [`PerformanceMonitoringInfo::fixed_function_counters`]
+    /// contiguous counters.
+    pub fn is_fixed_counter_supported(&self, n: u8) -> bool {
+        let in_bitmap = n < 32 && self.ecx & (1 << n) > 0;
+        in_bitmap || self.fixed_function_counters() > n
+    }
+
     check_bit_fn!(
         doc = "AnyThread deprecation",
         has_any_thread_deprecation,
@@ -4108,6 +7423,13 @@ impl PerformanceMonitoringInfo {
         ebx,
         PerformanceMonitoringFeaturesEbx::BRANCH_MISPRED_EV_UNAVAILABLE
     );
+
+    check_flag!(
+        doc = "Top-down slots event not available if 1.",
+        is_topdown_slots_ev_unavailable,
+        ebx,
+        PerformanceMonitoringFeaturesEbx::TOPDOWN_SLOTS_EV_UNAVAILABLE
+    );
 }
 
 impl Debug for PerformanceMonitoringInfo {
@@ -4122,6 +7444,7 @@ impl Debug for PerformanceMonitoringInfo {
                 "fixed_function_counters_bit_width",
                 &self.fixed_function_counters_bit_width(),
             )
+            .field("fixed_counter_bitmap", &self.fixed_counter_bitmap())
             .finish()
     }
 }
@@ -4144,6 +7467,197 @@ bitflags! {
         const BRANCH_INST_RET_EV_UNAVAILABLE = 1 << 5;
         /// Branch mispredict retired event not available if 1. (Bit 06)
         const BRANCH_MISPRED_EV_UNAVAILABLE = 1 << 6;
+        /// Top-down slots event not available if 1. (Bit 07)
+        const TOPDOWN_SLOTS_EV_UNAVAILABLE = 1 << 7;
+    }
+}
+
+/// Extended architectural performance monitoring (LEAF=0x23).
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PerformanceMonitoringExtInfo {
+    eax: u32,
+    ebx: u32,
+    general_counters: u32,
+    fixed_counters: u32,
+    events: ArchitecturalEvents,
+}
+
+impl PerformanceMonitoringExtInfo {
+    /// Bit vector of the valid sub-leafs (bit n is set if sub-leaf n is valid).
+    pub fn valid_subleafs(&self) -> u32 {
+        self.eax
+    }
+
+    check_bit_fn!(
+        doc = "UnitMask2 field in the IA32_PERFEVTSELx MSRs is supported.",
+        has_umask2,
+        ebx,
+        0
+    );
+
+    check_bit_fn!(
+        doc = "EQ (equal) bit in the IA32_PERFEVTSELx MSRs is supported.",
+        has_eq,
+        ebx,
+        1
+    );
+
+    /// Bit vector of the general-purpose counters, bit n is set if IA32_PMCn
+    /// exists (0 if sub-leaf 1 is not valid).
+    pub fn general_counters(&self) -> u32 {
+        self.general_counters
+    }
+
+    /// Bit vector of the fixed-function counters, bit n is set if
+    /// IA32_FIXED_CTRn exists (0 if sub-leaf 1 is not valid).
+    pub fn fixed_counters(&self) -> u32 {
+        self.fixed_counters
+    }
+
+    /// Bit vector of the supported architectural events, including events this
+    /// library doesn't know yet (0 if sub-leaf 3 is not valid).
+    pub fn events(&self) -> u32 {
+        self.events.bits()
+    }
+
+    check_flag!(
+        doc = "Core cycles event is supported.",
+        has_core_cycles_ev,
+        events,
+        ArchitecturalEvents::CORE_CYCLES
+    );
+
+    check_flag!(
+        doc = "Instructions retired event is supported.",
+        has_inst_retired_ev,
+        events,
+        ArchitecturalEvents::INST_RETIRED
+    );
+
+    check_flag!(
+        doc = "Reference cycles event is supported.",
+        has_ref_cycles_ev,
+        events,
+        ArchitecturalEvents::REF_CYCLES
+    );
+
+    check_flag!(
+        doc = "Last-level cache references event is supported.",
+        has_llc_references_ev,
+        events,
+        ArchitecturalEvents::LLC_REFERENCES
+    );
+
+    check_flag!(
+        doc = "Last-level cache misses event is supported.",
+        has_llc_misses_ev,
+        events,
+        ArchitecturalEvents::LLC_MISSES
+    );
+
+    check_flag!(
+        doc = "Branch instructions retired event is supported.",
+        has_branch_inst_retired_ev,
+        events,
+        ArchitecturalEvents::BRANCH_INST_RETIRED
+    );
+
+    check_flag!(
+        doc = "Branch mispredicts retired event is supported.",
+        has_branch_mispredicts_retired_ev,
+        events,
+        ArchitecturalEvents::BRANCH_MISPREDICTS_RETIRED
+    );
+
+    check_flag!(
+        doc = "Topdown slots event is supported.",
+        has_topdown_slots_ev,
+        events,
+        ArchitecturalEvents::TOPDOWN_SLOTS
+    );
+
+    check_flag!(
+        doc = "Topdown backend bound event is supported.",
+        has_topdown_backend_bound_ev,
+        events,
+        ArchitecturalEvents::TOPDOWN_BACKEND_BOUND
+    );
+
+    check_flag!(
+        doc = "Topdown bad speculation event is supported.",
+        has_topdown_bad_speculation_ev,
+        events,
+        ArchitecturalEvents::TOPDOWN_BAD_SPECULATION
+    );
+
+    check_flag!(
+        doc = "Topdown frontend bound event is supported.",
+        has_topdown_frontend_bound_ev,
+        events,
+        ArchitecturalEvents::TOPDOWN_FRONTEND_BOUND
+    );
+
+    check_flag!(
+        doc = "Topdown retiring event is supported.",
+        has_topdown_retiring_ev,
+        events,
+        ArchitecturalEvents::TOPDOWN_RETIRING
+    );
+
+    check_flag!(
+        doc = "LBR inserts event is supported.",
+        has_lbr_inserts_ev,
+        events,
+        ArchitecturalEvents::LBR_INSERTS
+    );
+}
+
+impl Debug for PerformanceMonitoringExtInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PerformanceMonitoringExtInfo")
+            .field("valid_subleafs", &self.valid_subleafs())
+            .field("has_umask2", &self.has_umask2())
+            .field("has_eq", &self.has_eq())
+            .field("general_counters", &self.general_counters())
+            .field("fixed_counters", &self.fixed_counters())
+            .field("events", &self.events)
+            .finish()
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct ArchitecturalEvents: u32 {
+        /// Core cycles. (Bit 00)
+        const CORE_CYCLES = 1 << 0;
+        /// Instructions retired. (Bit 01)
+        const INST_RETIRED = 1 << 1;
+        /// Reference cycles. (Bit 02)
+        const REF_CYCLES = 1 << 2;
+        /// Last-level cache references. (Bit 03)
+        const LLC_REFERENCES = 1 << 3;
+        /// Last-level cache misses. (Bit 04)
+        const LLC_MISSES = 1 << 4;
+        /// Branch instructions retired. (Bit 05)
+        const BRANCH_INST_RETIRED = 1 << 5;
+        /// Branch mispredicts retired. (Bit 06)
+        const BRANCH_MISPREDICTS_RETIRED = 1 << 6;
+        /// Topdown slots. (Bit 07)
+        const TOPDOWN_SLOTS = 1 << 7;
+        /// Topdown backend bound. (Bit 08)
+        const TOPDOWN_BACKEND_BOUND = 1 << 8;
+        /// Topdown bad speculation. (Bit 09)
+        const TOPDOWN_BAD_SPECULATION = 1 << 9;
+        /// Topdown frontend bound. (Bit 10)
+        const TOPDOWN_FRONTEND_BOUND = 1 << 10;
+        /// Topdown retiring. (Bit 11)
+        const TOPDOWN_RETIRING = 1 << 11;
+        /// LBR inserts. (Bit 12)
+        const LBR_INSERTS = 1 << 12;
     }
 }
 
@@ -4160,6 +7674,8 @@ pub struct ExtendedTopologyIter<R: CpuIdReader> {
     read: R,
     level: u32,
     is_v2: bool,
+    /// Set once we've seen the invalid level, no more CPUID after that.
+    done: bool,
 }
 
 /// Gives information about the current level in the topology.
@@ -4199,14 +7715,15 @@ impl ExtendedTopologyLevel {
 
     // Level type.
     pub fn level_type(&self) -> TopologyType {
-        match get_bits(self.ecx, 8, 15) {
+        match get_bits(self.ecx, 8, 15) as u8 {
             0 => TopologyType::Invalid,
             1 => TopologyType::SMT,
             2 => TopologyType::Core,
             3 => TopologyType::Module,
             4 => TopologyType::Tile,
             5 => TopologyType::Die,
-            _ => unreachable!(),
+            6 => TopologyType::DieGrp,
+            typ => TopologyType::Unknown(typ),
         }
     }
 
@@ -4224,14 +7741,19 @@ impl ExtendedTopologyLevel {
 
 /// What type of core we have at this level in the topology (real CPU or hyper-threaded).
 #[derive(PartialEq, Eq, Debug)]
+#[non_exhaustive]
 pub enum TopologyType {
-    Invalid = 0,
+    Invalid,
     /// Hyper-thread (Simultaneous multithreading)
-    SMT = 1,
-    Core = 2,
-    Module = 3,
-    Tile = 4,
-    Die = 5,
+    SMT,
+    Core,
+    Module,
+    Tile,
+    Die,
+    /// Group of dies (LEAF=0x1F only).
+    DieGrp,
+    /// A level type this library doesn't know yet, the raw encoding.
+    Unknown(u8),
 }
 
 impl fmt::Display for TopologyType {
@@ -4243,6 +7765,8 @@ impl fmt::Display for TopologyType {
             TopologyType::Module => "Module",
             TopologyType::Tile => "Tile",
             TopologyType::Die => "Die",
+            TopologyType::DieGrp => "DieGrp",
+            TopologyType::Unknown(typ) => return write!(f, "Unknown ({})", typ),
         };
 
         f.write_str(data)
@@ -4253,6 +7777,10 @@ impl<R: CpuIdReader> Iterator for ExtendedTopologyIter<R> {
     type Item = ExtendedTopologyLevel;
 
     fn next(&mut self) -> Option<ExtendedTopologyLevel> {
+        if self.done {
+            return None;
+        }
+
         let res = if self.is_v2 {
             self.read.cpuid2(EAX_EXTENDED_TOPOLOGY_INFO_V2, self.level)
         } else {
@@ -4268,12 +7796,17 @@ impl<R: CpuIdReader> Iterator for ExtendedTopologyIter<R> {
         };
 
         match et.level_type() {
-            TopologyType::Invalid => None,
+            TopologyType::Invalid => {
+                self.done = true;
+                None
+            }
             _ => Some(et),
         }
     }
 }
 
+impl<R: CpuIdReader> FusedIterator for ExtendedTopologyIter<R> {}
+
 impl<R: CpuIdReader> Debug for ExtendedTopologyIter<R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let mut debug = f.debug_list();
@@ -4323,6 +7856,9 @@ bitflags! {
 
         /// AMX TILEDATA state (Bit 17)
         const AMX_TILEDATA = 1 << 18;
+
+        /// APX extended GPRs state (Bit 19)
+        const APX = 1 << 19;
     }
 }
 
@@ -4369,7 +7905,7 @@ pub struct ExtendedStateInfo<R: CpuIdReader> {
     eax1: u32,
     ebx1: u32,
     ecx1: ExtendedStateInfoXSSFlags,
-    _edx1: u32,
+    edx1: u32,
 }
 
 impl<F: CpuIdReader> ExtendedStateInfo<F> {
@@ -4436,6 +7972,13 @@ impl<F: CpuIdReader> ExtendedStateInfo<F> {
         ExtendedStateInfoXCR0Flags::PKRU
     );
 
+    check_flag!(
+        doc = "Support for the APX extended GPRs (R16 to R31) in XCR0.",
+        xcr0_supports_apx,
+        eax,
+        ExtendedStateInfoXCR0Flags::APX
+    );
+
     check_flag!(
         doc = "Support for PT in IA32_XSS.",
         ia32_xss_supports_pt,
@@ -4443,6 +7986,20 @@ impl<F: CpuIdReader> ExtendedStateInfo<F> {
         ExtendedStateInfoXSSFlags::PT
     );
 
+    check_flag!(
+        doc = "Support for CET user state (IA32_U_CET and IA32_PL3_SSP) in IA32_XSS.",
+        ia32_xss_supports_cet_user,
+        ecx1,
+        ExtendedStateInfoXSSFlags::CET_USER
+    );
+
+    check_flag!(
+        doc = "Support for CET supervisor state (IA32_PL0_SSP to IA32_PL2_SSP) in IA32_XSS.",
+        ia32_xss_supports_cet_supervisor,
+        ecx1,
+        ExtendedStateInfoXSSFlags::CET_SUPERVISOR
+    );
+
     check_flag!(
         doc = "Support for HDC in IA32_XSS.",
         ia32_xss_supports_hdc,
@@ -4489,6 +8046,12 @@ impl<F: CpuIdReader> ExtendedStateInfo<F> {
         self.ebx1
     }
 
+    /// Bit mask of all state components that can be set in the IA32_XSS MSR
+    /// (ECX for the lower and EDX for the upper 32 bits).
+    pub fn ia32_xss_supported_mask(&self) -> u64 {
+        self.ecx1.bits() as u64 | (self.edx1 as u64) << 32
+    }
+
     /// Iterator over extended state enumeration levels >= 2.
     pub fn iter(&self) -> ExtendedStateIter<F> {
         ExtendedStateIter {
@@ -4518,6 +8081,7 @@ impl<R: CpuIdReader> Debug for ExtendedStateInfo<R> {
             .field("has_xgetbv", &self.has_xgetbv())
             .field("has_xsaves_xrstors", &self.has_xsaves_xrstors())
             .field("xsave_size", &self.xsave_size())
+            .field("ia32_xss_supported_mask", &self.ia32_xss_supported_mask())
             .field("extended_state_iter", &self.iter())
             .finish()
     }
@@ -4542,26 +8106,27 @@ impl<R: CpuIdReader> Iterator for ExtendedStateIter<R> {
     type Item = ExtendedState;
 
     fn next(&mut self) -> Option<ExtendedState> {
-        self.level += 1;
-        if self.level > 31 {
-            return None;
-        }
-
-        let bit = 1 << self.level;
-        if (self.supported_xcr0 & bit > 0) || (self.supported_xss & bit > 0) {
-            let res = self.read.cpuid2(EAX_EXTENDED_STATE_INFO, self.level);
-            return Some(ExtendedState {
-                subleaf: self.level,
-                eax: res.eax,
-                ebx: res.ebx,
-                ecx: res.ecx,
-            });
+        while self.level < 31 {
+            self.level += 1;
+
+            let bit = 1 << self.level;
+            if (self.supported_xcr0 & bit > 0) || (self.supported_xss & bit > 0) {
+                let res = self.read.cpuid2(EAX_EXTENDED_STATE_INFO, self.level);
+                return Some(ExtendedState {
+                    component: XSaveComponent::from(self.level),
+                    eax: res.eax,
+                    ebx: res.ebx,
+                    ecx: res.ecx,
+                });
+            }
         }
 
-        self.next()
+        None
     }
 }
 
+impl<R: CpuIdReader> FusedIterator for ExtendedStateIter<R> {}
+
 impl<R: CpuIdReader> Debug for ExtendedStateIter<R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let mut debug = f.debug_list();
@@ -4584,7 +8149,10 @@ pub enum ExtendedRegisterType {
     Avx512ZmmHi16,
     Pt,
     Pkru,
+    CetUser,
+    CetSupervisor,
     Hdc,
+    Apx,
     Unknown(u32),
 }
 
@@ -4599,7 +8167,10 @@ impl From<u32> for ExtendedRegisterType {
             0x7 => ExtendedRegisterType::Avx512ZmmHi16,
             0x8 => ExtendedRegisterType::Pt,
             0x9 => ExtendedRegisterType::Pkru,
+            0xb => ExtendedRegisterType::CetUser,
+            0xc => ExtendedRegisterType::CetSupervisor,
             0xd => ExtendedRegisterType::Hdc,
+            0x13 => ExtendedRegisterType::Apx,
             x => ExtendedRegisterType::Unknown(x),
         }
     }
@@ -4616,7 +8187,10 @@ impl fmt::Display for ExtendedRegisterType {
             ExtendedRegisterType::Avx512ZmmHi16 => "AVX-512 Hi16_ZMM",
             ExtendedRegisterType::Pkru => "PKRU",
             ExtendedRegisterType::Pt => "PT",
+            ExtendedRegisterType::CetUser => "CET_U",
+            ExtendedRegisterType::CetSupervisor => "CET_S",
             ExtendedRegisterType::Hdc => "HDC",
+            ExtendedRegisterType::Apx => "APX",
             ExtendedRegisterType::Unknown(t) => {
                 return write!(f, "Unknown({})", t);
             }
@@ -4626,6 +8200,134 @@ impl fmt::Display for ExtendedRegisterType {
     }
 }
 
+/// An XSAVE state component, identified by its bit in XCR0/IA32_XSS (which is
+/// also the sub-leaf of LEAF=0x0D describing it).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum XSaveComponent {
+    /// x87 FPU state (0)
+    X87,
+    /// SSE state, XMM registers and MXCSR (1)
+    Sse,
+    /// AVX state, upper halves of the YMM registers (2)
+    Avx,
+    /// MPX bound registers BND0-BND3 (3)
+    MpxBndregs,
+    /// MPX configuration and status registers BNDCFGU and BNDSTATUS (4)
+    MpxBndcsr,
+    /// AVX-512 opmask registers k0-k7 (5)
+    Avx512Opmask,
+    /// AVX-512 upper halves of ZMM0-ZMM15 (6)
+    Avx512ZmmHi256,
+    /// AVX-512 registers ZMM16-ZMM31 (7)
+    Avx512ZmmHi16,
+    /// Processor Trace state (8)
+    Pt,
+    /// Protection key register PKRU (9)
+    Pkru,
+    /// CET user state (11)
+    CetUser,
+    /// CET supervisor state (12)
+    CetSupervisor,
+    /// Hardware Duty Cycling state (13)
+    Hdc,
+    /// Architectural LBR state (15)
+    Lbr,
+    /// AMX tile configuration register TILECFG (17)
+    TileCfg,
+    /// AMX tile data registers TMM0-TMM7 (18)
+    TileData,
+    /// APX extended general purpose registers R16-R31 (19)
+    Apx,
+    /// A component this library does not know about.
+    Unknown(u32),
+}
+
+impl XSaveComponent {
+    /// The component's bit in XCR0/IA32_XSS and sub-leaf index in LEAF=0x0D.
+    pub fn index(&self) -> u32 {
+        match self {
+            XSaveComponent::X87 => 0x0,
+            XSaveComponent::Sse => 0x1,
+            XSaveComponent::Avx => 0x2,
+            XSaveComponent::MpxBndregs => 0x3,
+            XSaveComponent::MpxBndcsr => 0x4,
+            XSaveComponent::Avx512Opmask => 0x5,
+            XSaveComponent::Avx512ZmmHi256 => 0x6,
+            XSaveComponent::Avx512ZmmHi16 => 0x7,
+            XSaveComponent::Pt => 0x8,
+            XSaveComponent::Pkru => 0x9,
+            XSaveComponent::CetUser => 0xb,
+            XSaveComponent::CetSupervisor => 0xc,
+            XSaveComponent::Hdc => 0xd,
+            XSaveComponent::Lbr => 0xf,
+            XSaveComponent::TileCfg => 0x11,
+            XSaveComponent::TileData => 0x12,
+            XSaveComponent::Apx => 0x13,
+            XSaveComponent::Unknown(x) => *x,
+        }
+    }
+}
+
+impl From<u32> for XSaveComponent {
+    fn from(value: u32) -> XSaveComponent {
+        match value {
+            0x0 => XSaveComponent::X87,
+            0x1 => XSaveComponent::Sse,
+            0x2 => XSaveComponent::Avx,
+            0x3 => XSaveComponent::MpxBndregs,
+            0x4 => XSaveComponent::MpxBndcsr,
+            0x5 => XSaveComponent::Avx512Opmask,
+            0x6 => XSaveComponent::Avx512ZmmHi256,
+            0x7 => XSaveComponent::Avx512ZmmHi16,
+            0x8 => XSaveComponent::Pt,
+            0x9 => XSaveComponent::Pkru,
+            0xb => XSaveComponent::CetUser,
+            0xc => XSaveComponent::CetSupervisor,
+            0xd => XSaveComponent::Hdc,
+            0xf => XSaveComponent::Lbr,
+            0x11 => XSaveComponent::TileCfg,
+            0x12 => XSaveComponent::TileData,
+            0x13 => XSaveComponent::Apx,
+            x => XSaveComponent::Unknown(x),
+        }
+    }
+}
+
+impl From<XSaveComponent> for u32 {
+    fn from(component: XSaveComponent) -> u32 {
+        component.index()
+    }
+}
+
+impl fmt::Display for XSaveComponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let data = match self {
+            XSaveComponent::X87 => "x87",
+            XSaveComponent::Sse => "SSE",
+            XSaveComponent::Avx => "AVX/YMM",
+            XSaveComponent::MpxBndregs => "MPX BNDREGS",
+            XSaveComponent::MpxBndcsr => "MPX BNDCSR",
+            XSaveComponent::Avx512Opmask => "AVX-512 opmask",
+            XSaveComponent::Avx512ZmmHi256 => "AVX-512 ZMM_Hi256",
+            XSaveComponent::Avx512ZmmHi16 => "AVX-512 Hi16_ZMM",
+            XSaveComponent::Pt => "PT",
+            XSaveComponent::Pkru => "PKRU",
+            XSaveComponent::CetUser => "CET_U",
+            XSaveComponent::CetSupervisor => "CET_S",
+            XSaveComponent::Hdc => "HDC",
+            XSaveComponent::Lbr => "LBR",
+            XSaveComponent::TileCfg => "AMX TILECFG",
+            XSaveComponent::TileData => "AMX TILEDATA",
+            XSaveComponent::Apx => "APX",
+            XSaveComponent::Unknown(t) => {
+                return write!(f, "Unknown({})", t);
+            }
+        };
+
+        f.write_str(data)
+    }
+}
+
 /// Where the extended register state is stored.
 #[derive(PartialEq, Eq, Debug)]
 pub enum ExtendedRegisterStateLocation {
@@ -4646,16 +8348,28 @@ impl fmt::Display for ExtendedRegisterStateLocation {
 
 /// ExtendedState subleaf structure for things that need to be restored.
 pub struct ExtendedState {
-    pub subleaf: u32,
+    component: XSaveComponent,
     eax: u32,
     ebx: u32,
     ecx: u32,
 }
 
 impl ExtendedState {
+    /// Returns which state component this specific extended subleaf contains
+    /// information for.
+    pub fn component(&self) -> XSaveComponent {
+        self.component
+    }
+
+    /// The sub-leaf index (and XCR0/IA32_XSS bit) of this state component.
+    pub fn subleaf(&self) -> u32 {
+        self.component.index()
+    }
+
     /// Returns which register this specific extended subleaf contains information for.
+    #[deprecated(since = "11.4.0", note = "Use `component` instead")]
     pub fn register(&self) -> ExtendedRegisterType {
-        self.subleaf.into()
+        self.subleaf().into()
     }
 
     /// The size in bytes (from the offset specified in EBX) of the save area
@@ -4703,16 +8417,25 @@ impl ExtendedState {
     pub fn is_compacted_format(&self) -> bool {
         self.ecx & 0b10 > 0
     }
+
+    /// Returns true if the state component supports extended feature
+    /// disable (XFD), i.e., its bit can be set in IA32_XFD to make the first
+    /// use of the component fault (used for lazily allocating AMX tile data).
+    pub fn is_xfd_supported(&self) -> bool {
+        self.ecx & 0b100 > 0
+    }
 }
 
 impl Debug for ExtendedState {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("ExtendedState")
+            .field("component", &self.component())
             .field("size", &self.size())
             .field("offset", &self.offset())
             .field("is_in_ia32_xss", &self.is_in_ia32_xss())
             .field("is_in_xcr0", &self.is_in_xcr0())
             .field("is_compacted_format", &self.is_compacted_format())
+            .field("is_xfd_supported", &self.is_xfd_supported())
             .finish()
     }
 }
@@ -4746,6 +8469,7 @@ impl<R: CpuIdReader> RdtMonitoringInfo<R> {
         if self.has_l3_monitoring() {
             let res = self.read.cpuid2(EAX_RDT_MONITORING, 1);
             Some(L3MonitoringInfo {
+                eax: res.eax,
                 ebx: res.ebx,
                 ecx: res.ecx,
                 edx: res.edx,
@@ -4767,6 +8491,7 @@ impl<R: CpuIdReader> Debug for RdtMonitoringInfo<R> {
 
 /// Information about L3 cache monitoring.
 pub struct L3MonitoringInfo {
+    eax: u32,
     ebx: u32,
     ecx: u32,
     edx: u32,
@@ -4778,6 +8503,25 @@ impl L3MonitoringInfo {
         self.ebx
     }
 
+    /// Offset of the IA32_QM_CTR counter width from 24 bits.
+    pub fn counter_width_offset(&self) -> u8 {
+        get_bits(self.eax, 0, 7) as u8
+    }
+
+    /// Width of the IA32_QM_CTR counter in bits (24 + counter width offset).
+    ///
+    /// Bandwidth counters wrap around at `1 << counter_width()`.
+    pub fn counter_width(&self) -> u8 {
+        self.counter_width_offset().saturating_add(24)
+    }
+
+    check_bit_fn!(
+        doc = "Is bit 61 of IA32_QM_CTR an overflow bit?",
+        has_counter_overflow_bit,
+        eax,
+        8
+    );
+
     /// Maximum range (zero-based) of RMID of L3.
     pub fn maximum_rmid_range(&self) -> u32 {
         self.ecx
@@ -4809,6 +8553,8 @@ impl Debug for L3MonitoringInfo {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("L3MonitoringInfo")
             .field("conversion_factor", &self.conversion_factor())
+            .field("counter_width", &self.counter_width())
+            .field("has_counter_overflow_bit", &self.has_counter_overflow_bit())
             .field("maximum_rmid_range", &self.maximum_rmid_range())
             .finish()
     }
@@ -4857,6 +8603,7 @@ impl<R: CpuIdReader> RdtAllocationInfo<R> {
             Some(L2CatInfo {
                 eax: res.eax,
                 ebx: res.ebx,
+                ecx: res.ecx,
                 edx: res.edx,
             })
         } else {
@@ -4922,6 +8669,13 @@ impl L3CatInfo {
         ecx,
         2
     );
+
+    check_bit_fn!(
+        doc = "Can the bits set in the capacity bit masks be non-contiguous?",
+        has_noncontiguous_capacity_bitmask,
+        ecx,
+        3
+    );
 }
 
 impl Debug for L3CatInfo {
@@ -4930,6 +8684,10 @@ impl Debug for L3CatInfo {
             .field("capacity_mask_length", &self.capacity_mask_length())
             .field("isolation_bitmap", &self.isolation_bitmap())
             .field("highest_cos", &self.highest_cos())
+            .field(
+                "has_noncontiguous_capacity_bitmask",
+                &self.has_noncontiguous_capacity_bitmask(),
+            )
             .finish()
     }
 }
@@ -4939,6 +8697,7 @@ impl Debug for L3CatInfo {
 pub struct L2CatInfo {
     eax: u32,
     ebx: u32,
+    ecx: u32,
     edx: u32,
 }
 
@@ -4953,10 +8712,24 @@ impl L2CatInfo {
         self.ebx
     }
 
-    /// Highest COS number supported for this Leaf.
-    pub fn highest_cos(&self) -> u16 {
-        get_bits(self.edx, 0, 15) as u16
-    }
+    /// Highest COS number supported for this Leaf.
+    pub fn highest_cos(&self) -> u16 {
+        get_bits(self.edx, 0, 15) as u16
+    }
+
+    check_bit_fn!(
+        doc = "Is L2 Code and Data Prioritization Technology supported?",
+        has_code_data_prioritization,
+        ecx,
+        2
+    );
+
+    check_bit_fn!(
+        doc = "Can the bits set in the capacity bit masks be non-contiguous?",
+        has_noncontiguous_capacity_bitmask,
+        ecx,
+        3
+    );
 }
 
 impl Debug for L2CatInfo {
@@ -4965,6 +8738,14 @@ impl Debug for L2CatInfo {
             .field("capacity_mask_length", &self.capacity_mask_length())
             .field("isolation_bitmap", &self.isolation_bitmap())
             .field("highest_cos", &self.highest_cos())
+            .field(
+                "has_code_data_prioritization",
+                &self.has_code_data_prioritization(),
+            )
+            .field(
+                "has_noncontiguous_capacity_bitmask",
+                &self.has_noncontiguous_capacity_bitmask(),
+            )
             .finish()
     }
 }
@@ -4988,6 +8769,14 @@ impl MemBwAllocationInfo {
         get_bits(self.edx, 0, 15) as u16
     }
 
+    check_bit_fn!(
+        doc = "Are per-thread MBA controls supported (MBA 2.0)? Otherwise the \
+               throttling value of a core is the maximum of its threads' values.",
+        has_per_thread_throttling,
+        ecx,
+        0
+    );
+
     check_bit_fn!(
         doc = "Reports whether the response of the delay values is linear.",
         has_linear_response_delay,
@@ -5001,6 +8790,10 @@ impl Debug for MemBwAllocationInfo {
         f.debug_struct("MemBwAllocationInfo")
             .field("max_hba_throttling", &self.max_hba_throttling())
             .field("highest_cos", &self.highest_cos())
+            .field(
+                "has_per_thread_throttling",
+                &self.has_per_thread_throttling(),
+            )
             .field(
                 "has_linear_response_delay",
                 &self.has_linear_response_delay(),
@@ -5018,13 +8811,11 @@ impl Debug for MemBwAllocationInfo {
 pub struct SgxInfo<R: CpuIdReader> {
     read: R,
     eax: u32,
-    ebx: u32,
+    miscselect: SgxMiscSelect,
     _ecx: u32,
     edx: u32,
-    eax1: u32,
-    ebx1: u32,
-    ecx1: u32,
-    edx1: u32,
+    attributes: SgxAttributes,
+    xfrm: u64,
 }
 
 impl<F: CpuIdReader> SgxInfo<F> {
@@ -5045,11 +8836,28 @@ impl<F: CpuIdReader> SgxInfo<F> {
         6
     );
 
-    /// Bit vector of supported extended SGX features.
+    /// Bit vector of supported extended SGX features (the raw MISCSELECT value,
+    /// including bits this library doesn't know yet).
     pub fn miscselect(&self) -> u32 {
-        self.ebx
+        self.miscselect.bits()
     }
 
+    check_flag!(
+        doc = "MISCSELECT.EXINFO: Enclaves can have the page-fault and general-protection \
+               exception information (#PF and #GP) reported in the SSA.",
+        has_exinfo,
+        miscselect,
+        SgxMiscSelect::EXINFO
+    );
+
+    check_flag!(
+        doc = "MISCSELECT.CPINFO: Enclaves can have the control protection exception \
+               information (#CP) reported in the SSA.",
+        has_cpinfo,
+        miscselect,
+        SgxMiscSelect::CPINFO
+    );
+
     ///  The maximum supported enclave size in non-64-bit mode is 2^retval.
     pub fn max_enclave_size_non_64bit(&self) -> u8 {
         get_bits(self.edx, 0, 7) as u8
@@ -5062,15 +8870,70 @@ impl<F: CpuIdReader> SgxInfo<F> {
 
     /// Reports the valid bits of SECS.ATTRIBUTES\[127:0\] that software can set with ECREATE.
     pub fn secs_attributes(&self) -> (u64, u64) {
-        let lower = self.eax1 as u64 | (self.ebx1 as u64) << 32;
-        let upper = self.ecx1 as u64 | (self.edx1 as u64) << 32;
-        (lower, upper)
+        (self.attributes.bits(), self.xfrm)
+    }
+
+    check_flag!(
+        doc = "ATTRIBUTES.DEBUG: Enclaves can be created with debugger read and write access.",
+        secs_supports_debug,
+        attributes,
+        SgxAttributes::DEBUG
+    );
+
+    check_flag!(
+        doc = "ATTRIBUTES.MODE64BIT: Enclaves can be created as 64-bit enclaves.",
+        secs_supports_mode64bit,
+        attributes,
+        SgxAttributes::MODE64BIT
+    );
+
+    check_flag!(
+        doc = "ATTRIBUTES.PROVISIONKEY: Enclaves can be given access to the provisioning key.",
+        secs_supports_provisionkey,
+        attributes,
+        SgxAttributes::PROVISIONKEY
+    );
+
+    check_flag!(
+        doc = "ATTRIBUTES.EINITTOKEN_KEY: Enclaves can be given access to the EINIT token key.",
+        secs_supports_einittokenkey,
+        attributes,
+        SgxAttributes::EINITTOKEN_KEY
+    );
+
+    check_flag!(
+        doc = "ATTRIBUTES.CET: Enclaves can enable CET.",
+        secs_supports_cet,
+        attributes,
+        SgxAttributes::CET
+    );
+
+    check_flag!(
+        doc = "ATTRIBUTES.KSS: Enclaves can use the Key Separation and Sharing fields \
+               (CONFIGID, CONFIGSVN, ISVEXTPRODID and ISVFAMILYID).",
+        secs_supports_kss,
+        attributes,
+        SgxAttributes::KSS
+    );
+
+    check_flag!(
+        doc = "ATTRIBUTES.AEXNOTIFY: Enclaves can be notified about asynchronous exits.",
+        secs_supports_aexnotify,
+        attributes,
+        SgxAttributes::AEXNOTIFY
+    );
+
+    /// ATTRIBUTES.XFRM (SECS.ATTRIBUTES\[127:64\]): XSAVE feature request mask,
+    /// the XCR0 bits an enclave can be created with.
+    pub fn secs_xfrm_mask(&self) -> u64 {
+        self.xfrm
     }
     /// Iterator over SGX sub-leafs.
     pub fn iter(&self) -> SgxSectionIter<F> {
         SgxSectionIter {
             read: self.read.clone(),
             current: 2,
+            done: false,
         }
     }
 }
@@ -5081,6 +8944,22 @@ impl<R: CpuIdReader> Debug for SgxInfo<R> {
             .field("has_sgx1", &self.has_sgx1())
             .field("has_sgx2", &self.has_sgx2())
             .field("miscselect", &self.miscselect())
+            .field("has_exinfo", &self.has_exinfo())
+            .field("has_cpinfo", &self.has_cpinfo())
+            .field("secs_supports_debug", &self.secs_supports_debug())
+            .field("secs_supports_mode64bit", &self.secs_supports_mode64bit())
+            .field(
+                "secs_supports_provisionkey",
+                &self.secs_supports_provisionkey(),
+            )
+            .field(
+                "secs_supports_einittokenkey",
+                &self.secs_supports_einittokenkey(),
+            )
+            .field("secs_supports_cet", &self.secs_supports_cet())
+            .field("secs_supports_kss", &self.secs_supports_kss())
+            .field("secs_supports_aexnotify", &self.secs_supports_aexnotify())
+            .field("secs_xfrm_mask", &self.secs_xfrm_mask())
             .field(
                 "max_enclave_size_non_64bit",
                 &self.max_enclave_size_non_64bit(),
@@ -5099,17 +8978,57 @@ impl<R: CpuIdReader> Debug for SgxInfo<R> {
     }
 }
 
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct SgxMiscSelect: u32 {
+        /// Bit 00: EXINFO. Report #PF and #GP information in the SSA MISC region.
+        const EXINFO = 1 << 0;
+        /// Bit 01: CPINFO. Report #CP information in the SSA MISC region.
+        const CPINFO = 1 << 1;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct SgxAttributes: u64 {
+        /// Bit 00: INIT. The enclave has been initialized by EINIT.
+        const INIT = 1 << 0;
+        /// Bit 01: DEBUG. Debugger can read and write enclave data.
+        const DEBUG = 1 << 1;
+        /// Bit 02: MODE64BIT. Enclave runs in 64-bit mode.
+        const MODE64BIT = 1 << 2;
+        /// Bit 04: PROVISIONKEY. Provisioning key is available from EGETKEY.
+        const PROVISIONKEY = 1 << 4;
+        /// Bit 05: EINITTOKEN_KEY. EINIT token key is available from EGETKEY.
+        const EINITTOKEN_KEY = 1 << 5;
+        /// Bit 06: CET. Enable CET attributes.
+        const CET = 1 << 6;
+        /// Bit 07: KSS. Key Separation and Sharing enabled.
+        const KSS = 1 << 7;
+        /// Bit 10: AEXNOTIFY. Threads may receive AEX notifications.
+        const AEXNOTIFY = 1 << 10;
+    }
+}
+
 /// Iterator over the SGX sub-leafs (ECX >= 2).
 #[derive(Clone)]
 pub struct SgxSectionIter<R: CpuIdReader> {
     read: R,
     current: u32,
+    /// Set once we've seen an invalid sub-leaf, no more CPUID after that.
+    done: bool,
 }
 
 impl<R: CpuIdReader> Iterator for SgxSectionIter<R> {
     type Item = SgxSectionInfo;
 
     fn next(&mut self) -> Option<SgxSectionInfo> {
+        if self.done {
+            return None;
+        }
+
         let res = self.read.cpuid2(EAX_SGX, self.current);
         self.current += 1;
         match get_bits(res.eax, 0, 3) {
@@ -5119,11 +9038,16 @@ impl<R: CpuIdReader> Iterator for SgxSectionIter<R> {
                 ecx: res.ecx,
                 edx: res.edx,
             })),
-            _ => None,
+            _ => {
+                self.done = true;
+                None
+            }
         }
     }
 }
 
+impl<R: CpuIdReader> FusedIterator for SgxSectionIter<R> {}
+
 impl<R: CpuIdReader> Debug for SgxSectionIter<R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let mut debug = f.debug_list();
@@ -5172,15 +9096,38 @@ impl EpcSection {
 ///
 /// # Platforms
 /// ❌ AMD ✅ Intel
+#[derive(Clone, Copy)]
 pub struct ProcessorTraceInfo {
-    _eax: u32,
+    eax: u32,
     ebx: u32,
     ecx: u32,
-    _edx: u32,
+    edx: u32,
     leaf1: Option<CpuIdResult>,
 }
 
 impl ProcessorTraceInfo {
+    /// Maximum valid sub-leaf index of LEAF=0x14.
+    pub fn max_subleaf(&self) -> u32 {
+        self.eax
+    }
+
+    /// The unmodified registers of sub-leaf 0, e.g., for a VMM that forwards
+    /// the Intel PT capabilities to a guest.
+    pub fn raw_subleaf0(&self) -> CpuIdResult {
+        CpuIdResult {
+            eax: self.eax,
+            ebx: self.ebx,
+            ecx: self.ecx,
+            edx: self.edx,
+        }
+    }
+
+    /// The unmodified registers of sub-leaf 1 (`None` if the processor
+    /// doesn't enumerate it).
+    pub fn raw_subleaf1(&self) -> Option<CpuIdResult> {
+        self.leaf1
+    }
+
     // EBX features
     check_bit_fn!(
         doc = "If true, Indicates that IA32_RTIT_CTL.CR3Filter can be set to 1, and \
@@ -5263,6 +9210,26 @@ impl ProcessorTraceInfo {
         31
     );
 
+    /// Whether trace output can be written using `scheme`.
+    pub fn supports_output_scheme(&self, scheme: TraceOutputScheme) -> bool {
+        match scheme {
+            TraceOutputScheme::Topa => self.has_topa(),
+            TraceOutputScheme::SingleRange => self.has_single_range_output_scheme(),
+            TraceOutputScheme::TraceTransport => self.has_trace_transport_subsystem(),
+        }
+    }
+
+    /// All supported output schemes.
+    pub fn output_schemes(&self) -> impl Iterator<Item = TraceOutputScheme> {
+        let info = *self;
+        IntoIterator::into_iter([
+            TraceOutputScheme::Topa,
+            TraceOutputScheme::SingleRange,
+            TraceOutputScheme::TraceTransport,
+        ])
+        .filter(move |&scheme| info.supports_output_scheme(scheme))
+    }
+
     /// Number of configurable Address Ranges for filtering (Bits 2:0).
     pub fn configurable_address_ranges(&self) -> u8 {
         self.leaf1.map_or(0, |res| get_bits(res.eax, 0, 2) as u8)
@@ -5307,6 +9274,52 @@ impl Debug for ProcessorTraceInfo {
     }
 }
 
+/// Where Intel Processor Trace writes the trace output to (LEAF=0x14).
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum TraceOutputScheme {
+    /// Table of Physical Addresses, a linked list of output regions.
+    Topa,
+    /// A single contiguous physical memory range.
+    SingleRange,
+    /// An implementation specific trace transport subsystem (e.g., Intel Trace Hub).
+    TraceTransport,
+}
+
+impl fmt::Display for TraceOutputScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let t = match self {
+            TraceOutputScheme::Topa => "ToPA",
+            TraceOutputScheme::SingleRange => "single-range",
+            TraceOutputScheme::TraceTransport => "trace transport",
+        };
+        f.write_str(t)
+    }
+}
+
+/// Nominal core crystal clock frequency in Hz of processors that enumerate the
+/// TSC/core crystal clock ratio but not the crystal clock (LEAF=0x15 ECX is 0).
+///
+/// Values are from the Intel SDM Vol. 3 "Determining the Processor Base
+/// Frequency" and the processor datasheets, returns 0 for all other
+/// processors. The client parts with a 38.4 MHz crystal do enumerate it, but
+/// hypervisors commonly clear LEAF=0x15 ECX.
+const fn nominal_crystal_clock(family: u8, model: u8) -> u32 {
+    match (family, model) {
+        // Skylake, Kaby Lake and Coffee Lake client
+        (0x6, 0x4e) | (0x6, 0x5e) | (0x6, 0x8e) | (0x6, 0x9e) => 24_000_000,
+        // Xeon Scalable (Skylake-SP, Cascade Lake, Cooper Lake)
+        (0x6, 0x55) => 25_000_000,
+        // Atom Goldmont-D (Denverton)
+        (0x6, 0x5f) => 25_000_000,
+        // Atom Goldmont and Goldmont Plus
+        (0x6, 0x5c) | (0x6, 0x7a) => 19_200_000,
+        // Ice Lake, Tiger Lake, Alder Lake and Raptor Lake client
+        (0x6, 0x7d) | (0x6, 0x7e) | (0x6, 0x8c) | (0x6, 0x8d) => 38_400_000,
+        (0x6, 0x97) | (0x6, 0x9a) | (0x6, 0xb7) | (0x6, 0xba) | (0x6, 0xbf) => 38_400_000,
+        _ => 0,
+    }
+}
+
 /// Time Stamp Counter/Core Crystal Clock Information (LEAF=0x15).
 ///
 /// # Platforms
@@ -5315,6 +9328,8 @@ pub struct TscInfo {
     eax: u32,
     ebx: u32,
     ecx: u32,
+    /// Nominal crystal clock of the processor model, 0 if unknown.
+    crystal: u32,
 }
 
 impl fmt::Debug for TscInfo {
@@ -5323,6 +9338,7 @@ impl fmt::Debug for TscInfo {
             .field("denominator", &self.denominator())
             .field("numerator", &self.numerator())
             .field("nominal_frequency", &self.nominal_frequency())
+            .field("crystal_frequency", &self.crystal_frequency())
             .field("tsc_frequency", &self.tsc_frequency())
             .finish()
     }
@@ -5348,15 +9364,44 @@ impl TscInfo {
         self.ecx
     }
 
+    /// Frequency of the core crystal clock.
+    ///
+    /// This is [`nominal_frequency`](Self::nominal_frequency) if it is
+    /// enumerated. Otherwise, for processors that don't enumerate it (e.g.,
+    /// Skylake and Kaby Lake), the nominal crystal clock of the processor model
+    /// as documented in the SDM, with
+    /// [`FrequencySource::CrystalClockTable`] as its source.
+    pub fn crystal_frequency(&self) -> Option<FrequencyEstimate> {
+        if self.nominal_frequency() != 0 {
+            Some(FrequencyEstimate::new(
+                self.nominal_frequency() as u64,
+                FrequencySource::TscLeaf,
+            ))
+        } else if self.crystal != 0 {
+            Some(FrequencyEstimate::new(
+                self.crystal as u64,
+                FrequencySource::CrystalClockTable,
+            ))
+        } else {
+            None
+        }
+    }
+
     /// “TSC frequency” = “core crystal clock frequency” * EBX/EAX.
+    ///
+    /// Uses the [`crystal_frequency`](Self::crystal_frequency), i.e., falls back
+    /// to the nominal crystal clock of the processor model if leaf 0x15 doesn't
+    /// enumerate it. Use [`CpuId::frequency`] to learn where the value came
+    /// from.
     pub fn tsc_frequency(&self) -> Option<u64> {
         // In some case TscInfo is a valid leaf, but the values reported are still 0
         // we should avoid a division by zero in case denominator ends up being 0.
-        if self.nominal_frequency() == 0 || self.numerator() == 0 || self.denominator() == 0 {
+        let crystal = self.crystal_frequency()?;
+        if self.numerator() == 0 || self.denominator() == 0 {
             return None;
         }
 
-        Some(self.nominal_frequency() as u64 * self.numerator() as u64 / self.denominator() as u64)
+        Some(crystal.hz() * self.numerator() as u64 / self.denominator() as u64)
     }
 }
 
@@ -5371,19 +9416,19 @@ pub struct ProcessorFrequencyInfo {
 }
 
 impl ProcessorFrequencyInfo {
-    /// Processor Base Frequency (in MHz).
-    pub fn processor_base_frequency(&self) -> u16 {
-        get_bits(self.eax, 0, 15) as u16
+    /// Processor Base Frequency (`None` if not enumerated).
+    pub fn processor_base_frequency(&self) -> Option<Frequency> {
+        Frequency::from_mhz_field(self.eax)
     }
 
-    /// Maximum Frequency (in MHz).
-    pub fn processor_max_frequency(&self) -> u16 {
-        get_bits(self.ebx, 0, 15) as u16
+    /// Maximum Frequency (`None` if not enumerated).
+    pub fn processor_max_frequency(&self) -> Option<Frequency> {
+        Frequency::from_mhz_field(self.ebx)
     }
 
-    /// Bus (Reference) Frequency (in MHz).
-    pub fn bus_frequency(&self) -> u16 {
-        get_bits(self.ecx, 0, 15) as u16
+    /// Bus (Reference) Frequency (`None` if not enumerated).
+    pub fn bus_frequency(&self) -> Option<Frequency> {
+        Frequency::from_mhz_field(self.ecx)
     }
 }
 
@@ -5397,6 +9442,128 @@ impl fmt::Debug for ProcessorFrequencyInfo {
     }
 }
 
+const KHZ_TO_HZ: u64 = 1_000;
+const MHZ_TO_HZ: u64 = 1_000_000;
+
+/// A frequency, e.g., as enumerated by the processor frequency leaf (LEAF=0x16).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Frequency {
+    hz: u64,
+}
+
+impl Frequency {
+    /// Decodes a frequency in MHz from bits 15:0 of a register, a value of 0
+    /// means the frequency is not enumerated.
+    fn from_mhz_field(reg: u32) -> Option<Frequency> {
+        match get_bits(reg, 0, 15) as u64 {
+            0 => None,
+            mhz => Some(Frequency {
+                hz: mhz * MHZ_TO_HZ,
+            }),
+        }
+    }
+
+    /// Frequency in Hz.
+    pub fn as_hz(&self) -> u64 {
+        self.hz
+    }
+
+    /// Frequency in MHz (rounded down).
+    pub fn as_mhz(&self) -> u64 {
+        self.hz / MHZ_TO_HZ
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} MHz", self.as_mhz())
+    }
+}
+
+/// Where a frequency returned by [`CpuId::frequency`] was obtained from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrequencySource {
+    /// Computed from the TSC/core crystal clock ratio (LEAF=0x15).
+    TscLeaf,
+    /// Processor frequency information (LEAF=0x16).
+    FrequencyLeaf,
+    /// Reported by the hypervisor (LEAF=0x4000_0010).
+    Hypervisor,
+    /// Nominal frequency parsed from the processor brand string (LEAF=0x8000_000{2..4}).
+    BrandString,
+    /// Computed from the TSC/core crystal clock ratio (LEAF=0x15) and the nominal
+    /// crystal clock the SDM documents for the processor model, as leaf 0x15
+    /// doesn't enumerate it.
+    CrystalClockTable,
+}
+
+/// A frequency in Hz together with the source it was derived from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrequencyEstimate {
+    hz: u64,
+    source: FrequencySource,
+}
+
+impl FrequencyEstimate {
+    fn new(hz: u64, source: FrequencySource) -> Self {
+        Self { hz, source }
+    }
+
+    /// Frequency in Hz.
+    pub fn hz(&self) -> u64 {
+        self.hz
+    }
+
+    /// Where the frequency was obtained from.
+    pub fn source(&self) -> FrequencySource {
+        self.source
+    }
+}
+
+/// Best-effort processor frequencies as returned by [`CpuId::frequency`].
+///
+/// Each frequency is `None` if no CPUID leaf enumerates it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FrequencyInfo {
+    base: Option<FrequencyEstimate>,
+    max: Option<FrequencyEstimate>,
+    bus: Option<FrequencyEstimate>,
+    tsc: Option<FrequencyEstimate>,
+}
+
+impl FrequencyInfo {
+    /// Processor base (nominal) frequency.
+    pub fn base_frequency(&self) -> Option<FrequencyEstimate> {
+        self.base
+    }
+
+    /// Maximum (turbo) frequency.
+    pub fn max_frequency(&self) -> Option<FrequencyEstimate> {
+        self.max
+    }
+
+    /// Bus (reference) frequency.
+    pub fn bus_frequency(&self) -> Option<FrequencyEstimate> {
+        self.bus
+    }
+
+    /// Frequency the time stamp counter (RDTSC) increments at.
+    pub fn tsc_frequency(&self) -> Option<FrequencyEstimate> {
+        self.tsc
+    }
+}
+
+impl Debug for FrequencyInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrequencyInfo")
+            .field("base_frequency", &self.base_frequency())
+            .field("max_frequency", &self.max_frequency())
+            .field("bus_frequency", &self.bus_frequency())
+            .field("tsc_frequency", &self.tsc_frequency())
+            .finish()
+    }
+}
+
 /// Deterministic Address Translation Structure Iterator (LEAF=0x18).
 ///
 /// # Platforms
@@ -5433,15 +9600,51 @@ impl<R: CpuIdReader> Iterator for DatIter<R> {
             }
 
             return Some(DatInfo {
-                _eax: res.eax,
+                subleaf: self.current - 1,
+                eax: res.eax,
                 ebx: res.ebx,
                 ecx: res.ecx,
                 edx: res.edx,
             });
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Invalid sub-leaves are skipped, so only the upper bound is known.
+        let remaining = (self.count as usize + 1).saturating_sub(self.current as usize);
+        (0, Some(remaining))
+    }
+}
+
+impl<R: CpuIdReader> DatIter<R> {
+    /// Maximum input value of supported sub-leafs (EAX of sub-leaf 0).
+    ///
+    /// The iterator yields at most `max_subleaf() + 1` structures.
+    pub fn max_subleaf(&self) -> u32 {
+        self.count
+    }
+
+    /// Only yield the translation structures that can hold entries of the
+    /// given page size.
+    ///
+    /// # Example
+    /// ```
+    /// use raw_cpuid::{CpuId, PageSize};
+    /// let cpuid = CpuId::new();
+    ///
+    /// if let Some(dat) = cpuid.get_deterministic_address_translation_info() {
+    ///     for tlb in dat.tlbs_for_page_size(PageSize::Huge2M) {
+    ///         println!("L{} {}: {} entries", tlb.cache_level(), tlb.cache_type(), tlb.entries());
+    ///     }
+    /// }
+    /// ```
+    pub fn tlbs_for_page_size(self, size: PageSize) -> impl Iterator<Item = DatInfo> {
+        self.filter(move |dat| dat.supports_page_size(size))
+    }
 }
 
+impl<R: CpuIdReader> FusedIterator for DatIter<R> {}
+
 impl<R: CpuIdReader> Debug for DatIter<R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let mut debug = f.debug_list();
@@ -5454,7 +9657,8 @@ impl<R: CpuIdReader> Debug for DatIter<R> {
 
 /// Deterministic Address Translation Structure
 pub struct DatInfo {
-    _eax: u32,
+    subleaf: u32,
+    eax: u32,
     ebx: u32,
     ecx: u32,
     edx: u32,
@@ -5496,6 +9700,16 @@ impl DatInfo {
         8
     );
 
+    /// Does this structure hold entries for the given page size?
+    pub fn supports_page_size(&self, size: PageSize) -> bool {
+        match size {
+            PageSize::Small4K => self.has_4k_entries(),
+            PageSize::Huge2M => self.has_2mb_entries(),
+            PageSize::Huge4M => self.has_4mb_entries(),
+            PageSize::Huge1G => self.has_1gb_entries(),
+        }
+    }
+
     /// Partitioning (0: Soft partitioning between the logical processors sharing this structure).
     pub fn partitioning(&self) -> u8 {
         get_bits(self.ebx, 8, 10) as u8
@@ -5511,6 +9725,11 @@ impl DatInfo {
         self.ecx
     }
 
+    /// Total number of entries (ways * sets).
+    pub fn entries(&self) -> u64 {
+        self.ways() as u64 * self.sets() as u64
+    }
+
     /// Translation cache type field.
     pub fn cache_type(&self) -> DatType {
         match get_bits(self.edx, 0, 4) as u8 {
@@ -5520,7 +9739,7 @@ impl DatInfo {
             0b00000 => DatType::Null, // should never be returned as this indicates invalid struct!
             0b00100 => DatType::LoadOnly,
             0b00101 => DatType::StoreOnly,
-            _ => DatType::Unknown,
+            typ => DatType::Unknown(typ),
         }
     }
 
@@ -5529,53 +9748,169 @@ impl DatInfo {
         get_bits(self.edx, 5, 7) as u8
     }
 
-    /// Maximum number of addressable IDs for logical processors sharing this translation cache
-    pub fn max_addressable_ids(&self) -> u16 {
-        // Add one to the return value to get the result:
-        (get_bits(self.edx, 14, 25) + 1) as u16
+    /// Maximum number of addressable IDs for logical processors sharing this translation cache
+    pub fn max_addressable_ids(&self) -> u16 {
+        // Add one to the return value to get the result:
+        (get_bits(self.edx, 14, 25) + 1) as u16
+    }
+
+    /// Sub-leaf index (ECX input) this structure was read from.
+    pub fn subleaf(&self) -> u32 {
+        self.subleaf
+    }
+
+    /// Raw register values of the sub-leaf.
+    pub fn raw(&self) -> CpuIdResult {
+        CpuIdResult {
+            eax: self.eax,
+            ebx: self.ebx,
+            ecx: self.ecx,
+            edx: self.edx,
+        }
+    }
+}
+
+impl Debug for DatInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DatInfo")
+            .field("has_4k_entries", &self.has_4k_entries())
+            .field("has_2mb_entries", &self.has_2mb_entries())
+            .field("has_4mb_entries", &self.has_4mb_entries())
+            .field("has_1gb_entries", &self.has_1gb_entries())
+            .field("is_fully_associative", &self.is_fully_associative())
+            .finish()
+    }
+}
+
+/// Deterministic Address Translation cache type (EDX bits 04 -- 00)
+#[derive(Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum DatType {
+    /// Null (indicates this sub-leaf is not valid).
+    Null,
+    DataTLB,
+    InstructionTLB,
+    /// Some unified TLBs will allow a single TLB entry to satisfy data read/write
+    /// and instruction fetches. Others will require separate entries (e.g., one
+    /// loaded on data read/write and another loaded on an instruction fetch) .
+    /// Please see the Intel® 64 and IA-32 Architectures Optimization Reference Manual
+    /// for details of a particular product.
+    UnifiedTLB,
+    /// Load-only TLB, hit on loads; fills on both loads and stores.
+    LoadOnly,
+    /// Store-only TLB, hit on stores; fill on stores.
+    StoreOnly,
+    /// A reserved encoding, the raw value.
+    Unknown(u8),
+}
+
+impl fmt::Display for DatType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let t = match self {
+            DatType::Null => "invalid (0)",
+            DatType::DataTLB => "Data TLB",
+            DatType::InstructionTLB => "Instruction TLB",
+            DatType::UnifiedTLB => "Unified TLB",
+            DatType::LoadOnly => "Load Only",
+            DatType::StoreOnly => "Store Only",
+            DatType::Unknown(typ) => return write!(f, "Unknown ({})", typ),
+        };
+        f.write_str(t)
+    }
+}
+
+/// Paging capabilities as returned by [`CpuId::paging_info`].
+///
+/// # Platforms
+/// ✅ AMD ✅ Intel
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PagingInfo {
+    edx_ecx: FeatureInfoFlags,
+    ecx7: ExtendedFeaturesEcx,
+    pdpe1gb: bool,
+}
+
+impl PagingInfo {
+    /// Page Size Extension: 4 MiB pages with 32-bit paging (LEAF=0x01).
+    pub fn has_pse(&self) -> bool {
+        self.edx_ecx.contains(FeatureInfoFlags::PSE)
+    }
+
+    /// Physical Address Extension: 64-bit page table entries and 2 MiB pages (LEAF=0x01).
+    pub fn has_pae(&self) -> bool {
+        self.edx_ecx.contains(FeatureInfoFlags::PAE)
+    }
+
+    /// 36-Bit Page Size Extension: physical addresses above 4 GiB in 4 MiB pages (LEAF=0x01).
+    pub fn has_pse36(&self) -> bool {
+        self.edx_ecx.contains(FeatureInfoFlags::PSE36)
+    }
+
+    /// 1 GiB pages (LEAF=0x8000_0001).
+    pub fn has_pdpe1gb(&self) -> bool {
+        self.pdpe1gb
+    }
+
+    /// 5-level paging, i.e., 57-bit linear addresses (LEAF=0x07).
+    pub fn has_la57(&self) -> bool {
+        self.ecx7.contains(ExtendedFeaturesEcx::LA57)
+    }
+
+    /// Whether pages of `size` can be mapped (in any paging mode).
+    pub fn supports_page_size(&self, size: PageSize) -> bool {
+        match size {
+            PageSize::Small4K => true,
+            PageSize::Huge2M => self.has_pae(),
+            PageSize::Huge4M => self.has_pse(),
+            PageSize::Huge1G => self.has_pdpe1gb(),
+        }
+    }
+
+    /// All supported page sizes, smallest first.
+    pub fn page_sizes(&self) -> impl Iterator<Item = PageSize> {
+        let info = *self;
+        IntoIterator::into_iter([
+            PageSize::Small4K,
+            PageSize::Huge2M,
+            PageSize::Huge4M,
+            PageSize::Huge1G,
+        ])
+        .filter(move |&size| info.supports_page_size(size))
     }
 }
 
-impl Debug for DatInfo {
+impl Debug for PagingInfo {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("DatInfo")
-            .field("has_4k_entries", &self.has_4k_entries())
-            .field("has_2mb_entries", &self.has_2mb_entries())
-            .field("has_4mb_entries", &self.has_4mb_entries())
-            .field("has_1gb_entries", &self.has_1gb_entries())
-            .field("is_fully_associative", &self.is_fully_associative())
+        f.debug_struct("PagingInfo")
+            .field("has_pse", &self.has_pse())
+            .field("has_pae", &self.has_pae())
+            .field("has_pse36", &self.has_pse36())
+            .field("has_pdpe1gb", &self.has_pdpe1gb())
+            .field("has_la57", &self.has_la57())
             .finish()
     }
 }
 
-/// Deterministic Address Translation cache type (EDX bits 04 -- 00)
-#[derive(Eq, PartialEq, Debug)]
-pub enum DatType {
-    /// Null (indicates this sub-leaf is not valid).
-    Null = 0b00000,
-    DataTLB = 0b00001,
-    InstructionTLB = 0b00010,
-    /// Some unified TLBs will allow a single TLB entry to satisfy data read/write
-    /// and instruction fetches. Others will require separate entries (e.g., one
-    /// loaded on data read/write and another loaded on an instruction fetch) .
-    /// Please see the Intel® 64 and IA-32 Architectures Optimization Reference Manual
-    /// for details of a particular product.
-    UnifiedTLB = 0b00011,
-    LoadOnly = 0b0100,
-    StoreOnly = 0b0101,
-    Unknown,
+/// Page sizes a translation structure can cache entries for.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum PageSize {
+    /// 4 KiB pages.
+    Small4K,
+    /// 2 MiB pages (PAE/long mode large pages).
+    Huge2M,
+    /// 4 MiB pages (32-bit paging large pages).
+    Huge4M,
+    /// 1 GiB pages.
+    Huge1G,
 }
 
-impl fmt::Display for DatType {
+impl fmt::Display for PageSize {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let t = match self {
-            DatType::Null => "invalid (0)",
-            DatType::DataTLB => "Data TLB",
-            DatType::InstructionTLB => "Instruction TLB",
-            DatType::UnifiedTLB => "Unified TLB",
-            DatType::LoadOnly => "Load Only",
-            DatType::StoreOnly => "Store Only",
-            DatType::Unknown => "Unknown",
+            PageSize::Small4K => "4 KiB",
+            PageSize::Huge2M => "2 MiB",
+            PageSize::Huge4M => "4 MiB",
+            PageSize::Huge1G => "1 GiB",
         };
         f.write_str(t)
     }
@@ -5619,6 +9954,26 @@ impl<R: CpuIdReader> SoCVendorInfo<R> {
         }
     }
 
+    /// Highest sub-leaf index (MaxSOCID_Index) supported by leaf 0x17.
+    pub fn max_soc_id_index(&self) -> u32 {
+        self.eax
+    }
+
+    /// Look up the vendor attribute stored in sub-leaf `index`.
+    ///
+    /// Attributes start at sub-leaf 4 (sub-leafs 1 to 3 hold the brand
+    /// string), returns `None` if `index` is outside of `4..=MaxSOCID_Index`.
+    pub fn get_vendor_attribute(&self, index: u32) -> Option<SoCVendorAttribute> {
+        if index > 3 && index <= self.eax {
+            Some(SoCVendorAttribute {
+                index,
+                data: self.read.cpuid2(EAX_SOC_VENDOR_INFO, index),
+            })
+        } else {
+            None
+        }
+    }
+
     pub fn get_vendor_attributes(&self) -> Option<SoCVendorAttributesIter<R>> {
         if self.eax > 3 {
             Some(SoCVendorAttributesIter {
@@ -5661,15 +10016,64 @@ impl<R: CpuIdReader> fmt::Debug for SoCVendorAttributesIter<R> {
 }
 
 impl<R: CpuIdReader> Iterator for SoCVendorAttributesIter<R> {
-    type Item = CpuIdResult;
+    type Item = SoCVendorAttribute;
 
     /// Iterate over all SoC vendor specific attributes.
-    fn next(&mut self) -> Option<CpuIdResult> {
-        if self.current > self.count {
+    fn next(&mut self) -> Option<SoCVendorAttribute> {
+        if self.current >= self.count {
             return None;
         }
-        self.count += 1;
-        Some(self.read.cpuid2(EAX_SOC_VENDOR_INFO, self.count))
+        self.current += 1;
+        Some(SoCVendorAttribute {
+            index: self.current,
+            data: self.read.cpuid2(EAX_SOC_VENDOR_INFO, self.current),
+        })
+    }
+}
+
+impl<R: CpuIdReader> FusedIterator for SoCVendorAttributesIter<R> {}
+
+/// A SoC vendor attribute (LEAF=0x17, SUBLEAF>=4).
+///
+/// The encoding of the attribute is defined by the SoC vendor identified by
+/// [`SoCVendorInfo::get_soc_vendor_id`]. The sub-leaf index acts as the
+/// attribute ID and the four registers hold its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SoCVendorAttribute {
+    index: u32,
+    data: CpuIdResult,
+}
+
+impl SoCVendorAttribute {
+    /// Sub-leaf index of this attribute (its ID).
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Attribute value as returned in EAX, EBX, ECX and EDX.
+    pub fn value(&self) -> [u32; 4] {
+        [self.data.eax, self.data.ebx, self.data.ecx, self.data.edx]
+    }
+
+    pub fn eax(&self) -> u32 {
+        self.data.eax
+    }
+
+    pub fn ebx(&self) -> u32 {
+        self.data.ebx
+    }
+
+    pub fn ecx(&self) -> u32 {
+        self.data.ecx
+    }
+
+    pub fn edx(&self) -> u32 {
+        self.data.edx
+    }
+
+    /// Raw register values of the sub-leaf.
+    pub fn raw(&self) -> CpuIdResult {
+        self.data
     }
 }
 
@@ -5706,6 +10110,375 @@ impl fmt::Display for SoCVendorBrand {
     }
 }
 
+/// Hybrid Information (LEAF=0x1A).
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+#[derive(PartialEq, Eq)]
+pub struct HybridInfo {
+    eax: u32,
+}
+
+impl HybridInfo {
+    /// Core type of the logical processor.
+    pub fn core_type(&self) -> CoreType {
+        match get_bits(self.eax, 24, 31) {
+            0x20 => CoreType::Atom,
+            0x40 => CoreType::Core,
+            typ => CoreType::Unknown(typ as u8),
+        }
+    }
+
+    /// Native model ID of the core, identifies the core design together with
+    /// [`HybridInfo::core_type`].
+    pub fn native_model_id(&self) -> u32 {
+        get_bits(self.eax, 0, 23)
+    }
+}
+
+impl fmt::Debug for HybridInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HybridInfo")
+            .field("core_type", &self.core_type())
+            .field("native_model_id", &self.native_model_id())
+            .finish()
+    }
+}
+
+/// Core type of a hybrid processor, see [`HybridInfo::core_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoreType {
+    /// Intel Atom (efficiency core).
+    Atom,
+    /// Intel Core (performance core).
+    Core,
+    /// A core type this library doesn't know yet, the raw encoding.
+    Unknown(u8),
+}
+
+impl fmt::Display for CoreType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let typ = match self {
+            CoreType::Atom => "Intel Atom",
+            CoreType::Core => "Intel Core",
+            CoreType::Unknown(typ) => return write!(f, "Unknown ({:#x})", typ),
+        };
+
+        f.write_str(typ)
+    }
+}
+
+/// Iterates over the PCONFIG target identifiers (LEAF=0x1B).
+///
+/// Every sub-leaf of the target identifier type lists up to three targets in
+/// EBX, ECX and EDX, the iterator ends at the first sub-leaf of another type.
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+#[derive(Clone)]
+pub struct PconfigTargetIter<R: CpuIdReader> {
+    read: R,
+    subleaf: u32,
+    /// Next register of `res` (0 = EBX, 1 = ECX, 2 = EDX).
+    register: u8,
+    /// The current sub-leaf, `None` before reading it.
+    res: Option<CpuIdResult>,
+}
+
+impl<R: CpuIdReader> Iterator for PconfigTargetIter<R> {
+    type Item = PconfigTarget;
+
+    fn next(&mut self) -> Option<PconfigTarget> {
+        loop {
+            let res = match self.res {
+                Some(res) => res,
+                None => {
+                    let res = self.read.cpuid2(EAX_PCONFIG_INFO, self.subleaf);
+                    self.res = Some(res);
+                    res
+                }
+            };
+            // Sub-leaf type 0 is invalid, 1 lists target identifiers
+            if get_bits(res.eax, 0, 11) != 1 {
+                return None;
+            }
+
+            let id = match self.register {
+                0 => res.ebx,
+                1 => res.ecx,
+                _ => res.edx,
+            };
+            if self.register < 2 {
+                self.register += 1;
+            } else {
+                self.register = 0;
+                self.subleaf += 1;
+                self.res = None;
+            }
+
+            // Zero marks an unused slot
+            if id != 0 {
+                return Some(PconfigTarget::from(id));
+            }
+        }
+    }
+}
+
+impl<R: CpuIdReader> FusedIterator for PconfigTargetIter<R> {}
+
+impl<R: CpuIdReader> Debug for PconfigTargetIter<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_list();
+        self.clone().for_each(|ref item| {
+            debug.entry(item);
+        });
+        debug.finish()
+    }
+}
+
+/// A target of the PCONFIG instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PconfigTarget {
+    /// Multi-Key Total Memory Encryption.
+    Mktme,
+    /// A target this library doesn't know yet, the raw identifier.
+    Unknown(u32),
+}
+
+impl From<u32> for PconfigTarget {
+    fn from(id: u32) -> Self {
+        match id {
+            1 => PconfigTarget::Mktme,
+            id => PconfigTarget::Unknown(id),
+        }
+    }
+}
+
+impl fmt::Display for PconfigTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PconfigTarget::Mktme => f.write_str("MKTME"),
+            PconfigTarget::Unknown(id) => write!(f, "Unknown ({:#x})", id),
+        }
+    }
+}
+
+/// AMX Tile Information (LEAF=0x1D).
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+pub struct TileInfo<R: CpuIdReader> {
+    read: R,
+    max_palette: u32,
+}
+
+impl<R: CpuIdReader> TileInfo<R> {
+    /// Highest palette ID. Palette 0 is the initialized state and has no tiles.
+    pub fn max_palette(&self) -> u32 {
+        self.max_palette
+    }
+
+    /// Iterate over the palettes (sub-leafs 1 to [`TileInfo::max_palette`]).
+    pub fn palettes(&self) -> TilePaletteIter<R> {
+        TilePaletteIter {
+            read: self.read.clone(),
+            current: 1,
+            max_palette: self.max_palette,
+        }
+    }
+}
+
+impl<R: CpuIdReader> Debug for TileInfo<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TileInfo")
+            .field("max_palette", &self.max_palette())
+            .field("palettes", &self.palettes())
+            .finish()
+    }
+}
+
+/// Iterates over the AMX tile palettes (LEAF=0x1D).
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+#[derive(Clone)]
+pub struct TilePaletteIter<R: CpuIdReader> {
+    read: R,
+    current: u32,
+    max_palette: u32,
+}
+
+impl<R: CpuIdReader> Iterator for TilePaletteIter<R> {
+    type Item = TilePalette;
+
+    fn next(&mut self) -> Option<TilePalette> {
+        if self.current > self.max_palette {
+            return None;
+        }
+
+        let res = self.read.cpuid2(EAX_TILE_INFO, self.current);
+        let palette = TilePalette {
+            palette: self.current,
+            eax: res.eax,
+            ebx: res.ebx,
+            ecx: res.ecx,
+        };
+        self.current += 1;
+        Some(palette)
+    }
+}
+
+impl<R: CpuIdReader> FusedIterator for TilePaletteIter<R> {}
+
+impl<R: CpuIdReader> Debug for TilePaletteIter<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_list();
+        self.clone().for_each(|ref item| {
+            debug.entry(item);
+        });
+        debug.finish()
+    }
+}
+
+/// An AMX tile palette, i.e., the tile configuration LDTILECFG can select.
+///
+/// Use it to size the tile state: a palette has [`TilePalette::max_names`] tiles
+/// of up to [`TilePalette::max_rows`] rows of [`TilePalette::bytes_per_row`] bytes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TilePalette {
+    palette: u32,
+    eax: u32,
+    ebx: u32,
+    ecx: u32,
+}
+
+impl TilePalette {
+    /// Palette ID (the sub-leaf).
+    pub fn palette_id(&self) -> u32 {
+        self.palette
+    }
+
+    /// Size of all tile registers together in bytes.
+    pub fn total_tile_bytes(&self) -> u16 {
+        get_bits(self.eax, 0, 15) as u16
+    }
+
+    /// Size of a tile register in bytes.
+    pub fn bytes_per_tile(&self) -> u16 {
+        get_bits(self.eax, 16, 31) as u16
+    }
+
+    /// Size of a tile row in bytes.
+    pub fn bytes_per_row(&self) -> u16 {
+        get_bits(self.ebx, 0, 15) as u16
+    }
+
+    /// Number of tile registers.
+    pub fn max_names(&self) -> u16 {
+        get_bits(self.ebx, 16, 31) as u16
+    }
+
+    /// Maximum number of rows of a tile.
+    pub fn max_rows(&self) -> u16 {
+        get_bits(self.ecx, 0, 15) as u16
+    }
+}
+
+impl Debug for TilePalette {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TilePalette")
+            .field("palette_id", &self.palette_id())
+            .field("total_tile_bytes", &self.total_tile_bytes())
+            .field("bytes_per_tile", &self.bytes_per_tile())
+            .field("bytes_per_row", &self.bytes_per_row())
+            .field("max_names", &self.max_names())
+            .field("max_rows", &self.max_rows())
+            .finish()
+    }
+}
+
+/// TMUL Information (LEAF=0x1E).
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TmulInfo {
+    ebx: u32,
+}
+
+impl TmulInfo {
+    /// Maximum number of rows or columns (K) of a matrix multiply.
+    pub fn tmul_maxk(&self) -> u8 {
+        get_bits(self.ebx, 0, 7) as u8
+    }
+
+    /// Maximum number of bytes per column (N) of a matrix multiply.
+    pub fn tmul_maxn(&self) -> u16 {
+        get_bits(self.ebx, 8, 23) as u16
+    }
+}
+
+impl Debug for TmulInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TmulInfo")
+            .field("tmul_maxk", &self.tmul_maxk())
+            .field("tmul_maxn", &self.tmul_maxn())
+            .finish()
+    }
+}
+
+/// Processor History Reset (LEAF=0x20).
+///
+/// # Platforms
+/// ❌ AMD ✅ Intel
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct HresetInfo {
+    eax: u32,
+    ebx: HresetCapabilities,
+}
+
+impl HresetInfo {
+    /// Highest valid sub-leaf of the leaf.
+    pub fn max_subleaf(&self) -> u32 {
+        self.eax
+    }
+
+    /// Bit vector of the supported history reset capabilities (the bits that can
+    /// be set in IA32_HRESET_ENABLE, including bits this library doesn't know yet).
+    pub fn capabilities(&self) -> u32 {
+        self.ebx.bits()
+    }
+
+    check_flag!(
+        doc = "HRESET can reset the Intel Thread Director history.",
+        has_thread_director_reset,
+        ebx,
+        HresetCapabilities::THREAD_DIRECTOR
+    );
+}
+
+impl Debug for HresetInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HresetInfo")
+            .field("max_subleaf", &self.max_subleaf())
+            .field("capabilities", &self.capabilities())
+            .field(
+                "has_thread_director_reset",
+                &self.has_thread_director_reset(),
+            )
+            .finish()
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct HresetCapabilities: u32 {
+        /// Bit 00: Reset of the Intel Thread Director history.
+        const THREAD_DIRECTOR = 1 << 0;
+    }
+}
+
 /// Information about Hypervisor (LEAF=0x4000_0001)
 ///
 /// More information about this semi-official leaf can be found here
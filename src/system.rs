@@ -0,0 +1,169 @@
+//! CPUID values of all logical processors of a system.
+//!
+//! A [`CpuIdSnapshot`] captures the view of one logical processor. On hybrid parts
+//! (performance and efficiency cores report different leafs 0x1A, 0x04, ...) and on
+//! multi-socket systems, that view is not representative of the whole machine.
+//! [`SystemCpuIdSnapshot`] keeps every processor: the values most of them agree on
+//! in a shared base snapshot, plus the leafs that differ (APIC IDs, core types,
+//! cache sharing) per CPU.
+//!
+//! ```
+//! use raw_cpuid::system::SystemCpuIdSnapshot;
+//! use raw_cpuid::{CpuIdResult, CpuIdSnapshot};
+//!
+//! let cpu = |apic_id: u32| -> CpuIdSnapshot {
+//!     let leaf0 = CpuIdResult { eax: 0x1, ebx: 0x756e_6547, ecx: 0x6c65_746e, edx: 0x4965_6e69 };
+//!     let leaf1 = CpuIdResult { eax: 0x906a3, ebx: apic_id << 24, ecx: 0, edx: 0 };
+//!     [(0x0, 0x0, leaf0), (0x1, 0x0, leaf1)].iter().copied().collect()
+//! };
+//! let system: SystemCpuIdSnapshot = (0..4).map(|id| (id, cpu(id * 2))).collect();
+//!
+//! assert_eq!(system.len(), 4);
+//! assert_eq!(system.differing_leafs(), vec![(0x1, 0x0)]);
+//! assert_eq!(system.snapshot(3).unwrap().cpuid().apic_id(), Some(6));
+//! ```
+//!
+//! With the `serialize` feature, the whole system can be stored and loaded again.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "serialize")]
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{CpuIdResult, CpuIdSnapshot};
+
+/// CPUID values of a logical processor that differ from the base snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+struct CpuDelta {
+    cpu: u32,
+    /// Sorted by (leaf, sub-leaf), `None` if the leaf is missing on this CPU.
+    changes: Vec<(u32, u32, Option<CpuIdResult>)>,
+}
+
+/// Snapshots of the logical processors of a system, stored as a shared base and
+/// per-CPU deltas.
+///
+/// Build it from `(cpu, snapshot)` pairs, e.g., by pinning a thread to every CPU
+/// and taking [`CpuId::snapshot`](crate::CpuId::snapshot) there. The CPU numbers
+/// are labels (e.g., the OS CPU number) and don't need to be contiguous.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct SystemCpuIdSnapshot {
+    base: CpuIdSnapshot,
+    /// In the order the CPUs were added.
+    cpus: Vec<CpuDelta>,
+}
+
+impl SystemCpuIdSnapshot {
+    /// The values shared by most CPUs (for every leaf and sub-leaf the result the
+    /// most CPUs report, the first CPU wins ties).
+    pub fn base(&self) -> &CpuIdSnapshot {
+        &self.base
+    }
+
+    /// Number of CPUs.
+    pub fn len(&self) -> usize {
+        self.cpus.len()
+    }
+
+    /// No CPUs were added.
+    pub fn is_empty(&self) -> bool {
+        self.cpus.is_empty()
+    }
+
+    /// The CPU numbers, in the order they were added.
+    pub fn cpus(&self) -> impl Iterator<Item = u32> + '_ {
+        self.cpus.iter().map(|delta| delta.cpu)
+    }
+
+    /// The leafs in which `cpu` differs from [`SystemCpuIdSnapshot::base`], with its
+    /// result (`None` if `cpu` lacks the leaf).
+    pub fn delta(&self, cpu: u32) -> Option<&[(u32, u32, Option<CpuIdResult>)]> {
+        self.cpus
+            .iter()
+            .find(|delta| delta.cpu == cpu)
+            .map(|delta| delta.changes.as_slice())
+    }
+
+    /// The full snapshot of `cpu`.
+    pub fn snapshot(&self, cpu: u32) -> Option<CpuIdSnapshot> {
+        let changes = self.delta(cpu)?;
+        let changed = |leaf, subleaf| {
+            changes
+                .binary_search_by_key(&(leaf, subleaf), |&(l, s, _)| (l, s))
+                .is_ok()
+        };
+        Some(
+            self.base
+                .entries()
+                .iter()
+                .copied()
+                .filter(|&(leaf, subleaf, _)| !changed(leaf, subleaf))
+                .chain(
+                    changes
+                        .iter()
+                        .filter_map(|&(leaf, subleaf, res)| res.map(|res| (leaf, subleaf, res))),
+                )
+                .collect(),
+        )
+    }
+
+    /// The (leaf, sub-leaf) pairs that are not the same on all CPUs, sorted.
+    pub fn differing_leafs(&self) -> Vec<(u32, u32)> {
+        let mut keys: Vec<(u32, u32)> = self
+            .cpus
+            .iter()
+            .flat_map(|delta| delta.changes.iter().map(|&(l, s, _)| (l, s)))
+            .collect();
+        keys.sort_unstable();
+        keys.dedup();
+        keys
+    }
+}
+
+/// Build the system from `(cpu, snapshot)` pairs.
+impl core::iter::FromIterator<(u32, CpuIdSnapshot)> for SystemCpuIdSnapshot {
+    fn from_iter<I: IntoIterator<Item = (u32, CpuIdSnapshot)>>(iter: I) -> Self {
+        let snapshots: Vec<(u32, CpuIdSnapshot)> = iter.into_iter().collect();
+
+        let mut keys: Vec<(u32, u32)> = snapshots
+            .iter()
+            .flat_map(|(_, snapshot)| snapshot.entries().iter().map(|&(l, s, _)| (l, s)))
+            .collect();
+        keys.sort_unstable();
+        keys.dedup();
+
+        let base: CpuIdSnapshot = keys
+            .into_iter()
+            .filter_map(|(leaf, subleaf)| {
+                // Distinct results in order of appearance, with how many CPUs report them
+                let mut votes: Vec<(Option<CpuIdResult>, usize)> = Vec::new();
+                for (_, snapshot) in snapshots.iter() {
+                    let res = snapshot.get(leaf, subleaf);
+                    match votes.iter_mut().find(|(r, _)| *r == res) {
+                        Some((_, count)) => *count += 1,
+                        None => votes.push((res, 1)),
+                    }
+                }
+                let max = votes.iter().map(|&(_, count)| count).max()?;
+                let (res, _) = votes.into_iter().find(|&(_, count)| count == max)?;
+                res.map(|res| (leaf, subleaf, res))
+            })
+            .collect();
+
+        let cpus = snapshots
+            .iter()
+            .map(|(cpu, snapshot)| CpuDelta {
+                cpu: *cpu,
+                changes: base
+                    .diff(snapshot)
+                    .into_iter()
+                    .map(|(leaf, subleaf, _, res)| (leaf, subleaf, res))
+                    .collect(),
+            })
+            .collect();
+
+        SystemCpuIdSnapshot { base, cpus }
+    }
+}
@@ -0,0 +1,304 @@
+//! Compare decoded fields against values published in the vendor datasheets.
+//!
+//! The checks run on every dump in the [`CORPUS`](super::CORPUS) and, when
+//! `INSTLATX64_DIR` points to a local checkout of the
+//! [InstLatx64](https://github.com/InstLatx64/InstLatx64) repository, on every
+//! `*CPUID*.txt` dump in it. Dumps of processors not in [`DATASHEETS`] only get
+//! the generic checks.
+
+use crate::{CacheType, CpuId, CpuIdReader};
+use std::format;
+
+/// Size in KiB and associativity of a cache.
+type Cache = (usize, usize);
+
+/// Per-core cache geometry of a micro-architecture.
+struct Datasheet {
+    name: &'static str,
+    vendor: &'static str,
+    family: u8,
+    models: &'static [u8],
+    l1d: Cache,
+    l1i: Cache,
+    l2: Cache,
+}
+
+const fn intel(
+    name: &'static str,
+    models: &'static [u8],
+    l1d: Cache,
+    l1i: Cache,
+    l2: Cache,
+) -> Datasheet {
+    Datasheet {
+        name,
+        vendor: "GenuineIntel",
+        family: 0x6,
+        models,
+        l1d,
+        l1i,
+        l2,
+    }
+}
+
+const fn amd(
+    name: &'static str,
+    family: u8,
+    models: &'static [u8],
+    l1d: Cache,
+    l1i: Cache,
+    l2: Cache,
+) -> Datasheet {
+    Datasheet {
+        name,
+        vendor: "AuthenticAMD",
+        family,
+        models,
+        l1d,
+        l1i,
+        l2,
+    }
+}
+
+/// Hybrid parts are left out since leaf 0x04 depends on the core the dump was taken on.
+const DATASHEETS: &[Datasheet] = &[
+    intel(
+        "Sandy Bridge / Ivy Bridge",
+        &[0x2a, 0x2d, 0x3a, 0x3e],
+        (32, 8),
+        (32, 8),
+        (256, 8),
+    ),
+    intel(
+        "Haswell / Broadwell",
+        &[0x3c, 0x3f, 0x45, 0x46, 0x3d, 0x47, 0x4f, 0x56],
+        (32, 8),
+        (32, 8),
+        (256, 8),
+    ),
+    intel(
+        "Skylake client",
+        &[0x4e, 0x5e, 0x8e, 0x9e],
+        (32, 8),
+        (32, 8),
+        (256, 4),
+    ),
+    intel("Skylake server", &[0x55], (32, 8), (32, 8), (1024, 16)),
+    intel(
+        "Ice Lake client",
+        &[0x7d, 0x7e],
+        (48, 12),
+        (32, 8),
+        (512, 8),
+    ),
+    intel(
+        "Ice Lake server",
+        &[0x6a, 0x6c],
+        (48, 12),
+        (32, 8),
+        (1280, 20),
+    ),
+    intel(
+        "Sapphire Rapids / Emerald Rapids",
+        &[0x8f, 0xcf],
+        (48, 12),
+        (32, 8),
+        (2048, 16),
+    ),
+    amd(
+        "Zen / Zen+",
+        0x17,
+        &[0x01, 0x08, 0x11, 0x18],
+        (32, 8),
+        (64, 4),
+        (512, 8),
+    ),
+    amd(
+        "Zen 2",
+        0x17,
+        &[0x31, 0x60, 0x68, 0x71, 0x90],
+        (32, 8),
+        (32, 8),
+        (512, 8),
+    ),
+    amd(
+        "Zen 3",
+        0x19,
+        &[0x01, 0x08, 0x21, 0x50],
+        (32, 8),
+        (32, 8),
+        (512, 8),
+    ),
+    amd(
+        "Zen 4",
+        0x19,
+        &[0x11, 0x61, 0x74, 0xa0],
+        (32, 8),
+        (32, 8),
+        (1024, 8),
+    ),
+];
+
+/// Check `cpuid` against the generic rules and its datasheet, returns the name of the
+/// datasheet it was compared with.
+fn check<R: CpuIdReader>(name: &str, cpuid: &CpuId<R>) -> Option<&'static str> {
+    let vendor = cpuid.get_vendor_info().expect("Vendor leaf");
+    let info = cpuid.get_feature_info().expect("Feature leaf");
+
+    // CLFLUSH reports the line size in units of 8 bytes
+    assert_eq!(info.cflush_cache_line_size(), 8, "{}", name);
+
+    let datasheet = DATASHEETS.iter().find(|sheet| {
+        sheet.vendor == vendor.as_str()
+            && sheet.family == info.family_id()
+            && sheet.models.contains(&info.model_id())
+    })?;
+
+    let mut found = 0;
+    for cache in cpuid.get_cache_parameters().expect("Cache parameters") {
+        let expected = match (cache.level(), cache.cache_type()) {
+            (1, CacheType::Data) => datasheet.l1d,
+            (1, CacheType::Instruction) => datasheet.l1i,
+            (2, CacheType::Unified) => datasheet.l2,
+            _ => continue,
+        };
+        let size = cache.associativity()
+            * cache.physical_line_partitions()
+            * cache.coherency_line_size()
+            * cache.sets();
+        let what = format!(
+            "{}: L{} {} ({})",
+            name,
+            cache.level(),
+            cache.cache_type(),
+            datasheet.name
+        );
+
+        assert_eq!(cache.coherency_line_size(), 64, "{}", what);
+        assert_eq!((size / 1024, cache.associativity()), expected, "{}", what);
+        found += 1;
+    }
+    assert_eq!(found, 3, "{}: L1d, L1i and L2 ({})", name, datasheet.name);
+
+    Some(datasheet.name)
+}
+
+#[test]
+fn corpus() {
+    for (name, reader) in super::CORPUS {
+        check(name, &CpuId::with_cpuid_fn(*reader)).expect("Dumps in the corpus have a datasheet");
+    }
+}
+
+/// Parse an InstLatx64 dump, i.e., lines of the form
+/// `CPUID 00000004: 1C004121-01C0003F-0000003F-00000000 [SL 00]`.
+///
+/// Everything else in the file (headers, decoded output) is ignored.
+#[cfg(feature = "alloc")]
+fn parse_instlatx64(dump: &str) -> crate::CpuIdSnapshot {
+    use crate::CpuIdResult;
+
+    dump.lines()
+        .filter_map(|line| {
+            let (leaf, rest) = line.strip_prefix("CPUID ")?.split_once(':')?;
+            let leaf = u32::from_str_radix(leaf.trim(), 16).ok()?;
+            let (registers, subleaf) = match rest.split_once("[SL ") {
+                Some((registers, subleaf)) => (
+                    registers,
+                    u32::from_str_radix(subleaf.trim_end_matches(']').trim(), 16).ok()?,
+                ),
+                None => (rest, 0),
+            };
+
+            let mut registers = registers
+                .trim()
+                .split('-')
+                .map(|value| u32::from_str_radix(value, 16));
+            let mut next = || registers.next()?.ok();
+            let result = CpuIdResult {
+                eax: next()?,
+                ebx: next()?,
+                ecx: next()?,
+                edx: next()?,
+            };
+            Some((leaf, subleaf, result))
+        })
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn instlatx64_format() {
+    let snapshot = parse_instlatx64(
+        "CPUID Dump\n\
+         CPUID 00000000: 00000020-756E6547-6C65746E-49656E69\n\
+         CPUID 00000004: 00000121-02C0003F-0000003F-00000000 [SL 00]\n\
+         CPUID 00000004: 00000122-01C0003F-0000003F-00000000 [SL 01]\n\
+         CPUID 00000004: garbage\n",
+    );
+    let entries = snapshot.entries();
+
+    assert_eq!(entries.len(), 3);
+    assert_eq!(
+        (entries[2].0, entries[2].1, entries[2].2.ebx),
+        (4, 1, 0x01c0003f)
+    );
+    assert_eq!(
+        CpuId::with_cpuid_reader(&snapshot)
+            .get_vendor_info()
+            .unwrap()
+            .as_str(),
+        "GenuineIntel"
+    );
+}
+
+/// Runs the checks on a local InstLatx64 checkout, e.g.
+/// `INSTLATX64_DIR=~/InstLatx64 cargo test --features alloc instlatx64`.
+#[cfg(feature = "alloc")]
+#[test]
+fn instlatx64() {
+    use std::path::Path;
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+
+    fn dumps(dir: &Path, found: &mut Vec<std::path::PathBuf>) {
+        for entry in std::fs::read_dir(dir)
+            .expect("Can't read directory")
+            .flatten()
+        {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if path.is_dir() {
+                dumps(&path, found);
+            } else if name.contains("CPUID") && name.ends_with(".txt") {
+                found.push(path);
+            }
+        }
+    }
+
+    let dir = match std::env::var_os("INSTLATX64_DIR") {
+        Some(dir) => dir,
+        None => return,
+    };
+    let mut paths = Vec::new();
+    dumps(Path::new(&dir), &mut paths);
+    paths.sort();
+
+    let mut checked = 0;
+    for path in &paths {
+        let dump = std::fs::read(path).expect("Can't read dump");
+        let snapshot = parse_instlatx64(&String::from_utf8_lossy(&dump));
+        let cpuid = CpuId::with_cpuid_reader(&snapshot);
+        if cpuid.get_vendor_info().is_none() || cpuid.get_feature_info().is_none() {
+            continue;
+        }
+        if check(&path.display().to_string(), &cpuid).is_some() {
+            checked += 1;
+        }
+    }
+    std::println!(
+        "{} of {} InstLatx64 dumps had a datasheet",
+        checked,
+        paths.len()
+    );
+}
@@ -0,0 +1,377 @@
+//! KVM guest on an Intel Xeon 5th generation (Emerald Rapids) host.
+//!
+//! The hypervisor exposes the host family/model, a generic brand string ("Intel(R) Xeon(R)
+//! Processor") and hides the hybrid, power management and trace leafs.
+
+use crate::{CpuId, CpuIdResult};
+
+cpuid_dump! {
+0x00000000_00000000u64 => CpuIdResult { eax: 0x00000020, ebx: 0x756e6547, ecx: 0x6c65746e, edx: 0x49656e69 },
+0x00000001_00000000u64 => CpuIdResult { eax: 0x000c06f2, ebx: 0x00010800, ecx: 0xfffa3203, edx: 0x0f8bfbff },
+0x00000002_00000000u64 => CpuIdResult { eax: 0x00feff01, ebx: 0x000000f0, ecx: 0x00000000, edx: 0x00000000 },
+0x00000003_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000004_00000000u64 => CpuIdResult { eax: 0x00000121, ebx: 0x02c0003f, ecx: 0x0000003f, edx: 0x00000000 },
+0x00000004_00000001u64 => CpuIdResult { eax: 0x00000122, ebx: 0x01c0003f, ecx: 0x0000003f, edx: 0x00000000 },
+0x00000004_00000002u64 => CpuIdResult { eax: 0x00000143, ebx: 0x03c0003f, ecx: 0x000007ff, edx: 0x00000000 },
+0x00000004_00000003u64 => CpuIdResult { eax: 0x00000163, ebx: 0x04c0003f, ecx: 0x0003bfff, edx: 0x00000004 },
+0x00000004_00000004u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000005_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000006_00000000u64 => CpuIdResult { eax: 0x00000004, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000007_00000000u64 => CpuIdResult { eax: 0x00000002, ebx: 0xf1bf27eb, ecx: 0x1b415fde, edx: 0xbfd14410 },
+0x00000007_00000001u64 => CpuIdResult { eax: 0x00001c30, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000007_00000002u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x0000001f },
+0x00000008_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000009_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000a_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000b_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000001, ecx: 0x00000100, edx: 0x00000000 },
+0x0000000b_00000001u64 => CpuIdResult { eax: 0x00000005, ebx: 0x00000001, ecx: 0x00000201, edx: 0x00000000 },
+0x0000000b_00000002u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000002, edx: 0x00000000 },
+0x0000000c_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000000u64 => CpuIdResult { eax: 0x000602e7, ebx: 0x00002b00, ecx: 0x00002b00, edx: 0x00000000 },
+0x0000000d_00000001u64 => CpuIdResult { eax: 0x0000001f, ebx: 0x00002a00, ecx: 0x00001800, edx: 0x00000000 },
+0x0000000d_00000002u64 => CpuIdResult { eax: 0x00000100, ebx: 0x00000240, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000003u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000004u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000005u64 => CpuIdResult { eax: 0x00000040, ebx: 0x00000440, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000006u64 => CpuIdResult { eax: 0x00000200, ebx: 0x00000480, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000007u64 => CpuIdResult { eax: 0x00000400, ebx: 0x00000680, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000008u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000009u64 => CpuIdResult { eax: 0x00000008, ebx: 0x00000a80, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000000au64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000000bu64 => CpuIdResult { eax: 0x00000010, ebx: 0x00000000, ecx: 0x00000001, edx: 0x00000000 },
+0x0000000d_0000000cu64 => CpuIdResult { eax: 0x00000018, ebx: 0x00000000, ecx: 0x00000001, edx: 0x00000000 },
+0x0000000d_0000000du64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000000eu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000000fu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000010u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000011u64 => CpuIdResult { eax: 0x00000040, ebx: 0x00000ac0, ecx: 0x00000002, edx: 0x00000000 },
+0x0000000d_00000012u64 => CpuIdResult { eax: 0x00002000, ebx: 0x00000b00, ecx: 0x00000006, edx: 0x00000000 },
+0x0000000d_00000013u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000014u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000015u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000016u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000017u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000018u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000019u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000001au64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000001bu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000001cu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000001du64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000001eu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000001fu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000020u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000021u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000022u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000023u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000024u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000025u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000026u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000027u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000028u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000029u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000002au64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000002bu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000002cu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000002du64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000002eu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000002fu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000030u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000031u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000032u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000033u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000034u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000035u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000036u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000037u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000038u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_00000039u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000003au64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000003bu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000003cu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000003du64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000003eu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000d_0000003fu64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000e_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000f_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000f_00000001u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000f_00000002u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000000f_00000003u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000010_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000010_00000001u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000010_00000002u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000010_00000003u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000011_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000012_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000012_00000001u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000012_00000002u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000013_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000014_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000015_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000016_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000017_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000017_00000001u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000017_00000002u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000017_00000003u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000018_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x00000019_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000001a_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000001b_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000001c_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000001d_00000000u64 => CpuIdResult { eax: 0x00000001, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x0000001e_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00004010, ecx: 0x00000000, edx: 0x00000000 },
+0x0000001f_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000001, ecx: 0x00000100, edx: 0x00000000 },
+0x0000001f_00000001u64 => CpuIdResult { eax: 0x00000005, ebx: 0x00000001, ecx: 0x00000201, edx: 0x00000000 },
+0x0000001f_00000002u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000002, edx: 0x00000000 },
+0x00000020_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x40000000_00000000u64 => CpuIdResult { eax: 0x40000001, ebx: 0x4b4d564b, ecx: 0x564b4d56, edx: 0x0000004d },
+0x40000001_00000000u64 => CpuIdResult { eax: 0x01007efb, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x80000000_00000000u64 => CpuIdResult { eax: 0x80000008, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x80000001_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000121, edx: 0x2c100800 },
+0x80000002_00000000u64 => CpuIdResult { eax: 0x65746e49, ebx: 0x2952286c, ecx: 0x6f655820, edx: 0x2952286e },
+0x80000003_00000000u64 => CpuIdResult { eax: 0x6f725020, ebx: 0x73736563, ecx: 0x0000726f, edx: 0x00000000 },
+0x80000004_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x80000005_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
+0x80000006_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x08007040, edx: 0x00000000 },
+0x80000007_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000100 },
+0x80000008_00000000u64 => CpuIdResult { eax: 0x002e392e, ebx: 0x0100d200, ecx: 0x00000000, edx: 0x00000000 },}
+
+#[test]
+fn identification() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    assert_eq!(cpuid.get_vendor_info().unwrap().as_str(), "GenuineIntel");
+    assert_eq!(
+        cpuid.get_processor_brand_string().unwrap().as_str(),
+        "Intel(R) Xeon(R) Processor"
+    );
+
+    let info = cpuid.get_feature_info().unwrap();
+    assert_eq!(info.family_id(), 0x6);
+    assert_eq!(info.model_id(), 0xcf);
+    assert_eq!(info.stepping_id(), 0x2);
+    assert!(info.has_hypervisor());
+
+    let hv = cpuid.get_hypervisor_info().expect("Running in a VM");
+    assert_eq!(hv.identify(), crate::Hypervisor::KVM);
+}
+
+#[test]
+fn avx512() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let features = cpuid.get_extended_feature_info().unwrap();
+    assert!(features.has_avx512f());
+    assert!(features.has_avx512_fp16());
+    assert!(features.has_amx_tile());
+    assert_eq!(cpuid.x86_64_abi_level(), Some(crate::X86_64AbiLevel::V4));
+
+    let avx512 = features.avx512_profile();
+    assert!(avx512.has_avx512_icelake_set());
+    assert!(avx512.has_bf16());
+    assert!(avx512.has_fp16());
+}
+
+#[test]
+fn tile_info() {
+    // The dump only has sub-leaf 0, palette 1 as reported by Sapphire Rapids
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match (eax, ecx) {
+        (0x1d, 1) => CpuIdResult {
+            eax: 0x0400_2000,
+            ebx: 0x0008_0040,
+            ecx: 0x0000_0010,
+            edx: 0,
+        },
+        _ => cpuid_reader(eax, ecx),
+    });
+    let tiles = cpuid.get_tile_info().expect("AMX is supported");
+    assert_eq!(tiles.max_palette(), 1);
+
+    let mut palettes = tiles.palettes();
+    let palette = palettes.next().unwrap();
+    assert_eq!(palette.palette_id(), 1);
+    assert_eq!(palette.total_tile_bytes(), 8192);
+    assert_eq!(palette.bytes_per_tile(), 1024);
+    assert_eq!(palette.bytes_per_row(), 64);
+    assert_eq!(palette.max_names(), 8);
+    assert_eq!(palette.max_rows(), 16);
+    assert!(palettes.next().is_none());
+}
+
+#[test]
+fn extended_features() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let e = cpuid
+        .get_extended_feature_info()
+        .expect("Leaf is supported");
+
+    assert!(!e.has_waitpkg());
+    assert!(!e.has_kl());
+    assert!(e.has_bus_lock_detect());
+    assert!(e.has_cldemote());
+    assert!(e.has_movdiri());
+    assert!(e.has_movdir64b());
+    assert!(!e.has_enqcmd());
+    assert!(!e.has_pks());
+    assert!(e.has_serialize());
+    assert!(!e.has_hybrid());
+    assert!(e.has_tsxldtrk());
+    assert!(e.has_avx512_fp16());
+    assert!(!e.has_avx512_vp2intersect());
+    assert!(!e.has_avx512_4vnniw());
+    assert!(!e.has_avx512_4fmaps());
+}
+
+#[test]
+fn extended_features_subleaf2() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let e = cpuid
+        .get_extended_feature_info()
+        .expect("Leaf is supported");
+
+    assert!(e.has_psfd());
+    assert!(e.has_ipred_ctrl());
+    assert!(e.has_rrsba_ctrl());
+    assert!(e.has_ddpd_u());
+    assert!(e.has_bhi_ctrl());
+    assert!(!e.has_mcdt_no());
+
+    // Sub-leaf 2 is not read if sub-leaf 0 reports a lower max. sub-leaf
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match (eax, ecx) {
+        (0x7, 0) => CpuIdResult {
+            eax: 1,
+            ..cpuid_reader(eax, ecx)
+        },
+        (0x7, 2) => panic!("Sub-leaf 2 is not valid"),
+        _ => cpuid_reader(eax, ecx),
+    });
+    assert!(!cpuid.get_extended_feature_info().unwrap().has_psfd());
+}
+
+#[test]
+fn tmul_info() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let tmul = cpuid.get_tmul_info().expect("AMX is supported");
+    assert_eq!(tmul.tmul_maxk(), 16);
+    assert_eq!(tmul.tmul_maxn(), 64);
+
+    let e = cpuid.get_extended_feature_info().unwrap();
+    assert!(e.has_amx_tile());
+    assert!(e.has_amx_int8());
+    assert!(e.has_amx_bf16());
+    assert!(!e.has_amx_fp16());
+    assert!(!e.has_amx_complex());
+
+    // Leafs 0x1d and 0x1e are only valid with AMX-TILE
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match (eax, ecx) {
+        (0x7, 0) => CpuIdResult {
+            edx: cpuid_reader(eax, ecx).edx & !(1 << 24),
+            ..cpuid_reader(eax, ecx)
+        },
+        _ => cpuid_reader(eax, ecx),
+    });
+    assert!(cpuid.get_tile_info().is_none());
+    assert!(cpuid.get_tmul_info().is_none());
+}
+
+#[test]
+fn xsave_components() {
+    use crate::XSaveComponent;
+    use std::vec::Vec;
+
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let info = cpuid.get_extended_state_info().expect("Leaf is supported");
+    let components: Vec<XSaveComponent> = info.iter().map(|s| s.component()).collect();
+    assert_eq!(
+        components,
+        [
+            XSaveComponent::Avx,
+            XSaveComponent::Avx512Opmask,
+            XSaveComponent::Avx512ZmmHi256,
+            XSaveComponent::Avx512ZmmHi16,
+            XSaveComponent::Pkru,
+            XSaveComponent::CetUser,
+            XSaveComponent::CetSupervisor,
+            XSaveComponent::TileCfg,
+            XSaveComponent::TileData,
+        ]
+    );
+
+    assert_eq!(info.ia32_xss_supported_mask(), 0x1800);
+
+    // Only the tile data can be armed for XFD faulting
+    let xfd: Vec<XSaveComponent> = info
+        .iter()
+        .filter(|s| s.is_xfd_supported())
+        .map(|s| s.component())
+        .collect();
+    assert_eq!(xfd, [XSaveComponent::TileData]);
+
+    let tiledata = info.iter().last().unwrap();
+    assert_eq!(tiledata.subleaf(), 18);
+    assert_eq!(u32::from(tiledata.component()), 18);
+    assert_eq!(tiledata.size(), 8192);
+    assert_eq!(XSaveComponent::from(0x42), XSaveComponent::Unknown(0x42));
+    assert_eq!(XSaveComponent::Unknown(0x42).index(), 0x42);
+}
+
+#[test]
+fn performance_monitoring_ext_info() {
+    // The guest hides leaf 0x23, this is what a host passing it through reports
+    let reader = |eax: u32, ecx: u32| match (eax, ecx) {
+        (0x0, 0) => CpuIdResult {
+            eax: 0x23,
+            ..cpuid_reader(eax, ecx)
+        },
+        (0x7, 1) => CpuIdResult {
+            eax: cpuid_reader(eax, ecx).eax | 1 << 8,
+            ..cpuid_reader(eax, ecx)
+        },
+        (0x23, 0) => CpuIdResult {
+            eax: 0b1011,
+            ebx: 0b11,
+            ecx: 0,
+            edx: 0,
+        },
+        (0x23, 1) => CpuIdResult {
+            eax: 0xff,
+            ebx: 0xf,
+            ecx: 0,
+            edx: 0,
+        },
+        (0x23, 3) => CpuIdResult {
+            eax: 0xff,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        (0x23, _) => panic!("Sub-leaf {} is not valid", ecx),
+        _ => cpuid_reader(eax, ecx),
+    };
+    let cpuid = CpuId::with_cpuid_fn(reader);
+    let pmu = cpuid
+        .get_performance_monitoring_ext_info()
+        .expect("Leaf is supported");
+
+    assert_eq!(pmu.valid_subleafs(), 0b1011);
+    assert!(pmu.has_umask2());
+    assert!(pmu.has_eq());
+    assert_eq!(pmu.general_counters(), 0xff);
+    assert_eq!(pmu.fixed_counters(), 0xf);
+    assert_eq!(pmu.events(), 0xff);
+    assert!(pmu.has_topdown_slots_ev());
+    assert!(!pmu.has_topdown_backend_bound_ev());
+
+    // Without the enumeration bit the leaf is not valid
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    assert!(cpuid.get_performance_monitoring_ext_info().is_none());
+}
+
+#[cfg(feature = "uarch-db")]
+#[test]
+fn microarchitecture() {
+    use crate::uarch::{Confidence, UArch};
+
+    // The real model is passed through, so this is not a guess
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let uarch = cpuid.get_microarchitecture().unwrap();
+    assert_eq!(uarch.uarch(), UArch::EmeraldRapids);
+    assert_eq!(uarch.confidence(), Confidence::High);
+}
@@ -0,0 +1,112 @@
+//! A corpus of CPUID dumps taken from real machines.
+//!
+//! Each dump lives in its own module and is defined with [`cpuid_dump!`], which also
+//! generates a `cpuid_reader` for it. Dumps are registered in [`CORPUS`] so the checks in
+//! this module run on all of them, dump specific assertions go into the dump's module.
+//!
+//! To add a dump, print the entries of [`CpuIdSnapshot::entries`](crate::CpuIdSnapshot)
+//! on the machine in question (the order is already the one the macro expects).
+
+use crate::{CpuId, CpuIdResult};
+use std::format;
+
+/// Define a dump as `0xLEAF_SUBLEAFu64 => CpuIdResult { .. },` entries sorted by leaf and
+/// sub-leaf, and a `cpuid_reader` function for it.
+///
+/// Like most processors, the reader returns zero for leafs and sub-leafs not in the dump.
+macro_rules! cpuid_dump {
+    ($($key:literal => $value:expr,)*) => {
+        pub(super) static DUMP: &[(u64, CpuIdResult)] = &[$(($key, $value),)*];
+
+        pub(super) fn cpuid_reader(eax: u32, ecx: u32) -> CpuIdResult {
+            super::read(DUMP, eax, ecx)
+        }
+    };
+}
+
+mod datasheet;
+mod emr_kvm_guest;
+
+type Reader = fn(u32, u32) -> CpuIdResult;
+
+/// All dumps with a short description.
+const CORPUS: &[(&str, Reader)] = &[(
+    "Xeon (Emerald Rapids) KVM guest",
+    emr_kvm_guest::cpuid_reader,
+)];
+
+fn read(dump: &[(u64, CpuIdResult)], eax: u32, ecx: u32) -> CpuIdResult {
+    let key = (eax as u64) << u32::BITS | ecx as u64;
+    match dump.binary_search_by_key(&key, |(key, _)| *key) {
+        Ok(index) => dump[index].1,
+        Err(_) => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    }
+}
+
+#[test]
+fn dumps_are_sorted() {
+    for dump in [emr_kvm_guest::DUMP] {
+        assert!(dump.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
+
+/// Every accessor decodes every dump without panicking, and the results agree with each
+/// other.
+#[test]
+fn all_leafs() {
+    for (name, reader) in CORPUS {
+        let cpuid = CpuId::with_cpuid_fn(*reader);
+
+        // The Debug implementation calls every get_* function
+        assert!(!format!("{:?}", cpuid).is_empty(), "{}", name);
+
+        let vendor = cpuid.get_vendor_info().expect("Vendor leaf");
+        assert!(!vendor.as_str().is_empty(), "{}", name);
+
+        assert!(cpuid.vendor_consistent(), "{}", name);
+
+        let info = cpuid.get_feature_info().expect("Feature leaf");
+        assert!(info.family_id() != 0, "{}", name);
+        assert_eq!(
+            info.has_hypervisor(),
+            cpuid.get_hypervisor_info().is_some(),
+            "{}",
+            name
+        );
+
+        if let Some(brand) = cpuid.get_processor_brand_string() {
+            assert!(brand.as_str().is_ascii(), "{}", name);
+        }
+
+        if let Some(caches) = cpuid.get_cache_parameters() {
+            for cache in caches {
+                assert!((1..=4).contains(&cache.level()), "{}", name);
+                assert!(cache.sets() > 0, "{}", name);
+            }
+        }
+
+        if let Some(levels) = cpuid.get_extended_topology_info() {
+            for level in levels {
+                assert!(level.processors() > 0, "{}", name);
+            }
+        }
+
+        if let Some(state) = cpuid.get_extended_state_info() {
+            for section in state.iter() {
+                assert!(section.size() > 0, "{}", name);
+            }
+        }
+
+        let capabilities = cpuid.get_cpu_capabilities();
+        assert!(capabilities.has_sse2(), "{}", name);
+        assert!(cpuid.x86_64_abi_level().is_some(), "{}", name);
+
+        #[cfg(feature = "uarch-db")]
+        assert!(cpuid.get_microarchitecture().is_some(), "{}", name);
+    }
+}
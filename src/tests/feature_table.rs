@@ -0,0 +1,119 @@
+use crate::feature_table::{self, Register, Vendors, FEATURES};
+use crate::*;
+
+/// A processor that sets `bits` of every register in the feature leafs.
+#[cfg(feature = "alloc")]
+fn with_bits(bits: impl Fn(u32, u32, Register) -> u32 + Clone) -> CpuId<impl CpuIdReader> {
+    CpuId::with_cpuid_fn(move |a, c| match a {
+        0 => CpuIdResult {
+            eax: 0x7,
+            ebx: 0x756e_6547,
+            ecx: 0x6c65_746e,
+            edx: 0x4965_6e69,
+        },
+        0x8000_0000 => CpuIdResult {
+            eax: 0x8000_0001,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        // Sub-leaf 0 of leaf 7 reports the max sub-leaf
+        0x7 if c == 0 => CpuIdResult {
+            eax: 2,
+            ebx: bits(a, c, Register::Ebx),
+            ecx: bits(a, c, Register::Ecx),
+            edx: bits(a, c, Register::Edx),
+        },
+        0x1 | 0x7 | 0x8000_0001 => CpuIdResult {
+            eax: bits(a, c, Register::Eax),
+            ebx: bits(a, c, Register::Ebx),
+            ecx: bits(a, c, Register::Ecx),
+            edx: bits(a, c, Register::Edx),
+        },
+        _ => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    })
+}
+
+#[test]
+fn sorted_and_unique() {
+    let key = |f: &feature_table::FeatureBit| (f.leaf(), f.subleaf(), f.register(), f.bit());
+    assert!(FEATURES.windows(2).all(|w| key(&w[0]) < key(&w[1])));
+    for (idx, feature) in FEATURES.iter().enumerate() {
+        assert!(feature.bit() < 32);
+        assert!(!feature.description().is_empty());
+        assert!(
+            FEATURES[..idx].iter().all(|f| f.name() != feature.name()),
+            "{} is duplicated",
+            feature
+        );
+    }
+}
+
+#[test]
+fn lookup() {
+    let sse41 = feature_table::find("sse41").expect("Have SSE4.1");
+    assert_eq!(sse41.name(), "SSE41");
+    assert_eq!(feature_table::at(0x1, 0, Register::Ecx, 19), Some(sse41));
+    assert_eq!(std::format!("{}", sse41), "SSE41 (0x1/0x0.ecx[19])");
+    assert!(sse41.is_set(CpuIdResult {
+        eax: 0,
+        ebx: 0,
+        ecx: 1 << 19,
+        edx: 0,
+    }));
+    assert!(feature_table::at(0x1, 0, Register::Ecx, 11).is_none());
+
+    let svm = feature_table::find("SVM").expect("Have SVM");
+    assert_eq!(svm.vendors(), Vendors::Amd);
+    assert!(svm.vendors().amd() && !svm.vendors().intel());
+
+    assert_eq!(feature_table::in_leaf(0x7, 1).count(), 33);
+    assert!(feature_table::in_leaf(0x7, 1).all(|f| f.leaf() == 0x7 && f.subleaf() == 1));
+}
+
+/// The table and the flags decoded by [CpuId::features] agree.
+#[cfg(feature = "alloc")]
+#[test]
+fn matches_features() {
+    // Every flag the library decodes is in the table
+    let all = with_bits(|_, _, _| u32::MAX).features();
+    for name in all.iter() {
+        assert!(feature_table::find(name).is_some(), "{} is missing", name);
+    }
+    assert_eq!(all.len(), FEATURES.len());
+
+    // ...at the right location
+    for feature in FEATURES {
+        let cpuid = with_bits(|leaf, subleaf, register| {
+            if (leaf, subleaf, register) == (feature.leaf(), feature.subleaf(), feature.register())
+            {
+                1 << feature.bit()
+            } else {
+                0
+            }
+        });
+        assert_eq!(cpuid.features(), [feature.name()], "{}", feature);
+    }
+}
+
+/// Every feature flag in the table is a defined bit.
+#[test]
+fn features_are_defined_bits() {
+    for feature in FEATURES {
+        let mask = crate::defined_bits::find(feature.leaf(), feature.subleaf())
+            .map(|mask| mask.mask())
+            .unwrap_or_else(|| panic!("{}", feature.name()));
+        let register = match feature.register() {
+            Register::Eax => mask.eax,
+            Register::Ebx => mask.ebx,
+            Register::Ecx => mask.ecx,
+            Register::Edx => mask.edx,
+        };
+        assert!(register & (1 << feature.bit()) != 0, "{}", feature.name());
+    }
+}
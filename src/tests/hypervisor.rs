@@ -0,0 +1,93 @@
+use crate::hypervisor::*;
+use crate::*;
+
+/// A bare metal snapshot with a stale hypervisor leaf that must be replaced.
+fn host() -> CpuIdSnapshot {
+    let res = |eax, ebx, ecx, edx| CpuIdResult { eax, ebx, ecx, edx };
+    [
+        (0x0, 0, res(0x1, 0x756e_6547, 0x6c65_746e, 0x4965_6e69)),
+        (0x1, 0, res(0x906a3, 0, 0x0200_0000, 0x0200_0000)),
+        (0x4000_0000, 0, res(0x4000_0006, 0, 0, 0)),
+        (0x4000_0006, 0, res(0xdead_beef, 0, 0, 0)),
+    ]
+    .iter()
+    .copied()
+    .collect()
+}
+
+#[test]
+fn kvm_profile() {
+    let kvm = KvmProfile {
+        features: KvmFeatures::CLOCKSOURCE2 | KvmFeatures::PV_UNHALT,
+        hints: KvmHints::REALTIME,
+        ..Default::default()
+    };
+    let guest = host().with_hypervisor_leafs(kvm.leafs());
+    let cpuid = guest.cpuid();
+
+    let finfo = cpuid.get_feature_info().unwrap();
+    assert!(finfo.has_hypervisor() && finfo.has_aesni());
+    let info = cpuid.get_hypervisor_info().unwrap();
+    assert_eq!(info.identify(), Hypervisor::KVM);
+    assert_eq!(info.tsc_frequency(), None);
+    assert_eq!(guest.get(0x4000_0000, 0).unwrap().eax, 0x4000_0001);
+    assert_eq!(
+        guest.get(0x4000_0001, 0).unwrap(),
+        CpuIdResult {
+            eax: 0x88,
+            ebx: 0,
+            ecx: 0,
+            edx: 0x1
+        }
+    );
+    assert_eq!(guest.get(0x4000_0006, 0), None);
+
+    // Setting a frequency adds the timing leaf
+    let kvm = KvmProfile {
+        tsc_frequency_khz: Some(2_400_000),
+        ..kvm
+    };
+    let guest = host().with_hypervisor_leafs(kvm.leafs());
+    let info = guest.cpuid().get_hypervisor_info().unwrap();
+    assert_eq!(info.tsc_frequency(), Some(2_400_000));
+    assert_eq!(info.apic_frequency(), Some(0));
+}
+
+#[test]
+fn hyperv_profile() {
+    let hyperv = HyperVProfile {
+        build_number: 19041,
+        privileges: HyperVPrivileges::ACCESS_VP_INDEX | HyperVPrivileges::POST_MESSAGES,
+        features: HyperVFeatures::GUEST_CRASH_MSRS,
+        spinlock_retries: Some(0x1fff),
+        max_vcpus: 240,
+        ..Default::default()
+    };
+    let guest = host().with_hypervisor_leafs(hyperv.leafs());
+
+    let info = guest.cpuid().get_hypervisor_info().unwrap();
+    assert_eq!(info.identify(), Hypervisor::HyperV);
+    let leaf = |leaf| guest.get(leaf, 0).unwrap();
+    assert_eq!(leaf(0x4000_0000).eax, 0x4000_0005);
+    assert_eq!(&leaf(0x4000_0001).eax.to_le_bytes(), b"Hv#1");
+    assert_eq!(
+        (leaf(0x4000_0002).eax, leaf(0x4000_0002).ebx),
+        (19041, 10 << 16)
+    );
+    assert_eq!(
+        leaf(0x4000_0003),
+        CpuIdResult {
+            eax: 1 << 6,
+            ebx: 1 << 4,
+            ecx: 0,
+            edx: 1 << 10
+        }
+    );
+    assert_eq!(leaf(0x4000_0004).eax, 0x28);
+    assert_eq!(leaf(0x4000_0004).ebx, 0x1fff);
+    assert_eq!(leaf(0x4000_0005).eax, 240);
+    assert_eq!(guest.get(0x4000_0006, 0), None);
+
+    let never = HyperVProfile::default().leafs();
+    assert_eq!(never[4].2.ebx, u32::MAX);
+}
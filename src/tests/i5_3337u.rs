@@ -153,6 +153,7 @@ fn cache_parameters() {
 #[test]
 fn monitor_mwait_features() {
     let mmfeatures = MonitorMwaitInfo {
+        vendor: Vendor::Intel,
         eax: 64,
         ebx: 64,
         ecx: 3,
@@ -178,7 +179,7 @@ fn thermal_power_features() {
         eax: ThermalPowerFeaturesEax::from_bits_truncate(119),
         ebx: 2,
         ecx: ThermalPowerFeaturesEcx::from_bits_truncate(9),
-        _edx: 0,
+        edx: 0,
     };
 
     assert!(tpfeatures.eax.contains(ThermalPowerFeaturesEax::DTS));
@@ -212,7 +213,7 @@ fn thermal_power_features() {
             | ThermalPowerFeaturesEax::HDC,
         ebx: 2,
         ecx: ThermalPowerFeaturesEcx::HW_COORD_FEEDBACK | ThermalPowerFeaturesEcx::ENERGY_BIAS_PREF,
-        _edx: 0,
+        edx: 0,
     };
 
     assert!(tpfeatures.has_dts());
@@ -241,9 +242,10 @@ fn extended_features() {
         ecx: ExtendedFeaturesEcx::from_bits_truncate(0),
         edx: ExtendedFeaturesEdx::from_bits_truncate(0),
         eax1: ExtendedFeaturesEax1::from_bits_truncate(0),
-        _ebx1: 0,
+        ebx1: ExtendedFeaturesEbx1::from_bits_truncate(0),
         _ecx1: 0,
         edx1: ExtendedFeaturesEdx1::from_bits_truncate(0),
+        edx2: ExtendedFeaturesEdx2::from_bits_truncate(0),
     };
     assert!(tpfeatures._eax == 0);
     assert!(tpfeatures.has_fsgsbase());
@@ -279,9 +281,10 @@ fn extended_features() {
         ecx: ExtendedFeaturesEcx::from_bits_truncate(0),
         edx: ExtendedFeaturesEdx::from_bits_truncate(201326592),
         eax1: ExtendedFeaturesEax1::from_bits_truncate(0),
-        _ebx1: 0,
+        ebx1: ExtendedFeaturesEbx1::from_bits_truncate(0),
         _ecx1: 0,
         edx1: ExtendedFeaturesEdx1::from_bits_truncate(0),
+        edx2: ExtendedFeaturesEdx2::from_bits_truncate(0),
     };
 
     assert!(tpfeatures2.has_fsgsbase());
@@ -328,7 +331,7 @@ fn performance_monitoring_info() {
     let pm = PerformanceMonitoringInfo {
         eax: 120587267,
         ebx: PerformanceMonitoringFeaturesEbx::from_bits_truncate(0),
-        _ecx: 0,
+        ecx: 0,
         edx: 1539,
     };
 
@@ -431,7 +434,7 @@ fn extended_state_info() {
         eax1: 1,
         ebx1: 0,
         ecx1: ExtendedStateInfoXSSFlags::from_bits_truncate(0),
-        _edx1: 0,
+        edx1: 0,
     };
 
     assert!(es.xsave_area_size_enabled_features() == 832);
@@ -466,7 +469,7 @@ fn extended_state_info3() {
         eax1: 15,
         ebx1: 2560,
         ecx1: ExtendedStateInfoXSSFlags::PT,
-        _edx1: 0,
+        edx1: 0,
     };
 
     assert!(esi.xcr0_supports_legacy_x87());
@@ -492,49 +495,49 @@ fn extended_state_info3() {
 
     let es = [
         ExtendedState {
-            subleaf: 2,
+            component: XSaveComponent::from(2),
             eax: 256,
             ebx: 576,
             ecx: 0,
         },
         ExtendedState {
-            subleaf: 3,
+            component: XSaveComponent::from(3),
             eax: 64,
             ebx: 960,
             ecx: 0,
         },
         ExtendedState {
-            subleaf: 4,
+            component: XSaveComponent::from(4),
             eax: 64,
             ebx: 1024,
             ecx: 0,
         },
         ExtendedState {
-            subleaf: 5,
+            component: XSaveComponent::from(5),
             eax: 64,
             ebx: 1088,
             ecx: 0,
         },
         ExtendedState {
-            subleaf: 6,
+            component: XSaveComponent::from(6),
             eax: 512,
             ebx: 1152,
             ecx: 0,
         },
         ExtendedState {
-            subleaf: 7,
+            component: XSaveComponent::from(7),
             eax: 1024,
             ebx: 1664,
             ecx: 0,
         },
         ExtendedState {
-            subleaf: 8,
+            component: XSaveComponent::from(8),
             eax: 128,
             ebx: 0,
             ecx: 1,
         },
         ExtendedState {
-            subleaf: 9,
+            component: XSaveComponent::from(9),
             eax: 8,
             ebx: 2688,
             ecx: 0,
@@ -542,7 +545,8 @@ fn extended_state_info3() {
     ];
 
     let e = &es[0];
-    assert!(e.subleaf == 2);
+    assert!(e.subleaf() == 2);
+    assert!(e.component() == XSaveComponent::Avx);
     assert!(e.size() == 256);
     assert!(e.offset() == 576);
     assert!(e.is_in_xcr0());
@@ -550,7 +554,7 @@ fn extended_state_info3() {
     assert!(!e.is_compacted_format());
 
     let e = &es[1];
-    assert!(e.subleaf == 3);
+    assert!(e.subleaf() == 3);
     assert!(e.size() == 64);
     assert!(e.offset() == 960);
     assert!(e.is_in_xcr0());
@@ -558,7 +562,7 @@ fn extended_state_info3() {
     assert!(!e.is_compacted_format());
 
     let e = &es[2];
-    assert!(e.subleaf == 4);
+    assert!(e.subleaf() == 4);
     assert!(e.size() == 64);
     assert!(e.offset() == 1024);
     assert!(e.is_in_xcr0());
@@ -566,7 +570,7 @@ fn extended_state_info3() {
     assert!(!e.is_compacted_format());
 
     let e = &es[3];
-    assert!(e.subleaf == 5);
+    assert!(e.subleaf() == 5);
     assert!(e.size() == 64);
     assert!(e.offset() == 1088);
     assert!(e.is_in_xcr0());
@@ -574,7 +578,7 @@ fn extended_state_info3() {
     assert!(!e.is_compacted_format());
 
     let e = &es[4];
-    assert!(e.subleaf == 6);
+    assert!(e.subleaf() == 6);
     assert!(e.size() == 512);
     assert!(e.offset() == 1152);
     assert!(e.is_in_xcr0());
@@ -582,7 +586,7 @@ fn extended_state_info3() {
     assert!(!e.is_compacted_format());
 
     let e = &es[5];
-    assert!(e.subleaf == 7);
+    assert!(e.subleaf() == 7);
     assert!(e.size() == 1024);
     assert!(e.offset() == 1664);
     assert!(e.is_in_xcr0());
@@ -590,7 +594,7 @@ fn extended_state_info3() {
     assert!(!e.is_compacted_format());
 
     let e = &es[6];
-    assert!(e.subleaf == 8);
+    assert!(e.subleaf() == 8);
     assert!(e.size() == 128);
     assert!(e.offset() == 0);
     assert!(!e.is_in_xcr0());
@@ -598,7 +602,7 @@ fn extended_state_info3() {
     assert!(!e.is_compacted_format());
 
     let e = &es[7];
-    assert!(e.subleaf == 9);
+    assert!(e.subleaf() == 9);
     assert!(e.size() == 8);
     assert!(e.offset() == 2688);
     assert!(e.is_in_xcr0());
@@ -617,7 +621,7 @@ fn extended_state_info2() {
         eax1: 15,
         ebx1: 960,
         ecx1: ExtendedStateInfoXSSFlags::from_bits_truncate(256),
-        _edx1: 0,
+        edx1: 0,
     };
 
     assert!(es.xcr0_supports_legacy_x87());
@@ -640,19 +644,19 @@ fn extended_state_info2() {
 
     let esiter: [ExtendedState; 3] = [
         ExtendedState {
-            subleaf: 2,
+            component: XSaveComponent::from(2),
             eax: 256,
             ebx: 576,
             ecx: 0,
         },
         ExtendedState {
-            subleaf: 3,
+            component: XSaveComponent::from(3),
             eax: 64,
             ebx: 960,
             ecx: 0,
         },
         ExtendedState {
-            subleaf: 4,
+            component: XSaveComponent::from(4),
             eax: 64,
             ebx: 1024,
             ecx: 0,
@@ -660,21 +664,21 @@ fn extended_state_info2() {
     ];
 
     let e = &esiter[0];
-    assert!(e.subleaf == 2);
+    assert!(e.subleaf() == 2);
     assert!(e.size() == 256);
     assert!(e.offset() == 576);
     assert!(e.is_in_xcr0());
     assert!(!e.is_in_ia32_xss());
 
     let e = &esiter[1];
-    assert!(e.subleaf == 3);
+    assert!(e.subleaf() == 3);
     assert!(e.size() == 64);
     assert!(e.offset() == 960);
     assert!(e.is_in_xcr0());
     assert!(!e.is_in_ia32_xss());
 
     let e = &esiter[2];
-    assert!(e.subleaf == 4);
+    assert!(e.subleaf() == 4);
     assert!(e.size() == 64);
     assert!(e.offset() == 1024);
     assert!(e.is_in_xcr0());
@@ -739,6 +743,7 @@ fn processor_brand_string() {
     ]);
 
     assert_eq!(pbs.as_str(), "Intel(R) Core(TM) i5-3337U CPU @ 1.80GHz");
+    assert_eq!(pbs.nominal_frequency().map(|f| f.hz()), Some(1_800_000_000));
 }
 
 #[cfg(test)]
@@ -747,13 +752,11 @@ fn sgx_test() {
     let sgx = SgxInfo {
         read: CpuIdReaderNative,
         eax: 1,
-        ebx: 0,
+        miscselect: SgxMiscSelect::from_bits_retain(0),
         _ecx: 0,
         edx: 9247,
-        eax1: 54,
-        ebx1: 0,
-        ecx1: 31,
-        edx1: 0,
+        attributes: SgxAttributes::from_bits_retain(54),
+        xfrm: 31,
     };
 
     assert!(sgx.max_enclave_size_64bit() == 0x24);
@@ -761,5 +764,23 @@ fn sgx_test() {
     assert!(sgx.has_sgx1());
     assert!(!sgx.has_sgx2());
     assert!(sgx.miscselect() == 0x0);
+    assert!(!sgx.has_exinfo());
+    assert!(!sgx.has_cpinfo());
+
+    let sgx = SgxInfo {
+        miscselect: SgxMiscSelect::from_bits_retain(0b101),
+        ..sgx
+    };
+    assert!(sgx.has_exinfo());
+    assert!(!sgx.has_cpinfo());
+    assert_eq!(sgx.miscselect(), 0b101);
     assert!(sgx.secs_attributes() == (0x0000000000000036, 0x000000000000001f));
+    assert!(sgx.secs_supports_debug());
+    assert!(sgx.secs_supports_mode64bit());
+    assert!(sgx.secs_supports_provisionkey());
+    assert!(sgx.secs_supports_einittokenkey());
+    assert!(!sgx.secs_supports_cet());
+    assert!(!sgx.secs_supports_kss());
+    assert!(!sgx.secs_supports_aexnotify());
+    assert_eq!(sgx.secs_xfrm_mask(), 0x1f);
 }
@@ -1,4 +1,4 @@
-use crate::{CpuId, CpuIdResult};
+use crate::{CpuCapabilities, CpuId, CpuIdResult};
 
 // CPU:
 //    vendor_id = "GenuineIntel"
@@ -743,6 +743,7 @@ static CPUID_VALUE_MAP: phf::Map<u64, CpuIdResult> = phf::phf_map! {
     0x00000004_00000001u64 => CpuIdResult { eax: 0xfc004122, ebx: 0x01c0003f, ecx: 0x0000003f,  edx: 0x00000000 },
     0x00000004_00000002u64 => CpuIdResult { eax: 0xfc01c143, ebx: 0x0240003f, ecx: 0x000007ff,  edx: 0x00000000 },
     0x00000004_00000003u64 => CpuIdResult { eax: 0xfc1fc163, ebx: 0x0240003f, ecx: 0x00009fff,  edx: 0x00000004 },
+    0x00000004_00000004u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000,  edx: 0x00000000 },
     0x00000005_00000000u64 => CpuIdResult { eax: 0x00000040, ebx: 0x00000040, ecx: 0x00000003,  edx: 0x10102020 },
     0x00000006_00000000u64 => CpuIdResult { eax: 0x00dfcff7, ebx: 0x00000002, ecx: 0x00000401,  edx: 0x00000003 },
     0x00000007_00000000u64 => CpuIdResult { eax: 0x00000002, ebx: 0x239c27eb, ecx: 0x98c027bc,  edx: 0xfc1cc410 },
@@ -753,6 +754,7 @@ static CPUID_VALUE_MAP: phf::Map<u64, CpuIdResult> = phf::phf_map! {
     0x0000000a_00000000u64 => CpuIdResult { eax: 0x07300605, ebx: 0x00000000, ecx: 0x00000007,  edx: 0x00008603 },
     0x0000000b_00000000u64 => CpuIdResult { eax: 0x00000001, ebx: 0x00000002, ecx: 0x00000100,  edx: 0x00000000 },
     0x0000000b_00000001u64 => CpuIdResult { eax: 0x00000007, ebx: 0x00000014, ecx: 0x00000201,  edx: 0x00000000 },
+    0x0000000b_00000002u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000002,  edx: 0x00000000 },
     0x0000000c_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000,  edx: 0x00000000 },
     0x0000000d_00000000u64 => CpuIdResult { eax: 0x00000207, ebx: 0x00000a88, ecx: 0x00000a88,  edx: 0x00000000 },
     0x0000000d_00000001u64 => CpuIdResult { eax: 0x0000000f, ebx: 0x00000670, ecx: 0x00019900,  edx: 0x00000000 },
@@ -786,6 +788,7 @@ static CPUID_VALUE_MAP: phf::Map<u64, CpuIdResult> = phf::phf_map! {
     0x00000019_00000000u64 => CpuIdResult { eax: 0x00000007, ebx: 0x00000014, ecx: 0x00000003,  edx: 0x00000000 },
     0x0000001a_00000000u64 => CpuIdResult { eax: 0x40000001, ebx: 0x00000000, ecx: 0x00000000,  edx: 0x00000000 },
     0x0000001b_00000000u64 => CpuIdResult { eax: 0x00000001, ebx: 0x00000001, ecx: 0x00000000,  edx: 0x00000000 },
+    0x0000001b_00000001u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000,  edx: 0x00000000 },
     0x0000001c_00000000u64 => CpuIdResult { eax: 0x4000000b, ebx: 0x00000007, ecx: 0x00000007,  edx: 0x00000000 },
     0x0000001d_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000,  edx: 0x00000000 },
     0x0000001e_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000,  edx: 0x00000000 },
@@ -820,11 +823,46 @@ fn vendor_check() {
     assert_eq!(v.as_str(), "GenuineIntel");
 }
 
+#[test]
+fn x86_64_abi_level() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    assert_eq!(cpuid.x86_64_abi_level(), Some(crate::X86_64AbiLevel::V3));
+    assert_eq!(
+        cpuid
+            .x86_64_abi_missing_features()
+            .collect::<std::vec::Vec<_>>(),
+        ["AVX512F", "AVX512BW", "AVX512CD", "AVX512DQ", "AVX512VL"]
+    );
+}
+
+#[cfg(feature = "uarch-db")]
+#[test]
+fn microarchitecture() {
+    use crate::uarch::UArch;
+
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let uarch = cpuid
+        .get_microarchitecture()
+        .expect("Known micro-architecture");
+    assert_eq!(uarch.uarch(), UArch::AlderLake);
+    assert_eq!(
+        crate::uarch::identify_from_leafs(cpuid_reader(0, 0), cpuid_reader(1, 0)),
+        Some(uarch)
+    );
+    assert_eq!(uarch.confidence(), crate::uarch::Confidence::High);
+
+    let actual = cpuid.get_cpu_capabilities();
+    assert!(uarch.expected_features().difference(&actual).is_empty());
+}
+
 /// Check feature info gives correct values for CPU
 #[test]
 fn version_info() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
     let f = cpuid.get_feature_info().expect("Need to find feature info");
+    assert_eq!(f.display_family(), 6);
+    assert_eq!(f.display_model(), 151);
+    assert_eq!(f.processor_type(), crate::ProcessorType::OriginalOem);
 
     assert_eq!(f.base_family_id(), 6);
     assert_eq!(f.base_model_id(), 7);
@@ -921,12 +959,34 @@ fn cache_info() {
     }
 }
 
+#[test]
+fn cache_and_tlb_info() {
+    use std::vec::Vec;
+
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let ci = cpuid.get_cache_info().expect("Leaf is supported");
+    assert!(ci.uses_leaf4());
+    assert!(ci.uses_leaf18());
+
+    let info = cpuid.get_cache_and_tlb_info().expect("Leaf is supported");
+    // Only the prefetch descriptor remains once 0xff and 0xfe are resolved
+    let nums: Vec<u8> = info.descriptors().map(|cache| cache.num).collect();
+    assert_eq!(nums, [0xf0]);
+    assert_eq!(
+        info.cache_parameters().map(|caches| caches.count()),
+        cpuid.get_cache_parameters().map(|caches| caches.count())
+    );
+    assert_eq!(info.tlb_info().map(|tlbs| tlbs.count()), Some(8));
+}
+
 #[test]
 fn processor_serial() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
     let psn = cpuid.get_processor_serial().expect("Leaf is supported");
     assert_eq!(psn.serial_lower(), 0x0);
     assert_eq!(psn.serial_middle(), 0x0);
+    assert_eq!(psn.serial_all(), (psn.serial_upper() as u128) << 64);
+    assert_eq!(std::format!("{}", psn), "0009-0672-0000-0000-0000-0000");
 }
 
 #[test]
@@ -946,6 +1006,8 @@ fn monitor_mwait() {
     assert_eq!(mw.supported_c5_states(), 0x1);
     assert_eq!(mw.supported_c6_states(), 0x0);
     assert_eq!(mw.supported_c7_states(), 0x1);
+
+    assert!(!cpuid.has_monitorx_mwaitx());
 }
 
 #[test]
@@ -972,10 +1034,17 @@ fn thermal_power() {
     assert!(mw.has_hwp_fast_access_mode());
     assert!(mw.has_hw_coord_feedback());
     assert!(mw.has_ignore_idle_processor_hwp_request());
+    assert!(mw.has_hw_feedback());
+    assert!(mw.has_thread_director());
     // some missing
     assert_eq!(mw.dts_irq_threshold(), 0x2);
     // some missing
     assert!(!mw.has_energy_bias_pref());
+    assert_eq!(mw.thread_director_classes(), 4);
+    assert!(mw.has_hfi_performance_reporting());
+    assert!(mw.has_hfi_efficiency_reporting());
+    assert_eq!(mw.hfi_table_size(), 1);
+    assert_eq!(mw.hfi_table_row_index(), 0);
 }
 
 #[test]
@@ -1025,9 +1094,104 @@ fn extended_features() {
     assert!(e.has_rdpid());
     assert!(e.has_waitpkg());
     assert!(!e.has_sgx_lc());
+    assert!(e.has_cet_ss());
+    assert!(e.has_cet_ibt());
+    assert!(e.has_kl());
+    assert!(!e.has_bus_lock_detect());
+    assert!(!e.has_cldemote());
+    assert!(e.has_movdiri());
+    assert!(e.has_movdir64b());
+    assert!(!e.has_enqcmd());
+    assert!(e.has_pks());
+    assert!(e.has_serialize());
+    assert!(e.has_hybrid());
+    assert!(!e.has_tsxldtrk());
+    assert!(!e.has_avx512_fp16());
+    assert!(!e.has_avx512_vp2intersect());
+    assert!(!e.has_avx512_4vnniw());
+    assert!(!e.has_avx512_4fmaps());
     assert_eq!(e.mawau_value(), 0x0);
 }
 
+#[test]
+fn extended_features_subleaf1() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let e = cpuid
+        .get_extended_feature_info()
+        .expect("Leaf is supported");
+
+    assert!(e.has_avx_vnni());
+    assert!(!e.has_avx512_bf16());
+    assert!(!e.has_fzrm());
+    assert!(e.has_fsrs());
+    assert!(!e.has_fsrcrs());
+    assert!(e.has_hreset());
+    assert!(!e.has_cmpccxadd());
+    assert!(!e.has_lam());
+    assert!(!e.has_ppin());
+    assert!(!e.has_avx_vnni_int8());
+
+    // Sub-leaf 1 of a Sierra Forest like part
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match (eax, ecx) {
+        (0x7, 1) => CpuIdResult {
+            eax: 0x0c8c_0c90,
+            ebx: 0x1,
+            ecx: 0x0,
+            edx: 0x4_0430,
+        },
+        _ => cpuid_reader(eax, ecx),
+    });
+    let e = cpuid
+        .get_extended_feature_info()
+        .expect("Leaf is supported");
+
+    assert!(e.has_avx_vnni());
+    assert!(e.has_cmpccxadd());
+    assert!(e.has_fzrm());
+    assert!(e.has_fsrs());
+    assert!(e.has_lkgs());
+    assert!(e.has_wrmsrns());
+    assert!(e.has_avx_ifma());
+    assert!(e.has_lam());
+    assert!(e.has_msrlist());
+    assert!(!e.has_fred());
+    assert!(e.has_ppin());
+    assert!(!e.has_pbndkb());
+    assert!(e.has_avx_vnni_int8());
+    assert!(e.has_avx_ne_convert());
+    assert!(e.has_avx_vnni_int16());
+    assert!(e.has_cet_sss());
+    assert!(!e.has_avx10());
+    assert!(!e.has_apx_f());
+}
+
+#[test]
+fn cpu_capabilities() {
+    use core::cell::Cell;
+
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let caps = cpuid.get_cpu_capabilities();
+    assert!(caps.has_sse42());
+    assert!(caps.has_avx());
+    assert!(caps.has_avx2());
+    assert!(caps.has_fma());
+    assert!(caps.has_bmi2());
+    assert!(caps.has_popcnt());
+    assert!(caps.has_aesni());
+    assert!(caps.has_sha());
+    assert!(caps.has_vaes());
+    assert!(!caps.has_avx512f());
+    assert!(!caps.has_avx512bw());
+
+    let reads = Cell::new(0);
+    let direct = CpuCapabilities::with_cpuid_reader(|a, c| {
+        reads.set(reads.get() + 1);
+        cpuid_reader(a, c)
+    });
+    assert_eq!(reads.get(), 3);
+    assert_eq!(direct, caps);
+}
+
 #[test]
 fn direct_cache_access() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
@@ -1059,6 +1223,12 @@ fn perfmon_info() {
     assert_eq!(pm.fixed_function_counters(), 0x3);
     assert_eq!(pm.fixed_function_counters_bit_width(), 0x30);
     assert!(pm.has_any_thread_deprecation());
+    assert!(!pm.is_topdown_slots_ev_unavailable());
+    assert_eq!(pm.fixed_counter_bitmap(), 0x7);
+    assert!(pm.is_fixed_counter_supported(0));
+    assert!(pm.is_fixed_counter_supported(2));
+    assert!(!pm.is_fixed_counter_supported(3));
+    assert!(!pm.is_fixed_counter_supported(40));
 }
 
 #[test]
@@ -1109,6 +1279,56 @@ fn extended_topology_info_v2() {
     assert_eq!(t.x2apic_id(), 0);
 }
 
+#[test]
+fn topology_info() {
+    use crate::TopologyType;
+
+    let zero = CpuIdResult {
+        eax: 0,
+        ebx: 0,
+        ecx: 0,
+        edx: 0,
+    };
+    // Leaf 0x0B with a different processor count than leaf 0x1F
+    let reader = |empty_v2: bool| {
+        move |eax: u32, ecx: u32| match (eax, ecx) {
+            (0xb, 1) => CpuIdResult {
+                ebx: 16,
+                ..cpuid_reader(eax, ecx)
+            },
+            (0x1f, _) if empty_v2 => zero,
+            _ => cpuid_reader(eax, ecx),
+        }
+    };
+
+    let cpuid = CpuId::with_cpuid_fn(reader(false));
+    let levels = cpuid.get_topology_info().expect("Leaf is supported");
+    assert_eq!(levels.last().unwrap().processors(), 20);
+    assert_eq!(cpuid.logical_processors_per_package(), 20);
+
+    // Supported but empty leaf 0x1F falls back to leaf 0x0B
+    let cpuid = CpuId::with_cpuid_fn(reader(true));
+    assert_eq!(cpuid.get_extended_topology_info_v2().unwrap().count(), 0);
+    let levels = cpuid.get_topology_info().expect("Leaf is supported");
+    assert_eq!(levels.last().unwrap().processors(), 16);
+    assert_eq!(cpuid.logical_processors_per_package(), 16);
+
+    // Die groups only exist in leaf 0x1F
+    let cpuid = CpuId::with_cpuid_fn(|eax: u32, ecx: u32| match (eax, ecx) {
+        (0x1f, 2) => CpuIdResult {
+            eax: 0x9,
+            ebx: 0x40,
+            ecx: 0x602,
+            edx: 0,
+        },
+        (0x1f, 3) => zero,
+        _ => cpuid_reader(eax, ecx),
+    });
+    let t = cpuid.get_topology_info().unwrap().nth(2).unwrap();
+    assert_eq!(t.level_type(), TopologyType::DieGrp);
+    assert_eq!(format!("{}", t.level_type()), "DieGrp");
+}
+
 #[test]
 fn extended_state_info() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
@@ -1127,6 +1347,8 @@ fn extended_state_info() {
     assert!(e.ia32_xss_supports_pt());
     assert!(e.xcr0_supports_pkru());
     // ...
+    assert!(e.ia32_xss_supports_cet_user());
+    assert!(e.ia32_xss_supports_cet_supervisor());
     assert!(!e.ia32_xss_supports_hdc());
 
     assert_eq!(e.xsave_area_size_enabled_features(), 2696);
@@ -1137,6 +1359,7 @@ fn extended_state_info() {
     assert!(e.has_xsaves_xrstors());
     // ...
     assert_eq!(e.xsave_size(), 1648);
+    assert_eq!(e.ia32_xss_supported_mask(), 0x19900);
     // ...
 
     let mut e = e.iter();
@@ -1159,12 +1382,14 @@ fn extended_state_info() {
     assert!(!ee.is_compacted_format());
 
     let ee = e.next().expect("Has level 5");
+    assert_eq!(ee.component(), crate::XSaveComponent::CetUser);
     assert_eq!(ee.size(), 16);
     assert_eq!(ee.offset(), 0);
     assert!(!ee.is_in_xcr0());
     assert!(!ee.is_compacted_format());
 
     let ee = e.next().expect("Has level 6");
+    assert_eq!(ee.component(), crate::XSaveComponent::CetSupervisor);
     assert_eq!(ee.size(), 24);
     assert_eq!(ee.offset(), 0);
     assert!(!ee.is_in_xcr0());
@@ -1183,6 +1408,44 @@ fn extended_state_info() {
     assert!(!ee.is_compacted_format());
 }
 
+#[test]
+fn apx() {
+    use crate::XSaveComponent;
+
+    assert!(!CpuId::with_cpuid_fn(cpuid_reader)
+        .get_extended_feature_info()
+        .unwrap()
+        .has_apx_f());
+
+    // APX foundation with the extended GPRs in XCR0 (state component 19)
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match (eax, ecx) {
+        (0x7, 1) => CpuIdResult {
+            edx: 1 << 21,
+            ..cpuid_reader(eax, ecx)
+        },
+        (0xd, 0) => CpuIdResult {
+            eax: cpuid_reader(eax, ecx).eax | 1 << 19,
+            ..cpuid_reader(eax, ecx)
+        },
+        (0xd, 19) => CpuIdResult {
+            eax: 128,
+            ebx: 960,
+            ecx: 0,
+            edx: 0,
+        },
+        _ => cpuid_reader(eax, ecx),
+    });
+    assert!(cpuid.get_extended_feature_info().unwrap().has_apx_f());
+
+    let e = cpuid.get_extended_state_info().expect("Leaf is supported");
+    assert!(e.xcr0_supports_apx());
+    let apx = e.iter().last().expect("Has APX state");
+    assert_eq!(apx.component(), XSaveComponent::Apx);
+    assert_eq!(apx.size(), 128);
+    assert_eq!(apx.offset(), 960);
+    assert!(apx.is_in_xcr0());
+}
+
 #[test]
 fn rdt_monitoring_info() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
@@ -1213,6 +1476,9 @@ fn sgx_test() {
 
 #[test]
 fn processor_trace() {
+    use crate::TraceOutputScheme;
+    use std::vec::Vec;
+
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
     let pt = cpuid.get_processor_trace_info().expect("Leaf is available");
 
@@ -1232,6 +1498,48 @@ fn processor_trace() {
     assert_eq!(pt.supported_mtc_period_encodings(), 585);
     assert_eq!(pt.supported_cycle_threshold_value_encodings(), 63);
     assert_eq!(pt.supported_psb_frequency_encodings(), 63);
+
+    assert!(pt.supports_output_scheme(TraceOutputScheme::Topa));
+    assert!(!pt.supports_output_scheme(TraceOutputScheme::TraceTransport));
+    let schemes: Vec<TraceOutputScheme> = pt.output_schemes().collect();
+    assert_eq!(
+        schemes,
+        [TraceOutputScheme::Topa, TraceOutputScheme::SingleRange]
+    );
+
+    assert_eq!(pt.max_subleaf(), 1);
+    assert_eq!(pt.raw_subleaf0(), cpuid_reader(0x14, 0));
+    assert_eq!(pt.raw_subleaf1(), Some(cpuid_reader(0x14, 1)));
+}
+
+#[test]
+fn paging() {
+    use crate::PageSize;
+
+    let paging = CpuId::with_cpuid_fn(cpuid_reader).paging_info();
+    assert!(paging.has_pse());
+    assert!(paging.has_pae());
+    assert!(paging.has_pse36());
+    assert!(paging.has_pdpe1gb());
+    assert!(!paging.has_la57());
+    assert!(paging.supports_page_size(PageSize::Huge1G));
+    assert_eq!(
+        paging.page_sizes().collect::<std::vec::Vec<_>>(),
+        [
+            PageSize::Small4K,
+            PageSize::Huge2M,
+            PageSize::Huge4M,
+            PageSize::Huge1G
+        ]
+    );
+}
+
+#[test]
+fn cache_line_size() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let line = cpuid.cache_line_size().expect("Have line size");
+    assert_eq!(line.bytes(), 64);
+    assert!(!line.has_conflict());
 }
 
 #[test]
@@ -1241,6 +1549,26 @@ fn tsc() {
     assert_eq!(e.denominator(), 2);
     assert_eq!(e.numerator(), 188);
     assert_eq!(e.nominal_frequency(), 38400000);
+    assert_eq!(
+        e.crystal_frequency().map(|c| c.source()),
+        Some(crate::FrequencySource::TscLeaf)
+    );
+    assert_eq!(e.tsc_frequency(), Some(3609600000));
+
+    // A hypervisor that clears the crystal clock still gets the same frequency
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match (eax, ecx) {
+        (0x15, 0) => CpuIdResult {
+            ecx: 0,
+            ..cpuid_reader(eax, ecx)
+        },
+        _ => cpuid_reader(eax, ecx),
+    });
+    let e = cpuid.get_tsc_info().expect("Leaf is available");
+    assert_eq!(e.nominal_frequency(), 0);
+    assert_eq!(
+        e.crystal_frequency().map(|c| c.source()),
+        Some(crate::FrequencySource::CrystalClockTable)
+    );
     assert_eq!(e.tsc_frequency(), Some(3609600000));
 }
 
@@ -1251,9 +1579,78 @@ fn processor_frequency() {
         .get_processor_frequency_info()
         .expect("Leaf is supported");
 
-    assert_eq!(e.processor_base_frequency(), 3600);
-    assert_eq!(e.processor_max_frequency(), 5000);
-    assert_eq!(e.bus_frequency(), 100);
+    let base = e
+        .processor_base_frequency()
+        .expect("Base frequency is enumerated");
+    assert_eq!(base.as_mhz(), 3600);
+    assert_eq!(base.as_hz(), 3_600_000_000);
+    assert_eq!(std::format!("{}", base), "3600 MHz");
+    assert_eq!(e.processor_max_frequency().map(|f| f.as_mhz()), Some(5000));
+    assert_eq!(e.bus_frequency().map(|f| f.as_mhz()), Some(100));
+}
+
+#[test]
+fn hybrid_info() {
+    use crate::CoreType;
+
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let e = cpuid.get_hybrid_info().expect("Leaf is supported");
+
+    assert_eq!(e.core_type(), CoreType::Core);
+    assert_eq!(e.native_model_id(), 0x1);
+    assert_eq!(format!("{}", CoreType::Atom), "Intel Atom");
+
+    // Not a hybrid processor: the leaf exists but reads as zero
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match eax {
+        0x1a => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        _ => cpuid_reader(eax, ecx),
+    });
+    assert!(cpuid.get_hybrid_info().is_none());
+}
+
+#[test]
+fn pconfig_info() {
+    use crate::PconfigTarget;
+    use std::vec::Vec;
+
+    let reader = |eax: u32, ecx: u32| match (eax, ecx) {
+        // A second sub-leaf with an unknown target after an unused slot
+        (0x1b, 1) => CpuIdResult {
+            eax: 1,
+            ebx: 0,
+            ecx: 7,
+            edx: 0,
+        },
+        (0x1b, _) if ecx > 1 => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        _ => cpuid_reader(eax, ecx),
+    };
+    let cpuid = CpuId::with_cpuid_fn(reader);
+    let targets: Vec<PconfigTarget> = cpuid
+        .get_pconfig_info()
+        .expect("Leaf is supported")
+        .collect();
+    assert_eq!(targets, [PconfigTarget::Mktme, PconfigTarget::Unknown(7)]);
+    assert_eq!(format!("{}", PconfigTarget::Mktme), "MKTME");
+}
+
+#[test]
+fn hreset_info() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let e = cpuid.get_hreset_info().expect("Leaf is supported");
+
+    assert_eq!(e.max_subleaf(), 0);
+    assert_eq!(e.capabilities(), 0x1);
+    assert!(e.has_thread_director_reset());
 }
 
 #[test]
@@ -1340,7 +1737,7 @@ fn l2_l3_tlb_cache() {
     // Supported on Intel
     assert_eq!(e.l2cache_line_size(), 64);
     assert_eq!(e.l2cache_lines_per_tag(), 0);
-    assert_eq!(e.l2cache_associativity(), Associativity::Unknown);
+    assert_eq!(e.l2cache_associativity(), Associativity::Unknown(0x7));
     assert_eq!(e.l2cache_size(), 1280);
 
     // Unsupported on Intel
@@ -1416,9 +1813,22 @@ fn get_deterministic_address_translation_info() {
     let mut e = cpuid
         .get_deterministic_address_translation_info()
         .expect("Leaf is supported");
+    assert_eq!(e.max_subleaf(), 8);
+    assert_eq!(e.size_hint(), (0, Some(9)));
 
     // This is a null entry, so all of this should be 0/false/invalid/null
     let t = e.next().expect("Have level 1");
+    assert_eq!(t.subleaf(), 1);
+    assert_eq!(
+        t.raw(),
+        CpuIdResult {
+            eax: 0x0,
+            ebx: 0x00080001,
+            ecx: 0x00000020,
+            edx: 0x00004022
+        }
+    );
+    assert_eq!(e.size_hint(), (0, Some(7)));
     assert!(t.has_4k_entries());
     assert!(!t.has_2mb_entries());
     assert!(!t.has_4mb_entries());
@@ -1523,6 +1933,43 @@ fn get_deterministic_address_translation_info() {
     assert_eq!(t.max_addressable_ids(), 2);
 }
 
+#[test]
+fn frequency() {
+    use crate::FrequencySource;
+
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let f = cpuid.frequency();
+
+    let tsc = f.tsc_frequency().expect("Have TSC frequency");
+    assert_eq!(tsc.hz(), 3_609_600_000);
+    assert_eq!(tsc.source(), FrequencySource::TscLeaf);
+    assert_eq!(f.base_frequency().unwrap().hz(), 3_600_000_000);
+    assert_eq!(f.max_frequency().unwrap().hz(), 5_000_000_000);
+    assert_eq!(f.bus_frequency().unwrap().hz(), 100_000_000);
+}
+
+#[test]
+fn dat_tlbs_for_page_size() {
+    use crate::{DatInfo, DatType, PageSize};
+    use std::vec::Vec;
+
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let dat = cpuid
+        .get_deterministic_address_translation_info()
+        .expect("Leaf is supported");
+    assert_eq!(dat.clone().count(), 8);
+
+    let huge: Vec<DatInfo> = dat.clone().tlbs_for_page_size(PageSize::Huge2M).collect();
+    assert_eq!(huge.len(), 4);
+    assert!(huge.iter().all(|t| t.has_2mb_entries()));
+    assert_eq!(huge[3].cache_level(), 2);
+    assert_eq!(huge[3].entries(), 1024);
+
+    let giant: Vec<DatInfo> = dat.tlbs_for_page_size(PageSize::Huge1G).collect();
+    assert_eq!(giant.len(), 3);
+    assert_eq!(giant[0].cache_type(), DatType::StoreOnly);
+}
+
 #[test]
 fn get_soc_vendor() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
@@ -1540,3 +1987,96 @@ fn get_soc_vendor() {
 
     println!("{:?}", e.get_vendor_brand());
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn collections() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+
+    let features = cpuid.features();
+    assert!(features.contains(&"SSE3"));
+    assert!(features.contains(&"AVX2"));
+    assert!(features.contains(&"AVX_VNNI"));
+    assert!(features.contains(&"LAHF_SAHF"));
+    assert!(!features.contains(&"AVX512F"));
+
+    let caches = cpuid.caches();
+    assert_eq!(caches.len(), 4);
+    assert_eq!(caches[3].level(), 3);
+
+    let levels = cpuid.topology_levels();
+    assert_eq!(levels.len(), 2);
+    assert_eq!(levels[1].level_type(), crate::TopologyType::Core);
+
+    assert!(cpuid.report().starts_with("CpuId {"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn cache_totals() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    assert_eq!(cpuid.logical_processors_per_package(), 20);
+
+    let totals = cpuid.cache_totals();
+    assert_eq!(totals.len(), 4);
+    assert_eq!(totals[0].instance_size(), 48 * 1024);
+    assert_eq!(totals[0].instances(), 10);
+    // The L3 reports more sharing IDs than there are logical processors
+    assert_eq!(totals[3].threads_per_instance(), 20);
+    assert_eq!(totals[3].instances(), 1);
+    assert_eq!(totals[3].total_size(), 25 * 1024 * 1024);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn snapshot() {
+    use std::format;
+
+    // The dump doesn't contain every invalid sub-leaf, these read as zero
+    let reader = |eax: u32, ecx: u32| {
+        let key = (eax as u64) << u32::BITS | ecx as u64;
+        CPUID_VALUE_MAP.get(&key).copied().unwrap_or(CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        })
+    };
+    let cpuid = CpuId::with_cpuid_fn(reader);
+    let snapshot = cpuid.snapshot();
+
+    assert_eq!(
+        snapshot.get(0x18, 8),
+        CPUID_VALUE_MAP.get(&0x00000018_00000008).copied()
+    );
+    assert_eq!(
+        snapshot.get(0x1f, 2),
+        CPUID_VALUE_MAP.get(&0x0000001f_00000002).copied()
+    );
+    assert!(snapshot.get(0x1f, 3).is_none());
+    assert!(snapshot
+        .entries()
+        .windows(2)
+        .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+
+    assert_eq!(format!("{:?}", snapshot.cpuid()), format!("{:?}", cpuid));
+
+    let shared = snapshot.clone();
+    assert!(std::sync::Arc::ptr_eq(&shared.entries, &snapshot.entries));
+    assert_eq!(
+        format!("{:?}", CpuId::with_cpuid_reader(shared)),
+        format!("{:?}", cpuid)
+    );
+
+    // Collecting sorts the entries, the last duplicate wins
+    let zero = CpuIdResult {
+        eax: 0,
+        ebx: 0,
+        ecx: 0,
+        edx: 0,
+    };
+    let rebuilt: crate::CpuIdSnapshot = core::iter::once((0x1, 0x0, zero))
+        .chain(snapshot.entries().iter().rev().copied())
+        .collect();
+    assert_eq!(rebuilt, snapshot);
+}
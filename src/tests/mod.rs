@@ -1,8 +1,17 @@
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod i5_3337u;
 
+mod corpus;
+#[cfg(feature = "feature-db")]
+mod feature_table;
+#[cfg(feature = "alloc")]
+mod hypervisor;
 mod i7_12700k;
 mod ryzen_matisse;
+#[cfg(feature = "alloc")]
+mod system;
+#[cfg(feature = "uarch-db")]
+mod uarch;
 mod xeon_gold_6252;
 
 use crate::*;
@@ -13,3 +22,1039 @@ fn cpuid_impls_debug() {
 
     debug_required(CpuId::new());
 }
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn the_is_shared() {
+    let threads: std::vec::Vec<_> = (0..4)
+        .map(|_| std::thread::spawn(|| CpuId::the() as *const _ as usize))
+        .collect();
+    for thread in threads {
+        assert_eq!(thread.join().unwrap(), CpuId::the() as *const _ as usize);
+    }
+    assert!(CpuId::the().get_vendor_info() == CpuId::new().get_vendor_info());
+}
+
+#[test]
+fn tsx_rtm_status() {
+    let tsx = |ebx: u32, edx: u32| {
+        CpuId::with_cpuid_fn(move |a, _c| match a {
+            0 => CpuIdResult {
+                eax: 0x7,
+                ebx: 0x756e_6547,
+                ecx: 0x6c65_746e,
+                edx: 0x4965_6e69,
+            },
+            7 => CpuIdResult {
+                eax: 0,
+                ebx,
+                ecx: 0,
+                edx,
+            },
+            _ => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+        })
+        .tsx_info()
+    };
+    const HLE_RTM: u32 = 1 << 4 | 1 << 11;
+
+    assert_eq!(tsx(0, 0).rtm_status(), RtmStatus::NotSupported);
+    assert_eq!(tsx(HLE_RTM, 0).rtm_status(), RtmStatus::Available);
+    // Skylake client with the 2021 microcode update: RTM stays enumerated
+    let info = tsx(HLE_RTM, 1 << 11 | 1 << 13);
+    assert!(info.has_rtm() && info.has_rtm_always_abort());
+    assert_eq!(info.rtm_status(), RtmStatus::AlwaysAborts);
+    assert_eq!(
+        tsx(HLE_RTM, 1 << 13).rtm_status(),
+        RtmStatus::LikelyForceAborted
+    );
+}
+
+#[test]
+fn cache_line_size_conflict() {
+    // Pentium 4: 64 byte CLFLUSH line, sectored 128 byte L2 lines, no leaf 4
+    let cpuid = CpuId::with_cpuid_fn(|a, _c| match a {
+        0 => CpuIdResult {
+            eax: 0x2,
+            ebx: 0x756e_6547,
+            ecx: 0x6c65_746e,
+            edx: 0x4965_6e69,
+        },
+        1 => CpuIdResult {
+            eax: 0xf29,
+            ebx: 0x0001_0800,
+            ecx: 0,
+            edx: 1 << 19,
+        },
+        0x8000_0000 => CpuIdResult {
+            eax: 0x8000_0006,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        0x8000_0006 => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0x0200_8080,
+            edx: 0,
+        },
+        _ => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    });
+
+    let line = cpuid.cache_line_size().expect("Have line size");
+    assert_eq!(line.bytes(), 64);
+    assert!(line.has_conflict());
+}
+
+#[test]
+fn apic_id() {
+    let cpuid = |max_leaf: u32| {
+        CpuId::with_cpuid_fn(move |a, c| match (a, c) {
+            (0, _) => CpuIdResult {
+                eax: max_leaf,
+                ebx: 0x756e_6547,
+                ecx: 0x6c65_746e,
+                edx: 0x4965_6e69,
+            },
+            // 300 doesn't fit in the 8-bit initial APIC ID
+            (1, _) => CpuIdResult {
+                eax: 0,
+                ebx: (300 & 0xff) << 24,
+                ecx: 0,
+                edx: 0,
+            },
+            (0xb, 0) => CpuIdResult {
+                eax: 0x1,
+                ebx: 0x2,
+                ecx: 0x100,
+                edx: 300,
+            },
+            _ => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+        })
+    };
+
+    assert_eq!(cpuid(0xb).apic_id(), Some(300));
+    // Falls back to the truncated ID without LEAF=0x0B, and when LEAF=0x1F
+    // exists but has no valid levels
+    assert_eq!(cpuid(0x1).apic_id(), Some(300 & 0xff));
+    assert_eq!(cpuid(0x1f).apic_id(), Some(300));
+    assert_eq!(cpuid(0x0).apic_id(), None);
+}
+
+#[test]
+fn vendor_consistent() {
+    // "AuthenticAMD"
+    const AMD: (u32, u32, u32) = (0x6874_7541, 0x444d_4163, 0x6974_6e65);
+    // "GenuineIntel"
+    const INTEL: (u32, u32, u32) = (0x756e_6547, 0x6c65_746e, 0x4965_6e69);
+
+    let cpuid = |vendor: (u32, u32, u32), extended: (u32, u32, u32), signature: u32| {
+        CpuId::with_cpuid_fn(move |a, _| match a {
+            0 => CpuIdResult {
+                eax: 0x1,
+                ebx: vendor.0,
+                ecx: vendor.1,
+                edx: vendor.2,
+            },
+            1 => CpuIdResult {
+                eax: signature,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            0x8000_0000 => CpuIdResult {
+                eax: 0x8000_0001,
+                ebx: extended.0,
+                ecx: extended.1,
+                edx: extended.2,
+            },
+            _ => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+        })
+    };
+    // Zen 3 (family 0x19, model 0x21) and Alder Lake (family 0x6, model 0x97)
+    let (zen3, adl) = (0x00a2_0f10, 0x0009_0672);
+
+    assert!(cpuid(AMD, AMD, zen3).vendor_consistent());
+    assert!(cpuid(INTEL, (0, 0, 0), adl).vendor_consistent());
+    assert!(!cpuid(INTEL, AMD, adl).vendor_consistent());
+    assert!(!cpuid(AMD, INTEL, zen3).vendor_consistent());
+    #[cfg(feature = "uarch-db")]
+    assert!(!cpuid(INTEL, (0, 0, 0), zen3).vendor_consistent());
+    // Unknown models of the reported vendor are fine
+    assert!(cpuid(AMD, AMD, 0x00f0_0f00).vendor_consistent());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn sanitize() {
+    let ones = CpuIdResult {
+        eax: u32::MAX,
+        ebx: u32::MAX,
+        ecx: u32::MAX,
+        edx: u32::MAX,
+    };
+    let snapshot: CpuIdSnapshot = [(0x0, 0x0), (0x1, 0x0), (0x7, 0x0), (0x7, 0x3)]
+        .iter()
+        .map(|&(leaf, subleaf)| (leaf, subleaf, ones))
+        .collect();
+    let sanitized = snapshot.sanitize();
+
+    // Leafs without a mask are kept
+    assert_eq!(sanitized.get(0x0, 0x0), Some(ones));
+    assert_eq!(sanitized.get(0x7, 0x3), Some(ones));
+    let leaf1 = sanitized.get(0x1, 0x0).unwrap();
+    assert_eq!(leaf1.eax, 0x0fff_3fff);
+    assert_eq!(leaf1.ecx & (1 << 16), 0);
+    assert_eq!(leaf1.edx & (1 << 10 | 1 << 20), 0);
+    let leaf7 = sanitized.get(0x7, 0x0).unwrap();
+    assert_eq!(leaf7.ebx, !(1 << 22));
+
+    // Everything this library decodes is defined
+    let cpuid = sanitized.cpuid();
+    let info = cpuid.get_feature_info().unwrap();
+    assert!(info.has_sse3() && info.has_hypervisor() && info.has_pbe());
+    assert_eq!(
+        cpuid.get_cpu_capabilities(),
+        snapshot.cpuid().get_cpu_capabilities()
+    );
+}
+
+#[test]
+fn defined_bits_sorted() {
+    let masks = defined_bits::MASKS;
+    assert!(masks
+        .windows(2)
+        .all(|pair| (pair[0].leaf(), pair[0].subleaf()) < (pair[1].leaf(), pair[1].subleaf())));
+    assert_eq!(defined_bits::find(0x7, 0x1), Some(&masks[3]));
+    assert_eq!(defined_bits::find(0x7, 0x3), None);
+    assert_eq!(defined_bits::find(0x8000_0001, 0x5), Some(&masks[5]));
+}
+
+#[test]
+fn centaur_family_model() {
+    let signature = |vendor: &'static [u8; 12], eax: u32| {
+        let reg = |idx: usize| {
+            u32::from_le_bytes([
+                vendor[idx],
+                vendor[idx + 1],
+                vendor[idx + 2],
+                vendor[idx + 3],
+            ])
+        };
+        let (ebx, edx, ecx) = (reg(0), reg(4), reg(8));
+        CpuId::with_cpuid_fn(move |a, _| match a {
+            0 => CpuIdResult {
+                eax: 0x1,
+                ebx,
+                ecx,
+                edx,
+            },
+            1 => CpuIdResult {
+                eax,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            _ => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+        })
+    };
+    let family_model = |vendor, eax| {
+        let info = signature(vendor, eax).get_feature_info().unwrap();
+        (info.family_id(), info.model_id())
+    };
+
+    // Zhaoxin KX-6000 (WuDaoKou): the extended model applies to family 7
+    assert_eq!(family_model(b"  Shanghai  ", 0x0001_07b5), (0x7, 0x1b));
+    assert_eq!(family_model(b"CentaurHauls", 0x0001_07b5), (0x7, 0x1b));
+    // VIA Nano (Isaiah)
+    assert_eq!(family_model(b"CentaurHauls", 0x0000_06f2), (0x6, 0x0f));
+    // The extended fields only apply to the families that define them
+    assert_eq!(family_model(b"CentaurHauls", 0x0111_0540), (0x5, 0x4));
+    assert_eq!(family_model(b"CentaurHauls", 0x00f1_0f00), (0x1e, 0x10));
+
+    // DisplayFamily does not fit into a u8 for the maximum extended family
+    let info = signature(b"GenuineIntel", 0x0ff0_3f00)
+        .get_feature_info()
+        .unwrap();
+    assert_eq!(info.display_family(), 0x10e);
+    assert_eq!(info.family_id(), 0xff);
+    assert_eq!(info.display_model(), 0x0);
+    assert_eq!(info.processor_type(), crate::ProcessorType::Reserved);
+    let info = signature(b"GenuineIntel", 0x0000_1633)
+        .get_feature_info()
+        .unwrap();
+    assert_eq!(info.processor_type(), crate::ProcessorType::OverDrive);
+
+    #[cfg(feature = "uarch-db")]
+    assert_eq!(
+        signature(b"  Shanghai  ", 0x0001_07b5)
+            .get_microarchitecture()
+            .map(|m| m.uarch()),
+        Some(crate::uarch::UArch::WuDaoKou)
+    );
+}
+
+#[test]
+fn unknown_encodings() {
+    use std::format;
+
+    // Encodings that are reserved today
+    let cpuid = CpuId::with_cpuid_fn(|a, c| match (a, c) {
+        (0, _) => CpuIdResult {
+            eax: 0x18,
+            ebx: 0x756e_6547,
+            ecx: 0x6c65_746e,
+            edx: 0x4965_6e69,
+        },
+        (0xb, 0) => CpuIdResult {
+            eax: 0x1,
+            ebx: 0x2,
+            ecx: 0x700,
+            edx: 0,
+        },
+        (0x18, 0) => CpuIdResult {
+            eax: 1,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        (0x18, 1) => CpuIdResult {
+            eax: 0,
+            ebx: 0x0004_0001,
+            ecx: 0x10,
+            edx: 0x7,
+        },
+        (0x8000_0000, _) => CpuIdResult {
+            eax: 0x8000_0006,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        (0x8000_0006, _) => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0x0200_3040,
+            edx: 0,
+        },
+        _ => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    });
+
+    let level = cpuid.get_extended_topology_info().unwrap().next().unwrap();
+    assert_eq!(level.level_type(), TopologyType::Unknown(7));
+    assert_eq!(format!("{}", level.level_type()), "Unknown (7)");
+
+    let dat = cpuid
+        .get_deterministic_address_translation_info()
+        .unwrap()
+        .next()
+        .unwrap();
+    assert_eq!(dat.cache_type(), DatType::Unknown(7));
+    assert_eq!(format!("{}", dat.cache_type()), "Unknown (7)");
+
+    let l2 = cpuid.get_l2_l3_cache_and_tlb_info().unwrap();
+    assert_eq!(l2.l2cache_associativity(), Associativity::Unknown(0x3));
+
+    let cache = CacheParameter {
+        eax: 0x25,
+        ebx: 0,
+        ecx: 0,
+        edx: 0,
+    };
+    assert_eq!(cache.cache_type(), CacheType::Unknown(5));
+    assert_eq!(format!("{}", cache.cache_type()), "Unknown (5)");
+}
+
+#[test]
+fn dat_iter_skips_null_sub_leaves() {
+    // Sub-leaf 0 only reports the max sub-leaf; valid structures may follow
+    // invalid ones.
+    let cpuid = CpuId::with_cpuid_fn(|a, c| match (a, c) {
+        (0, _) => CpuIdResult {
+            eax: 0x18,
+            ebx: 0x756e_6547,
+            ecx: 0x6c65_746e,
+            edx: 0x4965_6e69,
+        },
+        (0x18, 0) => CpuIdResult {
+            eax: 3,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        (0x18, 2) => CpuIdResult {
+            eax: 0,
+            ebx: 0x0004_0001,
+            ecx: 0x10,
+            edx: 0x24,
+        },
+        (0x18, 3) => CpuIdResult {
+            eax: 0,
+            ebx: 0x0008_0002,
+            ecx: 0x8,
+            edx: 0x43,
+        },
+        (0x18, 4) => CpuIdResult {
+            eax: 0,
+            ebx: 0x0004_0001,
+            ecx: 0x10,
+            edx: 0x21,
+        },
+        _ => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    });
+
+    let mut dat = cpuid
+        .get_deterministic_address_translation_info()
+        .expect("Leaf is supported");
+    let t = dat.next().expect("Have sub-leaf 2");
+    assert_eq!(t.cache_type(), DatType::LoadOnly);
+    let t = dat.next().expect("Have sub-leaf 3");
+    assert_eq!(t.cache_type(), DatType::UnifiedTLB);
+    assert!(dat.next().is_none(), "Sub-leaf 4 is above the reported max");
+    assert!(dat.next().is_none());
+}
+
+#[test]
+fn dat_entries_do_not_overflow() {
+    let dat = DatInfo {
+        subleaf: 1,
+        eax: 0,
+        ebx: 0xffff_0000,
+        ecx: u32::MAX,
+        edx: 0x23,
+    };
+    assert_eq!(dat.entries(), 0xffff * u32::MAX as u64);
+}
+
+#[test]
+fn brand_string_nominal_frequency() {
+    use crate::extended::ProcessorBrandString;
+
+    fn brand(s: &str) -> ProcessorBrandString {
+        let mut bytes = [0u8; 48];
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+        let reg =
+            |i: usize| u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+        let res = |i: usize| CpuIdResult {
+            eax: reg(i),
+            ebx: reg(i + 4),
+            ecx: reg(i + 8),
+            edx: reg(i + 12),
+        };
+        ProcessorBrandString::new([res(0), res(16), res(32)])
+    }
+
+    let f = brand("Intel(R) Pentium(R) III CPU family      1133MHz")
+        .nominal_frequency()
+        .expect("Has a frequency");
+    assert_eq!(f.hz(), 1_133_000_000);
+    assert_eq!(f.source(), FrequencySource::BrandString);
+
+    let f = brand("Intel(R) Pentium(R) 4 CPU 2.40 GHz").nominal_frequency();
+    assert_eq!(f.map(|f| f.hz()), Some(2_400_000_000));
+    assert!(brand("AMD Ryzen 5 3600X 6-Core Processor")
+        .nominal_frequency()
+        .is_none());
+    assert!(brand("Unknown GHz").nominal_frequency().is_none());
+    // Multi-byte character right before the number
+    let f = brand("CPU é800MHz").nominal_frequency();
+    assert_eq!(f.map(|f| f.hz()), Some(800_000_000));
+    // The integer part fits, adding the fraction overflows
+    assert!(brand("18446744073.9GHz").nominal_frequency().is_none());
+}
+
+#[test]
+fn cache_parameters_sorted() {
+    use core::cell::Cell;
+
+    // Caches enumerated out of order: L2, L1i, L3, L1d
+    let reads = Cell::new(0);
+    let cpuid = CpuId::with_cpuid_fn(|a, c| {
+        if a == 4 {
+            reads.set(reads.get() + 1);
+        }
+        match (a, c) {
+            (0, _) => CpuIdResult {
+                eax: 0x4,
+                ebx: 0x756e_6547,
+                ecx: 0x6c65_746e,
+                edx: 0x4965_6e69,
+            },
+            (4, 0) => CpuIdResult {
+                eax: 0x43,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            (4, 1) => CpuIdResult {
+                eax: 0x22,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            (4, 2) => CpuIdResult {
+                eax: 0x63,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            (4, 3) => CpuIdResult {
+                eax: 0x21,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            _ => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+        }
+    });
+
+    let mut caches = cpuid.get_cache_parameters().expect("Leaf is supported");
+    let expected = [
+        (1, CacheType::Data),
+        (1, CacheType::Instruction),
+        (2, CacheType::Unified),
+        (3, CacheType::Unified),
+    ];
+    for (level, typ) in expected.iter() {
+        let cache = caches.next().expect("Have cache");
+        assert_eq!(cache.level(), *level);
+        assert_eq!(cache.cache_type(), *typ);
+    }
+    assert!(caches.next().is_none());
+    assert!(caches.next().is_none());
+    // Every sub-leaf up to the null cache is read only once
+    assert_eq!(reads.get(), 5);
+
+    let mut keys = cpuid.get_cache_parameters().unwrap().map(|c| c.key());
+    let l1d = keys.next().unwrap();
+    let l1i = keys.next().unwrap();
+    assert!(l1d < l1i);
+    assert!(l1i < keys.next().unwrap());
+}
+
+#[test]
+fn feature_checks_are_memoized() {
+    use core::cell::Cell;
+
+    let reads = Cell::new(0);
+    let cpuid = CpuId::with_cpuid_fn(|a, _c| {
+        reads.set(reads.get() + 1);
+        match a {
+            0 => CpuIdResult {
+                eax: 0x7,
+                ebx: 0x756e_6547,
+                ecx: 0x6c65_746e,
+                edx: 0x4965_6e69,
+            },
+            1 => CpuIdResult {
+                eax: 0x906a3,
+                ebx: 0,
+                ecx: 0x1,
+                edx: 0x0200_0000,
+            },
+            7 => CpuIdResult {
+                eax: 0,
+                ebx: 0x20,
+                ecx: 0,
+                edx: 0,
+            },
+            0x8000_0000 => CpuIdResult {
+                eax: 0x8000_0001,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            0x8000_0001 => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0x1,
+                edx: 0,
+            },
+            _ => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+        }
+    });
+    let after_init = reads.get();
+
+    for _ in 0..10 {
+        assert!(cpuid.get_vendor_info().is_some());
+        assert!(cpuid.get_feature_info().unwrap().has_sse());
+        assert!(cpuid.get_extended_feature_info().unwrap().has_avx2());
+        assert!(cpuid
+            .get_extended_processor_and_feature_identifiers()
+            .unwrap()
+            .has_lahf_sahf());
+    }
+    assert_eq!(reads.get(), after_init);
+}
+
+#[test]
+fn cached_reader() {
+    use core::cell::Cell;
+
+    let reads = Cell::new(0);
+    let reader = CachedCpuIdReader::new(|a, c| {
+        reads.set(reads.get() + 1);
+        match (a, c) {
+            (0, _) => CpuIdResult {
+                eax: 0x4,
+                ebx: 0x756e_6547,
+                ecx: 0x6c65_746e,
+                edx: 0x4965_6e69,
+            },
+            (4, 0) => CpuIdResult {
+                eax: 0x21,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            (4, 1) => CpuIdResult {
+                eax: 0x43,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            _ => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+        }
+    });
+
+    let cpuid = CpuId::with_cpuid_reader(&reader);
+    assert_eq!(cpuid.get_cache_parameters().unwrap().count(), 2);
+    let first_pass = reads.get();
+    assert_eq!(cpuid.get_cache_parameters().unwrap().count(), 2);
+    assert_eq!(reads.get(), first_pass);
+
+    // Once the cache is full, reads are forwarded
+    let read = &reader;
+    for leaf in 0..256 {
+        read.cpuid2(0x4000_0000, leaf);
+    }
+    let before = reads.get();
+    read.cpuid2(0x4000_0000, 255);
+    assert_eq!(reads.get(), before + 1);
+
+    reader.clear();
+    read.cpuid1(0);
+    assert_eq!(reads.get(), before + 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn recording_reader() {
+    let reader = RecordingReader::new(|a, c| match (a, c) {
+        (0, _) => CpuIdResult {
+            eax: 0x4,
+            ebx: 0x756e_6547,
+            ecx: 0x6c65_746e,
+            edx: 0x4965_6e69,
+        },
+        (1, _) => CpuIdResult {
+            eax: 0x906a3,
+            ebx: 0,
+            ecx: 0x0200_0000,
+            edx: 0x0200_0000,
+        },
+        (4, 0) => CpuIdResult {
+            eax: 0x21,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        _ => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    });
+
+    let cpuid = CpuId::with_cpuid_reader(&reader);
+    let queried = reader.queries();
+    assert_eq!(queried[0], (0, 0));
+    assert!(!queried.contains(&(4, 0)));
+
+    assert_eq!(cpuid.get_cache_parameters().unwrap().count(), 1);
+    assert!(cpuid.get_feature_info().unwrap().has_sse());
+    let queried = reader.queries();
+    assert!(queried.contains(&(4, 0)) && queried.contains(&(4, 1)));
+    // Every pair is recorded once
+    assert!(queried
+        .iter()
+        .enumerate()
+        .all(|(idx, pair)| !queried[..idx].contains(pair)));
+
+    // The snapshot replays exactly the recorded pairs
+    let snapshot = reader.snapshot();
+    assert_eq!(snapshot.entries().len(), queried.len());
+    let replay = snapshot.cpuid();
+    assert_eq!(replay.get_cache_parameters().unwrap().count(), 1);
+    assert!(replay.get_feature_info().unwrap().has_sse());
+
+    reader.clear();
+    assert!(reader.queries().is_empty());
+}
+
+#[test]
+fn cache_info_unknown_descriptor() {
+    let cpuid = CpuId::with_cpuid_fn(|a, _c| match a {
+        0 => CpuIdResult {
+            eax: 0x2,
+            ebx: 0x756e_6547,
+            ecx: 0x6c65_746e,
+            edx: 0x4965_6e69,
+        },
+        // 0x07 is not a valid descriptor, iteration continues after it
+        2 => CpuIdResult {
+            eax: 0x0000_0001,
+            ebx: 0x0007_0000,
+            ecx: 0,
+            edx: 0x00ff_0000,
+        },
+        _ => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    });
+
+    let mut ci = cpuid.get_cache_info().expect("Leaf is supported");
+    let unknown = ci.next().expect("Have unknown descriptor");
+    assert_eq!(unknown.num, 0x07);
+    assert!(matches!(unknown.typ, CacheInfoType::Unknown));
+    assert_eq!(unknown.desc(), "Unknown cache type!");
+    assert_eq!(ci.next().expect("Have 0xff descriptor").num, 0xff);
+    assert!(ci.next().is_none());
+
+    #[cfg(not(feature = "leaf2-table"))]
+    assert!((0..=u8::MAX)
+        .all(|num| matches!(CacheInfo::from_descriptor(num).typ, CacheInfoType::Unknown)));
+
+    #[cfg(feature = "leaf2-table")]
+    for info in CACHE_INFO_TABLE.iter() {
+        assert_eq!(CacheInfo::from_descriptor(info.num).num, info.num);
+        assert!(!matches!(
+            CacheInfo::from_descriptor(info.num).typ,
+            CacheInfoType::Unknown
+        ));
+    }
+}
+
+#[test]
+fn subleaf_iterators_are_fused() {
+    use core::cell::Cell;
+
+    let reads = Cell::new(0);
+    let cpuid = CpuId::with_cpuid_fn(|a, c| {
+        reads.set(reads.get() + 1);
+        match (a, c) {
+            (0, _) => CpuIdResult {
+                eax: 0x17,
+                ebx: 0x756e_6547,
+                ecx: 0x6c65_746e,
+                edx: 0x4965_6e69,
+            },
+            (1, _) => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            (4, 0) => CpuIdResult {
+                eax: 0x21,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            (0xb, 0) => CpuIdResult {
+                eax: 1,
+                ebx: 2,
+                ecx: 0x100,
+                edx: 0,
+            },
+            (0xb, 1) => CpuIdResult {
+                eax: 4,
+                ebx: 8,
+                ecx: 0x201,
+                edx: 0,
+            },
+            (0x17, 0) => CpuIdResult {
+                eax: 5,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            (0x17, c) => CpuIdResult {
+                eax: c,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+            _ => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+        }
+    });
+
+    let mut topology = cpuid.get_extended_topology_info().unwrap();
+    assert_eq!(topology.by_ref().count(), 2);
+    let before = reads.get();
+    for _ in 0..10 {
+        assert!(topology.next().is_none());
+    }
+    assert_eq!(reads.get(), before);
+
+    let mut caches = cpuid.get_cache_parameters().unwrap();
+    assert_eq!(caches.by_ref().count(), 1);
+    let before = reads.get();
+    assert!(caches.next().is_none());
+    assert_eq!(reads.get(), before);
+
+    // Attributes are in sub-leaf 4 and 5
+    let soc = cpuid.get_soc_vendor_info().unwrap();
+    let mut attributes = soc.get_vendor_attributes().unwrap();
+    assert_eq!(
+        attributes.next().map(|a| (a.index(), a.eax())),
+        Some((4, 4))
+    );
+    assert_eq!(
+        attributes.next().map(|a| (a.index(), a.eax())),
+        Some((5, 5))
+    );
+    assert!(attributes.next().is_none());
+    assert!(attributes.next().is_none());
+
+    assert_eq!(soc.max_soc_id_index(), 5);
+    assert!(soc.get_vendor_attribute(3).is_none());
+    assert_eq!(soc.get_vendor_attribute(5).map(|a| a.value()[0]), Some(5));
+    assert!(soc.get_vendor_attribute(6).is_none());
+}
+
+#[test]
+fn feature_checks_never_panic() {
+    const INFO: FeatureInfo = FeatureInfo {
+        vendor: Vendor::Intel,
+        eax: u32::MAX,
+        ebx: u32::MAX,
+        edx_ecx: FeatureInfoFlags::all(),
+    };
+    const { assert!(INFO.has_sse2()) };
+
+    let patterns = (0..32).map(|bit| 1u32 << bit).chain([0, u32::MAX]);
+    for vendor in [
+        (0x756e_6547, 0x6c65_746e, 0x4965_6e69),
+        (0x6874_7541, 0x444d_4163, 0x6974_6e65),
+    ] {
+        for bits in patterns.clone() {
+            let cpuid = CpuId::with_cpuid_fn(move |a, _c| match a {
+                0 => CpuIdResult {
+                    eax: 0x7,
+                    ebx: vendor.0,
+                    ecx: vendor.1,
+                    edx: vendor.2,
+                },
+                0x8000_0000 => CpuIdResult {
+                    eax: 0x8000_0001,
+                    ebx: 0,
+                    ecx: 0,
+                    edx: 0,
+                },
+                1 | 7 | 0x8000_0001 => CpuIdResult {
+                    eax: bits,
+                    ebx: bits,
+                    ecx: bits,
+                    edx: bits,
+                },
+                _ => CpuIdResult {
+                    eax: 0,
+                    ebx: 0,
+                    ecx: 0,
+                    edx: 0,
+                },
+            });
+
+            if let Some(info) = cpuid.get_feature_info() {
+                let _ = (info.family_id(), info.model_id(), info.stepping_id());
+                let _ = std::format!("{:?}", info);
+            }
+            let _ = std::format!("{:?}", cpuid.get_extended_feature_info());
+            let _ = std::format!(
+                "{:?}",
+                cpuid.get_extended_processor_and_feature_identifiers()
+            );
+        }
+    }
+}
+
+#[test]
+fn x86_64_abi_level_without_leafs() {
+    let cpuid = CpuId::with_cpuid_fn(|_a, _c| CpuIdResult {
+        eax: 0,
+        ebx: 0,
+        ecx: 0,
+        edx: 0,
+    });
+    assert_eq!(cpuid.x86_64_abi_level(), None);
+    assert_eq!(cpuid.x86_64_abi_missing_features().count(), 8);
+    assert_eq!(std::format!("{}", X86_64AbiLevel::V3), "x86-64-v3");
+    assert!(X86_64AbiLevel::V2 < X86_64AbiLevel::V4);
+}
+
+#[cfg(all(feature = "alloc", feature = "feature-db"))]
+#[test]
+fn codegen() {
+    let snapshot: CpuIdSnapshot = [
+        (
+            0x1,
+            0x0,
+            CpuIdResult {
+                eax: 0x000c_06f2,
+                ebx: 0x0001_0800,
+                ecx: 1 << 0 | 1 << 1 | 1 << 16,
+                edx: 0,
+            },
+        ),
+        (
+            0x4,
+            0x1,
+            CpuIdResult {
+                eax: 0x1c00_4122,
+                ebx: 0x01c0_003f,
+                ecx: 0x0000_003f,
+                edx: 0,
+            },
+        ),
+    ]
+    .iter()
+    .copied()
+    .collect();
+
+    let code = crate::codegen::rust_code(&snapshot, "host");
+    assert_eq!(
+        code,
+        "use raw_cpuid::{CpuIdResult, CpuIdSnapshot};
+
+pub fn host() -> CpuIdSnapshot {
+    [
+        (
+            0x1,
+            0x0,
+            CpuIdResult {
+                eax: 0x000c_06f2,
+                ebx: 0x0001_0800,
+                ecx: 0x0001_0000
+                    | 1 << 0 // SSE3
+                    | 1 << 1, // PCLMULQDQ
+                edx: 0x0000_0000,
+            },
+        ),
+        (
+            0x4,
+            0x1,
+            CpuIdResult {
+                eax: 0x1c00_4122,
+                ebx: 0x01c0_003f,
+                ecx: 0x0000_003f,
+                edx: 0x0000_0000,
+            },
+        ),
+    ]
+    .iter()
+    .copied()
+    .collect()
+}
+"
+    );
+}
+
+#[cfg(feature = "build-helper")]
+#[test]
+fn build_helper_cfgs() {
+    use crate::build_helper::{cfg_name, cfgs};
+
+    let cpuid = CpuId::with_cpuid_fn(|eax, _| match eax {
+        0x0 => CpuIdResult {
+            eax: 0x7,
+            ebx: 0x756e_6547,
+            ecx: 0x6c65_746e,
+            edx: 0x4965_6e69,
+        },
+        // SSE4.2
+        0x1 => CpuIdResult {
+            eax: 0x906a3,
+            ebx: 0,
+            ecx: 1 << 20,
+            edx: 0,
+        },
+        // AVX2
+        0x7 => CpuIdResult {
+            eax: 0,
+            ebx: 1 << 5,
+            ecx: 0,
+            edx: 0,
+        },
+        _ => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    });
+    assert_eq!(cfg_name("AVX512F"), "cpu_feature_avx512f");
+    assert_eq!(cfg_name("sse4.1"), "cpu_feature_sse4_1");
+    assert_eq!(cfg_name("avx-512 f"), "cpu_feature_avx_512_f");
+    assert_eq!(
+        cfgs(&cpuid, &["avx2", "SSE42", "avx512f"]),
+        vec!["cpu_feature_avx2", "cpu_feature_sse42"]
+    );
+}
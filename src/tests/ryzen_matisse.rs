@@ -689,11 +689,45 @@ fn vendor_check() {
     assert_eq!(v.as_str(), "AuthenticAMD");
 }
 
+#[test]
+fn x86_64_abi_level() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    assert_eq!(cpuid.x86_64_abi_level(), Some(crate::X86_64AbiLevel::V3));
+    assert_eq!(
+        cpuid
+            .x86_64_abi_missing_features()
+            .collect::<std::vec::Vec<_>>(),
+        ["AVX512F", "AVX512BW", "AVX512CD", "AVX512DQ", "AVX512VL"]
+    );
+}
+
+#[cfg(feature = "uarch-db")]
+#[test]
+fn microarchitecture() {
+    use crate::uarch::UArch;
+
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let uarch = cpuid
+        .get_microarchitecture()
+        .expect("Known micro-architecture");
+    assert_eq!(uarch.uarch(), UArch::Matisse);
+    assert_eq!(
+        crate::uarch::identify_from_leafs(cpuid_reader(0, 0), cpuid_reader(1, 0)),
+        Some(uarch)
+    );
+
+    let actual = cpuid.get_cpu_capabilities();
+    assert!(uarch.expected_features().difference(&actual).is_empty());
+}
+
 /// Check feature info gives correct values for CPU
 #[test]
 fn version_info() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
     let f = cpuid.get_feature_info().expect("Need to find feature info");
+    assert_eq!(f.display_family(), 0x17);
+    assert_eq!(f.display_model(), 0x71);
+    assert_eq!(f.processor_type(), crate::ProcessorType::OriginalOem);
 
     assert_eq!(f.base_family_id(), 0xf);
     assert_eq!(f.base_model_id(), 0x1);
@@ -790,7 +824,12 @@ fn monitor_mwait() {
     assert_eq!(mw.smallest_monitor_line(), 64);
     assert!(mw.interrupts_as_break_event());
     assert!(mw.extensions_supported());
-    // supported_cX_states functions are not supported according to the manual
+    // EDX is reserved on AMD (0x11 in this dump)
+    assert_eq!(mw.supported_c0_states(), 0);
+    assert_eq!(mw.supported_c1_states(), 0);
+    assert!(!std::format!("{:?}", mw).contains("supported_c0_states"));
+
+    assert!(cpuid.has_monitorx_mwaitx());
 }
 
 #[test]
@@ -817,6 +856,8 @@ fn thermal_power() {
     assert!(!mw.has_flexible_hwp());
     assert!(!mw.has_hwp_fast_access_mode());
     assert!(!mw.has_ignore_idle_processor_hwp_request());
+    assert!(!mw.has_hw_feedback());
+    assert!(!mw.has_thread_director());
     assert!(mw.has_hw_coord_feedback());
     assert!(!mw.has_energy_bias_pref());
 }
@@ -1098,7 +1139,7 @@ fn l2_l3_tlb_cache() {
 
     assert_eq!(e.l3cache_line_size(), 64);
     assert_eq!(e.l3cache_lines_per_tag(), 1);
-    assert_eq!(e.l3cache_associativity(), Associativity::Unknown);
+    assert_eq!(e.l3cache_associativity(), Associativity::Unknown(0x9));
     assert_eq!(e.l3cache_size(), 64);
 }
 
@@ -1185,10 +1226,159 @@ fn secure_encryption() {
 
     assert_eq!(e.c_bit_position(), 0x2f);
     assert_eq!(e.physical_address_reduction(), 0x5);
+    assert_eq!(e.c_bit_mask(), 1 << 47);
+    assert_eq!(e.physical_address_mask_with_reduction(48), (1 << 43) - 1);
+    assert_eq!(e.physical_address_mask_with_reduction(4), 0);
     assert_eq!(e.max_encrypted_guests(), 0x1fd);
     assert_eq!(e.min_sev_no_es_asid(), 0x1);
 }
 
+#[test]
+fn extended_feature_identification_2() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    assert!(cpuid.get_extended_feature_identification_2().is_none());
+
+    // Zen 4 (Genoa, family 0x19 model 0x11)
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match eax {
+        0x8000_0000 => CpuIdResult {
+            eax: 0x8000_0028,
+            ..cpuid_reader(eax, ecx)
+        },
+        0x8000_0021 => CpuIdResult {
+            eax: 0x0006_2fcf,
+            ebx: 0x0000_015c,
+            ecx: 0x0,
+            edx: 0x0,
+        },
+        _ => cpuid_reader(eax, ecx),
+    });
+    let e = cpuid
+        .get_extended_feature_identification_2()
+        .expect("Leaf is supported");
+
+    assert!(e.has_no_nested_data_bp());
+    assert!(e.has_fs_gs_base_non_serializing());
+    assert!(e.has_lfence_always_serializing());
+    assert!(e.has_smm_pg_cfg_lock());
+    assert!(!e.has_verw_clear());
+    assert!(e.has_null_select_clears_base());
+    assert!(e.has_upper_address_ignore());
+    assert!(e.has_automatic_ibrs());
+    assert!(e.has_no_smm_ctl_msr());
+    assert!(e.has_fsrs());
+    assert!(e.has_fsrc());
+    assert!(e.has_prefetch_ctl_msr());
+    assert!(e.has_cpuid_user_dis());
+    assert!(e.has_epsf());
+    assert!(!e.has_prefetchi());
+    assert!(!e.has_eraps());
+    assert!(!e.has_sbpb());
+    assert!(!e.has_ibpb_brtype());
+    assert!(!e.has_srso_no());
+    assert!(!e.has_srso_user_kernel_no());
+    assert!(!e.has_srso_msr_fix());
+    assert_eq!(e.microcode_patch_size(), 0x15c);
+    assert_eq!(e.rap_size(), 0);
+
+    // Zen 4 with the SRSO microcode update
+    let e = crate::ExtendedFeatureIdentification2::new(CpuIdResult {
+        eax: 0x1806_2fcf,
+        ebx: 0x0000_015c,
+        ecx: 0x0,
+        edx: 0x0,
+    });
+    assert!(e.has_sbpb());
+    assert!(e.has_ibpb_brtype());
+    assert!(!e.has_srso_no());
+}
+
+#[test]
+fn amd_perfmon_debug_info() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    assert!(cpuid.get_amd_perfmon_debug_info().is_none());
+
+    // PerfMonV2 with 6 core and 4 northbridge counters and a 16 entry LBR stack
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match eax {
+        0x8000_0000 => CpuIdResult {
+            eax: 0x8000_0028,
+            ..cpuid_reader(eax, ecx)
+        },
+        0x8000_0022 => CpuIdResult {
+            eax: 0x0000_0007,
+            ebx: 0x0000_1106,
+            ecx: 0x0,
+            edx: 0x0,
+        },
+        _ => cpuid_reader(eax, ecx),
+    });
+    let e = cpuid
+        .get_amd_perfmon_debug_info()
+        .expect("Leaf is supported");
+
+    assert!(e.has_perfmon_v2());
+    assert!(e.has_lbr_v2());
+    assert!(e.has_lbr_and_pmc_freeze());
+    assert_eq!(e.num_core_counters(), 6);
+    assert_eq!(e.lbr_v2_stack_size(), 16);
+    assert_eq!(e.num_northbridge_counters(), 4);
+    assert_eq!(e.num_umc_counters(), 0);
+    assert_eq!(e.active_umc_mask(), 0);
+
+    // Server parts also enumerate UMC counters and the active controllers
+    let e = crate::AmdPerfMonDebugInfo::new(CpuIdResult {
+        eax: 0x0000_0001,
+        ebx: 0x0020_1006,
+        ecx: 0x0000_0fff,
+        edx: 0x0,
+    });
+    assert!(e.has_perfmon_v2());
+    assert!(!e.has_lbr_v2());
+    assert_eq!(e.num_core_counters(), 6);
+    assert_eq!(e.num_northbridge_counters(), 4);
+    assert_eq!(e.num_umc_counters(), 32);
+    assert_eq!(e.active_umc_mask(), 0xfff);
+}
+
+#[test]
+fn cache_line_size() {
+    // The dump lacks the null sub-leaf that ends the 0x8000_001D enumeration
+    let cpuid = CpuId::with_cpuid_fn(|eax: u32, ecx: u32| {
+        let key = (eax as u64) << u32::BITS | ecx as u64;
+        CPUID_VALUE_MAP.get(&key).copied().unwrap_or(CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        })
+    });
+    let line = cpuid.cache_line_size().expect("Have line size");
+    assert_eq!(line.bytes(), 64);
+    assert!(!line.has_conflict());
+}
+
+#[test]
+fn sev_status() {
+    use crate::SevStatus;
+
+    // On the host the leaf only tells what guests could use
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    assert_eq!(cpuid.sev_status(), SevStatus::NotEncrypted);
+    assert_eq!(cpuid.sev_status().encryption_mask(), 0);
+
+    // Same processor seen from a guest
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| {
+        let mut res = cpuid_reader(eax, ecx);
+        if eax == 0x1 {
+            res.ecx |= 1 << 31;
+        }
+        res
+    });
+    let status = cpuid.sev_status();
+    assert_eq!(status, SevStatus::SevEs { c_bit: 47 });
+    assert_eq!(status.c_bit_position(), Some(47));
+    assert_eq!(status.encryption_mask(), 1 << 47);
+}
+
 #[test]
 fn svm() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
@@ -1269,3 +1459,33 @@ fn remaining_unsupported_leafs() {
     assert!(cpuid.get_soc_vendor_info().is_none());
     assert!(cpuid.get_extended_topology_info_v2().is_none());
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn cache_totals() {
+    let cpuid = CpuId::with_cpuid_fn(|eax: u32, ecx: u32| {
+        let key = (eax as u64) << u32::BITS | ecx as u64;
+        CPUID_VALUE_MAP.get(&key).copied().unwrap_or(CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        })
+    });
+    assert_eq!(cpuid.logical_processors_per_package(), 12);
+
+    let totals = cpuid.cache_totals();
+    assert_eq!(totals.len(), 4);
+    // One L2 per core
+    assert_eq!(totals[2].level(), 2);
+    assert_eq!(totals[2].instance_size(), 512 * 1024);
+    assert_eq!(totals[2].threads_per_instance(), 2);
+    assert_eq!(totals[2].instances(), 6);
+    assert_eq!(totals[2].total_size(), 3 * 1024 * 1024);
+    // One L3 per CCX
+    assert_eq!(totals[3].level(), 3);
+    assert_eq!(totals[3].instance_size(), 16 * 1024 * 1024);
+    assert_eq!(totals[3].threads_per_instance(), 6);
+    assert_eq!(totals[3].instances(), 2);
+    assert_eq!(totals[3].total_size(), 32 * 1024 * 1024);
+}
@@ -0,0 +1,58 @@
+use crate::system::SystemCpuIdSnapshot;
+use crate::*;
+
+/// A hybrid part: CPUs 0 and 1 are performance cores, 2 and 3 efficiency cores,
+/// and CPU 3 lacks the power management leaf.
+fn cpu(id: u32) -> CpuIdSnapshot {
+    let res = |eax, ebx, ecx, edx| CpuIdResult { eax, ebx, ecx, edx };
+    let core_type = if id < 2 { 0x4000_0001 } else { 0x2000_0001 };
+    let mut entries = vec![
+        (0x0, 0, res(0x1a, 0x756e_6547, 0x6c65_746e, 0x4965_6e69)),
+        (0x1, 0, res(0x906a3, id << 24, 0, 0)),
+        (0x6, 0, res(0x4, 0, 0, 0)),
+        (0x1a, 0, res(core_type, 0, 0, 0)),
+    ];
+    if id == 3 {
+        entries.remove(2);
+    }
+    entries.into_iter().collect()
+}
+
+#[test]
+fn deltas() {
+    let system: SystemCpuIdSnapshot = (0..4).map(|id| (id, cpu(id))).collect();
+    assert_eq!(system.len(), 4);
+    assert_eq!(system.cpus().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+    // Ties go to the first CPU
+    let base = system.base();
+    assert_eq!(base.get(0x1a, 0).unwrap().eax, 0x4000_0001);
+    assert!(base.get(0x6, 0).is_some());
+
+    assert_eq!(system.delta(0).unwrap(), &[]);
+    assert_eq!(system.delta(1).unwrap().len(), 1);
+    let delta = system.delta(3).unwrap();
+    assert_eq!(delta.len(), 3);
+    assert_eq!(delta[1], (0x6, 0, None));
+    assert_eq!(delta[2].2.unwrap().eax, 0x2000_0001);
+    assert_eq!(system.delta(4), None);
+
+    assert_eq!(
+        system.differing_leafs(),
+        vec![(0x1, 0), (0x6, 0), (0x1a, 0)]
+    );
+    for id in 0..4 {
+        assert_eq!(system.snapshot(id), Some(cpu(id)));
+    }
+}
+
+#[test]
+fn diff() {
+    assert_eq!(cpu(0).diff(&cpu(0)), vec![]);
+
+    let diff = cpu(0).diff(&cpu(3));
+    assert_eq!(diff.len(), 3);
+    assert_eq!(diff[0].0, 0x1);
+    assert_eq!((diff[1].0, diff[1].3), (0x6, None));
+    assert_eq!(diff[2].3.unwrap().eax, 0x2000_0001);
+}
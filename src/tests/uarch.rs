@@ -0,0 +1,736 @@
+use crate::uarch::*;
+use crate::{CpuCapabilities, CpuId, CpuIdResult};
+
+fn intel(family: u8, model: u8) -> Option<UArch> {
+    identify_micro_architecture(CpuVendor::Intel, family, model, 0).map(|m| m.uarch())
+}
+
+#[test]
+fn vendor_strings() {
+    assert_eq!(
+        CpuVendor::from_vendor_str("GenuineIntel"),
+        Some(CpuVendor::Intel)
+    );
+    assert_eq!(
+        CpuVendor::from_vendor_str("AuthenticAMD"),
+        Some(CpuVendor::Amd)
+    );
+    assert_eq!(CpuVendor::from_vendor_str("KVMKVMKVM"), None);
+}
+
+#[test]
+fn intel_family_model() {
+    assert_eq!(intel(0x5, 0x04), Some(UArch::P5));
+    assert_eq!(intel(0x6, 0x08), Some(UArch::P6));
+    assert_eq!(intel(0xf, 0x03), Some(UArch::NetBurst));
+    assert_eq!(intel(0x6, 0x0d), Some(UArch::PentiumM));
+    assert_eq!(intel(0x6, 0x0f), Some(UArch::Merom));
+    assert_eq!(intel(0x6, 0x17), Some(UArch::Penryn));
+    assert_eq!(intel(0x6, 0x1a), Some(UArch::Nehalem));
+    assert_eq!(intel(0x6, 0x2c), Some(UArch::Westmere));
+    assert_eq!(intel(0x6, 0x2a), Some(UArch::SandyBridge));
+    assert_eq!(intel(0x6, 0x2d), Some(UArch::SandyBridgeE));
+    // i5-3337U
+    assert_eq!(intel(0x6, 0x3a), Some(UArch::IvyBridge));
+    assert_eq!(intel(0x6, 0x3c), Some(UArch::Haswell));
+    assert_eq!(intel(0x6, 0x4f), Some(UArch::BroadwellE));
+    assert_eq!(intel(0x6, 0x5e), Some(UArch::SkylakeClient));
+    assert_eq!(intel(0x6, 0x55), Some(UArch::SkylakeServer));
+    assert_eq!(intel(0x6, 0x9e), Some(UArch::KabyLake));
+    assert_eq!(intel(0x6, 0xa5), Some(UArch::CometLake));
+    assert_eq!(intel(0x6, 0x7e), Some(UArch::IceLakeClient));
+    assert_eq!(intel(0x6, 0x6a), Some(UArch::IceLakeServer));
+    assert_eq!(intel(0x6, 0x8c), Some(UArch::TigerLake));
+    assert_eq!(intel(0x6, 0xa7), Some(UArch::RocketLake));
+    // i7-12700K
+    assert_eq!(intel(0x6, 0x97), Some(UArch::AlderLake));
+    assert_eq!(intel(0x6, 0xb7), Some(UArch::RaptorLake));
+    assert_eq!(intel(0x6, 0x8f), Some(UArch::SapphireRapids));
+    assert_eq!(intel(0x6, 0xcf), Some(UArch::EmeraldRapids));
+    assert_eq!(intel(0x6, 0xaa), Some(UArch::MeteorLake));
+    assert_eq!(intel(0x6, 0xc6), Some(UArch::ArrowLake));
+    assert_eq!(intel(0x6, 0xbd), Some(UArch::LunarLake));
+
+    assert_eq!(intel(0x6, 0x00), None);
+    assert_eq!(intel(0x7, 0x00), None);
+}
+
+#[test]
+fn intel_core_arch() {
+    let adl = identify_micro_architecture(CpuVendor::Intel, 0x6, 0x97, 0).unwrap();
+    assert_eq!(adl.vendor(), CpuVendor::Intel);
+    assert_eq!(adl.core(), CoreArch::GoldenCove);
+    assert_eq!(adl.efficiency_core(), Some(CoreArch::Gracemont));
+    assert!(adl.is_hybrid());
+
+    let clx = identify_micro_architecture(CpuVendor::Intel, 0x6, 0x55, 7).unwrap();
+    assert_eq!(clx.core(), CoreArch::Skylake);
+    assert_eq!(clx.efficiency_core(), None);
+    assert!(!clx.is_hybrid());
+
+    assert_eq!(UArch::KabyLake.core(), CoreArch::Skylake);
+    assert_eq!(UArch::EmeraldRapids.core(), CoreArch::RaptorCove);
+    assert_eq!(UArch::LunarLake.efficiency_core(), Some(CoreArch::Skymont));
+}
+
+fn amd(family: u8, model: u8) -> Option<UArch> {
+    identify_micro_architecture(CpuVendor::Amd, family, model, 0).map(|m| m.uarch())
+}
+
+#[test]
+fn amd_family_model() {
+    assert_eq!(amd(0xf, 0x2b), Some(UArch::K8));
+    assert_eq!(amd(0x10, 0x04), Some(UArch::K10));
+    assert_eq!(amd(0x12, 0x01), Some(UArch::Llano));
+    assert_eq!(amd(0x14, 0x02), Some(UArch::Bobcat));
+    assert_eq!(amd(0x15, 0x01), Some(UArch::Bulldozer));
+    assert_eq!(amd(0x15, 0x02), Some(UArch::Piledriver));
+    assert_eq!(amd(0x15, 0x13), Some(UArch::Piledriver));
+    assert_eq!(amd(0x15, 0x30), Some(UArch::Steamroller));
+    assert_eq!(amd(0x15, 0x65), Some(UArch::Excavator));
+    assert_eq!(amd(0x16, 0x00), Some(UArch::Jaguar));
+    assert_eq!(amd(0x16, 0x30), Some(UArch::Puma));
+    assert_eq!(amd(0x17, 0x01), Some(UArch::Zeppelin));
+    assert_eq!(amd(0x17, 0x08), Some(UArch::PinnacleRidge));
+    assert_eq!(amd(0x17, 0x11), Some(UArch::RavenRidge));
+    assert_eq!(amd(0x17, 0x18), Some(UArch::Picasso));
+    assert_eq!(amd(0x17, 0x31), Some(UArch::Rome));
+    assert_eq!(amd(0x17, 0x60), Some(UArch::Renoir));
+    // Ryzen 5 3600X
+    assert_eq!(amd(0x17, 0x71), Some(UArch::Matisse));
+    assert_eq!(amd(0x19, 0x01), Some(UArch::Milan));
+    assert_eq!(amd(0x19, 0x21), Some(UArch::Vermeer));
+    assert_eq!(amd(0x19, 0x44), Some(UArch::Rembrandt));
+    assert_eq!(amd(0x19, 0x50), Some(UArch::Cezanne));
+    assert_eq!(amd(0x19, 0x11), Some(UArch::Genoa));
+    assert_eq!(amd(0x19, 0x61), Some(UArch::Raphael));
+    assert_eq!(amd(0x19, 0x74), Some(UArch::Phoenix));
+    assert_eq!(amd(0x19, 0xa0), Some(UArch::Bergamo));
+    assert_eq!(amd(0x1a, 0x02), Some(UArch::Turin));
+    assert_eq!(amd(0x1a, 0x24), Some(UArch::StrixPoint));
+    assert_eq!(amd(0x1a, 0x44), Some(UArch::GraniteRidge));
+
+    assert_eq!(amd(0x17, 0xff), None);
+    assert_eq!(amd(0x6, 0x97), None);
+}
+
+#[test]
+fn amd_core_arch() {
+    assert_eq!(UArch::Zeppelin.core(), CoreArch::Zen);
+    assert_eq!(UArch::Picasso.core(), CoreArch::ZenPlus);
+    assert_eq!(UArch::Matisse.core(), CoreArch::Zen2);
+    assert_eq!(UArch::Rembrandt.core(), CoreArch::Zen3);
+    assert_eq!(UArch::Bergamo.core(), CoreArch::Zen4);
+    assert_eq!(UArch::GraniteRidge.core(), CoreArch::Zen5);
+    assert_eq!(UArch::Griffin.core(), CoreArch::K8);
+    assert!(!identify_micro_architecture(CpuVendor::Amd, 0x19, 0x61, 0)
+        .unwrap()
+        .is_hybrid());
+}
+
+#[test]
+fn names_roundtrip() {
+    use core::str::FromStr;
+    use std::string::ToString;
+
+    for (uarch, _, _) in UArch::ALL {
+        assert_eq!(UArch::from_str(&uarch.to_string()), Ok(*uarch));
+        assert_eq!(UArch::from_str(&std::format!("{:?}", uarch)), Ok(*uarch));
+    }
+    for (core, _, _) in CoreArch::ALL {
+        assert_eq!(CoreArch::from_str(&core.to_string()), Ok(*core));
+        assert_eq!(CoreArch::from_str(&std::format!("{:?}", core)), Ok(*core));
+    }
+
+    assert_eq!(CoreArch::Zen3.to_string(), "Zen 3");
+    assert_eq!("zen3".parse(), Ok(CoreArch::Zen3));
+    assert_eq!("zen+".parse(), Ok(CoreArch::ZenPlus));
+    assert_eq!("golden_cove".parse(), Ok(CoreArch::GoldenCove));
+    assert_eq!("skylake-sp".parse(), Ok(UArch::SkylakeServer));
+    assert_eq!("amd".parse(), Ok(CpuVendor::Amd));
+    assert_eq!("zen6".parse::<CoreArch>(), Err(ParseUArchError));
+
+    let adl = identify_micro_architecture(CpuVendor::Intel, 0x6, 0x97, 0).unwrap();
+    assert_eq!(
+        adl.to_string(),
+        "Intel Alder Lake (Golden Cove + Gracemont)"
+    );
+    let matisse = identify_micro_architecture(CpuVendor::Amd, 0x17, 0x71, 0).unwrap();
+    assert_eq!(matisse.to_string(), "AMD Matisse (Zen 2)");
+}
+
+#[test]
+fn metadata() {
+    let adl = identify_micro_architecture(CpuVendor::Intel, 0x6, 0x97, 0).unwrap();
+    assert_eq!(adl.process_node(), "Intel 7");
+    assert_eq!(adl.launch_year(), 2021);
+    assert_eq!(
+        adl.segments(),
+        &[MarketSegment::Desktop, MarketSegment::Mobile]
+    );
+    assert_eq!(adl.predecessor(), Some(UArch::TigerLake));
+    assert_eq!(adl.successor(), Some(UArch::RaptorLake));
+
+    assert_eq!(UArch::Genoa.process_node(), "TSMC N5");
+    assert_eq!(UArch::Genoa.predecessor(), Some(UArch::Milan));
+    assert_eq!(UArch::Genoa.successor(), Some(UArch::Turin));
+    assert_eq!(UArch::P5.predecessor(), None);
+
+    for (uarch, _, _) in UArch::ALL {
+        assert!(!uarch.segments().is_empty());
+        if let Some(successor) = uarch.successor() {
+            assert!(successor.launch_year() >= uarch.launch_year());
+        }
+        if let Some(predecessor) = uarch.predecessor() {
+            assert!(predecessor.launch_year() <= uarch.launch_year());
+        }
+    }
+}
+
+#[test]
+fn centaur_zhaoxin_family_model() {
+    assert_eq!(
+        CpuVendor::from_vendor_str("CentaurHauls"),
+        Some(CpuVendor::Centaur)
+    );
+    assert_eq!(
+        CpuVendor::from_vendor_str("  Shanghai  "),
+        Some(CpuVendor::Zhaoxin)
+    );
+
+    let centaur = |family, model| {
+        identify_micro_architecture(CpuVendor::Centaur, family, model, 0).map(|m| m.uarch())
+    };
+    let zhaoxin = |family, model| {
+        identify_micro_architecture(CpuVendor::Zhaoxin, family, model, 0).map(|m| m.uarch())
+    };
+    assert_eq!(centaur(0x6, 0x0a), Some(UArch::Esther));
+    assert_eq!(centaur(0x6, 0x0f), Some(UArch::Isaiah));
+    assert_eq!(centaur(0x6, 0x19), Some(UArch::ZhangJiang));
+    assert_eq!(zhaoxin(0x6, 0x19), Some(UArch::ZhangJiang));
+    assert_eq!(zhaoxin(0x7, 0x1b), Some(UArch::WuDaoKou));
+    assert_eq!(zhaoxin(0x7, 0x3b), Some(UArch::LuJiaZui));
+    assert_eq!(zhaoxin(0x7, 0x5b), Some(UArch::Yongfeng));
+    assert_eq!(zhaoxin(0x6, 0x0f), None);
+
+    assert_eq!(UArch::Yongfeng.core(), CoreArch::CenturyAvenue);
+    assert_eq!(UArch::Isaiah.successor(), Some(UArch::ZhangJiang));
+}
+
+#[test]
+fn zhaoxin_get_microarchitecture() {
+    use crate::{CpuId, CpuIdResult};
+
+    // KX-6000: "  Shanghai  ", family 7, model 0x3b
+    let cpuid = CpuId::with_cpuid_fn(|a, _c| match a {
+        0 => CpuIdResult {
+            eax: 0xd,
+            ebx: 0x6853_2020,
+            ecx: 0x2020_6961,
+            edx: 0x6867_6e61,
+        },
+        1 => CpuIdResult {
+            eax: 0x000307b0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        _ => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    });
+    assert_eq!(cpuid.get_vendor_info().unwrap().as_str(), "  Shanghai  ");
+    let uarch = cpuid.get_microarchitecture().unwrap();
+    assert_eq!(uarch.vendor(), CpuVendor::Zhaoxin);
+    assert_eq!(uarch.uarch(), UArch::LuJiaZui);
+}
+
+#[test]
+fn hygon_dhyana() {
+    use crate::{CpuId, CpuIdResult};
+
+    // Hygon C86 7185: "HygonGenuine", family 0x18, model 0x0
+    let cpuid = CpuId::with_cpuid_fn(|a, _c| match a {
+        0 => CpuIdResult {
+            eax: 0xd,
+            ebx: 0x6f67_7948,
+            ecx: 0x656e_6975,
+            edx: 0x6e65_476e,
+        },
+        1 => CpuIdResult {
+            eax: 0x00900f01,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        _ => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    });
+    assert_eq!(cpuid.get_vendor_info().unwrap().as_str(), "HygonGenuine");
+    let uarch = cpuid.get_microarchitecture().unwrap();
+    assert_eq!(uarch.vendor(), CpuVendor::Hygon);
+    assert_eq!(uarch.uarch(), UArch::Dhyana);
+    assert_eq!(uarch.core(), CoreArch::Zen);
+
+    // Family 0x18 is only Hygon
+    assert_eq!(amd(0x18, 0x00), None);
+}
+
+#[test]
+fn intel_atom_family_model() {
+    assert_eq!(intel(0x6, 0x1c), Some(UArch::Bonnell));
+    assert_eq!(intel(0x6, 0x36), Some(UArch::Saltwell));
+    assert_eq!(intel(0x6, 0x37), Some(UArch::BayTrail));
+    assert_eq!(intel(0x6, 0x4d), Some(UArch::Avoton));
+    assert_eq!(intel(0x6, 0x4c), Some(UArch::CherryTrail));
+    assert_eq!(intel(0x6, 0x5c), Some(UArch::ApolloLake));
+    assert_eq!(intel(0x6, 0x5f), Some(UArch::Denverton));
+    assert_eq!(intel(0x6, 0x7a), Some(UArch::GeminiLake));
+    assert_eq!(intel(0x6, 0x86), Some(UArch::SnowRidge));
+    assert_eq!(intel(0x6, 0x96), Some(UArch::ElkhartLake));
+    assert_eq!(intel(0x6, 0x9c), Some(UArch::JasperLake));
+    assert_eq!(intel(0x6, 0xbe), Some(UArch::AlderLakeN));
+    assert_eq!(intel(0x6, 0xaf), Some(UArch::SierraForest));
+    assert_eq!(intel(0x6, 0xb6), Some(UArch::GrandRidge));
+
+    assert_eq!(UArch::Avoton.core(), CoreArch::Silvermont);
+    assert_eq!(UArch::CherryTrail.core(), CoreArch::Airmont);
+    assert_eq!(UArch::Denverton.core(), CoreArch::Goldmont);
+    assert_eq!(UArch::GeminiLake.core(), CoreArch::GoldmontPlus);
+    assert_eq!(UArch::SnowRidge.core(), CoreArch::Tremont);
+    assert_eq!(UArch::AlderLakeN.core(), CoreArch::Gracemont);
+    assert_eq!(UArch::SierraForest.core(), CoreArch::Crestmont);
+    // E-core only parts are not hybrid
+    assert_eq!(UArch::AlderLakeN.efficiency_core(), None);
+}
+
+#[test]
+fn intel_xeon_phi() {
+    assert_eq!(intel(0xb, 0x01), Some(UArch::KnightsCorner));
+    assert_eq!(intel(0x6, 0x57), Some(UArch::KnightsLanding));
+    assert_eq!(intel(0x6, 0x85), Some(UArch::KnightsMill));
+    assert_eq!(UArch::KnightsMill.core(), CoreArch::KnightsMill);
+    assert_eq!(
+        UArch::KnightsCorner.successor(),
+        Some(UArch::KnightsLanding)
+    );
+    assert_eq!("knl".parse::<UArch>(), Err(ParseUArchError));
+    assert_eq!("knights-landing".parse(), Ok(UArch::KnightsLanding));
+}
+
+#[test]
+fn expected_features() {
+    let zen3 = UArch::Vermeer.expected_features();
+    assert!(zen3.has_avx2());
+    assert!(zen3.has_vaes());
+    assert!(!zen3.has_avx512f());
+    assert!(UArch::Raphael.expected_features().has_avx512f());
+
+    // Alder Lake ships with AVX-512 disabled
+    let alder_lake = UArch::AlderLake.expected_features();
+    assert!(alder_lake.has_sha());
+    assert!(!alder_lake.has_avx512f());
+
+    // Client generations only add features until AVX-512 was dropped in Alder Lake
+    let mut uarch = UArch::Merom;
+    while let Some(next) = uarch.successor().filter(|&next| next != UArch::AlderLake) {
+        let missing = uarch
+            .expected_features()
+            .difference(&next.expected_features());
+        assert!(missing.is_empty(), "{:?} -> {:?}", uarch, next);
+        uarch = next;
+    }
+
+    let missing = UArch::SkylakeServer
+        .expected_features()
+        .difference(&UArch::SkylakeClient.expected_features());
+    let names: std::vec::Vec<&str> = missing.iter_names().collect();
+    assert_eq!(
+        names,
+        ["AVX512F", "AVX512DQ", "AVX512CD", "AVX512BW", "AVX512VL"]
+    );
+}
+
+fn brand(vendor: CpuVendor, brand: &str) -> Option<UArch> {
+    identify_from_brand_string(vendor, brand).map(|m| m.uarch())
+}
+
+#[test]
+fn brand_string_intel() {
+    use CpuVendor::Intel;
+
+    assert_eq!(
+        brand(Intel, "12th Gen Intel(R) Core(TM) i7-12700K"),
+        Some(UArch::AlderLake)
+    );
+    assert_eq!(
+        brand(Intel, "11th Gen Intel(R) Core(TM) i7-1165G7 @ 2.80GHz"),
+        Some(UArch::TigerLake)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Core(TM) i7-10700K CPU @ 3.80GHz"),
+        Some(UArch::CometLake)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Core(TM) i5-3337U CPU @ 1.80GHz"),
+        Some(UArch::IvyBridge)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz"),
+        Some(UArch::KabyLake)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Core(TM) i5-8250U CPU @ 1.60GHz"),
+        Some(UArch::KabyLake)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz"),
+        Some(UArch::CoffeeLake)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Core(TM) Ultra 7 155H"),
+        Some(UArch::MeteorLake)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Core(TM) Ultra 7 258V"),
+        Some(UArch::LunarLake)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Xeon(R) Platinum 8480+"),
+        Some(UArch::SapphireRapids)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Xeon(R) Gold 6252 CPU @ 2.10GHz"),
+        Some(UArch::CascadeLake)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Xeon(R) CPU E5-2680 v4 @ 2.40GHz"),
+        Some(UArch::BroadwellE)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Xeon(R) CPU E5-2670 0 @ 2.60GHz"),
+        Some(UArch::SandyBridgeE)
+    );
+    assert_eq!(
+        brand(Intel, "Intel(R) Xeon(R) CPU E7- 4870  @ 2.40GHz"),
+        None
+    );
+    assert_eq!(brand(Intel, "Intel(R) Xeon(R) CPU E7-4870 @ 2.40GHz"), None);
+    assert_eq!(
+        brand(Intel, "Intel(R) Xeon(R) 6780E"),
+        Some(UArch::SierraForest)
+    );
+    assert_eq!(brand(Intel, "Intel(R) Xeon(R) CPU @ 2.20GHz"), None);
+    assert_eq!(brand(Intel, "AMD Ryzen 9 7950X 16-Core Processor"), None);
+}
+
+#[test]
+fn brand_string_amd() {
+    use CpuVendor::Amd;
+
+    assert_eq!(
+        brand(Amd, "AMD Ryzen 9 7950X 16-Core Processor"),
+        Some(UArch::Raphael)
+    );
+    assert_eq!(
+        brand(Amd, "AMD Ryzen 5 3600X 6-Core Processor"),
+        Some(UArch::Matisse)
+    );
+    assert_eq!(
+        brand(Amd, "AMD Ryzen 7 5700G with Radeon Graphics"),
+        Some(UArch::Cezanne)
+    );
+    assert_eq!(
+        brand(Amd, "AMD Ryzen 7 7840U w/ Radeon 780M Graphics"),
+        Some(UArch::Phoenix)
+    );
+    assert_eq!(
+        brand(Amd, "AMD Ryzen 7 7735HS with Radeon Graphics"),
+        Some(UArch::Rembrandt)
+    );
+    assert_eq!(
+        brand(Amd, "AMD Ryzen AI 9 HX 370 w/ Radeon 890M"),
+        Some(UArch::StrixPoint)
+    );
+    assert_eq!(
+        brand(Amd, "AMD EPYC 7763 64-Core Processor"),
+        Some(UArch::Milan)
+    );
+    assert_eq!(
+        brand(Amd, "AMD EPYC 9754 128-Core Processor"),
+        Some(UArch::Bergamo)
+    );
+    assert_eq!(
+        brand(Amd, "AMD FX(tm)-8350 Eight-Core Processor"),
+        Some(UArch::Piledriver)
+    );
+    assert_eq!(brand(Amd, "AMD Eng Sample: 100-000000000-00"), None);
+
+    let guess = identify_from_brand_string(Amd, "AMD Ryzen 9 9950X").unwrap();
+    assert_eq!(guess.confidence(), Confidence::Low);
+    assert_eq!(guess.core(), CoreArch::Zen5);
+}
+
+/// An Intel processor with the given leaf 0x01 signature and brand string.
+fn intel_reader(
+    max_leaf: u32,
+    signature: u32,
+    brand: &'static str,
+) -> impl Fn(u32, u32) -> CpuIdResult + Clone {
+    let mut bytes = [0u8; 48];
+    bytes[..brand.len()].copy_from_slice(brand.as_bytes());
+    let word =
+        move |i: usize| u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+
+    move |eax, _ecx| match eax {
+        0x0 => CpuIdResult {
+            eax: max_leaf,
+            ebx: 0x756e_6547,
+            ecx: 0x6c65_746e,
+            edx: 0x4965_6e69,
+        },
+        0x1 => CpuIdResult {
+            eax: signature,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        0x8000_0000 => CpuIdResult {
+            eax: 0x8000_0004,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+        0x8000_0002..=0x8000_0004 => {
+            let base = (eax - 0x8000_0002) as usize * 16;
+            CpuIdResult {
+                eax: word(base),
+                ebx: word(base + 4),
+                ecx: word(base + 8),
+                edx: word(base + 12),
+            }
+        }
+        _ => CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    }
+}
+
+#[test]
+fn brand_string_fallback() {
+    // Family 6, model 0xFF which is not in the table
+    let cpuid = CpuId::with_cpuid_fn(intel_reader(
+        0x1,
+        0x000f_06f0,
+        "Intel(R) Core(TM) Ultra 7 155H",
+    ));
+
+    let uarch = cpuid
+        .get_microarchitecture()
+        .expect("Guessed from brand string");
+    assert_eq!(uarch.uarch(), UArch::MeteorLake);
+    assert_eq!(uarch.confidence(), Confidence::Low);
+}
+
+#[test]
+fn intel_stepping() {
+    let intel = |model, stepping| {
+        identify_micro_architecture(CpuVendor::Intel, 0x6, model, stepping).map(|m| m.uarch())
+    };
+
+    assert_eq!(intel(0x55, 0x4), Some(UArch::SkylakeServer));
+    // Xeon Gold 6252
+    assert_eq!(intel(0x55, 0x7), Some(UArch::CascadeLake));
+    assert_eq!(intel(0x55, 0xb), Some(UArch::CooperLake));
+    assert_eq!(intel(0x8e, 0x9), Some(UArch::KabyLake));
+    assert_eq!(intel(0x8e, 0xa), Some(UArch::CoffeeLake));
+    assert_eq!(intel(0x8e, 0xb), Some(UArch::WhiskeyLake));
+    assert_eq!(intel(0x8e, 0xc), Some(UArch::CometLake));
+    assert_eq!(intel(0x9e, 0x9), Some(UArch::KabyLake));
+    assert_eq!(intel(0x9e, 0xd), Some(UArch::CoffeeLake));
+
+    assert_eq!(UArch::CascadeLake.core(), CoreArch::Skylake);
+    assert!(UArch::CascadeLake.expected_features().has_avx512vnni());
+    assert!(!UArch::SkylakeServer.expected_features().has_avx512vnni());
+    assert_eq!("cascade lake".parse(), Ok(UArch::CascadeLake));
+}
+
+#[test]
+fn intel_quark() {
+    assert_eq!(intel(0x5, 0x09), Some(UArch::Quark));
+    assert_eq!(intel(0x5, 0x0a), Some(UArch::Quark));
+    assert_eq!(UArch::Quark.core(), CoreArch::Lakemont);
+    assert!(UArch::Quark.expected_features().is_empty());
+    assert_eq!(
+        brand(CpuVendor::Intel, "Intel(R) Quark(TM) SoC X1000"),
+        Some(UArch::Quark)
+    );
+
+    // Only leaf 0x00 and 0x01, unsupported leafs return the data of the highest leaf
+    let cpuid = CpuId::with_cpuid_fn(|eax, _ecx| match eax {
+        0x0 => CpuIdResult {
+            eax: 0x1,
+            ebx: 0x756e_6547,
+            ecx: 0x6c65_746e,
+            edx: 0x4965_6e69,
+        },
+        _ => CpuIdResult {
+            eax: 0x590,
+            ebx: 0,
+            ecx: 0,
+            edx: 0x8000_0000,
+        },
+    });
+    let uarch = cpuid
+        .get_microarchitecture()
+        .expect("Known family and model");
+    assert_eq!(uarch.uarch(), UArch::Quark);
+    assert_eq!(uarch.confidence(), Confidence::High);
+    assert!(cpuid.get_processor_brand_string().is_none());
+    assert!(!cpuid.get_cpu_capabilities().has_sse());
+
+    // Leaf 0x01 is not reported at all, only the brand string is left
+    let cpuid = CpuId::with_cpuid_fn(intel_reader(0x0, 0x590, "Intel(R) Quark(TM) SoC X1000"));
+    assert!(cpuid.get_feature_info().is_none());
+    let uarch = cpuid
+        .get_microarchitecture()
+        .expect("Guessed from brand string");
+    assert_eq!(uarch.uarch(), UArch::Quark);
+    assert_eq!(uarch.confidence(), Confidence::Low);
+}
+
+#[test]
+fn cache_hints() {
+    let zen3 = UArch::Vermeer.cache_hints().expect("Zen 3 is known");
+    assert_eq!(zen3.l1d_size(), 32);
+    assert_eq!(zen3.l2_size(), 512);
+    assert_eq!(zen3.l3_size(), Some(4096));
+    assert_eq!(zen3.l1d_latency(), 4);
+
+    // Server parts have a larger L2
+    let skx = UArch::SkylakeServer.cache_hints().unwrap();
+    let skl = UArch::SkylakeClient.cache_hints().unwrap();
+    assert!(skx.l2_size() > skl.l2_size());
+
+    // Hybrid parts describe the P-cores, the E-cores are available through the core
+    let adl = identify_micro_architecture(CpuVendor::Intel, 0x6, 0x97, 0).unwrap();
+    assert_eq!(adl.cache_hints().unwrap().l1d_size(), 48);
+    let gracemont = adl.efficiency_core().and_then(|core| core.cache_hints());
+    assert_eq!(gracemont.unwrap().l1d_size(), 32);
+
+    assert_eq!(UArch::Merom.cache_hints().unwrap().l3_size(), None);
+    assert_eq!(UArch::Merom.cache_hints().unwrap().l3_latency(), None);
+    assert_eq!(UArch::P5.cache_hints(), None);
+}
+
+#[test]
+fn const_lookup() {
+    const AMD_LEAF0: CpuIdResult = CpuIdResult {
+        eax: 0x10,
+        ebx: 0x6874_7541,
+        ecx: 0x444d_4163,
+        edx: 0x6974_6e65,
+    };
+    // Family 0x19, model 0x21, stepping 0
+    const VERMEER: Option<MicroArchitecture> = identify_from_leafs(
+        AMD_LEAF0,
+        CpuIdResult {
+            eax: 0x00a2_0f10,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        },
+    );
+    const CORE: Option<CoreArch> = match VERMEER {
+        Some(uarch) => Some(uarch.core()),
+        None => None,
+    };
+    assert_eq!(CORE, Some(CoreArch::Zen3));
+
+    const CLX: Option<MicroArchitecture> =
+        identify_micro_architecture(CpuVendor::Intel, 0x6, 0x55, 0x7);
+    assert_eq!(CLX.map(|m| m.uarch()), Some(UArch::CascadeLake));
+
+    assert_eq!(CpuVendor::from_vendor_leaf(AMD_LEAF0), Some(CpuVendor::Amd));
+    assert_eq!(
+        CpuVendor::from_vendor_leaf(CpuIdResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        }),
+        None
+    );
+}
+
+#[test]
+fn virtualized() {
+    // QEMU kvm64 model on a Haswell host: family 0xF, model 6, stepping 1
+    let kvm64 = |ecx1: u32| {
+        move |eax: u32, _ecx: u32| match eax {
+            0x0 => CpuIdResult {
+                eax: 0x7,
+                ebx: 0x756e_6547,
+                ecx: 0x6c65_746e,
+                edx: 0x4965_6e69,
+            },
+            0x1 => CpuIdResult {
+                eax: 0xf61,
+                ebx: 0,
+                ecx: ecx1,
+                // SSE, SSE2
+                edx: (1 << 25) | (1 << 26),
+            },
+            // BMI1, AVX2, BMI2
+            0x7 => CpuIdResult {
+                eax: 0,
+                ebx: (1 << 3) | (1 << 5) | (1 << 8),
+                ecx: 0,
+                edx: 0,
+            },
+            _ => CpuIdResult {
+                eax: 0,
+                ebx: 0,
+                ecx: 0,
+                edx: 0,
+            },
+        }
+    };
+    // SSE3, PCLMULQDQ, SSSE3, FMA, SSE4.1, SSE4.2, MOVBE, POPCNT, AES, AVX, F16C
+    let haswell = [0, 1, 9, 12, 19, 20, 22, 23, 25, 28, 29]
+        .iter()
+        .fold(0, |ecx, bit| ecx | (1 << bit));
+
+    let cpuid = CpuId::with_cpuid_fn(kvm64(haswell | (1 << 31)));
+    let uarch = cpuid
+        .get_microarchitecture()
+        .expect("Guessed from features");
+    assert_eq!(uarch.confidence(), Confidence::Virtualized);
+    assert_eq!(uarch.uarch(), UArch::Haswell);
+
+    // Without the hypervisor bit this is a Pentium 4
+    let cpuid = CpuId::with_cpuid_fn(kvm64(haswell));
+    let uarch = cpuid.get_microarchitecture().unwrap();
+    assert_eq!(uarch.confidence(), Confidence::High);
+    assert_eq!(uarch.uarch(), UArch::NetBurst);
+
+    assert!(is_synthetic_signature(CpuVendor::Amd, 0xf, 0x6b, 0x1));
+    assert!(!is_synthetic_signature(CpuVendor::Intel, 0x6, 0x97, 0x2));
+
+    let guess = identify_virtualized(
+        CpuVendor::Amd,
+        Some("AMD EPYC 7763 64-Core Processor"),
+        &CpuCapabilities::with_cpuid_reader(kvm64(0)),
+    );
+    assert_eq!(guess.map(|m| m.uarch()), Some(UArch::Milan));
+}
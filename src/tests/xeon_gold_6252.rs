@@ -545,6 +545,7 @@ static CPUID_VALUE_MAP: phf::Map<u64, CpuIdResult> = phf_map! {
     0x00000004_00000001u64 => CpuIdResult { eax: 0x7c004122, ebx: 0x01c0003f, ecx: 0x0000003f, edx: 0x00000000 },
     0x00000004_00000002u64 => CpuIdResult { eax: 0x7c004143, ebx: 0x03c0003f, ecx: 0x000003ff, edx: 0x00000000 },
     0x00000004_00000003u64 => CpuIdResult { eax: 0x7c0fc163, ebx: 0x0280003f, ecx: 0x0000cfff, edx: 0x00000005 },
+    0x00000004_00000004u64 => CpuIdResult { eax: 0x00000000, ebx: 0x00000000, ecx: 0x00000000, edx: 0x00000000 },
     0x00000005_00000000u64 => CpuIdResult { eax: 0x00000040, ebx: 0x00000040, ecx: 0x00000003, edx: 0x00002020 },
     0x00000006_00000000u64 => CpuIdResult { eax: 0x00000077, ebx: 0x00000002, ecx: 0x00000009, edx: 0x00000000 },
     0x00000007_00000000u64 => CpuIdResult { eax: 0x00000000, ebx: 0xd39ff7eb, ecx: 0x00000818, edx: 0xbc000400 },
@@ -605,6 +606,29 @@ fn vendor_check() {
     assert_eq!(v.as_str(), "GenuineIntel");
 }
 
+#[test]
+fn x86_64_abi_level() {
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    assert_eq!(cpuid.x86_64_abi_level(), Some(crate::X86_64AbiLevel::V4));
+    assert_eq!(cpuid.x86_64_abi_missing_features().next(), None);
+}
+
+#[cfg(feature = "uarch-db")]
+#[test]
+fn microarchitecture() {
+    use crate::uarch::UArch;
+
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let uarch = cpuid
+        .get_microarchitecture()
+        .expect("Known micro-architecture");
+    assert_eq!(uarch.uarch(), UArch::CascadeLake);
+    assert_eq!(
+        crate::uarch::identify_from_leafs(cpuid_reader(0, 0), cpuid_reader(1, 0)),
+        Some(uarch)
+    );
+}
+
 /// Check feature info gives correct values for CPU
 #[test]
 fn version_info() {
@@ -692,18 +716,87 @@ fn cache_info() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
     let ci = cpuid.get_cache_info().expect("Leaf is supported");
 
-    for (idx, cache) in ci.enumerate() {
+    for (idx, cache) in ci.clone().enumerate() {
         match idx {
             0 => assert_eq!(cache.num, 0xff),
             1 => assert_eq!(cache.num, 0x63),
             2 => assert_eq!(cache.num, 0xb5),
             3 => assert_eq!(cache.num, 0x03),
             4 => assert_eq!(cache.num, 0xf0),
-            5 => assert_eq!(cache.num, 0x76),
-            6 => assert_eq!(cache.num, 0xc3),
+            5 => assert_eq!(cache.num, 0xc3),
+            6 => assert_eq!(cache.num, 0x76),
             _ => unreachable!(),
         }
     }
+
+    assert!(ci.uses_leaf4());
+    assert!(!ci.uses_leaf18());
+
+    #[cfg(feature = "leaf2-table")]
+    {
+        use crate::{Associativity, CacheInfo, PageSize};
+        use std::vec::Vec;
+
+        // Instruction TLB: 4KByte pages, 8-way set associative, 64 entries
+        let itlb = CacheInfo::from_descriptor(0xb5);
+        assert_eq!(itlb.level(), Some(1));
+        assert_eq!(itlb.associativity(), Some(Associativity::NWay(8)));
+        assert_eq!(itlb.entries(), Some(64));
+        assert_eq!(itlb.page_sizes().collect::<Vec<_>>(), [PageSize::Small4K]);
+        assert_eq!(itlb.size(), None);
+        assert_eq!(itlb.line_size(), None);
+
+        // Shared 2nd-Level TLB: 4 KByte /2 MByte pages, 6-way associative, 1536 entries
+        let stlb = CacheInfo::from_descriptor(0xc3);
+        assert_eq!(stlb.level(), Some(2));
+        assert_eq!(stlb.associativity(), Some(Associativity::NWay(6)));
+        assert_eq!(stlb.entries(), Some(1536));
+        assert!(stlb.supports_page_size(PageSize::Small4K));
+        assert!(stlb.supports_page_size(PageSize::Huge2M));
+        assert!(!stlb.supports_page_size(PageSize::Huge4M));
+
+        // DTLB: 4 KByte/2 MByte pages, 4-way associative, 16 entries
+        let dtlb = CacheInfo::from_descriptor(0xc2);
+        assert_eq!(dtlb.level(), Some(1));
+        assert_eq!(dtlb.entries(), Some(16));
+        assert_eq!(
+            dtlb.page_sizes().collect::<Vec<_>>(),
+            [PageSize::Small4K, PageSize::Huge2M]
+        );
+        assert_eq!(
+            dtlb.desc(),
+            "DTLB: 4 KByte/2 MByte pages, 4-way associative, 16 entries"
+        );
+
+        // Instruction TLB: 2M/4M pages, fully associative, 8 entries
+        let itlb = CacheInfo::from_descriptor(0x76);
+        assert_eq!(itlb.associativity(), Some(Associativity::FullyAssociative));
+        assert_eq!(
+            itlb.page_sizes().collect::<Vec<_>>(),
+            [PageSize::Huge2M, PageSize::Huge4M]
+        );
+
+        // 3rd-level cache: 1.5 MByte, 12-way set associative, 64 byte line size
+        let l3 = CacheInfo::from_descriptor(0xdc);
+        assert_eq!(l3.level(), Some(3));
+        assert_eq!(l3.size(), Some(1536 * 1024));
+        assert_eq!(l3.associativity(), Some(Associativity::NWay(12)));
+        assert_eq!(l3.line_size(), Some(64));
+        assert_eq!(l3.entries(), None);
+        assert_eq!(l3.page_sizes().count(), 0);
+
+        // Prefetchers and redirections have no numeric fields
+        for num in [0xf0, 0xff] {
+            let info = CacheInfo::from_descriptor(num);
+            assert_eq!(info.level(), None);
+            assert_eq!(info.associativity(), None);
+        }
+    }
+
+    let info = cpuid.get_cache_and_tlb_info().expect("Leaf is supported");
+    assert_eq!(info.descriptors().count(), 6);
+    assert!(info.cache_parameters().is_some());
+    assert!(info.tlb_info().is_none());
 }
 
 #[test]
@@ -712,6 +805,8 @@ fn processor_serial() {
     let psn = cpuid.get_processor_serial().expect("Leaf is supported");
     assert_eq!(psn.serial_lower(), 0x0);
     assert_eq!(psn.serial_middle(), 0x0);
+    assert_eq!(psn.serial_all(), (psn.serial_upper() as u128) << 64);
+    assert_eq!(std::format!("{}", psn), "0005-0657-0000-0000-0000-0000");
 }
 
 #[test]
@@ -809,9 +904,42 @@ fn extended_features() {
     assert!(e.has_avx512vnni());
     assert!(!e.has_rdpid());
     assert!(!e.has_sgx_lc());
+    assert!(!e.has_rtm_always_abort());
+    assert!(!e.has_tsx_force_abort());
+    assert!(e.has_arch_capabilities());
+    assert!(e.has_md_clear());
+    assert!(e.has_ibrs_ibpb());
+    assert!(e.has_stibp());
+    assert!(e.has_l1d_flush());
+    assert!(!e.has_core_capabilities());
+    assert!(e.has_ssbd());
     assert_eq!(e.mawau_value(), 0x0);
 }
 
+#[test]
+fn avx512_profile() {
+    // Cascade Lake: the Skylake subsets and VNNI
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let avx512 = cpuid.get_extended_feature_info().unwrap().avx512_profile();
+    assert!(avx512.has_avx512_skylake_set());
+    assert!(avx512.has_vnni());
+    assert!(!avx512.has_vbmi());
+    assert!(!avx512.has_avx512_icelake_set());
+    assert!(!avx512.has_bf16());
+}
+
+#[test]
+fn tsx() {
+    use crate::RtmStatus;
+
+    // TSX is disabled (and hidden) through IA32_TSX_CTRL on this machine
+    let tsx = CpuId::with_cpuid_fn(cpuid_reader).tsx_info();
+    assert!(!tsx.has_hle());
+    assert!(!tsx.has_rtm());
+    assert!(tsx.has_arch_capabilities());
+    assert_eq!(tsx.rtm_status(), RtmStatus::NotSupported);
+}
+
 #[test]
 fn direct_cache_access() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
@@ -843,6 +971,9 @@ fn perfmon_info() {
     assert_eq!(pm.fixed_function_counters(), 0x3);
     assert_eq!(pm.fixed_function_counters_bit_width(), 0x30);
     assert!(!pm.has_any_thread_deprecation());
+    assert_eq!(pm.fixed_counter_bitmap(), 0x0);
+    assert!(pm.is_fixed_counter_supported(2));
+    assert!(!pm.is_fixed_counter_supported(3));
 }
 
 #[test]
@@ -897,6 +1028,7 @@ fn extended_state_info() {
     assert!(e.has_xsaves_xrstors());
     // ...
     assert_eq!(e.xsave_size(), 2568);
+    assert_eq!(e.ia32_xss_supported_mask(), 0x100);
     // ...
 
     let mut e = e.iter();
@@ -963,6 +1095,26 @@ fn rdt_monitoring_info() {
     assert!(l3m.has_occupancy_monitoring());
     assert!(l3m.has_total_bandwidth_monitoring());
     assert!(l3m.has_local_bandwidth_monitoring());
+    assert_eq!(l3m.counter_width_offset(), 0);
+    assert_eq!(l3m.counter_width(), 24);
+    assert!(!l3m.has_counter_overflow_bit());
+
+    // 62-bit counters with an overflow bit (offset 38)
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match (eax, ecx) {
+        (0xf, 1) => CpuIdResult {
+            eax: 1 << 8 | 38,
+            ..cpuid_reader(eax, ecx)
+        },
+        _ => cpuid_reader(eax, ecx),
+    });
+    let l3m = cpuid
+        .get_rdt_monitoring_info()
+        .unwrap()
+        .l3_monitoring()
+        .unwrap();
+    assert_eq!(l3m.counter_width_offset(), 38);
+    assert_eq!(l3m.counter_width(), 62);
+    assert!(l3m.has_counter_overflow_bit());
 }
 
 #[test]
@@ -981,12 +1133,46 @@ fn rdt_allocation_info() {
     assert_eq!(l3c.isolation_bitmap(), 0x00000600);
     assert_eq!(l3c.highest_cos(), 15);
     assert!(l3c.has_code_data_prioritization());
+    assert!(!l3c.has_noncontiguous_capacity_bitmask());
     // infrequent updates of COS missing
 
     let mba = e.memory_bandwidth_allocation().expect("Leaf is available");
     assert_eq!(mba.max_hba_throttling(), 90);
+    assert!(!mba.has_per_thread_throttling());
     assert!(mba.has_linear_response_delay());
     assert_eq!(mba.highest_cos(), 0x7);
+
+    // Sparse bit masks and per-thread MBA as enumerated by newer parts
+    let cpuid = CpuId::with_cpuid_fn(|eax, ecx| match (eax, ecx) {
+        (0x10, 0) => CpuIdResult {
+            ebx: 0b1110,
+            ..cpuid_reader(eax, ecx)
+        },
+        (0x10, 1) => CpuIdResult {
+            ecx: 0b1100,
+            ..cpuid_reader(eax, ecx)
+        },
+        (0x10, 2) => CpuIdResult {
+            eax: 0xf,
+            ebx: 0,
+            ecx: 0b1000,
+            edx: 0x7,
+        },
+        (0x10, 3) => CpuIdResult {
+            ecx: 0b101,
+            ..cpuid_reader(eax, ecx)
+        },
+        _ => cpuid_reader(eax, ecx),
+    });
+    let e = cpuid.get_rdt_allocation_info().expect("Leaf is supported");
+    assert!(e.l3_cat().unwrap().has_noncontiguous_capacity_bitmask());
+    let l2c = e.l2_cat().expect("Leaf is available");
+    assert_eq!(l2c.capacity_mask_length(), 16);
+    assert!(!l2c.has_code_data_prioritization());
+    assert!(l2c.has_noncontiguous_capacity_bitmask());
+    let mba = e.memory_bandwidth_allocation().unwrap();
+    assert!(mba.has_per_thread_throttling());
+    assert!(mba.has_linear_response_delay());
 }
 
 #[test]
@@ -1025,7 +1211,8 @@ fn tsc() {
     assert_eq!(e.denominator(), 2);
     assert_eq!(e.numerator(), 168);
     assert_eq!(e.nominal_frequency(), 0x0);
-    assert_eq!(e.tsc_frequency(), None);
+    // Falls back to the 25 MHz crystal of model 0x55
+    assert_eq!(e.tsc_frequency(), Some(2_100_000_000));
 }
 
 #[test]
@@ -1035,9 +1222,9 @@ fn processor_frequency() {
         .get_processor_frequency_info()
         .expect("Leaf is supported");
 
-    assert_eq!(e.processor_base_frequency(), 2100);
-    assert_eq!(e.processor_max_frequency(), 3700);
-    assert_eq!(e.bus_frequency(), 100);
+    assert_eq!(e.processor_base_frequency().map(|f| f.as_mhz()), Some(2100));
+    assert_eq!(e.processor_max_frequency().map(|f| f.as_mhz()), Some(3700));
+    assert_eq!(e.bus_frequency().map(|f| f.as_mhz()), Some(100));
 }
 
 #[test]
@@ -1096,6 +1283,40 @@ fn brand_string() {
     assert_eq!(e.as_str(), "Intel(R) Xeon(R) Gold 6252 CPU @ 2.10GHz");
 }
 
+#[test]
+fn frequency() {
+    use crate::FrequencySource;
+
+    let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
+    let f = cpuid.frequency();
+
+    let base = f.base_frequency().expect("Have base frequency");
+    assert_eq!(base.hz(), 2_100_000_000);
+    assert_eq!(base.source(), FrequencySource::FrequencyLeaf);
+    assert_eq!(f.max_frequency().unwrap().hz(), 3_700_000_000);
+    assert_eq!(f.bus_frequency().unwrap().hz(), 100_000_000);
+
+    // Leaf 0x15 doesn't enumerate the crystal clock on Cascade Lake
+    let tsc = f.tsc_frequency().expect("Have TSC frequency");
+    assert_eq!(tsc.hz(), 2_100_000_000);
+    assert_eq!(tsc.source(), FrequencySource::CrystalClockTable);
+
+    let tsc_info = cpuid.get_tsc_info().expect("Have TSC leaf");
+    assert_eq!(tsc_info.nominal_frequency(), 0);
+    let crystal = tsc_info
+        .crystal_frequency()
+        .expect("Crystal from model table");
+    assert_eq!(crystal.hz(), 25_000_000);
+    assert_eq!(crystal.source(), FrequencySource::CrystalClockTable);
+
+    let brand = cpuid.get_processor_brand_string().unwrap();
+    let nominal = brand
+        .nominal_frequency()
+        .expect("Brand string has frequency");
+    assert_eq!(nominal.hz(), 2_100_000_000);
+    assert_eq!(nominal.source(), FrequencySource::BrandString);
+}
+
 #[test]
 fn l1_tlb_cache() {
     let cpuid = CpuId::with_cpuid_fn(cpuid_reader);
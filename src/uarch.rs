@@ -0,0 +1,2743 @@
+//! Identify the micro-architecture from the vendor and the display family/model
+//! (as returned by [`FeatureInfo::family_id`](crate::FeatureInfo::family_id) and
+//! [`FeatureInfo::model_id`](crate::FeatureInfo::model_id)).
+//!
+//! The tables are compiled in with the `uarch-db` feature. They are static data and
+//! [`identify_micro_architecture`] and [`identify_from_leafs`] are `const fn`s, so
+//! identification works in `no_std` environments without an allocator.
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use crate::{
+    CpuCapabilities, CpuIdResult, ExtendedFeaturesEbx as Ebx, ExtendedFeaturesEcx as Ecx,
+    ExtendedFeaturesEdx as Edx, FeatureInfoFlags as Leaf1,
+};
+
+#[cfg(feature = "serialize")]
+use serde_derive::{Deserialize, Serialize};
+
+/// Error returned when parsing an unknown vendor or micro-architecture name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseUArchError;
+
+impl Display for ParseUArchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown micro-architecture name")
+    }
+}
+
+impl core::error::Error for ParseUArchError {}
+
+/// Compare two names ignoring ASCII case, spaces, dashes and underscores.
+fn name_eq(a: &str, b: &str) -> bool {
+    let significant = |c: &char| !matches!(c, ' ' | '-' | '_');
+    a.chars()
+        .filter(significant)
+        .map(|c| c.to_ascii_lowercase())
+        .eq(b
+            .chars()
+            .filter(significant)
+            .map(|c| c.to_ascii_lowercase()))
+}
+
+/// Implements `name()`, `Display` and `FromStr` for a fieldless enum.
+///
+/// Parsing accepts the display name (`"Zen 3"`) as well as the variant name (`"Zen3"`),
+/// ignoring case, spaces, dashes and underscores (so `"zen3"` works too).
+macro_rules! names {
+    ($ty:ident { $($variant:ident => $name:literal,)* }) => {
+        impl $ty {
+            pub(crate) const ALL: &'static [($ty, &'static str, &'static str)] =
+                &[$(($ty::$variant, $name, stringify!($variant)),)*];
+
+            /// Human readable name.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($ty::$variant => $name,)*
+                }
+            }
+        }
+
+        impl Display for $ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = ParseUArchError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $ty::ALL
+                    .iter()
+                    .find(|(_, name, ident)| name_eq(s, name) || name_eq(s, ident))
+                    .map(|(variant, _, _)| *variant)
+                    .ok_or(ParseUArchError)
+            }
+        }
+    };
+}
+
+/// CPU vendors known to the micro-architecture tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum CpuVendor {
+    /// "GenuineIntel"
+    Intel,
+    /// "AuthenticAMD"
+    Amd,
+    /// "CentaurHauls" (VIA and early Zhaoxin)
+    Centaur,
+    /// "  Shanghai  " (Zhaoxin)
+    Zhaoxin,
+    /// "HygonGenuine"
+    Hygon,
+}
+
+names!(CpuVendor {
+    Intel => "Intel",
+    Amd => "AMD",
+    Centaur => "Centaur",
+    Zhaoxin => "Zhaoxin",
+    Hygon => "Hygon",
+});
+
+impl CpuVendor {
+    /// Map the raw registers of leaf 0x00 to a vendor.
+    pub const fn from_vendor_leaf(leaf: CpuIdResult) -> Option<CpuVendor> {
+        const VENDORS: [(&[u8; 12], CpuVendor); 5] = [
+            (b"GenuineIntel", CpuVendor::Intel),
+            (b"AuthenticAMD", CpuVendor::Amd),
+            (b"CentaurHauls", CpuVendor::Centaur),
+            (b"  Shanghai  ", CpuVendor::Zhaoxin),
+            (b"HygonGenuine", CpuVendor::Hygon),
+        ];
+        const fn word(name: &[u8; 12], i: usize) -> u32 {
+            u32::from_le_bytes([name[i], name[i + 1], name[i + 2], name[i + 3]])
+        }
+
+        let mut i = 0;
+        while i < VENDORS.len() {
+            let (name, vendor) = VENDORS[i];
+            // The vendor string is stored in ebx, edx, ecx (in that order)
+            if leaf.ebx == word(name, 0) && leaf.edx == word(name, 4) && leaf.ecx == word(name, 8) {
+                return Some(vendor);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Map a vendor string (LEAF=0x00) to a vendor.
+    pub fn from_vendor_str(vendor: &str) -> Option<CpuVendor> {
+        match vendor {
+            "GenuineIntel" => Some(CpuVendor::Intel),
+            "AuthenticAMD" => Some(CpuVendor::Amd),
+            "CentaurHauls" => Some(CpuVendor::Centaur),
+            "  Shanghai  " => Some(CpuVendor::Zhaoxin),
+            "HygonGenuine" => Some(CpuVendor::Hygon),
+            _ => None,
+        }
+    }
+}
+
+/// The design of a processor core.
+///
+/// Several products ([`UArch`]) can share the same core design, e.g., Kaby Lake and
+/// Comet Lake both use Skylake cores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum CoreArch {
+    /// Intel P5 (Pentium, Pentium MMX).
+    P5,
+    /// Intel P6 (Pentium Pro, Pentium II, Pentium III).
+    P6,
+    /// Intel NetBurst (Pentium 4, Pentium D).
+    NetBurst,
+    /// Intel Pentium M (Banias, Dothan, Yonah).
+    PentiumM,
+    /// Intel Core (Merom, Conroe, Woodcrest).
+    Core,
+    /// Intel Penryn (45nm Core shrink).
+    Penryn,
+    /// Intel Nehalem.
+    Nehalem,
+    /// Intel Westmere.
+    Westmere,
+    /// Intel Sandy Bridge.
+    SandyBridge,
+    /// Intel Ivy Bridge.
+    IvyBridge,
+    /// Intel Haswell.
+    Haswell,
+    /// Intel Broadwell.
+    Broadwell,
+    /// Intel Skylake (also used by Kaby, Coffee, Comet, Cascade and Cooper Lake).
+    Skylake,
+    /// Intel Palm Cove (Cannon Lake).
+    PalmCove,
+    /// Intel Sunny Cove (Ice Lake).
+    SunnyCove,
+    /// Intel Willow Cove (Tiger Lake).
+    WillowCove,
+    /// Intel Cypress Cove (Rocket Lake).
+    CypressCove,
+    /// Intel Golden Cove (Alder Lake P-core, Sapphire Rapids).
+    GoldenCove,
+    /// Intel Raptor Cove (Raptor Lake P-core, Emerald Rapids).
+    RaptorCove,
+    /// Intel Redwood Cove (Meteor Lake P-core, Granite Rapids).
+    RedwoodCove,
+    /// Intel Lion Cove (Arrow Lake and Lunar Lake P-core).
+    LionCove,
+    /// Intel Bonnell (Atom, 45nm).
+    Bonnell,
+    /// Intel Saltwell (Atom, 32nm).
+    Saltwell,
+    /// Intel Silvermont (Atom).
+    Silvermont,
+    /// Intel Airmont (Atom).
+    Airmont,
+    /// Intel Goldmont (Atom).
+    Goldmont,
+    /// Intel Goldmont Plus (Atom).
+    GoldmontPlus,
+    /// Intel Tremont (Atom).
+    Tremont,
+    /// Intel Gracemont (Atom, Alder Lake E-core).
+    Gracemont,
+    /// Intel Crestmont (Atom, Meteor Lake E-core).
+    Crestmont,
+    /// Intel Skymont (Arrow Lake and Lunar Lake E-core).
+    Skymont,
+    /// Intel Lakemont (Quark, 486 class in-order core).
+    Lakemont,
+    /// Intel Knights Corner (in-order, P54C derived many-core).
+    KnightsCorner,
+    /// Intel Knights Landing (Silvermont derived many-core with AVX-512).
+    KnightsLanding,
+    /// Intel Knights Mill (Knights Landing with deep learning extensions).
+    KnightsMill,
+    /// AMD K8.
+    K8,
+    /// AMD K10.
+    K10,
+    /// AMD Bobcat.
+    Bobcat,
+    /// AMD Jaguar.
+    Jaguar,
+    /// AMD Puma.
+    Puma,
+    /// AMD Bulldozer.
+    Bulldozer,
+    /// AMD Piledriver.
+    Piledriver,
+    /// AMD Steamroller.
+    Steamroller,
+    /// AMD Excavator.
+    Excavator,
+    /// AMD Zen.
+    Zen,
+    /// AMD Zen+.
+    ZenPlus,
+    /// AMD Zen 2.
+    Zen2,
+    /// AMD Zen 3 (and Zen 3+).
+    Zen3,
+    /// AMD Zen 4 (and Zen 4c).
+    Zen4,
+    /// AMD Zen 5 (and Zen 5c).
+    Zen5,
+    /// VIA Esther (C7).
+    Esther,
+    /// VIA Isaiah (Nano).
+    Isaiah,
+    /// Zhaoxin ZhangJiang.
+    ZhangJiang,
+    /// Zhaoxin WuDaoKou.
+    WuDaoKou,
+    /// Zhaoxin LuJiaZui.
+    LuJiaZui,
+    /// Zhaoxin Century Avenue.
+    CenturyAvenue,
+}
+
+names!(CoreArch {
+    P5 => "P5",
+    P6 => "P6",
+    NetBurst => "NetBurst",
+    PentiumM => "Pentium M",
+    Core => "Core",
+    Penryn => "Penryn",
+    Nehalem => "Nehalem",
+    Westmere => "Westmere",
+    SandyBridge => "Sandy Bridge",
+    IvyBridge => "Ivy Bridge",
+    Haswell => "Haswell",
+    Broadwell => "Broadwell",
+    Skylake => "Skylake",
+    PalmCove => "Palm Cove",
+    SunnyCove => "Sunny Cove",
+    WillowCove => "Willow Cove",
+    CypressCove => "Cypress Cove",
+    GoldenCove => "Golden Cove",
+    RaptorCove => "Raptor Cove",
+    RedwoodCove => "Redwood Cove",
+    LionCove => "Lion Cove",
+    Bonnell => "Bonnell",
+    Saltwell => "Saltwell",
+    Silvermont => "Silvermont",
+    Airmont => "Airmont",
+    Goldmont => "Goldmont",
+    GoldmontPlus => "Goldmont Plus",
+    Tremont => "Tremont",
+    Gracemont => "Gracemont",
+    Crestmont => "Crestmont",
+    Skymont => "Skymont",
+    Lakemont => "Lakemont",
+    KnightsCorner => "Knights Corner",
+    KnightsLanding => "Knights Landing",
+    KnightsMill => "Knights Mill",
+    K8 => "K8",
+    K10 => "K10",
+    Bobcat => "Bobcat",
+    Jaguar => "Jaguar",
+    Puma => "Puma",
+    Bulldozer => "Bulldozer",
+    Piledriver => "Piledriver",
+    Steamroller => "Steamroller",
+    Excavator => "Excavator",
+    Zen => "Zen",
+    ZenPlus => "Zen+",
+    Zen2 => "Zen 2",
+    Zen3 => "Zen 3",
+    Zen4 => "Zen 4",
+    Zen5 => "Zen 5",
+    Esther => "Esther",
+    Isaiah => "Isaiah",
+    ZhangJiang => "ZhangJiang",
+    WuDaoKou => "WuDaoKou",
+    LuJiaZui => "LuJiaZui",
+    CenturyAvenue => "Century Avenue",
+});
+
+/// A processor generation, usually known by its code name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum UArch {
+    /// Intel Pentium and Pentium MMX.
+    P5,
+    /// Intel Pentium Pro, Pentium II and Pentium III.
+    P6,
+    /// Intel Pentium 4 and Pentium D.
+    NetBurst,
+    /// Intel Pentium M (Banias, Dothan).
+    PentiumM,
+    /// Intel Core Solo/Duo.
+    Yonah,
+    /// Intel Core 2 (65nm).
+    Merom,
+    /// Intel Core 2 (45nm).
+    Penryn,
+    /// Intel 1st generation Core i (45nm).
+    Nehalem,
+    /// Intel 1st generation Core i (32nm).
+    Westmere,
+    /// Intel 2nd generation Core i.
+    SandyBridge,
+    /// Intel Xeon E5 v1.
+    SandyBridgeE,
+    /// Intel 3rd generation Core i.
+    IvyBridge,
+    /// Intel Xeon E5/E7 v2.
+    IvyBridgeE,
+    /// Intel 4th generation Core i.
+    Haswell,
+    /// Intel Xeon E5/E7 v3.
+    HaswellE,
+    /// Intel 5th generation Core i.
+    Broadwell,
+    /// Intel Xeon E5/E7 v4 and Xeon D-1500.
+    BroadwellE,
+    /// Intel 6th generation Core i.
+    SkylakeClient,
+    /// Intel 1st generation Xeon Scalable.
+    SkylakeServer,
+    /// Intel 2nd generation Xeon Scalable.
+    CascadeLake,
+    /// Intel 3rd generation Xeon Scalable (four and eight socket).
+    CooperLake,
+    /// Intel 7th generation Core i and 8th generation mobile refresh (Amber Lake).
+    KabyLake,
+    /// Intel 8th and 9th generation Core i (desktop and mobile H/U).
+    CoffeeLake,
+    /// Intel 8th generation Core i (mobile U, 2018).
+    WhiskeyLake,
+    /// Intel Core i3-8121U.
+    CannonLake,
+    /// Intel 10th generation Core i (14nm).
+    CometLake,
+    /// Intel 10th generation Core i (10nm).
+    IceLakeClient,
+    /// Intel 3rd generation Xeon Scalable.
+    IceLakeServer,
+    /// Intel 11th generation Core i (mobile).
+    TigerLake,
+    /// Intel 11th generation Core i (desktop).
+    RocketLake,
+    /// Intel Core with hybrid technology.
+    Lakefield,
+    /// Intel 12th generation Core i.
+    AlderLake,
+    /// Intel 13th and 14th generation Core i.
+    RaptorLake,
+    /// Intel 4th generation Xeon Scalable.
+    SapphireRapids,
+    /// Intel 5th generation Xeon Scalable.
+    EmeraldRapids,
+    /// Intel Xeon 6 with P-cores.
+    GraniteRapids,
+    /// Intel Core Ultra series 1.
+    MeteorLake,
+    /// Intel Core Ultra 200S/H/U.
+    ArrowLake,
+    /// Intel Core Ultra 200V.
+    LunarLake,
+    /// Intel Atom N200/N400/Z500 (Diamondville, Pineview, Lincroft).
+    Bonnell,
+    /// Intel Atom N2000/Z2000 (Cedarview, Penwell, Cloverview).
+    Saltwell,
+    /// Intel Atom Z3000, Celeron/Pentium J1000/N2000.
+    BayTrail,
+    /// Intel Atom C2000 (Avoton, Rangeley).
+    Avoton,
+    /// Intel Atom Z3400/Z3500 (Merrifield, Moorefield, SoFIA).
+    Merrifield,
+    /// Intel Atom x5/x7 Z8000, Celeron/Pentium N3000 (Braswell).
+    CherryTrail,
+    /// Intel Atom E3900, Celeron/Pentium N3350/J3455.
+    ApolloLake,
+    /// Intel Atom C3000.
+    Denverton,
+    /// Intel Celeron/Pentium Silver N4000/J5005.
+    GeminiLake,
+    /// Intel Atom P5900 and C5000 (Snow Ridge, Jacobsville, Parker Ridge).
+    SnowRidge,
+    /// Intel Atom x6000E, Celeron/Pentium J6000/N6000 embedded.
+    ElkhartLake,
+    /// Intel Celeron/Pentium Silver N4500/N6000.
+    JasperLake,
+    /// Intel N100/N200, Core i3-N300 (E-cores only).
+    AlderLakeN,
+    /// Intel Xeon 6 with E-cores.
+    SierraForest,
+    /// Intel Quark SoC X1000 and D1000/D2000 microcontrollers.
+    Quark,
+    /// Intel Xeon Phi x100 coprocessor.
+    KnightsCorner,
+    /// Intel Xeon Phi x200.
+    KnightsLanding,
+    /// Intel Xeon Phi 72x5.
+    KnightsMill,
+    /// Intel Atom P/C SoCs (Grand Ridge).
+    GrandRidge,
+    /// AMD Athlon 64, Opteron (family 0Fh).
+    K8,
+    /// AMD Phenom, Opteron 2300-6100 (family 10h).
+    K10,
+    /// AMD Turion X2 Ultra (family 11h).
+    Griffin,
+    /// AMD A-series APU (family 12h).
+    Llano,
+    /// AMD E/C-series APU (family 14h).
+    Bobcat,
+    /// AMD FX-8100, Opteron 6200 (family 15h).
+    Bulldozer,
+    /// AMD FX-8300, Trinity/Richland APU, Opteron 6300 (family 15h).
+    Piledriver,
+    /// AMD Kaveri/Godavari APU (family 15h).
+    Steamroller,
+    /// AMD Carrizo/Bristol Ridge APU (family 15h).
+    Excavator,
+    /// AMD Kabini/Temash APU (family 16h).
+    Jaguar,
+    /// AMD Beema/Mullins APU (family 16h).
+    Puma,
+    /// AMD Ryzen 1000, Threadripper 1000, EPYC 7001 (Zen).
+    Zeppelin,
+    /// AMD Ryzen 2000 desktop, Threadripper 2000 (Zen+).
+    PinnacleRidge,
+    /// AMD Ryzen 2000 APU (Zen).
+    RavenRidge,
+    /// AMD Ryzen 3000 APU (Zen+).
+    Picasso,
+    /// AMD Ryzen 3000 low-end APU (Zen).
+    Dali,
+    /// AMD EPYC 7002, Threadripper 3000 (Zen 2).
+    Rome,
+    /// AMD Ryzen 4000 APU (Zen 2).
+    Renoir,
+    /// AMD Ryzen 5000U APU (Zen 2).
+    Lucienne,
+    /// AMD Ryzen 3000 desktop (Zen 2).
+    Matisse,
+    /// AMD Steam Deck APU (Zen 2).
+    VanGogh,
+    /// AMD Ryzen 7020 APU (Zen 2).
+    Mendocino,
+    /// AMD EPYC 7003, Threadripper PRO 5000 (Zen 3).
+    Milan,
+    /// AMD Ryzen 5000 desktop (Zen 3).
+    Vermeer,
+    /// AMD Ryzen 6000 APU (Zen 3+).
+    Rembrandt,
+    /// AMD Ryzen 5000 APU (Zen 3).
+    Cezanne,
+    /// AMD EPYC 9004, Threadripper 7000 (Zen 4).
+    Genoa,
+    /// AMD Ryzen 7000 desktop (Zen 4).
+    Raphael,
+    /// AMD Ryzen 7040/8040 APU (Zen 4).
+    Phoenix,
+    /// AMD EPYC 97x4 and 8004 (Zen 4c).
+    Bergamo,
+    /// AMD EPYC 9005 (Zen 5 and Zen 5c).
+    Turin,
+    /// AMD Ryzen AI 300 APU (Zen 5).
+    StrixPoint,
+    /// AMD Ryzen 9000 desktop (Zen 5).
+    GraniteRidge,
+    /// AMD Ryzen AI 7/5 300 APU (Zen 5).
+    KrackanPoint,
+    /// AMD Ryzen AI Max APU (Zen 5).
+    StrixHalo,
+    /// VIA C7, C7-M and Eden.
+    Esther,
+    /// VIA Nano, Eden X2/X4 and QuadCore.
+    Isaiah,
+    /// Zhaoxin ZX-C.
+    ZhangJiang,
+    /// Zhaoxin KX-5000 and KH-20000.
+    WuDaoKou,
+    /// Zhaoxin KX-6000 and KH-30000.
+    LuJiaZui,
+    /// Zhaoxin KX-7000 and KH-40000.
+    Yongfeng,
+    /// Hygon C86 (family 18h, licensed Zen).
+    Dhyana,
+}
+
+names!(UArch {
+    P5 => "P5",
+    P6 => "P6",
+    NetBurst => "NetBurst",
+    PentiumM => "Pentium M",
+    Yonah => "Yonah",
+    Merom => "Merom",
+    Penryn => "Penryn",
+    Nehalem => "Nehalem",
+    Westmere => "Westmere",
+    SandyBridge => "Sandy Bridge",
+    SandyBridgeE => "Sandy Bridge-E",
+    IvyBridge => "Ivy Bridge",
+    IvyBridgeE => "Ivy Bridge-E",
+    Haswell => "Haswell",
+    HaswellE => "Haswell-E",
+    Broadwell => "Broadwell",
+    BroadwellE => "Broadwell-E",
+    SkylakeClient => "Skylake",
+    SkylakeServer => "Skylake-SP",
+    CascadeLake => "Cascade Lake",
+    CooperLake => "Cooper Lake",
+    KabyLake => "Kaby Lake",
+    CoffeeLake => "Coffee Lake",
+    WhiskeyLake => "Whiskey Lake",
+    CannonLake => "Cannon Lake",
+    CometLake => "Comet Lake",
+    IceLakeClient => "Ice Lake",
+    IceLakeServer => "Ice Lake-SP",
+    TigerLake => "Tiger Lake",
+    RocketLake => "Rocket Lake",
+    Lakefield => "Lakefield",
+    AlderLake => "Alder Lake",
+    RaptorLake => "Raptor Lake",
+    SapphireRapids => "Sapphire Rapids",
+    EmeraldRapids => "Emerald Rapids",
+    GraniteRapids => "Granite Rapids",
+    MeteorLake => "Meteor Lake",
+    ArrowLake => "Arrow Lake",
+    LunarLake => "Lunar Lake",
+    Bonnell => "Bonnell",
+    Saltwell => "Saltwell",
+    BayTrail => "Bay Trail",
+    Avoton => "Avoton",
+    Merrifield => "Merrifield",
+    CherryTrail => "Cherry Trail",
+    ApolloLake => "Apollo Lake",
+    Denverton => "Denverton",
+    GeminiLake => "Gemini Lake",
+    SnowRidge => "Snow Ridge",
+    ElkhartLake => "Elkhart Lake",
+    JasperLake => "Jasper Lake",
+    AlderLakeN => "Alder Lake-N",
+    SierraForest => "Sierra Forest",
+    Quark => "Quark",
+    KnightsCorner => "Knights Corner",
+    KnightsLanding => "Knights Landing",
+    KnightsMill => "Knights Mill",
+    GrandRidge => "Grand Ridge",
+    K8 => "K8",
+    K10 => "K10",
+    Griffin => "Griffin",
+    Llano => "Llano",
+    Bobcat => "Bobcat",
+    Bulldozer => "Bulldozer",
+    Piledriver => "Piledriver",
+    Steamroller => "Steamroller",
+    Excavator => "Excavator",
+    Jaguar => "Jaguar",
+    Puma => "Puma",
+    Zeppelin => "Zeppelin",
+    PinnacleRidge => "Pinnacle Ridge",
+    RavenRidge => "Raven Ridge",
+    Picasso => "Picasso",
+    Dali => "Dali",
+    Rome => "Rome",
+    Renoir => "Renoir",
+    Lucienne => "Lucienne",
+    Matisse => "Matisse",
+    VanGogh => "Van Gogh",
+    Mendocino => "Mendocino",
+    Milan => "Milan",
+    Vermeer => "Vermeer",
+    Rembrandt => "Rembrandt",
+    Cezanne => "Cezanne",
+    Genoa => "Genoa",
+    Raphael => "Raphael",
+    Phoenix => "Phoenix",
+    Bergamo => "Bergamo",
+    Turin => "Turin",
+    StrixPoint => "Strix Point",
+    GraniteRidge => "Granite Ridge",
+    KrackanPoint => "Krackan Point",
+    StrixHalo => "Strix Halo",
+    Esther => "Esther",
+    Isaiah => "Isaiah",
+    ZhangJiang => "ZhangJiang",
+    WuDaoKou => "WuDaoKou",
+    LuJiaZui => "LuJiaZui",
+    Yongfeng => "Yongfeng",
+    Dhyana => "Dhyana",
+});
+
+impl UArch {
+    /// The core design (for hybrid processors the performance core).
+    pub const fn core(&self) -> CoreArch {
+        match self {
+            UArch::P5 => CoreArch::P5,
+            UArch::P6 => CoreArch::P6,
+            UArch::NetBurst => CoreArch::NetBurst,
+            UArch::PentiumM | UArch::Yonah => CoreArch::PentiumM,
+            UArch::Merom => CoreArch::Core,
+            UArch::Penryn => CoreArch::Penryn,
+            UArch::Nehalem => CoreArch::Nehalem,
+            UArch::Westmere => CoreArch::Westmere,
+            UArch::SandyBridge | UArch::SandyBridgeE => CoreArch::SandyBridge,
+            UArch::IvyBridge | UArch::IvyBridgeE => CoreArch::IvyBridge,
+            UArch::Haswell | UArch::HaswellE => CoreArch::Haswell,
+            UArch::Broadwell | UArch::BroadwellE => CoreArch::Broadwell,
+            UArch::SkylakeClient
+            | UArch::SkylakeServer
+            | UArch::CascadeLake
+            | UArch::CooperLake
+            | UArch::KabyLake
+            | UArch::CoffeeLake
+            | UArch::WhiskeyLake
+            | UArch::CometLake => CoreArch::Skylake,
+            UArch::CannonLake => CoreArch::PalmCove,
+            UArch::IceLakeClient | UArch::IceLakeServer | UArch::Lakefield => CoreArch::SunnyCove,
+            UArch::TigerLake => CoreArch::WillowCove,
+            UArch::RocketLake => CoreArch::CypressCove,
+            UArch::AlderLake | UArch::SapphireRapids => CoreArch::GoldenCove,
+            UArch::RaptorLake | UArch::EmeraldRapids => CoreArch::RaptorCove,
+            UArch::MeteorLake | UArch::GraniteRapids => CoreArch::RedwoodCove,
+            UArch::ArrowLake | UArch::LunarLake => CoreArch::LionCove,
+            UArch::Bonnell => CoreArch::Bonnell,
+            UArch::Saltwell => CoreArch::Saltwell,
+            UArch::BayTrail => CoreArch::Silvermont,
+            UArch::Avoton => CoreArch::Silvermont,
+            UArch::Merrifield => CoreArch::Silvermont,
+            UArch::CherryTrail => CoreArch::Airmont,
+            UArch::ApolloLake => CoreArch::Goldmont,
+            UArch::Denverton => CoreArch::Goldmont,
+            UArch::GeminiLake => CoreArch::GoldmontPlus,
+            UArch::SnowRidge => CoreArch::Tremont,
+            UArch::ElkhartLake => CoreArch::Tremont,
+            UArch::JasperLake => CoreArch::Tremont,
+            UArch::AlderLakeN => CoreArch::Gracemont,
+            UArch::SierraForest => CoreArch::Crestmont,
+            UArch::Quark => CoreArch::Lakemont,
+            UArch::KnightsCorner => CoreArch::KnightsCorner,
+            UArch::KnightsLanding => CoreArch::KnightsLanding,
+            UArch::KnightsMill => CoreArch::KnightsMill,
+            UArch::GrandRidge => CoreArch::Crestmont,
+            UArch::K8 | UArch::Griffin => CoreArch::K8,
+            UArch::K10 | UArch::Llano => CoreArch::K10,
+            UArch::Bobcat => CoreArch::Bobcat,
+            UArch::Jaguar => CoreArch::Jaguar,
+            UArch::Puma => CoreArch::Puma,
+            UArch::Bulldozer => CoreArch::Bulldozer,
+            UArch::Piledriver => CoreArch::Piledriver,
+            UArch::Steamroller => CoreArch::Steamroller,
+            UArch::Excavator => CoreArch::Excavator,
+            UArch::Zeppelin | UArch::RavenRidge | UArch::Dali | UArch::Dhyana => CoreArch::Zen,
+            UArch::PinnacleRidge | UArch::Picasso => CoreArch::ZenPlus,
+            UArch::Rome
+            | UArch::Renoir
+            | UArch::Lucienne
+            | UArch::Matisse
+            | UArch::VanGogh
+            | UArch::Mendocino => CoreArch::Zen2,
+            UArch::Milan | UArch::Vermeer | UArch::Rembrandt | UArch::Cezanne => CoreArch::Zen3,
+            UArch::Genoa | UArch::Raphael | UArch::Phoenix | UArch::Bergamo => CoreArch::Zen4,
+            UArch::Turin
+            | UArch::StrixPoint
+            | UArch::GraniteRidge
+            | UArch::KrackanPoint
+            | UArch::StrixHalo => CoreArch::Zen5,
+            UArch::Esther => CoreArch::Esther,
+            UArch::Isaiah => CoreArch::Isaiah,
+            UArch::ZhangJiang => CoreArch::ZhangJiang,
+            UArch::WuDaoKou => CoreArch::WuDaoKou,
+            UArch::LuJiaZui => CoreArch::LuJiaZui,
+            UArch::Yongfeng => CoreArch::CenturyAvenue,
+        }
+    }
+
+    /// The efficiency core design of hybrid processors.
+    pub const fn efficiency_core(&self) -> Option<CoreArch> {
+        match self {
+            UArch::Lakefield => Some(CoreArch::Tremont),
+            UArch::AlderLake | UArch::RaptorLake => Some(CoreArch::Gracemont),
+            UArch::MeteorLake => Some(CoreArch::Crestmont),
+            UArch::ArrowLake | UArch::LunarLake => Some(CoreArch::Skymont),
+            _ => None,
+        }
+    }
+}
+
+/// Market segment a processor generation is sold in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum MarketSegment {
+    /// Desktop and workstation (including HEDT).
+    Desktop,
+    /// Notebooks and tablets.
+    Mobile,
+    /// Servers.
+    Server,
+    /// Embedded and semi-custom (consoles, appliances).
+    Embedded,
+}
+
+names!(MarketSegment {
+    Desktop => "Desktop",
+    Mobile => "Mobile",
+    Server => "Server",
+    Embedded => "Embedded",
+});
+
+/// Metadata of a processor generation.
+struct Metadata {
+    node: &'static str,
+    year: u16,
+    segments: &'static [MarketSegment],
+    predecessor: Option<UArch>,
+    successor: Option<UArch>,
+}
+
+impl UArch {
+    const fn metadata(&self) -> Metadata {
+        const D: &[MarketSegment] = &[MarketSegment::Desktop];
+        const DM: &[MarketSegment] = &[MarketSegment::Desktop, MarketSegment::Mobile];
+        const DME: &[MarketSegment] = &[
+            MarketSegment::Desktop,
+            MarketSegment::Mobile,
+            MarketSegment::Embedded,
+        ];
+        const DMS: &[MarketSegment] = &[
+            MarketSegment::Desktop,
+            MarketSegment::Mobile,
+            MarketSegment::Server,
+        ];
+        const DS: &[MarketSegment] = &[MarketSegment::Desktop, MarketSegment::Server];
+        const DSE: &[MarketSegment] = &[
+            MarketSegment::Desktop,
+            MarketSegment::Server,
+            MarketSegment::Embedded,
+        ];
+        const M: &[MarketSegment] = &[MarketSegment::Mobile];
+        const ME: &[MarketSegment] = &[MarketSegment::Mobile, MarketSegment::Embedded];
+        const E: &[MarketSegment] = &[MarketSegment::Embedded];
+        const SE: &[MarketSegment] = &[MarketSegment::Server, MarketSegment::Embedded];
+        const S: &[MarketSegment] = &[MarketSegment::Server];
+
+        const fn m(
+            node: &'static str,
+            year: u16,
+            segments: &'static [MarketSegment],
+            predecessor: Option<UArch>,
+            successor: Option<UArch>,
+        ) -> Metadata {
+            Metadata {
+                node,
+                year,
+                segments,
+                predecessor,
+                successor,
+            }
+        }
+
+        match self {
+            UArch::P5 => m("800nm", 1993, D, None, Some(UArch::P6)),
+            UArch::P6 => m("500nm", 1995, DS, Some(UArch::P5), Some(UArch::NetBurst)),
+            UArch::NetBurst => m("180nm", 2000, DMS, Some(UArch::P6), Some(UArch::Merom)),
+            UArch::PentiumM => m("130nm", 2003, M, Some(UArch::P6), Some(UArch::Yonah)),
+            UArch::Yonah => m("65nm", 2006, M, Some(UArch::PentiumM), Some(UArch::Merom)),
+            UArch::Merom => m("65nm", 2006, DMS, Some(UArch::Yonah), Some(UArch::Penryn)),
+            UArch::Penryn => m("45nm", 2007, DMS, Some(UArch::Merom), Some(UArch::Nehalem)),
+            UArch::Nehalem => m(
+                "45nm",
+                2008,
+                DMS,
+                Some(UArch::Penryn),
+                Some(UArch::Westmere),
+            ),
+            UArch::Westmere => m(
+                "32nm",
+                2010,
+                DMS,
+                Some(UArch::Nehalem),
+                Some(UArch::SandyBridge),
+            ),
+            UArch::SandyBridge => m(
+                "32nm",
+                2011,
+                DM,
+                Some(UArch::Westmere),
+                Some(UArch::IvyBridge),
+            ),
+            UArch::SandyBridgeE => m(
+                "32nm",
+                2011,
+                DS,
+                Some(UArch::Westmere),
+                Some(UArch::IvyBridgeE),
+            ),
+            UArch::IvyBridge => m(
+                "22nm",
+                2012,
+                DM,
+                Some(UArch::SandyBridge),
+                Some(UArch::Haswell),
+            ),
+            UArch::IvyBridgeE => m(
+                "22nm",
+                2013,
+                DS,
+                Some(UArch::SandyBridgeE),
+                Some(UArch::HaswellE),
+            ),
+            UArch::Haswell => m(
+                "22nm",
+                2013,
+                DM,
+                Some(UArch::IvyBridge),
+                Some(UArch::Broadwell),
+            ),
+            UArch::HaswellE => m(
+                "22nm",
+                2014,
+                DS,
+                Some(UArch::IvyBridgeE),
+                Some(UArch::BroadwellE),
+            ),
+            UArch::Broadwell => m(
+                "14nm",
+                2014,
+                DM,
+                Some(UArch::Haswell),
+                Some(UArch::SkylakeClient),
+            ),
+            UArch::BroadwellE => m(
+                "14nm",
+                2015,
+                DSE,
+                Some(UArch::HaswellE),
+                Some(UArch::SkylakeServer),
+            ),
+            UArch::SkylakeClient => m(
+                "14nm",
+                2015,
+                DM,
+                Some(UArch::Broadwell),
+                Some(UArch::KabyLake),
+            ),
+            UArch::SkylakeServer => m(
+                "14nm",
+                2017,
+                DS,
+                Some(UArch::BroadwellE),
+                Some(UArch::CascadeLake),
+            ),
+            UArch::CascadeLake => m(
+                "14nm",
+                2019,
+                DS,
+                Some(UArch::SkylakeServer),
+                Some(UArch::IceLakeServer),
+            ),
+            UArch::CooperLake => m(
+                "14nm",
+                2020,
+                S,
+                Some(UArch::CascadeLake),
+                Some(UArch::SapphireRapids),
+            ),
+            UArch::KabyLake => m(
+                "14nm",
+                2016,
+                DM,
+                Some(UArch::SkylakeClient),
+                Some(UArch::CoffeeLake),
+            ),
+            UArch::CoffeeLake => m(
+                "14nm",
+                2017,
+                DM,
+                Some(UArch::KabyLake),
+                Some(UArch::CometLake),
+            ),
+            UArch::WhiskeyLake => m(
+                "14nm",
+                2018,
+                M,
+                Some(UArch::KabyLake),
+                Some(UArch::CometLake),
+            ),
+            UArch::CannonLake => m(
+                "10nm",
+                2018,
+                M,
+                Some(UArch::KabyLake),
+                Some(UArch::IceLakeClient),
+            ),
+            UArch::CometLake => m(
+                "14nm",
+                2019,
+                DM,
+                Some(UArch::CoffeeLake),
+                Some(UArch::RocketLake),
+            ),
+            UArch::IceLakeClient => m(
+                "10nm",
+                2019,
+                M,
+                Some(UArch::CannonLake),
+                Some(UArch::TigerLake),
+            ),
+            UArch::IceLakeServer => m(
+                "10nm",
+                2021,
+                S,
+                Some(UArch::CascadeLake),
+                Some(UArch::SapphireRapids),
+            ),
+            UArch::TigerLake => m(
+                "10nm SuperFin",
+                2020,
+                M,
+                Some(UArch::IceLakeClient),
+                Some(UArch::AlderLake),
+            ),
+            UArch::RocketLake => m(
+                "14nm",
+                2021,
+                D,
+                Some(UArch::CometLake),
+                Some(UArch::AlderLake),
+            ),
+            UArch::Lakefield => m("10nm", 2020, M, None, Some(UArch::AlderLake)),
+            UArch::AlderLake => m(
+                "Intel 7",
+                2021,
+                DM,
+                Some(UArch::TigerLake),
+                Some(UArch::RaptorLake),
+            ),
+            UArch::RaptorLake => m(
+                "Intel 7",
+                2022,
+                DM,
+                Some(UArch::AlderLake),
+                Some(UArch::ArrowLake),
+            ),
+            UArch::SapphireRapids => m(
+                "Intel 7",
+                2023,
+                S,
+                Some(UArch::IceLakeServer),
+                Some(UArch::EmeraldRapids),
+            ),
+            UArch::EmeraldRapids => m(
+                "Intel 7",
+                2023,
+                S,
+                Some(UArch::SapphireRapids),
+                Some(UArch::GraniteRapids),
+            ),
+            UArch::GraniteRapids => m("Intel 3", 2024, S, Some(UArch::EmeraldRapids), None),
+            UArch::MeteorLake => m(
+                "Intel 4",
+                2023,
+                M,
+                Some(UArch::RaptorLake),
+                Some(UArch::LunarLake),
+            ),
+            UArch::ArrowLake => m("TSMC N3B", 2024, DM, Some(UArch::RaptorLake), None),
+            UArch::LunarLake => m("TSMC N3B", 2024, M, Some(UArch::MeteorLake), None),
+            UArch::Bonnell => m("45nm", 2008, ME, None, Some(UArch::Saltwell)),
+            UArch::Saltwell => m(
+                "32nm",
+                2011,
+                ME,
+                Some(UArch::Bonnell),
+                Some(UArch::BayTrail),
+            ),
+            UArch::BayTrail => m(
+                "22nm",
+                2013,
+                DME,
+                Some(UArch::Saltwell),
+                Some(UArch::CherryTrail),
+            ),
+            UArch::Avoton => m("22nm", 2013, SE, None, Some(UArch::Denverton)),
+            UArch::Merrifield => m("22nm", 2014, M, Some(UArch::Saltwell), None),
+            UArch::CherryTrail => m(
+                "14nm",
+                2015,
+                DME,
+                Some(UArch::BayTrail),
+                Some(UArch::ApolloLake),
+            ),
+            UArch::ApolloLake => m(
+                "14nm",
+                2016,
+                DME,
+                Some(UArch::CherryTrail),
+                Some(UArch::GeminiLake),
+            ),
+            UArch::Denverton => m(
+                "14nm",
+                2017,
+                SE,
+                Some(UArch::Avoton),
+                Some(UArch::SnowRidge),
+            ),
+            UArch::GeminiLake => m(
+                "14nm",
+                2017,
+                DME,
+                Some(UArch::ApolloLake),
+                Some(UArch::JasperLake),
+            ),
+            UArch::SnowRidge => m(
+                "10nm",
+                2020,
+                SE,
+                Some(UArch::Denverton),
+                Some(UArch::GrandRidge),
+            ),
+            UArch::ElkhartLake => m(
+                "10nm",
+                2021,
+                E,
+                Some(UArch::ApolloLake),
+                Some(UArch::AlderLakeN),
+            ),
+            UArch::JasperLake => m(
+                "10nm",
+                2021,
+                DM,
+                Some(UArch::GeminiLake),
+                Some(UArch::AlderLakeN),
+            ),
+            UArch::AlderLakeN => m("Intel 7", 2023, DME, Some(UArch::JasperLake), None),
+            UArch::SierraForest => m("Intel 3", 2024, S, None, None),
+            UArch::Quark => m("32nm", 2013, E, None, None),
+            UArch::KnightsCorner => m("22nm", 2012, S, None, Some(UArch::KnightsLanding)),
+            UArch::KnightsLanding => m(
+                "14nm",
+                2016,
+                DS,
+                Some(UArch::KnightsCorner),
+                Some(UArch::KnightsMill),
+            ),
+            UArch::KnightsMill => m("14nm", 2017, S, Some(UArch::KnightsLanding), None),
+            UArch::GrandRidge => m("Intel 4", 2024, SE, Some(UArch::SnowRidge), None),
+            UArch::K8 => m("130nm", 2003, DMS, None, Some(UArch::K10)),
+            UArch::K10 => m("65nm", 2007, DMS, Some(UArch::K8), Some(UArch::Bulldozer)),
+            UArch::Griffin => m("65nm", 2008, M, Some(UArch::K8), None),
+            UArch::Llano => m("32nm", 2011, DM, Some(UArch::K10), Some(UArch::Piledriver)),
+            UArch::Bobcat => m("40nm", 2011, ME, None, Some(UArch::Jaguar)),
+            UArch::Bulldozer => m("32nm", 2011, DS, Some(UArch::K10), Some(UArch::Piledriver)),
+            UArch::Piledriver => m(
+                "32nm",
+                2012,
+                DMS,
+                Some(UArch::Bulldozer),
+                Some(UArch::Steamroller),
+            ),
+            UArch::Steamroller => m(
+                "28nm",
+                2014,
+                DM,
+                Some(UArch::Piledriver),
+                Some(UArch::Excavator),
+            ),
+            UArch::Excavator => m(
+                "28nm",
+                2015,
+                DM,
+                Some(UArch::Steamroller),
+                Some(UArch::Zeppelin),
+            ),
+            UArch::Jaguar => m("28nm", 2013, ME, Some(UArch::Bobcat), Some(UArch::Puma)),
+            UArch::Puma => m("28nm", 2014, ME, Some(UArch::Jaguar), None),
+            UArch::Zeppelin => m(
+                "GF 14nm",
+                2017,
+                DS,
+                Some(UArch::Excavator),
+                Some(UArch::PinnacleRidge),
+            ),
+            UArch::PinnacleRidge => m(
+                "GF 12nm",
+                2018,
+                D,
+                Some(UArch::Zeppelin),
+                Some(UArch::Matisse),
+            ),
+            UArch::RavenRidge => m(
+                "GF 14nm",
+                2018,
+                DM,
+                Some(UArch::Excavator),
+                Some(UArch::Picasso),
+            ),
+            UArch::Picasso => m(
+                "GF 12nm",
+                2019,
+                DM,
+                Some(UArch::RavenRidge),
+                Some(UArch::Renoir),
+            ),
+            UArch::Dali => m(
+                "GF 14nm",
+                2020,
+                M,
+                Some(UArch::RavenRidge),
+                Some(UArch::Mendocino),
+            ),
+            UArch::Rome => m(
+                "TSMC N7",
+                2019,
+                DS,
+                Some(UArch::Zeppelin),
+                Some(UArch::Milan),
+            ),
+            UArch::Renoir => m(
+                "TSMC N7",
+                2020,
+                DM,
+                Some(UArch::Picasso),
+                Some(UArch::Cezanne),
+            ),
+            UArch::Lucienne => m("TSMC N7", 2021, M, Some(UArch::Renoir), None),
+            UArch::Matisse => m(
+                "TSMC N7",
+                2019,
+                D,
+                Some(UArch::PinnacleRidge),
+                Some(UArch::Vermeer),
+            ),
+            UArch::VanGogh => m("TSMC N7", 2022, ME, None, None),
+            UArch::Mendocino => m("TSMC N6", 2022, M, Some(UArch::Dali), None),
+            UArch::Milan => m("TSMC N7", 2021, DS, Some(UArch::Rome), Some(UArch::Genoa)),
+            UArch::Vermeer => m(
+                "TSMC N7",
+                2020,
+                D,
+                Some(UArch::Matisse),
+                Some(UArch::Raphael),
+            ),
+            UArch::Rembrandt => m(
+                "TSMC N6",
+                2022,
+                M,
+                Some(UArch::Cezanne),
+                Some(UArch::Phoenix),
+            ),
+            UArch::Cezanne => m(
+                "TSMC N7",
+                2021,
+                DM,
+                Some(UArch::Renoir),
+                Some(UArch::Rembrandt),
+            ),
+            UArch::Genoa => m("TSMC N5", 2022, DS, Some(UArch::Milan), Some(UArch::Turin)),
+            UArch::Raphael => m(
+                "TSMC N5",
+                2022,
+                D,
+                Some(UArch::Vermeer),
+                Some(UArch::GraniteRidge),
+            ),
+            UArch::Phoenix => m(
+                "TSMC N4",
+                2023,
+                DM,
+                Some(UArch::Rembrandt),
+                Some(UArch::StrixPoint),
+            ),
+            UArch::Bergamo => m("TSMC N5", 2023, S, Some(UArch::Milan), Some(UArch::Turin)),
+            UArch::Turin => m("TSMC N4P", 2024, S, Some(UArch::Genoa), None),
+            UArch::StrixPoint => m("TSMC N4P", 2024, M, Some(UArch::Phoenix), None),
+            UArch::GraniteRidge => m("TSMC N4P", 2024, D, Some(UArch::Raphael), None),
+            UArch::KrackanPoint => m("TSMC N4P", 2025, M, Some(UArch::Phoenix), None),
+            UArch::StrixHalo => m("TSMC N4P", 2025, M, None, None),
+            UArch::Esther => m("90nm", 2005, DME, None, Some(UArch::Isaiah)),
+            UArch::Isaiah => m(
+                "65nm",
+                2008,
+                DME,
+                Some(UArch::Esther),
+                Some(UArch::ZhangJiang),
+            ),
+            UArch::ZhangJiang => m("28nm", 2015, D, Some(UArch::Isaiah), Some(UArch::WuDaoKou)),
+            UArch::WuDaoKou => m(
+                "28nm",
+                2017,
+                DS,
+                Some(UArch::ZhangJiang),
+                Some(UArch::LuJiaZui),
+            ),
+            UArch::LuJiaZui => m(
+                "16nm",
+                2019,
+                DS,
+                Some(UArch::WuDaoKou),
+                Some(UArch::Yongfeng),
+            ),
+            UArch::Yongfeng => m("16nm", 2023, DS, Some(UArch::LuJiaZui), None),
+            UArch::Dhyana => m("GF 14nm", 2018, DS, None, None),
+        }
+    }
+
+    /// Manufacturing process of the (compute) die at launch, e.g., "14nm" or "TSMC N5".
+    pub const fn process_node(&self) -> &'static str {
+        self.metadata().node
+    }
+
+    /// Year the first products of this generation launched.
+    pub const fn launch_year(&self) -> u16 {
+        self.metadata().year
+    }
+
+    /// Market segments the generation was sold in.
+    pub const fn segments(&self) -> &'static [MarketSegment] {
+        self.metadata().segments
+    }
+
+    /// The generation this one replaced in its main product line.
+    pub const fn predecessor(&self) -> Option<UArch> {
+        self.metadata().predecessor
+    }
+
+    /// The generation that replaced this one in its main product line.
+    pub const fn successor(&self) -> Option<UArch> {
+        self.metadata().successor
+    }
+}
+
+/// Feature bits set in leaf 0x01 (ecx, edx) and leaf 0x07 (ebx, ecx, edx).
+const fn features(leaf1: u64, ebx7: u32, ecx7: u32, edx7: u32) -> CpuCapabilities {
+    CpuCapabilities {
+        edx_ecx: Leaf1::from_bits_truncate(leaf1),
+        ebx7: Ebx::from_bits_truncate(ebx7),
+        ecx7: Ecx::from_bits_truncate(ecx7),
+        edx7: Edx::from_bits_truncate(edx7),
+    }
+}
+
+const fn leaf1(bits: u64) -> CpuCapabilities {
+    features(bits, 0, 0, 0)
+}
+
+// Intel mainline cores, each generation builds on the previous one.
+const F_SSE2: CpuCapabilities = leaf1(Leaf1::SSE.bits() | Leaf1::SSE2.bits());
+const F_YONAH: CpuCapabilities = F_SSE2.union(leaf1(Leaf1::SSE3.bits()));
+const F_MEROM: CpuCapabilities = F_YONAH.union(leaf1(Leaf1::SSSE3.bits()));
+const F_PENRYN: CpuCapabilities = F_MEROM.union(leaf1(Leaf1::SSE41.bits()));
+const F_NEHALEM: CpuCapabilities =
+    F_PENRYN.union(leaf1(Leaf1::SSE42.bits() | Leaf1::POPCNT.bits()));
+const F_WESTMERE: CpuCapabilities =
+    F_NEHALEM.union(leaf1(Leaf1::AESNI.bits() | Leaf1::PCLMULQDQ.bits()));
+const F_SANDY_BRIDGE: CpuCapabilities = F_WESTMERE.union(leaf1(Leaf1::AVX.bits()));
+const F_IVY_BRIDGE: CpuCapabilities = F_SANDY_BRIDGE.union(leaf1(Leaf1::F16C.bits()));
+const F_HASWELL: CpuCapabilities = F_IVY_BRIDGE.union(features(
+    Leaf1::FMA.bits() | Leaf1::MOVBE.bits(),
+    Ebx::AVX2.bits() | Ebx::BMI1.bits() | Ebx::BMI2.bits(),
+    0,
+    0,
+));
+const F_BROADWELL: CpuCapabilities = F_HASWELL.union(features(0, Ebx::ADX.bits(), 0, 0));
+const F_SKYLAKE_SERVER: CpuCapabilities = F_BROADWELL.union(features(
+    0,
+    Ebx::AVX512F.bits()
+        | Ebx::AVX512DQ.bits()
+        | Ebx::AVX512CD.bits()
+        | Ebx::AVX512BW.bits()
+        | Ebx::AVX512VL.bits(),
+    0,
+    0,
+));
+const F_CASCADE_LAKE: CpuCapabilities =
+    F_SKYLAKE_SERVER.union(features(0, 0, Ecx::AVX512VNNI.bits(), 0));
+const F_CANNON_LAKE: CpuCapabilities = F_SKYLAKE_SERVER.union(features(
+    0,
+    Ebx::AVX512_IFMA.bits() | Ebx::SHA.bits(),
+    Ecx::AVX512VBMI.bits(),
+    0,
+));
+const F_ICE_LAKE: CpuCapabilities = F_CANNON_LAKE.union(features(
+    0,
+    0,
+    Ecx::AVX512VBMI2.bits()
+        | Ecx::AVX512VNNI.bits()
+        | Ecx::AVX512BITALG.bits()
+        | Ecx::AVX512VPOPCNTDQ.bits()
+        | Ecx::GFNI.bits()
+        | Ecx::VAES.bits()
+        | Ecx::VPCLMULQDQ.bits(),
+    0,
+));
+const F_TIGER_LAKE: CpuCapabilities =
+    F_ICE_LAKE.union(features(0, 0, 0, Edx::AVX512_VP2INTERSECT.bits()));
+const F_SAPPHIRE_RAPIDS: CpuCapabilities =
+    F_ICE_LAKE.union(features(0, 0, 0, Edx::AVX512_FP16.bits()));
+/// Hybrid client parts: AVX-512 is disabled since the E-cores lack it.
+const F_ALDER_LAKE: CpuCapabilities = F_BROADWELL.union(features(
+    0,
+    Ebx::SHA.bits(),
+    Ecx::GFNI.bits() | Ecx::VAES.bits() | Ecx::VPCLMULQDQ.bits(),
+    0,
+));
+
+// Intel Atom and Xeon Phi.
+const F_BONNELL: CpuCapabilities = F_MEROM.union(leaf1(Leaf1::MOVBE.bits()));
+const F_SILVERMONT: CpuCapabilities = F_BONNELL.union(leaf1(
+    Leaf1::SSE41.bits()
+        | Leaf1::SSE42.bits()
+        | Leaf1::POPCNT.bits()
+        | Leaf1::AESNI.bits()
+        | Leaf1::PCLMULQDQ.bits(),
+));
+const F_GOLDMONT: CpuCapabilities = F_SILVERMONT.union(features(0, Ebx::SHA.bits(), 0, 0));
+const F_TREMONT: CpuCapabilities = F_GOLDMONT.union(features(0, 0, Ecx::GFNI.bits(), 0));
+const F_KNIGHTS_LANDING: CpuCapabilities = F_HASWELL.union(features(
+    0,
+    Ebx::AVX512F.bits() | Ebx::AVX512CD.bits() | Ebx::AVX512ER.bits() | Ebx::AVX512PF.bits(),
+    0,
+    0,
+));
+const F_KNIGHTS_MILL: CpuCapabilities = F_KNIGHTS_LANDING.union(features(
+    0,
+    0,
+    Ecx::AVX512VPOPCNTDQ.bits(),
+    Edx::AVX512_4VNNIW.bits() | Edx::AVX512_4FMAPS.bits(),
+));
+
+// AMD.
+const F_K10: CpuCapabilities = F_YONAH.union(leaf1(Leaf1::POPCNT.bits()));
+const F_BOBCAT: CpuCapabilities = F_MEROM.union(leaf1(Leaf1::POPCNT.bits()));
+const F_BULLDOZER: CpuCapabilities = F_WESTMERE.union(leaf1(Leaf1::AVX.bits()));
+const F_PILEDRIVER: CpuCapabilities = F_BULLDOZER.union(features(
+    Leaf1::FMA.bits() | Leaf1::F16C.bits(),
+    Ebx::BMI1.bits(),
+    0,
+    0,
+));
+const F_EXCAVATOR: CpuCapabilities = F_PILEDRIVER.union(features(
+    Leaf1::MOVBE.bits(),
+    Ebx::AVX2.bits() | Ebx::BMI2.bits(),
+    0,
+    0,
+));
+const F_JAGUAR: CpuCapabilities = F_BULLDOZER.union(features(
+    Leaf1::F16C.bits() | Leaf1::MOVBE.bits(),
+    Ebx::BMI1.bits(),
+    0,
+    0,
+));
+const F_ZEN: CpuCapabilities =
+    F_EXCAVATOR.union(features(0, Ebx::ADX.bits() | Ebx::SHA.bits(), 0, 0));
+const F_ZEN3: CpuCapabilities =
+    F_ZEN.union(features(0, 0, Ecx::VAES.bits() | Ecx::VPCLMULQDQ.bits(), 0));
+const F_ZEN4: CpuCapabilities = F_ZEN3.union(features(
+    0,
+    Ebx::AVX512F.bits()
+        | Ebx::AVX512DQ.bits()
+        | Ebx::AVX512_IFMA.bits()
+        | Ebx::AVX512CD.bits()
+        | Ebx::AVX512BW.bits()
+        | Ebx::AVX512VL.bits(),
+    Ecx::AVX512VBMI.bits()
+        | Ecx::AVX512VBMI2.bits()
+        | Ecx::AVX512VNNI.bits()
+        | Ecx::AVX512BITALG.bits()
+        | Ecx::AVX512VPOPCNTDQ.bits()
+        | Ecx::GFNI.bits(),
+    0,
+));
+const F_ZEN5: CpuCapabilities = F_ZEN4.union(features(0, 0, 0, Edx::AVX512_VP2INTERSECT.bits()));
+
+impl UArch {
+    /// The instruction set extensions every full-featured part of this generation
+    /// reports in leaf 0x01 and leaf 0x07.
+    ///
+    /// Only the extensions tracked by [`CpuCapabilities`] are included. Low-end models
+    /// (e.g., Pentium and Celeron branded parts) may have some of them fused off, and a
+    /// hypervisor or the firmware can hide more. Compare with the actual capabilities
+    /// using [`CpuCapabilities::difference`].
+    pub const fn expected_features(&self) -> CpuCapabilities {
+        match self {
+            UArch::P5 | UArch::P6 | UArch::Quark | UArch::KnightsCorner => features(0, 0, 0, 0),
+            UArch::NetBurst | UArch::PentiumM | UArch::K8 | UArch::Griffin => F_SSE2,
+            UArch::Yonah | UArch::Esther => F_YONAH,
+            UArch::Merom => F_MEROM,
+            UArch::Penryn | UArch::Isaiah => F_PENRYN,
+            UArch::Nehalem => F_NEHALEM,
+            UArch::Westmere => F_WESTMERE,
+            UArch::SandyBridge | UArch::SandyBridgeE => F_SANDY_BRIDGE,
+            UArch::IvyBridge | UArch::IvyBridgeE => F_IVY_BRIDGE,
+            UArch::Haswell | UArch::HaswellE => F_HASWELL,
+            UArch::Broadwell
+            | UArch::BroadwellE
+            | UArch::SkylakeClient
+            | UArch::KabyLake
+            | UArch::CoffeeLake
+            | UArch::WhiskeyLake
+            | UArch::CometLake => F_BROADWELL,
+            UArch::SkylakeServer => F_SKYLAKE_SERVER,
+            UArch::CascadeLake | UArch::CooperLake => F_CASCADE_LAKE,
+            UArch::CannonLake => F_CANNON_LAKE,
+            UArch::IceLakeClient | UArch::IceLakeServer | UArch::RocketLake => F_ICE_LAKE,
+            UArch::TigerLake => F_TIGER_LAKE,
+            UArch::SapphireRapids | UArch::EmeraldRapids | UArch::GraniteRapids => {
+                F_SAPPHIRE_RAPIDS
+            }
+            UArch::AlderLake
+            | UArch::RaptorLake
+            | UArch::MeteorLake
+            | UArch::ArrowLake
+            | UArch::LunarLake
+            | UArch::AlderLakeN
+            | UArch::SierraForest
+            | UArch::GrandRidge => F_ALDER_LAKE,
+            UArch::Lakefield => F_TREMONT,
+            UArch::Bonnell | UArch::Saltwell => F_BONNELL,
+            UArch::BayTrail | UArch::Avoton | UArch::Merrifield | UArch::CherryTrail => {
+                F_SILVERMONT
+            }
+            UArch::ApolloLake | UArch::Denverton | UArch::GeminiLake => F_GOLDMONT,
+            UArch::SnowRidge | UArch::ElkhartLake | UArch::JasperLake => F_TREMONT,
+            UArch::KnightsLanding => F_KNIGHTS_LANDING,
+            UArch::KnightsMill => F_KNIGHTS_MILL,
+            UArch::K10 | UArch::Llano => F_K10,
+            UArch::Bobcat => F_BOBCAT,
+            UArch::Bulldozer => F_BULLDOZER,
+            UArch::Piledriver | UArch::Steamroller => F_PILEDRIVER,
+            UArch::Excavator => F_EXCAVATOR,
+            UArch::Jaguar | UArch::Puma => F_JAGUAR,
+            UArch::Zeppelin
+            | UArch::PinnacleRidge
+            | UArch::RavenRidge
+            | UArch::Picasso
+            | UArch::Dali
+            | UArch::Dhyana
+            | UArch::Rome
+            | UArch::Renoir
+            | UArch::Lucienne
+            | UArch::Matisse
+            | UArch::VanGogh
+            | UArch::Mendocino => F_ZEN,
+            UArch::Milan | UArch::Vermeer | UArch::Rembrandt | UArch::Cezanne => F_ZEN3,
+            UArch::Genoa | UArch::Raphael | UArch::Phoenix | UArch::Bergamo => F_ZEN4,
+            UArch::Turin
+            | UArch::StrixPoint
+            | UArch::GraniteRidge
+            | UArch::KrackanPoint
+            | UArch::StrixHalo => F_ZEN5,
+            UArch::ZhangJiang | UArch::WuDaoKou | UArch::LuJiaZui => F_WESTMERE,
+            UArch::Yongfeng => F_HASWELL,
+        }
+    }
+}
+
+/// Typical cache sizes and load-to-use latencies of a core design.
+///
+/// These come from vendor documentation and published measurements of common parts and
+/// are meant as a fallback when the cache leafs are not available (e.g., in dumps or VMs
+/// that hide them). The L3 size varies a lot between products, so it is given as the
+/// share per core of a typical desktop or server part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct CacheHints {
+    l1d_size: u32,
+    l1i_size: u32,
+    l2_size: u32,
+    l3_size: Option<u32>,
+    l1d_latency: u8,
+    l2_latency: u8,
+    l3_latency: Option<u8>,
+}
+
+impl CacheHints {
+    /// Size of the L1 data cache per core in KiB.
+    pub const fn l1d_size(&self) -> u32 {
+        self.l1d_size
+    }
+
+    /// Size of the L1 instruction cache per core in KiB.
+    pub const fn l1i_size(&self) -> u32 {
+        self.l1i_size
+    }
+
+    /// Size of the L2 cache per core in KiB (for shared L2 caches the share per core).
+    pub const fn l2_size(&self) -> u32 {
+        self.l2_size
+    }
+
+    /// Share of the L3 cache per core in KiB, `None` if there typically is no L3.
+    pub const fn l3_size(&self) -> Option<u32> {
+        self.l3_size
+    }
+
+    /// Load-to-use latency of the L1 data cache in core clock cycles.
+    pub const fn l1d_latency(&self) -> u8 {
+        self.l1d_latency
+    }
+
+    /// Load-to-use latency of the L2 cache in core clock cycles.
+    pub const fn l2_latency(&self) -> u8 {
+        self.l2_latency
+    }
+
+    /// Approximate load-to-use latency of the L3 cache in core clock cycles.
+    pub const fn l3_latency(&self) -> Option<u8> {
+        self.l3_latency
+    }
+}
+
+/// Sizes in KiB and latencies in cycles, an L3 size of 0 means there is no L3.
+const fn cache(
+    l1d_size: u32,
+    l1i_size: u32,
+    l2_size: u32,
+    l3_size: u32,
+    l1d_latency: u8,
+    l2_latency: u8,
+    l3_latency: u8,
+) -> Option<CacheHints> {
+    Some(CacheHints {
+        l1d_size,
+        l1i_size,
+        l2_size,
+        l3_size: if l3_size == 0 { None } else { Some(l3_size) },
+        l1d_latency,
+        l2_latency,
+        l3_latency: if l3_size == 0 { None } else { Some(l3_latency) },
+    })
+}
+
+impl CoreArch {
+    /// Typical caches of client parts using this core, `None` if not known.
+    pub const fn cache_hints(&self) -> Option<CacheHints> {
+        match self {
+            CoreArch::PentiumM => cache(32, 32, 1024, 0, 3, 10, 0),
+            CoreArch::Core => cache(32, 32, 2048, 0, 3, 14, 0),
+            CoreArch::Penryn => cache(32, 32, 3072, 0, 3, 15, 0),
+            CoreArch::Nehalem => cache(32, 32, 256, 2048, 4, 10, 38),
+            CoreArch::Westmere => cache(32, 32, 256, 2048, 4, 10, 40),
+            CoreArch::SandyBridge => cache(32, 32, 256, 2048, 4, 12, 29),
+            CoreArch::IvyBridge => cache(32, 32, 256, 2048, 4, 12, 30),
+            CoreArch::Haswell => cache(32, 32, 256, 2048, 4, 12, 34),
+            CoreArch::Broadwell => cache(32, 32, 256, 1536, 4, 12, 38),
+            CoreArch::Skylake => cache(32, 32, 256, 2048, 4, 12, 42),
+            CoreArch::PalmCove => cache(32, 32, 256, 2048, 5, 13, 42),
+            CoreArch::SunnyCove => cache(48, 32, 512, 2048, 5, 13, 42),
+            CoreArch::WillowCove => cache(48, 32, 1280, 3072, 5, 14, 50),
+            CoreArch::CypressCove => cache(48, 32, 512, 2048, 5, 13, 44),
+            CoreArch::GoldenCove => cache(48, 32, 1280, 3072, 5, 15, 65),
+            CoreArch::RaptorCove => cache(48, 32, 2048, 3072, 5, 16, 70),
+            CoreArch::RedwoodCove => cache(48, 64, 2048, 3072, 5, 16, 75),
+            CoreArch::LionCove => cache(48, 64, 3072, 3072, 5, 17, 80),
+            CoreArch::Bonnell | CoreArch::Saltwell => cache(24, 32, 512, 0, 3, 16, 0),
+            CoreArch::Silvermont | CoreArch::Airmont => cache(24, 32, 512, 0, 3, 14, 0),
+            CoreArch::Goldmont => cache(24, 32, 512, 0, 3, 17, 0),
+            CoreArch::GoldmontPlus => cache(24, 64, 1024, 0, 3, 19, 0),
+            CoreArch::Tremont => cache(32, 32, 1152, 0, 4, 19, 0),
+            CoreArch::Gracemont => cache(32, 64, 512, 1536, 3, 17, 70),
+            CoreArch::Crestmont => cache(32, 64, 512, 1536, 3, 20, 75),
+            CoreArch::Skymont => cache(32, 64, 1024, 1536, 3, 19, 80),
+            CoreArch::KnightsLanding | CoreArch::KnightsMill => cache(32, 32, 512, 0, 4, 17, 0),
+            CoreArch::K8 => cache(64, 64, 512, 0, 3, 12, 0),
+            CoreArch::K10 => cache(64, 64, 512, 1536, 3, 15, 45),
+            CoreArch::Bobcat => cache(32, 32, 512, 0, 3, 17, 0),
+            CoreArch::Jaguar | CoreArch::Puma => cache(32, 32, 512, 0, 3, 25, 0),
+            CoreArch::Bulldozer => cache(16, 32, 1024, 1024, 4, 21, 65),
+            CoreArch::Piledriver => cache(16, 32, 1024, 1024, 4, 20, 65),
+            CoreArch::Steamroller => cache(16, 48, 1024, 0, 4, 19, 0),
+            CoreArch::Excavator => cache(32, 48, 512, 0, 4, 17, 0),
+            CoreArch::Zen => cache(32, 64, 512, 2048, 4, 17, 40),
+            CoreArch::ZenPlus => cache(32, 64, 512, 2048, 4, 12, 39),
+            CoreArch::Zen2 => cache(32, 32, 512, 4096, 4, 12, 39),
+            CoreArch::Zen3 => cache(32, 32, 512, 4096, 4, 12, 46),
+            CoreArch::Zen4 => cache(32, 32, 1024, 4096, 4, 14, 50),
+            CoreArch::Zen5 => cache(48, 32, 1024, 4096, 4, 14, 50),
+            _ => None,
+        }
+    }
+}
+
+impl UArch {
+    /// Typical caches of this generation, `None` if not known.
+    ///
+    /// For hybrid processors this describes the performance cores, see
+    /// [`CoreArch::cache_hints`] for the efficiency cores.
+    pub const fn cache_hints(&self) -> Option<CacheHints> {
+        match self {
+            UArch::SandyBridgeE | UArch::IvyBridgeE | UArch::HaswellE | UArch::BroadwellE => {
+                cache(32, 32, 256, 2560, 4, 12, 45)
+            }
+            UArch::SkylakeServer | UArch::CascadeLake | UArch::CooperLake => {
+                cache(32, 32, 1024, 1408, 4, 14, 70)
+            }
+            UArch::IceLakeServer => cache(48, 32, 1280, 1536, 5, 14, 80),
+            UArch::SapphireRapids => cache(48, 32, 2048, 1920, 5, 16, 110),
+            UArch::EmeraldRapids => cache(48, 32, 2048, 5120, 5, 16, 110),
+            UArch::GraniteRapids => cache(48, 64, 2048, 4032, 5, 16, 120),
+            UArch::AlderLakeN => cache(32, 64, 512, 1536, 3, 17, 70),
+            UArch::SierraForest | UArch::GrandRidge => cache(32, 64, 1024, 1536, 3, 20, 110),
+            // APUs have half the L3 per core of the desktop and server parts
+            UArch::Renoir | UArch::Lucienne | UArch::VanGogh | UArch::Mendocino => {
+                cache(32, 32, 512, 1024, 4, 12, 39)
+            }
+            UArch::Cezanne | UArch::Rembrandt => cache(32, 32, 512, 2048, 4, 12, 46),
+            UArch::Phoenix => cache(32, 32, 1024, 2048, 4, 14, 50),
+            UArch::StrixPoint | UArch::KrackanPoint => cache(48, 32, 1024, 2048, 4, 14, 50),
+            _ => self.core().cache_hints(),
+        }
+    }
+}
+
+/// How an identification was made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub enum Confidence {
+    /// The family and model were found in the tables.
+    High,
+    /// Guessed from the processor brand string because the family and model are not
+    /// known. Brand strings can be changed by firmware and hypervisors.
+    Low,
+    /// Running under a hypervisor that reports a synthetic family/model (e.g., QEMU's
+    /// `qemu64` or `kvm64` models). The generation is a best-effort guess from the brand
+    /// string or the feature bits the guest can see.
+    Virtualized,
+}
+
+/// The result of identifying a processor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct MicroArchitecture {
+    vendor: CpuVendor,
+    uarch: UArch,
+    confidence: Confidence,
+}
+
+impl MicroArchitecture {
+    /// The vendor of the processor.
+    pub const fn vendor(&self) -> CpuVendor {
+        self.vendor
+    }
+
+    /// The processor generation.
+    pub const fn uarch(&self) -> UArch {
+        self.uarch
+    }
+
+    /// Whether the result comes from the family/model tables or the brand string.
+    pub const fn confidence(&self) -> Confidence {
+        self.confidence
+    }
+
+    /// The core design (for hybrid processors the performance core).
+    pub const fn core(&self) -> CoreArch {
+        self.uarch.core()
+    }
+
+    /// The efficiency core design, if this is a hybrid processor.
+    pub const fn efficiency_core(&self) -> Option<CoreArch> {
+        self.uarch.efficiency_core()
+    }
+
+    /// Manufacturing process, see [`UArch::process_node`].
+    pub const fn process_node(&self) -> &'static str {
+        self.uarch.process_node()
+    }
+
+    /// Launch year, see [`UArch::launch_year`].
+    pub const fn launch_year(&self) -> u16 {
+        self.uarch.launch_year()
+    }
+
+    /// Market segments, see [`UArch::segments`].
+    pub const fn segments(&self) -> &'static [MarketSegment] {
+        self.uarch.segments()
+    }
+
+    /// The previous generation, see [`UArch::predecessor`].
+    pub const fn predecessor(&self) -> Option<UArch> {
+        self.uarch.predecessor()
+    }
+
+    /// The next generation, see [`UArch::successor`].
+    pub const fn successor(&self) -> Option<UArch> {
+        self.uarch.successor()
+    }
+
+    /// Instruction set extensions of the generation, see [`UArch::expected_features`].
+    pub const fn expected_features(&self) -> CpuCapabilities {
+        self.uarch.expected_features()
+    }
+
+    /// Typical caches, see [`UArch::cache_hints`].
+    pub const fn cache_hints(&self) -> Option<CacheHints> {
+        self.uarch.cache_hints()
+    }
+
+    /// Does the processor combine two different core designs?
+    pub const fn is_hybrid(&self) -> bool {
+        self.efficiency_core().is_some()
+    }
+}
+
+impl Display for MicroArchitecture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} ({}", self.vendor, self.uarch, self.core())?;
+        if let Some(efficiency_core) = self.efficiency_core() {
+            write!(f, " + {}", efficiency_core)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// The models of a family that belong to an entry.
+enum Models {
+    /// Individual model numbers.
+    List(&'static [u8]),
+    /// An inclusive range of model numbers.
+    Range(u8, u8),
+    /// A single model number limited to an inclusive range of steppings. These entries
+    /// need to come before the entry covering the remaining steppings.
+    Steppings(u8, u8, u8),
+}
+
+impl Models {
+    const fn contains(&self, model: u8, stepping: u8) -> bool {
+        match *self {
+            Models::List(models) => {
+                let mut i = 0;
+                while i < models.len() {
+                    if models[i] == model {
+                        return true;
+                    }
+                    i += 1;
+                }
+                false
+            }
+            Models::Range(first, last) => first <= model && model <= last,
+            Models::Steppings(m, first, last) => {
+                m == model && first <= stepping && stepping <= last
+            }
+        }
+    }
+}
+
+/// Family and models that belong to a processor generation.
+struct ModelEntry {
+    family: u8,
+    models: Models,
+    uarch: UArch,
+}
+
+/// Intel family/model signatures (Intel SDM Vol. 4, Table 2-1 and the Linux
+/// `intel-family.h` list).
+const INTEL_MODELS: &[ModelEntry] = &[
+    ModelEntry {
+        family: 0x5,
+        models: Models::List(&[0x01, 0x02, 0x03, 0x04, 0x07, 0x08]),
+        uarch: UArch::P5,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x01, 0x03, 0x05, 0x06, 0x07, 0x08, 0x0A, 0x0B]),
+        uarch: UArch::P6,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x09, 0x0D, 0x15]),
+        uarch: UArch::PentiumM,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x0E]),
+        uarch: UArch::Yonah,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x0F, 0x16]),
+        uarch: UArch::Merom,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x17, 0x1D]),
+        uarch: UArch::Penryn,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x1A, 0x1E, 0x1F, 0x2E]),
+        uarch: UArch::Nehalem,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x25, 0x2C, 0x2F]),
+        uarch: UArch::Westmere,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x2A]),
+        uarch: UArch::SandyBridge,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x2D]),
+        uarch: UArch::SandyBridgeE,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x3A]),
+        uarch: UArch::IvyBridge,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x3E]),
+        uarch: UArch::IvyBridgeE,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x3C, 0x45, 0x46]),
+        uarch: UArch::Haswell,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x3F]),
+        uarch: UArch::HaswellE,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x3D, 0x47]),
+        uarch: UArch::Broadwell,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x4F, 0x56]),
+        uarch: UArch::BroadwellE,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x4E, 0x5E]),
+        uarch: UArch::SkylakeClient,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::Steppings(0x55, 0x5, 0x7),
+        uarch: UArch::CascadeLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::Steppings(0x55, 0xA, 0xB),
+        uarch: UArch::CooperLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x55]),
+        uarch: UArch::SkylakeServer,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::Steppings(0x8E, 0xA, 0xA),
+        uarch: UArch::CoffeeLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::Steppings(0x8E, 0xB, 0xB),
+        uarch: UArch::WhiskeyLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::Steppings(0x8E, 0xC, 0xC),
+        uarch: UArch::CometLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::Steppings(0x9E, 0xA, 0xD),
+        uarch: UArch::CoffeeLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x8E, 0x9E]),
+        uarch: UArch::KabyLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x66]),
+        uarch: UArch::CannonLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xA5, 0xA6]),
+        uarch: UArch::CometLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x7D, 0x7E]),
+        uarch: UArch::IceLakeClient,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x6A, 0x6C]),
+        uarch: UArch::IceLakeServer,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x8C, 0x8D]),
+        uarch: UArch::TigerLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xA7]),
+        uarch: UArch::RocketLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x8A]),
+        uarch: UArch::Lakefield,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x97, 0x9A]),
+        uarch: UArch::AlderLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xB7, 0xBA, 0xBF]),
+        uarch: UArch::RaptorLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x8F]),
+        uarch: UArch::SapphireRapids,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xCF]),
+        uarch: UArch::EmeraldRapids,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xAD, 0xAE]),
+        uarch: UArch::GraniteRapids,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xAA, 0xAC]),
+        uarch: UArch::MeteorLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xB5, 0xC5, 0xC6]),
+        uarch: UArch::ArrowLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xBD]),
+        uarch: UArch::LunarLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x1C, 0x26]),
+        uarch: UArch::Bonnell,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x27, 0x35, 0x36]),
+        uarch: UArch::Saltwell,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x37]),
+        uarch: UArch::BayTrail,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x4D]),
+        uarch: UArch::Avoton,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x4A, 0x5A, 0x5D]),
+        uarch: UArch::Merrifield,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x4C]),
+        uarch: UArch::CherryTrail,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x5C]),
+        uarch: UArch::ApolloLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x5F]),
+        uarch: UArch::Denverton,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x7A]),
+        uarch: UArch::GeminiLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x86]),
+        uarch: UArch::SnowRidge,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x96]),
+        uarch: UArch::ElkhartLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x9C]),
+        uarch: UArch::JasperLake,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xBE]),
+        uarch: UArch::AlderLakeN,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xAF]),
+        uarch: UArch::SierraForest,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0xB6]),
+        uarch: UArch::GrandRidge,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x57]),
+        uarch: UArch::KnightsLanding,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x85]),
+        uarch: UArch::KnightsMill,
+    },
+    ModelEntry {
+        family: 0x5,
+        models: Models::List(&[0x09, 0x0A]),
+        uarch: UArch::Quark,
+    },
+    ModelEntry {
+        family: 0xB,
+        models: Models::List(&[0x01]),
+        uarch: UArch::KnightsCorner,
+    },
+    ModelEntry {
+        family: 0xF,
+        models: Models::List(&[0x00, 0x01, 0x02, 0x03, 0x04, 0x06]),
+        uarch: UArch::NetBurst,
+    },
+];
+
+/// AMD family/model signatures (AMD revision guides and the Linux `amd_nb.c` /
+/// `x86/kernel/cpu/amd.c` model ranges).
+const AMD_MODELS: &[ModelEntry] = &[
+    ModelEntry {
+        family: 0xF,
+        models: Models::Range(0x00, 0xFF),
+        uarch: UArch::K8,
+    },
+    ModelEntry {
+        family: 0x10,
+        models: Models::Range(0x00, 0xFF),
+        uarch: UArch::K10,
+    },
+    ModelEntry {
+        family: 0x11,
+        models: Models::Range(0x00, 0xFF),
+        uarch: UArch::Griffin,
+    },
+    ModelEntry {
+        family: 0x12,
+        models: Models::Range(0x00, 0xFF),
+        uarch: UArch::Llano,
+    },
+    ModelEntry {
+        family: 0x14,
+        models: Models::Range(0x00, 0xFF),
+        uarch: UArch::Bobcat,
+    },
+    ModelEntry {
+        family: 0x15,
+        models: Models::Range(0x00, 0x01),
+        uarch: UArch::Bulldozer,
+    },
+    ModelEntry {
+        family: 0x15,
+        models: Models::Range(0x02, 0x02),
+        uarch: UArch::Piledriver,
+    },
+    ModelEntry {
+        family: 0x15,
+        models: Models::Range(0x10, 0x1F),
+        uarch: UArch::Piledriver,
+    },
+    ModelEntry {
+        family: 0x15,
+        models: Models::Range(0x30, 0x3F),
+        uarch: UArch::Steamroller,
+    },
+    ModelEntry {
+        family: 0x15,
+        models: Models::Range(0x60, 0x7F),
+        uarch: UArch::Excavator,
+    },
+    ModelEntry {
+        family: 0x16,
+        models: Models::Range(0x00, 0x0F),
+        uarch: UArch::Jaguar,
+    },
+    ModelEntry {
+        family: 0x16,
+        models: Models::Range(0x30, 0x3F),
+        uarch: UArch::Puma,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0x01, 0x01),
+        uarch: UArch::Zeppelin,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0x08, 0x08),
+        uarch: UArch::PinnacleRidge,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0x11, 0x11),
+        uarch: UArch::RavenRidge,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0x18, 0x18),
+        uarch: UArch::Picasso,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0x20, 0x20),
+        uarch: UArch::Dali,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0x30, 0x3F),
+        uarch: UArch::Rome,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0x60, 0x67),
+        uarch: UArch::Renoir,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0x68, 0x6F),
+        uarch: UArch::Lucienne,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0x70, 0x7F),
+        uarch: UArch::Matisse,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0x90, 0x9F),
+        uarch: UArch::VanGogh,
+    },
+    ModelEntry {
+        family: 0x17,
+        models: Models::Range(0xA0, 0xAF),
+        uarch: UArch::Mendocino,
+    },
+    ModelEntry {
+        family: 0x19,
+        models: Models::Range(0x00, 0x0F),
+        uarch: UArch::Milan,
+    },
+    ModelEntry {
+        family: 0x19,
+        models: Models::Range(0x10, 0x1F),
+        uarch: UArch::Genoa,
+    },
+    ModelEntry {
+        family: 0x19,
+        models: Models::Range(0x20, 0x2F),
+        uarch: UArch::Vermeer,
+    },
+    ModelEntry {
+        family: 0x19,
+        models: Models::Range(0x40, 0x4F),
+        uarch: UArch::Rembrandt,
+    },
+    ModelEntry {
+        family: 0x19,
+        models: Models::Range(0x50, 0x5F),
+        uarch: UArch::Cezanne,
+    },
+    ModelEntry {
+        family: 0x19,
+        models: Models::Range(0x60, 0x6F),
+        uarch: UArch::Raphael,
+    },
+    ModelEntry {
+        family: 0x19,
+        models: Models::Range(0x70, 0x7F),
+        uarch: UArch::Phoenix,
+    },
+    ModelEntry {
+        family: 0x19,
+        models: Models::Range(0xA0, 0xAF),
+        uarch: UArch::Bergamo,
+    },
+    ModelEntry {
+        family: 0x1A,
+        models: Models::Range(0x00, 0x1F),
+        uarch: UArch::Turin,
+    },
+    ModelEntry {
+        family: 0x1A,
+        models: Models::Range(0x20, 0x2F),
+        uarch: UArch::StrixPoint,
+    },
+    ModelEntry {
+        family: 0x1A,
+        models: Models::Range(0x40, 0x4F),
+        uarch: UArch::GraniteRidge,
+    },
+    ModelEntry {
+        family: 0x1A,
+        models: Models::Range(0x60, 0x6F),
+        uarch: UArch::KrackanPoint,
+    },
+    ModelEntry {
+        family: 0x1A,
+        models: Models::Range(0x70, 0x7F),
+        uarch: UArch::StrixHalo,
+    },
+];
+
+/// VIA/Centaur family/model signatures. The first Zhaoxin parts still report the
+/// Centaur vendor string.
+const CENTAUR_MODELS: &[ModelEntry] = &[
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x0A, 0x0D]),
+        uarch: UArch::Esther,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x0F]),
+        uarch: UArch::Isaiah,
+    },
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x19]),
+        uarch: UArch::ZhangJiang,
+    },
+];
+
+/// Zhaoxin family/model signatures.
+const ZHAOXIN_MODELS: &[ModelEntry] = &[
+    ModelEntry {
+        family: 0x6,
+        models: Models::List(&[0x19]),
+        uarch: UArch::ZhangJiang,
+    },
+    ModelEntry {
+        family: 0x7,
+        models: Models::List(&[0x1B]),
+        uarch: UArch::WuDaoKou,
+    },
+    ModelEntry {
+        family: 0x7,
+        models: Models::List(&[0x3B]),
+        uarch: UArch::LuJiaZui,
+    },
+    ModelEntry {
+        family: 0x7,
+        models: Models::List(&[0x5B]),
+        uarch: UArch::Yongfeng,
+    },
+];
+
+/// Hygon family/model signatures. Family 18h is Hygon's variant of AMD family 17h.
+const HYGON_MODELS: &[ModelEntry] = &[ModelEntry {
+    family: 0x18,
+    models: Models::Range(0x00, 0x0F),
+    uarch: UArch::Dhyana,
+}];
+
+const fn models_of(vendor: CpuVendor) -> &'static [ModelEntry] {
+    match vendor {
+        CpuVendor::Intel => INTEL_MODELS,
+        CpuVendor::Amd => AMD_MODELS,
+        CpuVendor::Centaur => CENTAUR_MODELS,
+        CpuVendor::Zhaoxin => ZHAOXIN_MODELS,
+        CpuVendor::Hygon => HYGON_MODELS,
+    }
+}
+
+const fn lookup(table: &[ModelEntry], family: u8, model: u8, stepping: u8) -> Option<UArch> {
+    // Not using iterators to stay usable in const contexts
+    let mut i = 0;
+    while i < table.len() {
+        let entry = &table[i];
+        if entry.family == family && entry.models.contains(model, stepping) {
+            return Some(entry.uarch);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Identify the micro-architecture from the vendor and the display family, model and
+/// stepping.
+///
+/// The stepping is only needed to tell apart generations that share a model number
+/// (e.g., Skylake-SP, Cascade Lake and Cooper Lake are all model 0x55).
+///
+/// Returns `None` if the family/model combination is not known.
+///
+/// This is a `const fn` and neither allocates nor formats, so it can be used early
+/// during boot.
+pub const fn identify_micro_architecture(
+    vendor: CpuVendor,
+    family: u8,
+    model: u8,
+    stepping: u8,
+) -> Option<MicroArchitecture> {
+    match lookup(models_of(vendor), family, model, stepping) {
+        Some(uarch) => Some(MicroArchitecture {
+            vendor,
+            uarch,
+            confidence: Confidence::High,
+        }),
+        None => None,
+    }
+}
+
+/// Identify the micro-architecture from the raw results of leaf 0x00 and leaf 0x01.
+///
+/// Like [`identify_micro_architecture`] this is a `const fn`. It decodes the vendor and
+/// the display family, model and stepping itself, so no [`CpuId`](crate::CpuId) is needed.
+pub const fn identify_from_leafs(
+    vendor_leaf: CpuIdResult,
+    feature_leaf: CpuIdResult,
+) -> Option<MicroArchitecture> {
+    let vendor = match CpuVendor::from_vendor_leaf(vendor_leaf) {
+        Some(vendor) => vendor,
+        None => return None,
+    };
+
+    let eax = feature_leaf.eax;
+    let stepping = (eax & 0xf) as u8;
+    let base_model = ((eax >> 4) & 0xf) as u8;
+    let base_family = ((eax >> 8) & 0xf) as u8;
+    let extended_model = ((eax >> 16) & 0xf) as u8;
+    let extended_family = ((eax >> 20) & 0xff) as u8;
+
+    // Same rules as FeatureInfo::family_id and FeatureInfo::model_id
+    let (use_extended_family, use_extended_model) = match vendor {
+        CpuVendor::Intel => (base_family == 0xf, base_family == 0x6 || base_family == 0xf),
+        CpuVendor::Amd | CpuVendor::Hygon => (base_family == 0xf, base_family == 0xf),
+        CpuVendor::Centaur | CpuVendor::Zhaoxin => {
+            (base_family == 0xf, matches!(base_family, 0x6 | 0x7 | 0xf))
+        }
+    };
+    let family = if use_extended_family {
+        base_family.saturating_add(extended_family)
+    } else {
+        base_family
+    };
+    let model = if use_extended_model {
+        (extended_model << 4) | base_model
+    } else {
+        base_model
+    };
+
+    identify_micro_architecture(vendor, family, model, stepping)
+}
+
+/// Words of a brand string without trademark markers, e.g., "Intel(R) Core(TM) i7-12700K"
+/// becomes "Intel", "Core", "i7-12700K".
+fn brand_words(brand: &str) -> impl Iterator<Item = &str> + Clone {
+    brand.split_whitespace().map(|word| {
+        ["(R)", "(TM)", "(r)", "(tm)"]
+            .iter()
+            .fold(word, |word, mark| word.trim_end_matches(mark))
+    })
+}
+
+/// Split a model number like "12700K" into the number, its number of digits and the
+/// suffix.
+fn model_number(word: &str) -> Option<(u32, usize, &str)> {
+    let digits = word.bytes().take_while(u8::is_ascii_digit).count();
+    let number = word[..digits].parse().ok()?;
+    Some((number, digits, &word[digits..]))
+}
+
+/// Intel Core i3/i5/i7/i9, e.g., "i7-12700K" or "i5-1135G7".
+fn intel_core(number: u32, digits: usize, suffix: &str) -> Option<UArch> {
+    if digits != 4 && digits != 5 {
+        return None;
+    }
+    // Low power mobile parts from the 10th generation on have 4 digits, e.g., "1165G7"
+    let generation = if digits == 4 && number / 1000 == 1 {
+        number / 100
+    } else {
+        number / 1000
+    };
+    // Mobile parts with integrated Iris graphics ("G7") were built on different dies
+    let graphics = suffix.starts_with('G') && suffix.len() == 2;
+    Some(match generation {
+        2 => UArch::SandyBridge,
+        3 => UArch::IvyBridge,
+        4 => UArch::Haswell,
+        5 => UArch::Broadwell,
+        6 => UArch::SkylakeClient,
+        7 => UArch::KabyLake,
+        // 8th generation mobile parts, e.g., "8550U", are Kaby Lake R
+        8 if digits == 4 && (suffix.starts_with('U') || suffix.starts_with('Y')) => UArch::KabyLake,
+        8 | 9 => UArch::CoffeeLake,
+        10 if graphics => UArch::IceLakeClient,
+        10 => UArch::CometLake,
+        11 if graphics || suffix.starts_with('H') => UArch::TigerLake,
+        11 => UArch::RocketLake,
+        12 => UArch::AlderLake,
+        13 | 14 => UArch::RaptorLake,
+        _ => return None,
+    })
+}
+
+fn intel_brand(brand: &str) -> Option<UArch> {
+    let mut words = brand_words(brand);
+    while let Some(word) = words.next() {
+        match word {
+            // "Core(TM) Ultra 7 155H", "Core Ultra 5 226V"
+            "Ultra" => {
+                let (number, digits, suffix) = words.nth(1).and_then(model_number)?;
+                return match (number / 100, digits) {
+                    (1, 3) => Some(UArch::MeteorLake),
+                    (2, 3) if suffix.starts_with('V') => Some(UArch::LunarLake),
+                    (2, 3) => Some(UArch::ArrowLake),
+                    _ => None,
+                };
+            }
+            // "Xeon(R) Platinum 8480+", "Xeon(R) CPU E5-2680 v4", "Xeon(R) 6980P"
+            "Xeon" => {
+                let mut version = None;
+                for word in words.clone() {
+                    if let Some(v) = word.strip_prefix('v').and_then(|v| v.parse::<u8>().ok()) {
+                        version = Some(v);
+                    }
+                }
+                // Xeon Scalable has a metal tier, Xeon 6 does not
+                let mut tier = false;
+                for word in words.by_ref() {
+                    match word {
+                        "Phi" => return Some(UArch::KnightsLanding),
+                        "Platinum" | "Gold" | "Silver" | "Bronze" => tier = true,
+                        _ => {}
+                    }
+                    if word.starts_with("E5-") || word.starts_with("E7-") {
+                        return Some(match version {
+                            // Without a version E5 is Sandy Bridge-EP, E7 Westmere-EX
+                            None if word.starts_with("E7-") => return None,
+                            None => UArch::SandyBridgeE,
+                            Some(2) => UArch::IvyBridgeE,
+                            Some(3) => UArch::HaswellE,
+                            Some(4) => UArch::BroadwellE,
+                            Some(_) => return None,
+                        });
+                    }
+                    if let Some((number, 4, suffix)) = model_number(word) {
+                        if !tier {
+                            return match number / 1000 {
+                                6 if suffix.starts_with('E') => Some(UArch::SierraForest),
+                                6 => Some(UArch::GraniteRapids),
+                                _ => None,
+                            };
+                        }
+                        return match number / 100 % 10 {
+                            1 => Some(UArch::SkylakeServer),
+                            2 => Some(UArch::CascadeLake),
+                            3 if suffix.starts_with('H') => Some(UArch::CooperLake),
+                            3 => Some(UArch::IceLakeServer),
+                            4 => Some(UArch::SapphireRapids),
+                            5 => Some(UArch::EmeraldRapids),
+                            _ => None,
+                        };
+                    }
+                }
+                return None;
+            }
+            "Quark" => return Some(UArch::Quark),
+            _ => {
+                let core = ["i3-", "i5-", "i7-", "i9-"]
+                    .iter()
+                    .find_map(|prefix| word.strip_prefix(prefix));
+                if let Some((number, digits, suffix)) = core.and_then(model_number) {
+                    return intel_core(number, digits, suffix);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// AMD Ryzen, e.g., "Ryzen 9 7950X" or "Ryzen 7 7840U".
+fn amd_ryzen(number: u32, suffix: &str) -> Option<UArch> {
+    // APUs carry a G suffix on the desktop and U/H/HS/HX on mobile
+    let apu = suffix.starts_with('G') || suffix.starts_with('U') || suffix.starts_with('H');
+    Some(match number / 1000 {
+        1 => UArch::Zeppelin,
+        2 if apu => UArch::RavenRidge,
+        2 => UArch::PinnacleRidge,
+        3 if apu => UArch::Picasso,
+        3 => UArch::Matisse,
+        4 => UArch::Renoir,
+        5 if apu => UArch::Cezanne,
+        5 => UArch::Vermeer,
+        6 => UArch::Rembrandt,
+        // Dragon Range mobile parts use the desktop die
+        7 if suffix.starts_with("HX") => UArch::Raphael,
+        // Since the 7000 series, mobile parts encode the architecture in the third digit
+        7 if apu => match (number / 10 % 10, number % 10) {
+            (2, _) => UArch::Mendocino,
+            (3, 5) => UArch::Rembrandt,
+            (3, _) => UArch::Cezanne,
+            (4, _) => UArch::Phoenix,
+            _ => return None,
+        },
+        7 => UArch::Raphael,
+        8 => UArch::Phoenix,
+        9 => UArch::GraniteRidge,
+        _ => return None,
+    })
+}
+
+fn amd_brand(brand: &str) -> Option<UArch> {
+    let mut words = brand_words(brand);
+    while let Some(word) = words.next() {
+        match word {
+            // "Ryzen AI 9 HX 370", "Ryzen AI Max+ 395"
+            "Ryzen" | "RYZEN" => {
+                let mut rest = words.clone();
+                if rest.next() == Some("AI") {
+                    if rest.any(|word| word.starts_with("Max")) {
+                        return Some(UArch::StrixHalo);
+                    }
+                    let number = words.find_map(|word| match model_number(word) {
+                        Some((number, 3, _)) => Some(number),
+                        _ => None,
+                    })?;
+                    return if number < 360 {
+                        Some(UArch::KrackanPoint)
+                    } else {
+                        Some(UArch::StrixPoint)
+                    };
+                }
+                let (number, suffix) = words.find_map(|word| match model_number(word) {
+                    Some((number, 4, suffix)) => Some((number, suffix)),
+                    _ => None,
+                })?;
+                return amd_ryzen(number, suffix);
+            }
+            // "EPYC 7763 64-Core Processor", "EPYC 9754 128-Core Processor"
+            "EPYC" => {
+                let (number, _, _) = words.next().and_then(model_number)?;
+                return match (number / 1000, number % 10) {
+                    (_, 1) => Some(UArch::Zeppelin),
+                    (_, 2) => Some(UArch::Rome),
+                    (_, 3) => Some(UArch::Milan),
+                    (4, 4) => Some(UArch::Raphael),
+                    (9, 4) if number / 100 % 10 == 7 => Some(UArch::Bergamo),
+                    (_, 4) => Some(UArch::Genoa),
+                    (_, 5) => Some(UArch::Turin),
+                    _ => None,
+                };
+            }
+            // "Threadripper 3990X 64-Core Processor"
+            "Threadripper" => {
+                let (number, _, _) = words.find_map(model_number)?;
+                return match number / 1000 {
+                    1 => Some(UArch::Zeppelin),
+                    2 => Some(UArch::PinnacleRidge),
+                    3 => Some(UArch::Rome),
+                    5 => Some(UArch::Milan),
+                    7 => Some(UArch::Genoa),
+                    9 => Some(UArch::Turin),
+                    _ => None,
+                };
+            }
+            // "FX(tm)-8350 Eight-Core Processor"
+            _ if word.starts_with("FX") => {
+                let number = word.trim_start_matches(|c: char| !c.is_ascii_digit());
+                let (number, _, _) = model_number(number)?;
+                return match number / 100 % 10 {
+                    1 => Some(UArch::Bulldozer),
+                    3 | 5 => Some(UArch::Piledriver),
+                    _ => None,
+                };
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Guess the micro-architecture from the processor brand string (as returned by
+/// [`CpuId::get_processor_brand_string`](crate::CpuId::get_processor_brand_string)).
+///
+/// This understands the common Intel Core and Xeon as well as AMD Ryzen, EPYC,
+/// Threadripper and FX naming schemes. The result is marked with [`Confidence::Low`]
+/// since the name does not always determine the die (e.g., some Ryzen 5000 and 7000
+/// mobile parts are rebadged older generations) and the string can be set by firmware.
+pub fn identify_from_brand_string(vendor: CpuVendor, brand: &str) -> Option<MicroArchitecture> {
+    let uarch = match vendor {
+        CpuVendor::Intel => intel_brand(brand),
+        CpuVendor::Amd => amd_brand(brand),
+        _ => None,
+    }?;
+
+    Some(MicroArchitecture {
+        vendor,
+        uarch,
+        confidence: Confidence::Low,
+    })
+}
+
+/// Is this one of the generic family/model/stepping combinations hypervisors report
+/// instead of the host's (QEMU `qemu32`, `qemu64`, `kvm32` and `kvm64`)?
+///
+/// Some of them collide with real processors (e.g., `kvm64` looks like a 65nm Pentium 4),
+/// so only treat them as synthetic if the hypervisor bit is set.
+pub const fn is_synthetic_signature(
+    vendor: CpuVendor,
+    family: u8,
+    model: u8,
+    stepping: u8,
+) -> bool {
+    matches!(
+        (vendor, family, model, stepping),
+        (CpuVendor::Amd, 0xf, 0x6b, 0x1)
+            | (CpuVendor::Intel, 0xf, 0x6, 0x1)
+            | (CpuVendor::Intel, 0x6, 0x6, 0x3)
+    )
+}
+
+/// Best-effort identification of the host inside a virtual machine whose family/model is
+/// masked or not known.
+///
+/// The brand string is tried first (hypervisors often pass it through). Otherwise this
+/// picks the generation of `vendor` with the most of its [`UArch::expected_features`]
+/// present in `capabilities`, preferring the earliest one on ties. As hypervisors can hide
+/// features this is a lower bound. The result is marked with [`Confidence::Virtualized`].
+pub fn identify_virtualized(
+    vendor: CpuVendor,
+    brand: Option<&str>,
+    capabilities: &CpuCapabilities,
+) -> Option<MicroArchitecture> {
+    let from_brand = match vendor {
+        CpuVendor::Intel => brand.and_then(intel_brand),
+        CpuVendor::Amd => brand.and_then(amd_brand),
+        _ => None,
+    };
+
+    let uarch = from_brand.or_else(|| {
+        let mut best: Option<(usize, UArch)> = None;
+        for entry in models_of(vendor) {
+            let expected = entry.uarch.expected_features();
+            if !expected.difference(capabilities).is_empty() {
+                continue;
+            }
+            let score = expected.iter_names().count();
+            let better = best.is_none_or(|(best_score, best_uarch)| {
+                score > best_score
+                    || (score == best_score && entry.uarch.launch_year() < best_uarch.launch_year())
+            });
+            if better {
+                best = Some((score, entry.uarch));
+            }
+        }
+        best.map(|(_, uarch)| uarch)
+    })?;
+
+    Some(MicroArchitecture {
+        vendor,
+        uarch,
+        confidence: Confidence::Virtualized,
+    })
+}
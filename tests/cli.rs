@@ -0,0 +1,131 @@
+//! Golden output tests for the `cpuid` binary.
+//!
+//! The binary decodes a fixed dump (`--file`, or JSON on stdin) and the output is compared with the files
+//! in `tests/golden`. After an intended change of the output, regenerate them with
+//! `UPDATE_GOLDEN=1 cargo test --features cli --test cli`.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn data(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/data")
+        .join(name)
+}
+
+/// Run the binary in `tests/data` with `args` and `stdin` as standard input.
+fn cpuid(args: &[&str], stdin: Option<PathBuf>) -> Output {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_cpuid"));
+    cmd.args(args)
+        .current_dir(data(""))
+        // The table layout depends on the terminal width
+        .env("COLUMNS", "120");
+    if let Some(stdin) = stdin {
+        cmd.stdin(File::open(stdin).expect("Can't open input"));
+    }
+    cmd.output().expect("Can't run cpuid")
+}
+
+fn check_golden(format: &str, output: Output) {
+    assert!(output.status.success(), "{:?}", output);
+    check_stdout(format, output);
+}
+
+/// Compare the standard output with `tests/golden/emr_kvm_guest.<format>.txt`.
+fn check_stdout(format: &str, output: Output) {
+    let actual = String::from_utf8(output.stdout).expect("Output is UTF-8");
+
+    let golden = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(format!("tests/golden/emr_kvm_guest.{}.txt", format));
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&golden, &actual).expect("Can't write golden file");
+        return;
+    }
+    let expected = std::fs::read_to_string(&golden).expect("Can't read golden file");
+    assert!(
+        actual == expected,
+        "Output differs from {}, run with UPDATE_GOLDEN=1 to update it:\n{}",
+        golden.display(),
+        actual
+    );
+}
+
+fn check_file(format: &str) {
+    let file = data("emr_kvm_guest.txt");
+    let output = cpuid(
+        &["--file", file.to_str().unwrap(), "--format", format],
+        None,
+    );
+    check_golden(format, output);
+}
+
+#[test]
+fn raw() {
+    check_file("raw");
+}
+
+#[test]
+fn cli() {
+    check_file("cli");
+}
+
+#[test]
+fn env() {
+    check_file("env");
+}
+
+#[test]
+fn jsonl() {
+    check_file("jsonl");
+}
+
+#[test]
+fn codegen() {
+    let output = cpuid(&["--file", "emr_kvm_guest.txt", "codegen"], None);
+    check_golden("codegen", output);
+}
+
+#[test]
+fn stdin_raw() {
+    let output = cpuid(&["--file", "-"], Some(data("emr_kvm_guest.txt")));
+    check_golden("cli", output);
+}
+
+#[test]
+fn stdin_json() {
+    let output = cpuid(
+        &["--stdin", "--format", "raw"],
+        Some(data("emr_kvm_guest.json")),
+    );
+    check_golden("raw", output);
+}
+
+#[test]
+fn invalid_file() {
+    let output = cpuid(&["--file", "../cli.rs"], None);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("line 1"));
+}
+
+#[test]
+fn compare_cpus() {
+    let output = cpuid(
+        &[
+            "compare-cpus",
+            "emr_kvm_guest.txt",
+            "emr_kvm_guest_cpu1.txt",
+        ],
+        None,
+    );
+    assert_eq!(output.status.code(), Some(2), "{:?}", output);
+    check_stdout("compare", output);
+
+    // APIC IDs are expected to differ
+    let output = cpuid(
+        &["compare-cpus", "emr_kvm_guest.txt", "emr_kvm_guest.json"],
+        None,
+    );
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("All 2 CPUs"));
+}
@@ -0,0 +1,148 @@
+//! Checks that decoding never touches the heap.
+//!
+//! A global allocator that fails every allocation while armed is installed, and every
+//! accessor of the library is called (the `Debug` implementation of `CpuId` calls all
+//! `get_*` functions and walks their iterators). Any allocation aborts the test. Run it
+//! with `cargo test --features heap-audit --test no_heap`, add `alloc` to check that the
+//! convenience functions didn't creep into the core paths.
+//!
+//! This doesn't use the default test harness, which allocates on other threads while a
+//! test runs.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use raw_cpuid::{CachedCpuIdReader, CpuId, CpuIdReader, CpuIdResult};
+
+/// Forwards to the system allocator, unless `ARMED` is set.
+struct FailingAllocator;
+
+static ARMED: AtomicBool = AtomicBool::new(false);
+
+unsafe impl GlobalAlloc for FailingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if ARMED.load(Ordering::SeqCst) {
+            // Makes the runtime abort with "memory allocation of N bytes failed"
+            return std::ptr::null_mut();
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: FailingAllocator = FailingAllocator;
+
+/// Counts the formatted bytes instead of storing them.
+struct Sink(usize);
+
+impl Write for Sink {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Run `f` with the allocator armed.
+fn without_heap<T>(f: impl FnOnce() -> T) -> T {
+    ARMED.store(true, Ordering::SeqCst);
+    let res = f();
+    ARMED.store(false, Ordering::SeqCst);
+    res
+}
+
+/// Call every accessor, returns the length of the debug output.
+fn decode<R: CpuIdReader>(cpuid: &CpuId<R>) -> usize {
+    let mut sink = Sink(0);
+    write!(sink, "{:?}", cpuid).unwrap();
+    write!(
+        sink,
+        "{:?}{:?}{:?}{:?}{:?}{:?}{:?}{:?}",
+        cpuid.tsx_info(),
+        cpuid.cache_line_size(),
+        cpuid.paging_info(),
+        cpuid.frequency(),
+        cpuid.sev_status(),
+        cpuid.apic_id(),
+        cpuid.x86_64_abi_level(),
+        cpuid.get_cpu_capabilities(),
+    )
+    .unwrap();
+    for feature in cpuid.x86_64_abi_missing_features() {
+        sink.write_str(feature).unwrap();
+    }
+    write!(
+        sink,
+        "{}{}",
+        cpuid.vendor_consistent(),
+        cpuid.logical_processors_per_package()
+    )
+    .unwrap();
+    if let Some(brand) = cpuid.get_processor_brand_string() {
+        sink.write_str(brand.as_str()).unwrap();
+    }
+    #[cfg(feature = "uarch-db")]
+    write!(sink, "{:?}", cpuid.get_microarchitecture()).unwrap();
+    #[cfg(feature = "feature-db")]
+    write!(sink, "{:?}", raw_cpuid::feature_table::find("avx2")).unwrap();
+    write!(sink, "{:?}", raw_cpuid::defined_bits::find(0x7, 0x0)).unwrap();
+    sink.0
+}
+
+/// Parse a dump in the `raw` format of the cpuid binary (before arming the allocator).
+fn parse_raw(dump: &str) -> Vec<(u32, u32, CpuIdResult)> {
+    dump.lines()
+        .filter(|line| line.starts_with('('))
+        .map(|line| {
+            let numbers: Vec<u32> = line
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .filter_map(|word| word.strip_prefix("0x"))
+                .map(|hex| u32::from_str_radix(hex, 16).unwrap())
+                .collect();
+            let [leaf, subleaf, eax, ebx, ecx, edx] = numbers[..] else {
+                panic!("Can't parse {}", line);
+            };
+            (leaf, subleaf, CpuIdResult { eax, ebx, ecx, edx })
+        })
+        .collect()
+}
+
+fn main() {
+    let dump = parse_raw(include_str!("data/emr_kvm_guest.txt"));
+    let read = |eax: u32, ecx: u32| {
+        dump.iter()
+            .find(|&&(leaf, subleaf, _)| leaf == eax && subleaf == ecx)
+            .map_or(
+                CpuIdResult {
+                    eax: 0,
+                    ebx: 0,
+                    ecx: 0,
+                    edx: 0,
+                },
+                |&(_, _, res)| res,
+            )
+    };
+
+    let len = without_heap(|| decode(&CpuId::with_cpuid_fn(read)));
+    assert!(len > 0);
+    println!("dump: {} bytes of debug output without allocating", len);
+
+    let len = without_heap(|| {
+        let reader = CachedCpuIdReader::new(read);
+        decode(&CpuId::with_cpuid_reader(&reader))
+    });
+    println!(
+        "cached dump: {} bytes of debug output without allocating",
+        len
+    );
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        let len = without_heap(|| decode(&CpuId::new()));
+        println!("native: {} bytes of debug output without allocating", len);
+    }
+}